@@ -28,12 +28,18 @@
 //! Also handles SIGTERM/SIGINT signals by propagating them to the child.
 //!
 //! On Unix: Creates a new process group and uses it for signal propagation.
+//! On Linux the parent is watched through a pidfd, so its death is noticed immediately.
 //! On Windows: Uses taskkill with /T for tree termination.
 //!
+//! When `PROCESS_WRAPPER_STATUS_FILE` is set, a JSON status file is written there on exit
+//! with the cause of death, the exit code or signal and the child's resource usage.
+//!
 //! Signal Safety: Signal handlers only set atomic flags. All termination logic
 //! runs in the main thread to avoid async-signal-safety issues.
 
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::{exit, Child, Command};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
@@ -41,9 +47,50 @@ use std::time::Duration;
 
 const POLL_INTERVAL_MS: u64 = 200;
 const GRACEFUL_SHUTDOWN_SECS: u64 = 10;
+const PARENT_CHECK_INTERVAL: u64 = 10;
+const STATUS_FILE_ENV: &str = "PROCESS_WRAPPER_STATUS_FILE";
 
 static SHOULD_TERMINATE: AtomicBool = AtomicBool::new(false);
 
+/// Why the wrapper stopped, reported in the status file
+#[derive(Clone, Copy)]
+enum ExitCause {
+    /// The child exited on its own
+    Exited,
+    /// The child was killed by a signal it didn't get from the wrapper
+    Signaled,
+    /// The parent died and the wrapper terminated the child
+    ParentDied,
+    /// The wrapper was asked to stop and terminated the child
+    Terminated,
+}
+
+impl ExitCause {
+    fn as_str(self) -> &'static str {
+        match self {
+            ExitCause::Exited => "exited",
+            ExitCause::Signaled => "signaled",
+            ExitCause::ParentDied => "parent_died",
+            ExitCause::Terminated => "terminated",
+        }
+    }
+}
+
+#[derive(Default)]
+struct ResourceUsage {
+    user_cpu_ms: Option<u64>,
+    system_cpu_ms: Option<u64>,
+    max_rss_kb: Option<u64>,
+    read_bytes: Option<u64>,
+    write_bytes: Option<u64>,
+}
+
+struct ChildExit {
+    exit_code: Option<i32>,
+    signal: Option<i32>,
+    usage: ResourceUsage,
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
 
@@ -62,6 +109,7 @@ fn main() {
 
     let binary = &args[2];
     let binary_args = &args[3..];
+    let status_file = env::var_os(STATUS_FILE_ENV).map(PathBuf::from);
 
     let mut child = match spawn_child(binary, binary_args) {
         Ok(child) => child,
@@ -73,30 +121,41 @@ fn main() {
 
     setup_signal_handlers();
 
-    let mut parent_check_counter: u64 = 0;
-    const PARENT_CHECK_INTERVAL: u64 = 10;
+    let mut parent_watch = ParentWatch::new(parent_pid);
+    if !is_parent_alive(parent_pid) {
+        finish(
+            &status_file,
+            ExitCause::ParentDied,
+            terminate_child(&mut child),
+        );
+    }
 
     loop {
         if SHOULD_TERMINATE.load(Ordering::SeqCst) {
-            terminate_child(&mut child);
-            exit(0);
-        }
-
-        parent_check_counter += 1;
-        if parent_check_counter >= PARENT_CHECK_INTERVAL {
-            parent_check_counter = 0;
-            if !is_parent_alive(parent_pid) {
-                terminate_child(&mut child);
-                exit(0);
-            }
+            finish(
+                &status_file,
+                ExitCause::Terminated,
+                terminate_child(&mut child),
+            );
         }
 
-        match child.try_wait() {
-            Ok(Some(status)) => {
-                exit(status.code().unwrap_or(0));
+        match wait_child(&mut child, false) {
+            Ok(Some(child_exit)) => {
+                let cause = if child_exit.signal.is_some() {
+                    ExitCause::Signaled
+                } else {
+                    ExitCause::Exited
+                };
+                finish(&status_file, cause, child_exit);
             }
             Ok(None) => {
-                thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+                if parent_watch.wait_for_exit(Duration::from_millis(POLL_INTERVAL_MS)) {
+                    finish(
+                        &status_file,
+                        ExitCause::ParentDied,
+                        terminate_child(&mut child),
+                    );
+                }
             }
             Err(e) => {
                 eprintln!("Error waiting for child: {}", e);
@@ -107,6 +166,51 @@ fn main() {
     }
 }
 
+/// Writes the status file and exits, with `128 + signal` when the child was killed by a signal
+fn finish(status_file: &Option<PathBuf>, cause: ExitCause, child_exit: ChildExit) -> ! {
+    if let Some(path) = status_file {
+        if let Err(e) = write_status_file(path, cause, &child_exit) {
+            eprintln!("Failed to write status file {}: {}", path.display(), e);
+        }
+    }
+
+    match cause {
+        ExitCause::Exited => exit(child_exit.exit_code.unwrap_or(0)),
+        ExitCause::Signaled => exit(128 + child_exit.signal.unwrap_or(0)),
+        ExitCause::ParentDied | ExitCause::Terminated => exit(0),
+    }
+}
+
+fn write_status_file(
+    path: &Path,
+    cause: ExitCause,
+    child_exit: &ChildExit,
+) -> Result<(), std::io::Error> {
+    fn number<T: ToString>(value: Option<T>) -> String {
+        value.map_or_else(|| "null".to_string(), |v| v.to_string())
+    }
+
+    let usage = &child_exit.usage;
+    let json = format!(
+        "{{\"cause\":\"{}\",\"exit_code\":{},\"signal\":{},\"user_cpu_ms\":{},\"system_cpu_ms\":{},\"max_rss_kb\":{},\"read_bytes\":{},\"write_bytes\":{}}}\n",
+        cause.as_str(),
+        number(child_exit.exit_code),
+        number(child_exit.signal),
+        number(usage.user_cpu_ms),
+        number(usage.system_cpu_ms),
+        number(usage.max_rss_kb),
+        number(usage.read_bytes),
+        number(usage.write_bytes),
+    );
+
+    // Written next to the target and renamed, so readers never see a partial file
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+    fs::write(&tmp_path, json)?;
+    fs::rename(tmp_path, path)
+}
+
 #[cfg(unix)]
 fn spawn_child(binary: &str, args: &[String]) -> Result<Child, std::io::Error> {
     use std::os::unix::process::CommandExt;
@@ -114,6 +218,7 @@ fn spawn_child(binary: &str, args: &[String]) -> Result<Child, std::io::Error> {
     unsafe {
         Command::new(binary)
             .args(args)
+            .env_remove(STATUS_FILE_ENV)
             .pre_exec(|| {
                 libc::setpgid(0, 0);
                 Ok(())
@@ -124,7 +229,10 @@ fn spawn_child(binary: &str, args: &[String]) -> Result<Child, std::io::Error> {
 
 #[cfg(windows)]
 fn spawn_child(binary: &str, args: &[String]) -> Result<Child, std::io::Error> {
-    Command::new(binary).args(args).spawn()
+    Command::new(binary)
+        .args(args)
+        .env_remove(STATUS_FILE_ENV)
+        .spawn()
 }
 
 #[cfg(unix)]
@@ -155,8 +263,122 @@ fn is_parent_alive(pid: u32) -> bool {
     unsafe { libc::kill(pid.cast_signed(), 0) == 0 }
 }
 
+/// Watches the parent process, through a pidfd on Linux and by polling elsewhere.
+///
+/// `PR_SET_PDEATHSIG` is not used because it fires when the parent *thread* that spawned
+/// the wrapper exits, which happens routinely with the app's async runtime.
+struct ParentWatch {
+    pid: u32,
+    #[cfg(target_os = "linux")]
+    pidfd: Option<libc::c_int>,
+    check_counter: u64,
+}
+
+impl ParentWatch {
+    fn new(pid: u32) -> Self {
+        Self {
+            pid,
+            #[cfg(target_os = "linux")]
+            pidfd: open_pidfd(pid),
+            check_counter: 0,
+        }
+    }
+
+    /// Waits up to `timeout`, returns true once the parent is gone
+    fn wait_for_exit(&mut self, timeout: Duration) -> bool {
+        #[cfg(target_os = "linux")]
+        if let Some(pidfd) = self.pidfd {
+            let mut poll_fd = libc::pollfd {
+                fd: pidfd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let timeout_ms = libc::c_int::try_from(timeout.as_millis()).unwrap_or(libc::c_int::MAX);
+            let ready = unsafe { libc::poll(&mut poll_fd, 1, timeout_ms) };
+            // Interrupted by a signal, the main loop handles it
+            return ready > 0 && poll_fd.revents & libc::POLLIN != 0;
+        }
+
+        thread::sleep(timeout);
+        self.check_counter += 1;
+        if self.check_counter >= PARENT_CHECK_INTERVAL {
+            self.check_counter = 0;
+            return !is_parent_alive(self.pid);
+        }
+        false
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn open_pidfd(pid: u32) -> Option<libc::c_int> {
+    // pidfd_open is available since Linux 5.3, older kernels fall back to polling
+    let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid.cast_signed(), 0) };
+    libc::c_int::try_from(fd).ok().filter(|fd| *fd >= 0)
+}
+
+/// Reaps the child when it has exited, collecting its resource usage
 #[cfg(unix)]
-fn terminate_child(child: &mut Child) {
+fn wait_child(child: &mut Child, block: bool) -> Result<Option<ChildExit>, std::io::Error> {
+    let pid = child.id().cast_signed();
+    let mut status: libc::c_int = 0;
+    let mut rusage: libc::rusage = unsafe { std::mem::zeroed() };
+    let flags = if block { 0 } else { libc::WNOHANG };
+
+    let result = unsafe { libc::wait4(pid, &mut status, flags, &mut rusage) };
+    if result < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    if result == 0 {
+        return Ok(None);
+    }
+
+    let (exit_code, signal) = if libc::WIFSIGNALED(status) {
+        (None, Some(libc::WTERMSIG(status)))
+    } else {
+        (Some(libc::WEXITSTATUS(status)), None)
+    };
+    Ok(Some(ChildExit {
+        exit_code,
+        signal,
+        usage: resource_usage(&rusage),
+    }))
+}
+
+#[cfg(unix)]
+fn resource_usage(rusage: &libc::rusage) -> ResourceUsage {
+    fn millis(time: libc::timeval) -> Option<u64> {
+        let secs = u64::try_from(time.tv_sec).ok()?;
+        let micros = u64::try_from(time.tv_usec).ok()?;
+        Some(secs * 1000 + micros / 1000)
+    }
+
+    let max_rss = u64::try_from(rusage.ru_maxrss).ok();
+    // Linux reports the peak RSS in kilobytes and block I/O in 512 byte units, macOS reports bytes
+    // for the RSS and only counts block operations
+    #[cfg(target_os = "linux")]
+    let (max_rss_kb, read_bytes, write_bytes) = (
+        max_rss,
+        u64::try_from(rusage.ru_inblock)
+            .ok()
+            .map(|blocks| blocks * 512),
+        u64::try_from(rusage.ru_oublock)
+            .ok()
+            .map(|blocks| blocks * 512),
+    );
+    #[cfg(not(target_os = "linux"))]
+    let (max_rss_kb, read_bytes, write_bytes) = (max_rss.map(|bytes| bytes / 1024), None, None);
+
+    ResourceUsage {
+        user_cpu_ms: millis(rusage.ru_utime),
+        system_cpu_ms: millis(rusage.ru_stime),
+        max_rss_kb,
+        read_bytes,
+        write_bytes,
+    }
+}
+
+#[cfg(unix)]
+fn terminate_child(child: &mut Child) -> ChildExit {
     let child_pid = child.id().cast_signed();
 
     unsafe {
@@ -165,9 +387,10 @@ fn terminate_child(child: &mut Child) {
 
     let deadline = std::time::Instant::now() + Duration::from_secs(GRACEFUL_SHUTDOWN_SECS);
     while std::time::Instant::now() < deadline {
-        match child.try_wait() {
-            Ok(Some(_)) => return,
-            _ => thread::sleep(Duration::from_millis(100)),
+        match wait_child(child, false) {
+            Ok(Some(child_exit)) => return child_exit,
+            Ok(None) => thread::sleep(Duration::from_millis(100)),
+            Err(_) => break,
         }
     }
 
@@ -175,7 +398,14 @@ fn terminate_child(child: &mut Child) {
         libc::kill(-child_pid, libc::SIGKILL);
     }
 
-    drop(child.wait());
+    match wait_child(child, true) {
+        Ok(Some(child_exit)) => child_exit,
+        _ => ChildExit {
+            exit_code: None,
+            signal: Some(libc::SIGKILL),
+            usage: ResourceUsage::default(),
+        },
+    }
 }
 
 #[cfg(windows)]
@@ -213,13 +443,35 @@ fn is_parent_alive(pid: u32) -> bool {
     }
 }
 
+/// Resource usage is not collected on Windows, only the exit code
 #[cfg(windows)]
-fn terminate_child(child: &mut Child) {
+fn wait_child(child: &mut Child, block: bool) -> Result<Option<ChildExit>, std::io::Error> {
+    let status = if block {
+        Some(child.wait()?)
+    } else {
+        child.try_wait()?
+    };
+    Ok(status.map(|status| ChildExit {
+        exit_code: status.code(),
+        signal: None,
+        usage: ResourceUsage::default(),
+    }))
+}
+
+#[cfg(windows)]
+fn terminate_child(child: &mut Child) -> ChildExit {
     let child_pid = child.id();
 
     let _ = Command::new("taskkill")
         .args(["/F", "/T", "/PID", &child_pid.to_string()])
         .output();
 
-    drop(child.wait());
+    match wait_child(child, true) {
+        Ok(Some(child_exit)) => child_exit,
+        _ => ChildExit {
+            exit_code: None,
+            signal: None,
+            usage: ResourceUsage::default(),
+        },
+    }
 }
@@ -21,6 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use anyhow::anyhow;
+use base64::prelude::*;
 use der::Encode;
 use der::asn1::{BitString, ObjectIdentifier};
 use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
@@ -35,7 +36,9 @@ use tauri::{AppHandle, Manager};
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::{
     UniverseAppState,
+    commands::SignWsDataResponse,
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    requests::connectivity,
     tasks_tracker::TasksTrackers,
 };
 
@@ -127,7 +130,13 @@ pub async fn send_new_block_mined(app: AppHandle, block_height: u64) {
 
         let hashed_view_private_key = get_wallet_view_key_hashed(app.clone()).await;
 
-        let client = reqwest::Client::new();
+        let client = match connectivity::client() {
+            Ok(client) => client,
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "error at building client for /miner/mined-block {e}");
+                return;
+            }
+        };
         let url = format!("{base_url}/miner/mined-block");
         let message = AirdropMinedBlockMessage {
             wallet_view_key_hashed: hashed_view_private_key,
@@ -180,3 +189,15 @@ pub fn get_der_encode_pub_key(key_pair: &Ed25519KeyPair) -> anyhow::Result<Strin
     let der_encoded = spki.to_der().map_err(|e| anyhow::anyhow!(e.to_string()))?;
     Ok(hex::encode(der_encoded))
 }
+
+/// Signs `data` with `key_pair`, returning the base64 signature together with the DER encoded
+/// public key a verifier needs to check it.
+pub fn sign_with_key(key_pair: &Ed25519KeyPair, data: &str) -> anyhow::Result<SignWsDataResponse> {
+    let pub_key = get_der_encode_pub_key(key_pair)?;
+    let signature = key_pair.sign(data.as_bytes());
+
+    Ok(SignWsDataResponse {
+        signature: BASE64_STANDARD.encode(signature.as_ref()),
+        pub_key,
+    })
+}
@@ -22,15 +22,46 @@
 
 use std::path::PathBuf;
 
-use crate::utils::platform_utils::{CurrentOperatingSystem, PlatformUtils};
+use crate::setup::setup_manager::SetupPhase;
+use crate::utils::platform_utils::{CurrentArchitecture, CurrentOperatingSystem, PlatformUtils};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BinaryPlatformAssets {
     LinuxX64,
+    LinuxArm64,
     WindowsX64,
     MacOSX64,
     MacOSArm64,
 }
 
+impl BinaryPlatformAssets {
+    pub fn current() -> Self {
+        Self::for_platform(
+            PlatformUtils::detect_current_os(),
+            PlatformUtils::detect_current_architecture(),
+        )
+    }
+
+    pub fn for_platform(os: CurrentOperatingSystem, architecture: CurrentArchitecture) -> Self {
+        match (os, architecture) {
+            // There are no Windows on ARM builds, x64 binaries run under emulation
+            (CurrentOperatingSystem::Windows, _) => BinaryPlatformAssets::WindowsX64,
+            (CurrentOperatingSystem::Linux, CurrentArchitecture::X64) => {
+                BinaryPlatformAssets::LinuxX64
+            }
+            (CurrentOperatingSystem::Linux, CurrentArchitecture::Arm64) => {
+                BinaryPlatformAssets::LinuxArm64
+            }
+            (CurrentOperatingSystem::MacOS, CurrentArchitecture::X64) => {
+                BinaryPlatformAssets::MacOSX64
+            }
+            (CurrentOperatingSystem::MacOS, CurrentArchitecture::Arm64) => {
+                BinaryPlatformAssets::MacOSArm64
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Binaries {
     Xmrig,
@@ -38,6 +69,10 @@ pub enum Binaries {
     MinotariNode,
     Wallet,
     Tor,
+    /// obfs4, webtunnel and meek_lite pluggable transport client, from the Tor expert bundle
+    Lyrebird,
+    /// Snowflake pluggable transport client, from the Tor expert bundle
+    Snowflake,
     BridgeTapplet,
     LolMiner,
 }
@@ -49,21 +84,71 @@ impl Binaries {
             Binaries::MinotariNode => "minotari_node",
             Binaries::Wallet => "wallet",
             Binaries::Tor => "tor",
+            Binaries::Lyrebird => "lyrebird",
+            Binaries::Snowflake => "snowflake",
             Binaries::BridgeTapplet => "bridge",
             Binaries::LolMiner => "lolminer",
         }
     }
 
+    pub fn is_miner(&self) -> bool {
+        matches!(self, Binaries::Xmrig | Binaries::LolMiner)
+    }
+
+    /// Whether the publisher ships a build of the binary for `platform`
+    pub fn has_build_for(&self, platform: BinaryPlatformAssets) -> bool {
+        match self {
+            Binaries::Xmrig => platform != BinaryPlatformAssets::LinuxArm64,
+            Binaries::LolMiner => matches!(
+                platform,
+                BinaryPlatformAssets::WindowsX64 | BinaryPlatformAssets::LinuxX64
+            ),
+            _ => true,
+        }
+    }
+
+    pub fn all() -> Vec<Binaries> {
+        vec![
+            Binaries::MinotariNode,
+            Binaries::Wallet,
+            Binaries::MergeMiningProxy,
+            Binaries::Xmrig,
+            Binaries::LolMiner,
+            Binaries::Tor,
+            Binaries::Lyrebird,
+            Binaries::Snowflake,
+            Binaries::BridgeTapplet,
+        ]
+    }
+
+    /// Setup phase that launches the binary and has to restart to pick up a different build
+    pub fn setup_phase(&self) -> SetupPhase {
+        match self {
+            Binaries::Xmrig | Binaries::MergeMiningProxy => SetupPhase::CpuMining,
+            Binaries::LolMiner => SetupPhase::GpuMining,
+            Binaries::MinotariNode | Binaries::Tor | Binaries::Lyrebird | Binaries::Snowflake => {
+                SetupPhase::Node
+            }
+            Binaries::Wallet | Binaries::BridgeTapplet => SetupPhase::Wallet,
+        }
+    }
+
     pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap_or_else(|| panic!("Unknown binary name: {name}"))
+    }
+
+    pub fn try_from_name(name: &str) -> Option<Self> {
         match name {
-            "xmrig" => Binaries::Xmrig,
-            "mmproxy" => Binaries::MergeMiningProxy,
-            "minotari_node" => Binaries::MinotariNode,
-            "wallet" => Binaries::Wallet,
-            "tor" => Binaries::Tor,
-            "bridge" => Binaries::BridgeTapplet,
-            "lolminer" => Binaries::LolMiner,
-            _ => panic!("Unknown binary name: {name}"),
+            "xmrig" => Some(Binaries::Xmrig),
+            "mmproxy" => Some(Binaries::MergeMiningProxy),
+            "minotari_node" => Some(Binaries::MinotariNode),
+            "wallet" => Some(Binaries::Wallet),
+            "tor" => Some(Binaries::Tor),
+            "lyrebird" => Some(Binaries::Lyrebird),
+            "snowflake" => Some(Binaries::Snowflake),
+            "bridge" => Some(Binaries::BridgeTapplet),
+            "lolminer" => Some(Binaries::LolMiner),
+            _ => None,
         }
     }
 
@@ -99,6 +184,18 @@ impl Binaries {
                 let file_name = "tor";
                 Self::append_exe_if_windows(&mut PathBuf::from(file_name))
             }
+            Binaries::Lyrebird => {
+                let file_name = "lyrebird";
+                Self::append_exe_if_windows(
+                    &mut PathBuf::from("pluggable_transports").join(file_name),
+                )
+            }
+            Binaries::Snowflake => {
+                let file_name = "snowflake-client";
+                Self::append_exe_if_windows(
+                    &mut PathBuf::from("pluggable_transports").join(file_name),
+                )
+            }
             Binaries::BridgeTapplet => {
                 let file_name = format!("bridge-{version}");
                 PathBuf::from(file_name).join("bridge")
@@ -133,11 +230,17 @@ impl Binaries {
                 BinaryPlatformAssets::MacOSArm64 => {
                     format!("xmrig-{version}-macos-arm64.tar.gz")
                 }
+                // XMRig doesn't publish Linux ARM64 builds, CPU mining is disabled there
+                BinaryPlatformAssets::LinuxArm64 => "Not available for this platform".to_string(),
             },
-            Binaries::Tor => match platform {
+            // Pluggable transports are downloaded with the Tor expert bundle they ship in
+            Binaries::Tor | Binaries::Lyrebird | Binaries::Snowflake => match platform {
                 BinaryPlatformAssets::LinuxX64 => {
                     format!("tor-expert-bundle-linux-x86_64-{version}.tar.gz")
                 }
+                BinaryPlatformAssets::LinuxArm64 => {
+                    format!("tor-expert-bundle-linux-aarch64-{version}.tar.gz")
+                }
                 BinaryPlatformAssets::WindowsX64 => {
                     format!("tor-expert-bundle-windows-x86_64-{version}.tar.gz")
                 }
@@ -152,6 +255,9 @@ impl Binaries {
                 BinaryPlatformAssets::LinuxX64 => {
                     format!("tari_suite-{version}-{network}-{hash}-linux-x86_64.zip")
                 }
+                BinaryPlatformAssets::LinuxArm64 => {
+                    format!("tari_suite-{version}-{network}-{hash}-linux-arm64.zip")
+                }
                 BinaryPlatformAssets::WindowsX64 => {
                     format!("tari_suite-{version}-{network}-{hash}-windows-x64.exe.zip")
                 }
@@ -166,6 +272,9 @@ impl Binaries {
                 BinaryPlatformAssets::LinuxX64 => {
                     format!("tari_suite-{version}-{network}-{hash}-linux-x86_64.zip")
                 }
+                BinaryPlatformAssets::LinuxArm64 => {
+                    format!("tari_suite-{version}-{network}-{hash}-linux-arm64.zip")
+                }
                 BinaryPlatformAssets::WindowsX64 => {
                     format!("tari_suite-{version}-{network}-{hash}-windows-x64.exe.zip")
                 }
@@ -180,6 +289,9 @@ impl Binaries {
                 BinaryPlatformAssets::LinuxX64 => {
                     format!("tari_suite-{version}-{network}-{hash}-linux-x86_64.zip")
                 }
+                BinaryPlatformAssets::LinuxArm64 => {
+                    format!("tari_suite-{version}-{network}-{hash}-linux-arm64.zip")
+                }
                 BinaryPlatformAssets::WindowsX64 => {
                     format!("tari_suite-{version}-{network}-{hash}-windows-x64.exe.zip")
                 }
@@ -197,7 +309,10 @@ impl Binaries {
                 BinaryPlatformAssets::WindowsX64 => {
                     format!("lolMiner_v{version}_Win64.zip")
                 }
-                _ => "Not available for this platform".to_string(),
+                // lolMiner is x64 only, GPU mining is disabled on other platforms
+                BinaryPlatformAssets::LinuxArm64
+                | BinaryPlatformAssets::MacOSX64
+                | BinaryPlatformAssets::MacOSArm64 => "Not available for this platform".to_string(),
             },
         }
     }
@@ -0,0 +1,125 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Tests for platform detection and release asset names
+
+use crate::binaries::Binaries;
+use crate::binaries::binaries_list::BinaryPlatformAssets;
+use crate::utils::platform_utils::{CurrentArchitecture, CurrentOperatingSystem};
+
+const PLATFORMS: [BinaryPlatformAssets; 5] = [
+    BinaryPlatformAssets::LinuxX64,
+    BinaryPlatformAssets::LinuxArm64,
+    BinaryPlatformAssets::WindowsX64,
+    BinaryPlatformAssets::MacOSX64,
+    BinaryPlatformAssets::MacOSArm64,
+];
+
+fn asset_name(binary: Binaries, platform: BinaryPlatformAssets) -> String {
+    binary.get_binary_platform_name(
+        platform,
+        "1.2.3".to_string(),
+        "mainnet".to_string(),
+        "abc123".to_string(),
+    )
+}
+
+// ==================== Platform detection ====================
+
+#[test]
+fn architecture_selects_platform_assets() {
+    use CurrentArchitecture::{Arm64, X64};
+    use CurrentOperatingSystem::{Linux, MacOS, Windows};
+
+    let cases = [
+        (Linux, X64, BinaryPlatformAssets::LinuxX64),
+        (Linux, Arm64, BinaryPlatformAssets::LinuxArm64),
+        (MacOS, X64, BinaryPlatformAssets::MacOSX64),
+        (MacOS, Arm64, BinaryPlatformAssets::MacOSArm64),
+        (Windows, X64, BinaryPlatformAssets::WindowsX64),
+        (Windows, Arm64, BinaryPlatformAssets::WindowsX64),
+    ];
+    for (os, architecture, expected) in cases {
+        assert_eq!(
+            BinaryPlatformAssets::for_platform(os.clone(), architecture),
+            expected,
+            "{os} {architecture}"
+        );
+    }
+}
+
+// ==================== Asset names ====================
+
+#[test]
+fn linux_arm64_asset_names() {
+    let platform = BinaryPlatformAssets::LinuxArm64;
+
+    assert_eq!(
+        asset_name(Binaries::MinotariNode, platform),
+        "tari_suite-1.2.3-mainnet-abc123-linux-arm64.zip"
+    );
+    assert_eq!(
+        asset_name(Binaries::Wallet, platform),
+        "tari_suite-1.2.3-mainnet-abc123-linux-arm64.zip"
+    );
+    assert_eq!(
+        asset_name(Binaries::Tor, platform),
+        "tor-expert-bundle-linux-aarch64-1.2.3.tar.gz"
+    );
+    assert!(!Binaries::Xmrig.has_build_for(platform));
+    assert!(!Binaries::LolMiner.has_build_for(platform));
+}
+
+#[test]
+fn x64_asset_names() {
+    assert_eq!(
+        asset_name(Binaries::Xmrig, BinaryPlatformAssets::LinuxX64),
+        "xmrig-1.2.3-linux-static-x64.tar.gz"
+    );
+    assert_eq!(
+        asset_name(Binaries::MinotariNode, BinaryPlatformAssets::LinuxX64),
+        "tari_suite-1.2.3-mainnet-abc123-linux-x86_64.zip"
+    );
+    assert_eq!(
+        asset_name(Binaries::LolMiner, BinaryPlatformAssets::WindowsX64),
+        "lolMiner_v1.2.3_Win64.zip"
+    );
+    assert_eq!(
+        asset_name(Binaries::Xmrig, BinaryPlatformAssets::MacOSArm64),
+        "xmrig-1.2.3-macos-arm64.tar.gz"
+    );
+}
+
+#[test]
+fn asset_names_exist_only_where_builds_are_published() {
+    for binary in Binaries::all() {
+        for platform in PLATFORMS {
+            let name = asset_name(binary, platform);
+            assert_eq!(
+                binary.has_build_for(platform),
+                name != "Not available for this platform",
+                "{} on {platform:?}: {name}",
+                binary.name()
+            );
+        }
+    }
+}
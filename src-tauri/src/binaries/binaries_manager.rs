@@ -27,6 +27,7 @@ use tari_common::configuration::Network;
 use tari_shutdown::Shutdown;
 use tauri_plugin_sentry::sentry;
 use tokio::sync::watch::{Sender, channel};
+use url::Url;
 
 use crate::{
     LOG_TARGET_APP_LOGIC,
@@ -40,7 +41,13 @@ use crate::{
 use super::{
     Binaries,
     binaries_list::BinaryPlatformAssets,
-    binaries_resolver::{BinaryDownloadInfo, LatestVersionApiAdapter},
+    binaries_resolver::{BinaryDownloadInfo, BinaryResolveError, LatestVersionApiAdapter},
+    binary_overrides::rewrite_to_mirror,
+    signature_verification::{
+        SignatureAuditRecord, SigningPolicy, append_signature_audit, signing_policy,
+        verify_file_signature,
+    },
+    version_history::compare_versions,
 };
 
 #[derive(Deserialize, Serialize, Default)]
@@ -119,7 +126,7 @@ impl BinaryManager {
         (version_requirement, hash)
     }
 
-    fn construct_binary_download_info(&self) -> BinaryDownloadInfo {
+    fn construct_binary_download_info(&self, mirror: Option<&Url>) -> BinaryDownloadInfo {
         let selected_version = self.selected_version.clone();
         let selected_hash = self.selected_hash.clone();
         let binary = Binaries::from_name(&self.binary_name);
@@ -146,17 +153,7 @@ impl BinaryManager {
             Network::Igor => "testnet",
         };
 
-        let platform = match PlatformUtils::detect_current_os() {
-            CurrentOperatingSystem::Windows => BinaryPlatformAssets::WindowsX64,
-            CurrentOperatingSystem::Linux => BinaryPlatformAssets::LinuxX64,
-            CurrentOperatingSystem::MacOS => {
-                if cfg!(target_arch = "aarch64") {
-                    BinaryPlatformAssets::MacOSArm64
-                } else {
-                    BinaryPlatformAssets::MacOSX64
-                }
-            }
-        };
+        let platform = BinaryPlatformAssets::current();
 
         let name = binary.get_binary_platform_name(
             platform,
@@ -165,10 +162,29 @@ impl BinaryManager {
             selected_hash.unwrap_or("".to_string()),
         );
 
+        let main_url = format!("{}/{}", main_url, name.clone());
+        let fallback_url = format!("{fallback_url}/{name}");
+
+        if let Some(mirror) = mirror {
+            match rewrite_to_mirror(&main_url, mirror) {
+                // Falling back to the CDN keeps a partially populated mirror usable when online
+                Ok(mirror_url) => {
+                    return BinaryDownloadInfo {
+                        name,
+                        main_url: mirror_url,
+                        fallback_url: main_url,
+                    };
+                }
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Failed to map {main_url} to mirror {mirror}: {e:?}");
+                }
+            }
+        }
+
         BinaryDownloadInfo {
-            name: name.clone(),
-            main_url: format!("{}/{}", main_url, name.clone()),
-            fallback_url: format!("{fallback_url}/{name}"),
+            name,
+            main_url,
+            fallback_url,
         }
     }
 
@@ -233,10 +249,75 @@ impl BinaryManager {
         }
     }
 
+    async fn verify_signature(
+        &self,
+        download_info: BinaryDownloadInfo,
+        destination_dir: PathBuf,
+        in_progress_file_zip: PathBuf,
+    ) -> Result<(), BinaryResolveError> {
+        let binary = Binaries::from_name(&self.binary_name);
+        let selected_version = self.selected_version.clone();
+        info!(target: LOG_TARGET_APP_LOGIC, "Verifying signature for binary: {} with version: {:?}", self.binary_name, selected_version);
+
+        let signature_error = |error: String| {
+            std::fs::remove_dir_all(destination_dir.clone()).ok();
+            error!(target: LOG_TARGET_APP_LOGIC, "Signature verification failed for binary: {} with version: {:?}. Error: {}", self.binary_name, selected_version, error);
+            BinaryResolveError::SignatureVerification {
+                binary: self.binary_name.clone(),
+                version: selected_version.clone(),
+                error,
+            }
+        };
+
+        let pinned_keys = match signing_policy(binary)
+            .map_err(|e| signature_error(e.to_string()))?
+        {
+            SigningPolicy::Pinned(keys) => keys,
+            SigningPolicy::Unsigned { reason } => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Skipping signature verification for binary: {} with version: {:?}. Releases are not signed: {}", self.binary_name, selected_version, reason);
+                return Ok(());
+            }
+        };
+        let signature_file = self
+            .adapter
+            .download_and_get_signature_path(destination_dir.clone(), download_info.clone())
+            .await
+            .map_err(|e| signature_error(format!("Signature file unavailable: {e:?}")))?;
+        let signature = tokio::fs::read_to_string(&signature_file)
+            .await
+            .map_err(|e| signature_error(format!("Failed to read signature file: {e:?}")))?;
+
+        let verified = verify_file_signature(&in_progress_file_zip, &signature, &pinned_keys)
+            .await
+            .map_err(|e| signature_error(e.to_string()))?;
+        info!(target: LOG_TARGET_APP_LOGIC, "Signature verified for binary: {} with version: {:?}, signed by: {} ({})", self.binary_name, selected_version, verified.key_label, verified.key_id);
+
+        let record = SignatureAuditRecord {
+            version: selected_version,
+            asset: download_info.name,
+            key_id: verified.key_id,
+            key_label: verified.key_label,
+            trusted_comment: verified.trusted_comment,
+            verified_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+        };
+        if let Err(e) = append_signature_audit(binary, record).await {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to record signature audit for binary: {}. Error: {:?}", self.binary_name, e);
+        }
+
+        Ok(())
+    }
+
     pub fn check_if_files_for_version_exist(&self) -> bool {
         debug!(target: LOG_TARGET_APP_LOGIC,"Checking if files for selected version exist: {:?}", self.selected_version);
 
-        debug!(target: LOG_TARGET_APP_LOGIC, "Selected version: {:?}", self.selected_version);
+        self.check_if_files_exist_for_version(&self.selected_version)
+    }
+
+    pub fn check_if_files_exist_for_version(&self, version: &str) -> bool {
+        debug!(target: LOG_TARGET_APP_LOGIC, "Selected version: {version:?}");
 
         let binary_folder = match self.adapter.get_binary_folder() {
             Ok(path) => path,
@@ -246,13 +327,12 @@ impl BinaryManager {
             }
         };
 
-        let mut version_folder = binary_folder.join(&self.selected_version);
+        let mut version_folder = binary_folder.join(version);
         if let Some(subfolder) = self.binary_subfolder() {
             version_folder.push(subfolder);
         }
-        let binary_file = version_folder.join(
-            Binaries::from_name(&self.binary_name).binary_file_name(self.selected_version.clone()),
-        );
+        let binary_file = version_folder
+            .join(Binaries::from_name(&self.binary_name).binary_file_name(version.to_string()));
         let binary_file_with_exe = binary_file.with_extension("exe");
         let binary_file_with_html = version_folder.join("index.html");
 
@@ -280,6 +360,8 @@ impl BinaryManager {
                 Binaries::Wallet => &TasksTrackers::current().wallet_phase,
                 Binaries::MinotariNode => &TasksTrackers::current().node_phase,
                 Binaries::Tor => &TasksTrackers::current().node_phase,
+                Binaries::Lyrebird => &TasksTrackers::current().node_phase,
+                Binaries::Snowflake => &TasksTrackers::current().node_phase,
                 Binaries::MergeMiningProxy => &TasksTrackers::current().cpu_mining_phase,
                 Binaries::BridgeTapplet => &TasksTrackers::current().wallet_phase,
                 Binaries::LolMiner => &TasksTrackers::current().gpu_mining_phase,
@@ -325,7 +407,8 @@ impl BinaryManager {
     pub async fn download_version_with_retries(
         &self,
         progress_channel: Option<IncrementalProgressTracker>,
-    ) -> Result<(), Error> {
+        mirror: Option<Url>,
+    ) -> Result<(), BinaryResolveError> {
         #[cfg(target_os = "windows")]
         {
             // Add Windows Defender exclusions before download to prevent interference
@@ -337,13 +420,24 @@ impl BinaryManager {
         let mut last_error_message = String::new();
         for retry in 0..3 {
             match self
-                .download_selected_version(progress_channel.clone())
+                .download_selected_version(progress_channel.clone(), mirror.as_ref())
                 .await
             {
                 Ok(_) => {
                     info!(target: LOG_TARGET_APP_LOGIC, "Successfully downloaded binary: {} on retry: {}", self.binary_name, retry);
                     return Ok(());
                 }
+                Err(error @ BinaryResolveError::SignatureVerification { .. }) => {
+                    // Retrying won't change who signed the archive
+                    sentry::capture_message(
+                        &format!(
+                            "Failed to download binary: {}. Error: {:?}",
+                            self.binary_name, error
+                        ),
+                        sentry::Level::Error,
+                    );
+                    return Err(error);
+                }
                 Err(error) => {
                     last_error_message = format!(
                         "Failed to download binary: {}. Error: {:?}",
@@ -356,16 +450,17 @@ impl BinaryManager {
         }
         sentry::capture_message(&last_error_message, sentry::Level::Error);
         error!(target: LOG_TARGET_APP_LOGIC, "{last_error_message}");
-        Err(anyhow!(last_error_message))
+        Err(BinaryResolveError::Other(anyhow!(last_error_message)))
     }
 
     pub async fn download_selected_version(
         &self,
         progress_channel: Option<IncrementalProgressTracker>,
-    ) -> Result<(), Error> {
+        mirror: Option<&Url>,
+    ) -> Result<(), BinaryResolveError> {
         let version = self.selected_version.clone();
 
-        let download_info = self.construct_binary_download_info();
+        let download_info = self.construct_binary_download_info(mirror);
 
         let binary_folder = self
             .adapter
@@ -379,18 +474,24 @@ impl BinaryManager {
 
         info!(target: LOG_TARGET_APP_LOGIC, "Downloading binary: {} from url: {}", self.binary_name, &download_url);
         let archive_destination_path: PathBuf;
+        let download_client: HttpFileClient;
 
         let (chunk_progress_sender, main_progress_sender_shutdown) = self
             .resolve_progress_channel(progress_channel.clone())
             .await
             .map_err(|e| anyhow!("Error resolving progress channel: {:?}", e))?;
 
-        let main_file_download_result = HttpFileClient::builder()
-            .with_cloudflare_cache_check()
+        let mut main_client_builder = HttpFileClient::builder();
+        if mirror.is_none() {
+            main_client_builder = main_client_builder.with_cloudflare_cache_check();
+        }
+        let main_client = main_client_builder
             .with_file_extract()
+            .with_deferred_extract()
             .with_progress_status_sender(chunk_progress_sender.clone())
             .with_download_resume()
-            .build(download_url.clone(), destination_dir.clone())?
+            .build(download_url.clone(), destination_dir.clone())?;
+        let main_file_download_result = main_client
             .execute()
             .await
             .map_err(|e| anyhow!("Error downloading version: {:?}. Error: {:?}", version, e));
@@ -406,31 +507,37 @@ impl BinaryManager {
                 .await
                 .map_err(|e| anyhow!("Error resolving progress channel: {:?}", e))?;
 
-            archive_destination_path = HttpFileClient::builder()
+            let fallback_client = HttpFileClient::builder()
                 .with_file_extract()
+                .with_deferred_extract()
                 .with_progress_status_sender(chunk_progress_sender.clone())
                 .with_download_resume()
-                .build(fallback_url.clone(), destination_dir.clone())?
-                .execute()
-                .await
-                .inspect_err(|_| {
-                    if let Some(mut progress_sender_shutdown) = fallback_progress_sender_shutdown {
-                        progress_sender_shutdown.trigger();
-                    }
-                })?;
+                .build(fallback_url.clone(), destination_dir.clone())?;
+            archive_destination_path = fallback_client.execute().await.inspect_err(|_| {
+                if let Some(mut progress_sender_shutdown) = fallback_progress_sender_shutdown {
+                    progress_sender_shutdown.trigger();
+                }
+            })?;
+            download_client = fallback_client;
         } else {
             archive_destination_path = main_file_download_result?;
+            download_client = main_client;
         }
 
         if self.should_validate_checksum {
             self.validate_checksum(
-                download_info,
+                download_info.clone(),
                 destination_dir.clone(),
-                archive_destination_path,
+                archive_destination_path.clone(),
             )
             .await?;
         }
 
+        self.verify_signature(download_info, destination_dir, archive_destination_path)
+            .await?;
+
+        download_client.extract().await?;
+
         Ok(())
     }
 
@@ -439,9 +546,31 @@ impl BinaryManager {
     }
 
     pub fn get_base_dir(&self) -> Result<PathBuf, Error> {
-        let selected_version = self.selected_version.clone();
+        self.get_base_dir_for_version(&self.selected_version)
+    }
+
+    pub fn get_base_dir_for_version(&self, version: &str) -> Result<PathBuf, Error> {
         let binary_folder_path = self.adapter.get_binary_folder()?;
-        Ok(binary_folder_path.join(selected_version))
+        Ok(binary_folder_path.join(version))
+    }
+
+    /// Versions with complete files in the binary folder, oldest first
+    pub fn list_installed_versions(&self) -> Vec<String> {
+        let Ok(binary_folder) = self.adapter.get_binary_folder() else {
+            return Vec::new();
+        };
+        let Ok(entries) = std::fs::read_dir(&binary_folder) else {
+            return Vec::new();
+        };
+
+        let mut versions: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|version| self.check_if_files_exist_for_version(version))
+            .collect();
+        versions.sort_by(|a, b| compare_versions(a, b));
+        versions
     }
 
     /// Add Windows Defender exclusions for the downloaded binary
@@ -20,14 +20,20 @@ use crate::LOG_TARGET_APP_LOGIC;
 // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use crate::configs::config_core::ConfigCore;
+use crate::configs::trait_config::ConfigImpl;
 use crate::progress_trackers::progress_stepper::IncrementalProgressTracker;
+use crate::requests::clients::http_file_client::HttpFileClient;
+use crate::requests::connectivity;
 use anyhow::{Error, anyhow};
 use async_trait::async_trait;
-use log::debug;
+use log::{debug, info, warn};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::LazyLock;
-use tokio::sync::Mutex as AsyncMutex;
+use tokio::sync::{Mutex as AsyncMutex, RwLock};
+use url::Url;
 
 use super::Binaries;
 use super::adapter_bridge::BridgeTappletAdapter;
@@ -35,12 +41,19 @@ use super::adapter_github::GithubReleasesAdapter;
 use super::adapter_tor::TorReleaseAdapter;
 use super::adapter_xmrig::XmrigVersionApiAdapter;
 use super::binaries_manager::BinaryManager;
+use super::binary_overrides::{BinaryOverride, mirror_base_url, probe_binary_version};
+use super::signature_verification::SIGNATURE_FILE_EXTENSION;
+use super::version_history::{
+    BinaryVersionsInfo, VersionHistory, compare_versions, rollback_target,
+};
 
 static INSTANCE: LazyLock<BinaryResolver> = LazyLock::new(BinaryResolver::new);
 
 // Lock to prevent concurrent downloads of tari suite binaries (MergeMiningProxy, MinotariNode, Wallet)
 // that all come from the same zip file and would conflict when downloading in parallel
 static TARI_SUITE_DOWNLOAD_LOCK: LazyLock<AsyncMutex<()>> = LazyLock::new(|| AsyncMutex::new(()));
+// Same for Tor and the pluggable transports (Lyrebird, Snowflake) shipped in the Tor expert bundle
+static TOR_BUNDLE_DOWNLOAD_LOCK: LazyLock<AsyncMutex<()>> = LazyLock::new(|| AsyncMutex::new(()));
 
 #[derive(Debug)]
 pub enum BinaryResolveError {
@@ -49,10 +62,22 @@ pub enum BinaryResolveError {
         expected_path: PathBuf,
         error: String,
     },
+    /// Archive signature missing or not made by a key pinned for the binary
+    SignatureVerification {
+        binary: String,
+        version: String,
+        error: String,
+    },
     /// Other error occurred
     Other(Error),
 }
 
+impl From<Error> for BinaryResolveError {
+    fn from(error: Error) -> Self {
+        BinaryResolveError::Other(error)
+    }
+}
+
 impl From<BinaryResolveError> for Error {
     fn from(error: BinaryResolveError) -> Self {
         match error {
@@ -66,6 +91,13 @@ impl From<BinaryResolveError> for Error {
                     expected_path.display()
                 )
             }
+            BinaryResolveError::SignatureVerification {
+                binary,
+                version,
+                error,
+            } => {
+                anyhow!("Signature verification failed for {binary} {version}: {error}")
+            }
             BinaryResolveError::Other(error) => error,
         }
     }
@@ -91,6 +123,30 @@ pub trait LatestVersionApiAdapter: Send + Sync + 'static {
         download_info: BinaryDownloadInfo,
     ) -> Result<PathBuf, Error>;
 
+    /// Detached signature is checked against pinned keys, so mirrors are as good as the origin
+    async fn download_and_get_signature_path(
+        &self,
+        directory: PathBuf,
+        download_info: BinaryDownloadInfo,
+    ) -> Result<PathBuf, Error> {
+        let signature_url = format!("{}.{SIGNATURE_FILE_EXTENSION}", download_info.main_url);
+        match HttpFileClient::builder()
+            .build(signature_url, directory.clone())?
+            .execute()
+            .await
+        {
+            Ok(signature_path) => Ok(signature_path),
+            Err(_) => {
+                let signature_fallback_url =
+                    format!("{}.{SIGNATURE_FILE_EXTENSION}", download_info.fallback_url);
+                HttpFileClient::builder()
+                    .build(signature_fallback_url, directory)?
+                    .execute()
+                    .await
+            }
+        }
+    }
+
     fn get_binary_folder(&self) -> Result<PathBuf, Error>;
     fn get_base_main_download_url(&self, version: &str) -> String;
     fn get_base_fallback_download_url(&self, version: &str) -> String;
@@ -98,6 +154,10 @@ pub trait LatestVersionApiAdapter: Send + Sync + 'static {
 
 pub struct BinaryResolver {
     managers: HashMap<Binaries, BinaryManager>,
+    /// Versions reported by `--version` of locally overridden executables
+    override_versions: RwLock<HashMap<Binaries, String>>,
+    /// Installed version launched for each binary, when it differs from the release version
+    active_versions: RwLock<HashMap<Binaries, String>>,
 }
 
 impl BinaryResolver {
@@ -190,8 +250,30 @@ impl BinaryResolver {
             ),
         );
 
+        binary_manager.insert(
+            Binaries::Lyrebird,
+            BinaryManager::new(
+                Binaries::Lyrebird.name().to_string(),
+                Some("tor".to_string()),
+                Box::new(TorReleaseAdapter {}),
+                true,
+            ),
+        );
+
+        binary_manager.insert(
+            Binaries::Snowflake,
+            BinaryManager::new(
+                Binaries::Snowflake.name().to_string(),
+                Some("tor".to_string()),
+                Box::new(TorReleaseAdapter {}),
+                true,
+            ),
+        );
+
         Self {
             managers: binary_manager,
+            override_versions: RwLock::new(HashMap::new()),
+            active_versions: RwLock::new(HashMap::new()),
         }
     }
 
@@ -199,23 +281,47 @@ impl BinaryResolver {
         &INSTANCE
     }
 
+    async fn get_binary_override(binary: Binaries) -> Option<BinaryOverride> {
+        ConfigCore::content()
+            .await
+            .binary_overrides()
+            .get(binary.name())
+            .cloned()
+    }
+
     async fn resolve_path_to_binary_files(
         &self,
         binary: Binaries,
     ) -> Result<PathBuf, BinaryResolveError> {
+        if let Some(BinaryOverride::LocalExecutable { path }) =
+            Self::get_binary_override(binary).await
+        {
+            if path.exists() {
+                debug!(target: LOG_TARGET_APP_LOGIC, "Using overridden binary at: {}", path.display());
+                return Ok(path);
+            }
+            return Err(BinaryResolveError::Other(anyhow!(
+                "Overridden binary for {} not found at {}",
+                binary.name(),
+                path.display()
+            )));
+        }
+
         let manager = self.managers.get(&binary).ok_or_else(|| {
             BinaryResolveError::Other(anyhow!("No latest version manager for this binary"))
         })?;
 
-        let version = manager.get_selected_version();
+        let version = self.get_active_version(binary, manager).await;
 
-        let base_dir: PathBuf = manager.get_base_dir().map_err(|error| {
-            BinaryResolveError::Other(anyhow!(
-                "No base directory for binary {}, Error: {}",
-                binary.name(),
-                error
-            ))
-        })?;
+        let base_dir: PathBuf = manager
+            .get_base_dir_for_version(&version)
+            .map_err(|error| {
+                BinaryResolveError::Other(anyhow!(
+                    "No base directory for binary {}, Error: {}",
+                    binary.name(),
+                    error
+                ))
+            })?;
 
         // For bridge tapplet, we return the base dir as it's a folder, not a binary file
         // Skip the rest of the checks
@@ -261,40 +367,215 @@ impl BinaryResolver {
             .get(&binary)
             .ok_or_else(|| anyhow!("Couldn't find manager for binary: {}", binary.name()))?;
 
+        let mirror = match Self::get_binary_override(binary).await {
+            Some(BinaryOverride::LocalExecutable { path }) => {
+                if !path.exists() {
+                    return Err(anyhow!(
+                        "Overridden binary for {} not found at {}",
+                        binary.name(),
+                        path.display()
+                    ));
+                }
+                // The bridge tapplet is a folder of static files, there is nothing to run
+                let version = if binary.eq(&Binaries::BridgeTapplet) {
+                    manager.get_selected_version()
+                } else {
+                    probe_binary_version(&path).await?
+                };
+                info!(target: LOG_TARGET_APP_LOGIC, "Using overridden binary {} version {} at: {}", binary.name(), version, path.display());
+                self.override_versions.write().await.insert(binary, version);
+                return Ok(());
+            }
+            Some(BinaryOverride::Mirror { location }) => Some(mirror_base_url(&location)?),
+            None => None,
+        };
+        self.override_versions.write().await.remove(&binary);
+
+        let release_version = manager.get_selected_version();
+        let state = VersionHistory::get(binary).await;
+        let mut pinned = state.pinned;
+        // A rollback only holds until a newer release replaces the version that misbehaved
+        if let Some(rolled_back_from) = state.rolled_back_from
+            && compare_versions(&release_version, &rolled_back_from) == Ordering::Greater
+        {
+            info!(target: LOG_TARGET_APP_LOGIC, "Release {} {} supersedes rolled back {}, unpinning", binary.name(), release_version, rolled_back_from);
+            VersionHistory::set_pinned(binary, None).await?;
+            pinned = None;
+        }
+
+        if let Some(pinned) = pinned
+            && pinned != release_version
+        {
+            if manager.check_if_files_exist_for_version(&pinned) {
+                info!(target: LOG_TARGET_APP_LOGIC, "Using pinned {} version {}", binary.name(), pinned);
+                self.activate_version(binary, pinned).await;
+                return Ok(());
+            }
+            warn!(target: LOG_TARGET_APP_LOGIC, "Pinned {} version {} is not installed, using release version {}", binary.name(), pinned, release_version);
+        }
+
+        self.install_release_version(binary, manager, progress_channel, mirror)
+            .await?;
+        self.activate_version(binary, release_version).await;
+        Ok(())
+    }
+
+    async fn install_release_version(
+        &self,
+        binary: Binaries,
+        manager: &BinaryManager,
+        progress_channel: Option<IncrementalProgressTracker>,
+        mirror: Option<Url>,
+    ) -> Result<(), Error> {
         if manager.check_if_files_for_version_exist() {
             // If files already exist, we can skip the download
             return Ok(());
         }
 
-        // These 3 binaries are downloaded as one zip so processing them in parallel would cause file conflicts
+        // Binaries sharing an archive would conflict when downloaded in parallel
         // To keep it safe, we lock the download for these binaries and then check again if files exist after acquiring the lock
-        let needs_tari_suite_lock = matches!(
-            binary,
-            Binaries::MergeMiningProxy | Binaries::MinotariNode | Binaries::Wallet
-        );
+        let shared_archive_lock = match binary {
+            Binaries::MergeMiningProxy | Binaries::MinotariNode | Binaries::Wallet => {
+                Some(&TARI_SUITE_DOWNLOAD_LOCK)
+            }
+            Binaries::Tor | Binaries::Lyrebird | Binaries::Snowflake => {
+                Some(&TOR_BUNDLE_DOWNLOAD_LOCK)
+            }
+            Binaries::Xmrig | Binaries::BridgeTapplet | Binaries::LolMiner => None,
+        };
 
-        if needs_tari_suite_lock {
-            let _lock = TARI_SUITE_DOWNLOAD_LOCK.lock().await;
+        if let Some(shared_archive_lock) = shared_archive_lock {
+            let _lock = shared_archive_lock.lock().await;
 
             if manager.check_if_files_for_version_exist() {
                 return Ok(());
             }
-            manager
-                .download_version_with_retries(progress_channel.clone())
-                .await?;
+            Self::download_release_version(binary, manager, progress_channel, mirror).await?;
         } else {
-            manager
-                .download_version_with_retries(progress_channel.clone())
-                .await?;
+            Self::download_release_version(binary, manager, progress_channel, mirror).await?;
         }
 
         Ok(())
     }
 
+    async fn download_release_version(
+        binary: Binaries,
+        manager: &BinaryManager,
+        progress_channel: Option<IncrementalProgressTracker>,
+        mirror: Option<Url>,
+    ) -> Result<(), BinaryResolveError> {
+        let download = manager.download_version_with_retries(progress_channel, mirror);
+        match binary {
+            // Traffic routed through Tor can't wait for Tor to be downloaded
+            Binaries::Tor | Binaries::Lyrebird | Binaries::Snowflake => {
+                connectivity::download_tor_bundle(download).await
+            }
+            _ => download.await,
+        }
+    }
+
     pub async fn get_binary_version(&self, binary: Binaries) -> String {
+        if let Some(version) = self.override_versions.read().await.get(&binary) {
+            return version.clone();
+        }
+        let manager = self
+            .managers
+            .get(&binary)
+            .unwrap_or_else(|| panic!("Couldn't find manager for binary: {}", binary.name()));
+        self.get_active_version(binary, manager).await
+    }
+
+    async fn get_active_version(&self, binary: Binaries, manager: &BinaryManager) -> String {
+        self.active_versions
+            .read()
+            .await
+            .get(&binary)
+            .cloned()
+            .unwrap_or_else(|| manager.get_selected_version())
+    }
+
+    async fn activate_version(&self, binary: Binaries, version: String) {
+        if let Err(e) = VersionHistory::record_activation(binary, &version).await {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to record active {} version: {e:?}", binary.name());
+        }
+        self.active_versions.write().await.insert(binary, version);
+    }
+
+    fn get_manager(&self, binary: Binaries) -> Result<&BinaryManager, Error> {
         self.managers
             .get(&binary)
-            .unwrap_or_else(|| panic!("Couldn't find manager for binary: {}", binary.name()))
-            .get_selected_version()
+            .ok_or_else(|| anyhow!("Couldn't find manager for binary: {}", binary.name()))
+    }
+
+    pub async fn get_versions_info(&self, binary: Binaries) -> Result<BinaryVersionsInfo, Error> {
+        let manager = self.get_manager(binary)?;
+        let state = VersionHistory::get(binary).await;
+        Ok(BinaryVersionsInfo {
+            binary: binary.name().to_string(),
+            release_version: manager.get_selected_version(),
+            active_version: self.get_binary_version(binary).await,
+            installed_versions: manager.list_installed_versions(),
+            is_overridden: self.override_versions.read().await.contains_key(&binary),
+            state,
+        })
+    }
+
+    /// Pins an installed version, or goes back to the release version with `None`. Takes effect
+    /// the next time the binary is initialized.
+    pub async fn pin_version(
+        &self,
+        binary: Binaries,
+        version: Option<String>,
+    ) -> Result<(), Error> {
+        let manager = self.get_manager(binary)?;
+        if let Some(version) = &version
+            && !manager.check_if_files_exist_for_version(version)
+        {
+            return Err(anyhow!(
+                "Version {} of {} is not installed",
+                version,
+                binary.name()
+            ));
+        }
+        VersionHistory::set_pinned(binary, version).await
+    }
+
+    /// Pins the version that was active before the current one. Returns the version rolled back to.
+    pub async fn rollback(&self, binary: Binaries) -> Result<String, Error> {
+        let manager = self.get_manager(binary)?;
+        let active_version = self.get_active_version(binary, manager).await;
+        let mut state = VersionHistory::get(binary).await;
+        state.active = Some(active_version.clone());
+
+        let target =
+            rollback_target(&state, &manager.list_installed_versions()).ok_or_else(|| {
+                anyhow!(
+                    "No older installed version of {} to roll back to",
+                    binary.name()
+                )
+            })?;
+        warn!(target: LOG_TARGET_APP_LOGIC, "Rolling back {} from {} to {}", binary.name(), active_version, target);
+        VersionHistory::record_rollback(binary, active_version, target.clone()).await?;
+        Ok(target)
+    }
+
+    /// Called by the process watcher after repeated failed startups. Rolls back binaries that were
+    /// upgraded and haven't started up healthy since. Returns whether a rollback happened.
+    pub async fn handle_repeated_startup_failures(&self, binary: Binaries) -> bool {
+        if self.override_versions.read().await.contains_key(&binary)
+            || VersionHistory::get(binary)
+                .await
+                .pending_upgrade_since
+                .is_none()
+        {
+            return false;
+        }
+        match self.rollback(binary).await {
+            Ok(_) => true,
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Automatic rollback of {} failed: {e:?}", binary.name());
+                false
+            }
+        }
     }
 }
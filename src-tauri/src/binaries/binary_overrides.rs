@@ -0,0 +1,124 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! User supplied replacements and mirrors for the pinned release binaries.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Error, anyhow};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[cfg(target_os = "windows")]
+use crate::consts::PROCESS_CREATION_NO_WINDOW;
+
+const VERSION_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BinaryOverride {
+    /// Absolute path to an executable (or folder, for the bridge tapplet) used instead of the
+    /// downloaded release
+    LocalExecutable { path: PathBuf },
+    /// Base URL or absolute directory mirroring `https://cdn-universe.tari.com`
+    Mirror { location: String },
+}
+
+impl BinaryOverride {
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            BinaryOverride::LocalExecutable { path } => {
+                if !path.is_absolute() {
+                    return Err(anyhow!(
+                        "Override path must be absolute: {}",
+                        path.display()
+                    ));
+                }
+                if !path.exists() {
+                    return Err(anyhow!("Override path does not exist: {}", path.display()));
+                }
+                Ok(())
+            }
+            BinaryOverride::Mirror { location } => mirror_base_url(location).map(|_| ()),
+        }
+    }
+}
+
+/// Accepts `http(s)://` and `file://` URLs as well as bare absolute directory paths
+pub fn mirror_base_url(location: &str) -> Result<Url, Error> {
+    let location = location.trim();
+    if let Ok(url) = Url::parse(location)
+        && matches!(url.scheme(), "http" | "https" | "file")
+    {
+        return Ok(url);
+    }
+
+    let path = Path::new(location);
+    if !path.is_absolute() {
+        return Err(anyhow!(
+            "Mirror must be an http(s) URL or an absolute directory: {location}"
+        ));
+    }
+    Url::from_directory_path(path).map_err(|_| anyhow!("Invalid mirror directory: {location}"))
+}
+
+/// Keeps the path of the release asset and swaps the host for the mirror, so a mirror populated by
+/// copying the CDN tree serves every binary, checksum and signature file
+pub fn rewrite_to_mirror(url: &str, mirror: &Url) -> Result<String, Error> {
+    let asset_url = Url::parse(url)?;
+    let base = mirror.as_str().trim_end_matches('/');
+    Ok(format!("{base}{}", asset_url.path()))
+}
+
+/// Picks the first semver-looking token from `--version` output, e.g. `XMRig 6.22.2` or
+/// `minotari_node 4.1.0-pre.0`
+pub fn parse_version_output(output: &str) -> Option<String> {
+    Regex::new(r"v?(\d+\.\d+\.\d+(?:[-+][0-9A-Za-z.\-+]*)?)")
+        .ok()?
+        .captures(output)
+        .and_then(|captures| captures.get(1))
+        .map(|version| version.as_str().to_string())
+}
+
+pub async fn probe_binary_version(path: &Path) -> Result<String, Error> {
+    let mut command = tokio::process::Command::new(path);
+    command.arg("--version").kill_on_drop(true);
+    #[cfg(target_os = "windows")]
+    command.creation_flags(PROCESS_CREATION_NO_WINDOW);
+
+    let output = tokio::time::timeout(VERSION_PROBE_TIMEOUT, command.output())
+        .await
+        .map_err(|_| anyhow!("{} --version timed out", path.display()))??;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    parse_version_output(&stdout)
+        .or_else(|| parse_version_output(&stderr))
+        .ok_or_else(|| {
+            anyhow!(
+                "Could not read a version from {} --version: {}",
+                path.display(),
+                stdout.trim()
+            )
+        })
+}
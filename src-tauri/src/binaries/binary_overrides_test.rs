@@ -0,0 +1,120 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Tests for local binary overrides and mirror URL mapping
+
+use std::path::PathBuf;
+
+use crate::binaries::binary_overrides::{
+    BinaryOverride, mirror_base_url, parse_version_output, rewrite_to_mirror,
+};
+use crate::testing::test_utils::TestContext;
+
+// ==================== Version probing ====================
+
+#[test]
+fn version_is_read_from_typical_version_output() {
+    assert_eq!(
+        parse_version_output("XMRig 6.22.2\n built on Jan  1 2025 with GCC 13.2.0"),
+        Some("6.22.2".to_string())
+    );
+    assert_eq!(
+        parse_version_output("minotari_node 4.1.0-pre.0"),
+        Some("4.1.0-pre.0".to_string())
+    );
+    assert_eq!(
+        parse_version_output("Tor version 0.4.8.16."),
+        Some("0.4.8".to_string())
+    );
+}
+
+#[test]
+fn output_without_version_is_rejected() {
+    assert_eq!(parse_version_output("Usage: xmrig [OPTIONS]"), None);
+}
+
+// ==================== Mirrors ====================
+
+#[test]
+fn release_asset_paths_are_kept_on_the_mirror() {
+    let mirror = mirror_base_url("http://10.0.0.5:8080/tari-mirror/").unwrap();
+
+    let url = rewrite_to_mirror(
+        "https://cdn-universe.tari.com/tari-project/tari/releases/download/v4.1.0/tari_suite.zip",
+        &mirror,
+    )
+    .unwrap();
+
+    assert_eq!(
+        url,
+        "http://10.0.0.5:8080/tari-mirror/tari-project/tari/releases/download/v4.1.0/tari_suite.zip"
+    );
+}
+
+#[test]
+fn local_directory_mirror_maps_to_file_url() {
+    let ctx = TestContext::new();
+    let mirror = mirror_base_url(ctx.data_dir.to_str().unwrap()).unwrap();
+
+    let url = rewrite_to_mirror(
+        "https://cdn-universe.tari.com/xmrig/xmrig/releases/download/v6.22.2/xmrig.tar.gz",
+        &mirror,
+    )
+    .unwrap();
+
+    assert_eq!(mirror.scheme(), "file");
+    assert!(url.starts_with("file://"));
+    assert!(url.ends_with("/xmrig/xmrig/releases/download/v6.22.2/xmrig.tar.gz"));
+}
+
+#[test]
+fn relative_mirror_directory_is_rejected() {
+    assert!(mirror_base_url("mirror/binaries").is_err());
+}
+
+// ==================== Validation ====================
+
+#[test]
+fn local_executable_must_exist() {
+    let ctx = TestContext::new();
+    let existing = ctx.data_dir.join("xmrig");
+    std::fs::write(&existing, b"#!/bin/sh").unwrap();
+
+    assert!(
+        BinaryOverride::LocalExecutable { path: existing }
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        BinaryOverride::LocalExecutable {
+            path: ctx.data_dir.join("missing")
+        }
+        .validate()
+        .is_err()
+    );
+    assert!(
+        BinaryOverride::LocalExecutable {
+            path: PathBuf::from("xmrig")
+        }
+        .validate()
+        .is_err()
+    );
+}
@@ -29,7 +29,18 @@ mod binaries_manager;
 mod windows_defender;
 
 pub mod binaries_list;
+#[cfg(test)]
+mod binaries_list_test;
 pub mod binaries_resolver;
+pub mod binary_overrides;
+#[cfg(test)]
+mod binary_overrides_test;
+pub mod signature_verification;
+#[cfg(test)]
+mod signature_verification_test;
+pub mod version_history;
+#[cfg(test)]
+mod version_history_test;
 
 pub use binaries_list::Binaries;
 pub use binaries_resolver::BinaryResolver;
@@ -0,0 +1,371 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Detached minisign signature checks for downloaded binary archives.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use base64::prelude::*;
+use blake2::{Blake2b512, Digest};
+use dirs::config_dir;
+use log::warn;
+use ring::signature::{ED25519, UnparsedPublicKey};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::io::AsyncReadExt;
+
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+use super::Binaries;
+
+pub const SIGNATURE_FILE_EXTENSION: &str = "minisig";
+
+const ALGORITHM_ED25519: [u8; 2] = *b"Ed";
+const ALGORITHM_ED25519_PREHASHED: [u8; 2] = *b"ED";
+const KEY_ID_LENGTH: usize = 8;
+const PUBLIC_KEY_LENGTH: usize = 32;
+const SIGNATURE_LENGTH: usize = 64;
+const UNTRUSTED_COMMENT_PREFIX: &str = "untrusted comment:";
+const TRUSTED_COMMENT_PREFIX: &str = "trusted comment: ";
+const AUDIT_FILE_NAME: &str = "binary_signatures.json";
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+const SIGNING_KEYS_JSON: &str = include_str!("../../binaries-versions/binaries_signing_keys.json");
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignatureError {
+    #[error("Malformed {0}")]
+    Malformed(&'static str),
+    #[error("Unsupported signature algorithm")]
+    UnsupportedAlgorithm,
+    #[error("Signed with key {0} which is not pinned for this binary")]
+    UnknownKey(String),
+    #[error("Signature does not match the archive")]
+    InvalidSignature,
+    #[error("Trusted comment signature is invalid")]
+    InvalidTrustedComment,
+    #[error("No signing keys are pinned for {0}")]
+    NoPinnedKeys(String),
+    #[error("Invalid signing keys file: {0}")]
+    InvalidKeysFile(String),
+    #[error("Failed to read archive: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PinnedSigningKey {
+    pub label: String,
+    /// Minisign public key, base64 encoded
+    pub public_key: String,
+}
+
+#[derive(Deserialize)]
+struct SigningKeysJsonContent {
+    #[serde(default)]
+    keys: HashMap<String, Vec<PinnedSigningKey>>,
+    /// Binaries whose publisher doesn't sign releases, with the reason
+    #[serde(default)]
+    unsigned: HashMap<String, String>,
+}
+
+/// How releases of a binary are authenticated on top of their checksum
+#[derive(Debug, Clone)]
+pub enum SigningPolicy {
+    /// Only signatures from these keys are accepted
+    Pinned(Vec<PinnedSigningKey>),
+    /// The publisher doesn't sign releases
+    Unsigned { reason: String },
+}
+
+pub fn signing_policy(binary: Binaries) -> Result<SigningPolicy, SignatureError> {
+    parse_signing_policy(SIGNING_KEYS_JSON, binary.name())
+}
+
+/// Reads how `binary_name` is signed. A binary has to be listed either with pinned keys or as
+/// unsigned; anything else is an error rather than a reason to skip verification.
+pub fn parse_signing_policy(
+    json: &str,
+    binary_name: &str,
+) -> Result<SigningPolicy, SignatureError> {
+    let content: SigningKeysJsonContent =
+        serde_json::from_str(json).map_err(|e| SignatureError::InvalidKeysFile(e.to_string()))?;
+    let keys = content
+        .keys
+        .get(binary_name)
+        .filter(|keys| !keys.is_empty());
+    match (keys, content.unsigned.get(binary_name)) {
+        (Some(keys), None) => Ok(SigningPolicy::Pinned(keys.clone())),
+        (None, Some(reason)) => Ok(SigningPolicy::Unsigned {
+            reason: reason.clone(),
+        }),
+        (Some(_), Some(_)) => Err(SignatureError::InvalidKeysFile(format!(
+            "{binary_name} is listed with pinned keys and as unsigned"
+        ))),
+        (None, None) => Err(SignatureError::NoPinnedKeys(binary_name.to_string())),
+    }
+}
+
+fn decode_base64_line<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    what: &'static str,
+) -> Result<Vec<u8>, SignatureError> {
+    let line = lines.next().ok_or(SignatureError::Malformed(what))?;
+    BASE64_STANDARD
+        .decode(line.trim())
+        .map_err(|_| SignatureError::Malformed(what))
+}
+
+fn key_id_hex(key_id: [u8; KEY_ID_LENGTH]) -> String {
+    format!("{:016X}", u64::from_le_bytes(key_id))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinisignPublicKey {
+    key_id: [u8; KEY_ID_LENGTH],
+    key: [u8; PUBLIC_KEY_LENGTH],
+}
+
+impl MinisignPublicKey {
+    /// Accepts either the bare base64 key or the contents of a `.pub` file
+    pub fn decode(encoded: &str) -> Result<Self, SignatureError> {
+        let mut lines = encoded
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with(UNTRUSTED_COMMENT_PREFIX));
+        let bytes = decode_base64_line(&mut lines, "public key")?;
+        if bytes.len() != 2 + KEY_ID_LENGTH + PUBLIC_KEY_LENGTH || bytes[..2] != ALGORITHM_ED25519 {
+            return Err(SignatureError::Malformed("public key"));
+        }
+
+        let mut key_id = [0u8; KEY_ID_LENGTH];
+        key_id.copy_from_slice(&bytes[2..2 + KEY_ID_LENGTH]);
+        let mut key = [0u8; PUBLIC_KEY_LENGTH];
+        key.copy_from_slice(&bytes[2 + KEY_ID_LENGTH..]);
+        Ok(Self { key_id, key })
+    }
+
+    pub fn key_id(&self) -> String {
+        key_id_hex(self.key_id)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        UnparsedPublicKey::new(&ED25519, &self.key)
+            .verify(message, signature)
+            .is_ok()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MinisignSignature {
+    prehashed: bool,
+    key_id: [u8; KEY_ID_LENGTH],
+    signature: [u8; SIGNATURE_LENGTH],
+    trusted_comment: String,
+    global_signature: [u8; SIGNATURE_LENGTH],
+}
+
+impl MinisignSignature {
+    pub fn decode(encoded: &str) -> Result<Self, SignatureError> {
+        let mut lines = encoded
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+        if !lines
+            .next()
+            .is_some_and(|line| line.starts_with(UNTRUSTED_COMMENT_PREFIX))
+        {
+            return Err(SignatureError::Malformed("signature comment"));
+        }
+
+        let bytes = decode_base64_line(&mut lines, "signature")?;
+        if bytes.len() != 2 + KEY_ID_LENGTH + SIGNATURE_LENGTH {
+            return Err(SignatureError::Malformed("signature"));
+        }
+        let prehashed = match [bytes[0], bytes[1]] {
+            ALGORITHM_ED25519 => false,
+            ALGORITHM_ED25519_PREHASHED => true,
+            _ => return Err(SignatureError::UnsupportedAlgorithm),
+        };
+        let mut key_id = [0u8; KEY_ID_LENGTH];
+        key_id.copy_from_slice(&bytes[2..2 + KEY_ID_LENGTH]);
+        let mut signature = [0u8; SIGNATURE_LENGTH];
+        signature.copy_from_slice(&bytes[2 + KEY_ID_LENGTH..]);
+
+        let trusted_comment = lines
+            .next()
+            .and_then(|line| line.strip_prefix(TRUSTED_COMMENT_PREFIX))
+            .ok_or(SignatureError::Malformed("trusted comment"))?
+            .to_string();
+
+        let global_bytes = decode_base64_line(&mut lines, "global signature")?;
+        let global_signature: [u8; SIGNATURE_LENGTH] = global_bytes
+            .try_into()
+            .map_err(|_| SignatureError::Malformed("global signature"))?;
+
+        Ok(Self {
+            prehashed,
+            key_id,
+            signature,
+            trusted_comment,
+            global_signature,
+        })
+    }
+
+    pub fn key_id(&self) -> String {
+        key_id_hex(self.key_id)
+    }
+
+    pub fn trusted_comment(&self) -> &str {
+        &self.trusted_comment
+    }
+
+    /// Whether the signature covers the BLAKE2b-512 digest of the file rather than the file itself
+    pub fn is_prehashed(&self) -> bool {
+        self.prehashed
+    }
+
+    /// Checks the signature over `message` (the file, or its digest when prehashed) and the
+    /// signature over the trusted comment
+    pub fn verify(
+        &self,
+        public_key: &MinisignPublicKey,
+        message: &[u8],
+    ) -> Result<(), SignatureError> {
+        if public_key.key_id != self.key_id {
+            return Err(SignatureError::UnknownKey(self.key_id()));
+        }
+        if !public_key.verify(message, &self.signature) {
+            return Err(SignatureError::InvalidSignature);
+        }
+
+        let mut global_message = self.signature.to_vec();
+        global_message.extend_from_slice(self.trusted_comment.as_bytes());
+        if !public_key.verify(&global_message, &self.global_signature) {
+            return Err(SignatureError::InvalidTrustedComment);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifiedSignature {
+    pub key_id: String,
+    pub key_label: String,
+    pub trusted_comment: String,
+}
+
+async fn blake2b_file_digest(path: &Path) -> Result<Vec<u8>, SignatureError> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Blake2b512::new();
+    let mut buffer = vec![0u8; HASH_CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.finalize().to_vec())
+}
+
+/// Verifies the archive against a detached minisign signature made by one of the pinned keys
+pub async fn verify_file_signature(
+    archive: &Path,
+    signature: &str,
+    pinned_keys: &[PinnedSigningKey],
+) -> Result<VerifiedSignature, SignatureError> {
+    let signature = MinisignSignature::decode(signature)?;
+    let (pinned_key, public_key) = pinned_keys
+        .iter()
+        .filter_map(|pinned| match MinisignPublicKey::decode(&pinned.public_key) {
+            Ok(public_key) => Some((pinned, public_key)),
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Ignoring invalid pinned key {}: {e}", pinned.label);
+                None
+            }
+        })
+        .find(|(_, public_key)| public_key.key_id == signature.key_id)
+        .ok_or_else(|| SignatureError::UnknownKey(signature.key_id()))?;
+
+    let message = if signature.is_prehashed() {
+        blake2b_file_digest(archive).await?
+    } else {
+        tokio::fs::read(archive).await?
+    };
+    signature.verify(&public_key, &message)?;
+
+    Ok(VerifiedSignature {
+        key_id: public_key.key_id(),
+        key_label: pinned_key.label.clone(),
+        trusted_comment: signature.trusted_comment().to_string(),
+    })
+}
+
+/// Which key signed an installed version of a binary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureAuditRecord {
+    pub version: String,
+    pub asset: String,
+    pub key_id: String,
+    pub key_label: String,
+    pub trusted_comment: String,
+    /// Unix timestamp (seconds) of the verification
+    pub verified_at: u64,
+}
+
+fn audit_file_path() -> PathBuf {
+    config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(APPLICATION_FOLDER_ID)
+        .join("app_configs")
+        .join(Network::get_current_or_user_setting_or_default().as_key_str())
+        .join(AUDIT_FILE_NAME)
+}
+
+/// Signature audit records keyed by binary name, oldest first
+pub async fn read_signature_audit() -> HashMap<String, Vec<SignatureAuditRecord>> {
+    match tokio::fs::read_to_string(audit_file_path()).await {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to parse binary signature audit: {e:?}");
+            HashMap::new()
+        }),
+        Err(_) => HashMap::new(),
+    }
+}
+
+pub async fn append_signature_audit(
+    binary: Binaries,
+    record: SignatureAuditRecord,
+) -> Result<(), anyhow::Error> {
+    let mut audit = read_signature_audit().await;
+    audit
+        .entry(binary.name().to_string())
+        .or_default()
+        .push(record);
+
+    let path = audit_file_path();
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(&path, serde_json::to_string_pretty(&audit)?).await?;
+    Ok(())
+}
@@ -0,0 +1,323 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Tests for minisign signature verification of downloaded archives
+
+use base64::prelude::*;
+use blake2::{Blake2b512, Digest};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+
+use crate::binaries::Binaries;
+use crate::binaries::signature_verification::{
+    MinisignPublicKey, MinisignSignature, PinnedSigningKey, SignatureError, SigningPolicy,
+    parse_signing_policy, signing_policy, verify_file_signature,
+};
+use crate::testing::test_utils::TestContext;
+
+const KEY_ID: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+const OTHER_KEY_ID: [u8; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+
+fn generate_key_pair() -> Ed25519KeyPair {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+    Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+}
+
+fn encode_public_key(key_pair: &Ed25519KeyPair, key_id: [u8; 8]) -> String {
+    let mut bytes = b"Ed".to_vec();
+    bytes.extend_from_slice(&key_id);
+    bytes.extend_from_slice(key_pair.public_key().as_ref());
+    BASE64_STANDARD.encode(bytes)
+}
+
+/// Produces a `.minisig` file the same way `minisign -S` does
+fn sign(
+    key_pair: &Ed25519KeyPair,
+    key_id: [u8; 8],
+    data: &[u8],
+    prehashed: bool,
+    trusted_comment: &str,
+) -> String {
+    let (algorithm, signature) = if prehashed {
+        let digest = Blake2b512::digest(data);
+        (b"ED", key_pair.sign(&digest))
+    } else {
+        (b"Ed", key_pair.sign(data))
+    };
+    let mut signature_bytes = algorithm.to_vec();
+    signature_bytes.extend_from_slice(&key_id);
+    signature_bytes.extend_from_slice(signature.as_ref());
+
+    let mut global_message = signature.as_ref().to_vec();
+    global_message.extend_from_slice(trusted_comment.as_bytes());
+    let global_signature = key_pair.sign(&global_message);
+
+    format!(
+        "untrusted comment: signature from minisign secret key\n{}\ntrusted comment: {}\n{}\n",
+        BASE64_STANDARD.encode(signature_bytes),
+        trusted_comment,
+        BASE64_STANDARD.encode(global_signature.as_ref())
+    )
+}
+
+fn pinned(key_pair: &Ed25519KeyPair, key_id: [u8; 8]) -> PinnedSigningKey {
+    PinnedSigningKey {
+        label: "release".to_string(),
+        public_key: encode_public_key(key_pair, key_id),
+    }
+}
+
+// ==================== Decoding ====================
+
+#[test]
+fn public_key_decodes_from_pub_file_contents() {
+    let key_pair = generate_key_pair();
+    let contents = format!(
+        "untrusted comment: minisign public key 0807060504030201\n{}\n",
+        encode_public_key(&key_pair, KEY_ID)
+    );
+
+    let public_key = MinisignPublicKey::decode(&contents).unwrap();
+
+    assert_eq!(public_key.key_id(), "0807060504030201");
+}
+
+#[test]
+fn signature_without_trusted_comment_is_malformed() {
+    let key_pair = generate_key_pair();
+    let signature = sign(&key_pair, KEY_ID, b"archive", false, "timestamp:1");
+    let truncated: String = signature.lines().take(2).collect::<Vec<_>>().join("\n");
+
+    let result = MinisignSignature::decode(&truncated);
+
+    assert!(matches!(result, Err(SignatureError::Malformed(_))));
+}
+
+// ==================== Verification ====================
+
+#[test]
+fn legacy_and_prehashed_signatures_verify() {
+    let key_pair = generate_key_pair();
+    let public_key = MinisignPublicKey::decode(&encode_public_key(&key_pair, KEY_ID)).unwrap();
+
+    let legacy =
+        MinisignSignature::decode(&sign(&key_pair, KEY_ID, b"archive", false, "file:a")).unwrap();
+    assert!(!legacy.is_prehashed());
+    legacy.verify(&public_key, b"archive").unwrap();
+
+    let prehashed =
+        MinisignSignature::decode(&sign(&key_pair, KEY_ID, b"archive", true, "file:a")).unwrap();
+    assert!(prehashed.is_prehashed());
+    prehashed
+        .verify(&public_key, &Blake2b512::digest(b"archive"))
+        .unwrap();
+}
+
+#[test]
+fn tampered_data_is_rejected() {
+    let key_pair = generate_key_pair();
+    let public_key = MinisignPublicKey::decode(&encode_public_key(&key_pair, KEY_ID)).unwrap();
+    let signature =
+        MinisignSignature::decode(&sign(&key_pair, KEY_ID, b"archive", false, "file:a")).unwrap();
+
+    let result = signature.verify(&public_key, b"archivf");
+
+    assert!(matches!(result, Err(SignatureError::InvalidSignature)));
+}
+
+#[test]
+fn tampered_trusted_comment_is_rejected() {
+    let key_pair = generate_key_pair();
+    let public_key = MinisignPublicKey::decode(&encode_public_key(&key_pair, KEY_ID)).unwrap();
+    let encoded = sign(&key_pair, KEY_ID, b"archive", false, "file:a")
+        .replace("trusted comment: file:a", "trusted comment: file:b");
+    let signature = MinisignSignature::decode(&encoded).unwrap();
+
+    let result = signature.verify(&public_key, b"archive");
+
+    assert!(matches!(result, Err(SignatureError::InvalidTrustedComment)));
+}
+
+// ==================== Pinned keys ====================
+
+#[tokio::test]
+async fn archive_signed_by_pinned_key_verifies() {
+    let ctx = TestContext::new();
+    let archive = ctx.data_dir.join("xmrig.tar.gz");
+    tokio::fs::write(&archive, b"archive contents")
+        .await
+        .unwrap();
+    let key_pair = generate_key_pair();
+    let signature = sign(&key_pair, KEY_ID, b"archive contents", true, "xmrig 6.22.0");
+
+    let verified = verify_file_signature(&archive, &signature, &[pinned(&key_pair, KEY_ID)])
+        .await
+        .unwrap();
+
+    assert_eq!(verified.key_label, "release");
+    assert_eq!(verified.key_id, "0807060504030201");
+    assert_eq!(verified.trusted_comment, "xmrig 6.22.0");
+}
+
+#[tokio::test]
+async fn archive_signed_by_unpinned_key_is_rejected() {
+    let ctx = TestContext::new();
+    let archive = ctx.data_dir.join("xmrig.tar.gz");
+    tokio::fs::write(&archive, b"archive contents")
+        .await
+        .unwrap();
+    let pinned_pair = generate_key_pair();
+    let attacker_pair = generate_key_pair();
+    let signature = sign(
+        &attacker_pair,
+        OTHER_KEY_ID,
+        b"archive contents",
+        true,
+        "xmrig",
+    );
+
+    let result = verify_file_signature(&archive, &signature, &[pinned(&pinned_pair, KEY_ID)]).await;
+
+    assert!(matches!(result, Err(SignatureError::UnknownKey(_))));
+}
+
+#[tokio::test]
+async fn attacker_reusing_pinned_key_id_is_rejected() {
+    let ctx = TestContext::new();
+    let archive = ctx.data_dir.join("xmrig.tar.gz");
+    tokio::fs::write(&archive, b"archive contents")
+        .await
+        .unwrap();
+    let pinned_pair = generate_key_pair();
+    let attacker_pair = generate_key_pair();
+    let signature = sign(&attacker_pair, KEY_ID, b"archive contents", true, "xmrig");
+
+    let result = verify_file_signature(&archive, &signature, &[pinned(&pinned_pair, KEY_ID)]).await;
+
+    assert!(matches!(result, Err(SignatureError::InvalidSignature)));
+}
+
+#[tokio::test]
+async fn archive_not_matching_its_signature_is_rejected() {
+    let ctx = TestContext::new();
+    let archive = ctx.data_dir.join("xmrig.tar.gz");
+    tokio::fs::write(&archive, b"swapped archive")
+        .await
+        .unwrap();
+    let key_pair = generate_key_pair();
+    let signature = sign(&key_pair, KEY_ID, b"archive contents", true, "xmrig");
+
+    let result = verify_file_signature(&archive, &signature, &[pinned(&key_pair, KEY_ID)]).await;
+
+    assert!(matches!(result, Err(SignatureError::InvalidSignature)));
+}
+
+#[tokio::test]
+async fn unsigned_archive_is_rejected() {
+    let ctx = TestContext::new();
+    let archive = ctx.data_dir.join("xmrig.tar.gz");
+    tokio::fs::write(&archive, b"archive contents")
+        .await
+        .unwrap();
+    let key_pair = generate_key_pair();
+
+    let result = verify_file_signature(&archive, "", &[pinned(&key_pair, KEY_ID)]).await;
+
+    assert!(matches!(result, Err(SignatureError::Malformed(_))));
+}
+
+// ==================== Keys file ====================
+
+#[test]
+fn binary_without_pinned_keys_fails_verification() {
+    let json =
+        r#"{ "keys": { "tor": [], "xmrig": [{ "label": "release", "public_key": "key" }] } }"#;
+
+    assert!(matches!(
+        parse_signing_policy(json, "tor"),
+        Err(SignatureError::NoPinnedKeys(name)) if name == "tor"
+    ));
+    assert!(matches!(
+        parse_signing_policy(json, "lyrebird"),
+        Err(SignatureError::NoPinnedKeys(name)) if name == "lyrebird"
+    ));
+    assert!(matches!(
+        parse_signing_policy(json, "xmrig"),
+        Ok(SigningPolicy::Pinned(keys)) if keys.len() == 1
+    ));
+}
+
+#[test]
+fn binary_listed_as_unsigned_skips_verification() {
+    let json = r#"{ "keys": {}, "unsigned": { "tor": "not signed upstream" } }"#;
+
+    assert!(matches!(
+        parse_signing_policy(json, "tor"),
+        Ok(SigningPolicy::Unsigned { reason }) if reason == "not signed upstream"
+    ));
+}
+
+#[test]
+fn binary_both_pinned_and_unsigned_is_rejected() {
+    let json = r#"{
+        "keys": { "xmrig": [{ "label": "release", "public_key": "key" }] },
+        "unsigned": { "xmrig": "not signed upstream" }
+    }"#;
+
+    assert!(matches!(
+        parse_signing_policy(json, "xmrig"),
+        Err(SignatureError::InvalidKeysFile(_))
+    ));
+}
+
+#[test]
+fn unreadable_keys_file_fails_verification() {
+    assert!(matches!(
+        parse_signing_policy("not json", "xmrig"),
+        Err(SignatureError::InvalidKeysFile(_))
+    ));
+}
+
+#[test]
+fn shipped_keys_file_covers_every_binary() {
+    for binary in Binaries::all() {
+        match signing_policy(binary) {
+            Ok(SigningPolicy::Pinned(keys)) => {
+                for key in keys {
+                    assert!(
+                        MinisignPublicKey::decode(&key.public_key).is_ok(),
+                        "{} has an unreadable pinned key {}",
+                        binary.name(),
+                        key.label
+                    );
+                }
+            }
+            Ok(SigningPolicy::Unsigned { reason }) => {
+                assert!(
+                    !reason.is_empty(),
+                    "{} is unsigned without a reason",
+                    binary.name()
+                );
+            }
+            Err(e) => panic!("{} has no usable signing policy: {e}", binary.name()),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Active, previous and pinned versions of each installed binary.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use dirs::config_dir;
+use log::{info, warn};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::sync::RwLock;
+
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+use super::Binaries;
+
+/// Consecutive restarts before the process reported healthy that trigger an automatic rollback of a
+/// freshly upgraded binary
+pub const AUTO_ROLLBACK_STARTUP_FAILURES: u64 = 3;
+
+static INSTANCE: LazyLock<RwLock<VersionHistory>> =
+    LazyLock::new(|| RwLock::new(VersionHistory::new()));
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BinaryVersionState {
+    pub active: Option<String>,
+    /// Version that was active before `active`
+    pub previous: Option<String>,
+    pub pinned: Option<String>,
+    /// Unix timestamp of the upgrade to `active`, cleared once it started up healthy
+    pub pending_upgrade_since: Option<u64>,
+    /// Version that was abandoned by the last rollback
+    pub rolled_back_from: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BinaryVersionsInfo {
+    pub binary: String,
+    /// Version pinned in the embedded versions file of this release
+    pub release_version: String,
+    pub active_version: String,
+    /// Oldest first
+    pub installed_versions: Vec<String>,
+    /// A local executable override is used instead of the installed versions
+    pub is_overridden: bool,
+    #[serde(flatten)]
+    pub state: BinaryVersionState,
+}
+
+/// Orders release versions, falling back to comparing numeric segments for non-semver versions
+/// such as lolMiner's `1.98a` or Tor's `14.5.1`
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parse = |v: &str| Version::parse(v.trim_start_matches('v')).ok();
+    if let (Some(a), Some(b)) = (parse(a), parse(b)) {
+        return a.cmp(&b);
+    }
+
+    let segments = |v: &str| -> Vec<u64> {
+        v.split(|c: char| !c.is_ascii_digit())
+            .filter(|segment| !segment.is_empty())
+            .filter_map(|segment| segment.parse().ok())
+            .collect()
+    };
+    segments(a).cmp(&segments(b)).then_with(|| a.cmp(b))
+}
+
+/// Records `version` as active. Moving to a newer version starts the upgrade grace period in which
+/// startup failures roll back to `previous`. Returns whether the state changed.
+pub fn apply_activation(state: &mut BinaryVersionState, version: &str, now: u64) -> bool {
+    if state.active.as_deref() == Some(version) {
+        return false;
+    }
+
+    let is_upgrade = state
+        .active
+        .as_deref()
+        .is_some_and(|active| compare_versions(version, active) == Ordering::Greater);
+    state.previous = state.active.replace(version.to_string());
+    state.pending_upgrade_since = is_upgrade.then_some(now);
+    true
+}
+
+/// Version to roll back to: the one active before the current if it is older, or else the newest
+/// older install
+pub fn rollback_target(state: &BinaryVersionState, installed: &[String]) -> Option<String> {
+    let active = state.active.as_deref()?;
+    if let Some(previous) = &state.previous
+        && compare_versions(previous, active) == Ordering::Less
+        && installed.contains(previous)
+    {
+        return Some(previous.clone());
+    }
+
+    installed
+        .iter()
+        .filter(|version| compare_versions(version, active) == Ordering::Less)
+        .max_by(|a, b| compare_versions(a, b))
+        .cloned()
+}
+
+pub struct VersionHistory {
+    states: HashMap<String, BinaryVersionState>,
+    history_path: PathBuf,
+}
+
+impl VersionHistory {
+    fn new() -> Self {
+        let history_path = Self::_get_history_path();
+        let states = Self::_load(&history_path);
+        Self {
+            states,
+            history_path,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    fn _get_history_path() -> PathBuf {
+        let config_dir = config_dir().unwrap_or_else(std::env::temp_dir);
+        config_dir
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str())
+            .join("binary_versions.json")
+    }
+
+    fn _load(path: &PathBuf) -> HashMap<String, BinaryVersionState> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to parse binary version history: {e:?}");
+                HashMap::new()
+            }),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    async fn _save(&self) -> Result<(), anyhow::Error> {
+        if let Some(parent) = self.history_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let contents = serde_json::to_string_pretty(&self.states)?;
+        tokio::fs::write(&self.history_path, contents).await?;
+        Ok(())
+    }
+
+    async fn _update<F>(binary: Binaries, update: F) -> Result<(), anyhow::Error>
+    where
+        F: FnOnce(&mut BinaryVersionState) -> bool,
+    {
+        let mut history = Self::current().write().await;
+        let state = history.states.entry(binary.name().to_string()).or_default();
+        if update(state) {
+            history._save().await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get(binary: Binaries) -> BinaryVersionState {
+        Self::current()
+            .read()
+            .await
+            .states
+            .get(binary.name())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub async fn record_activation(binary: Binaries, version: &str) -> Result<(), anyhow::Error> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        Self::_update(binary, |state| {
+            let previous = state.active.clone();
+            let changed = apply_activation(state, version, now);
+            if changed {
+                info!(target: LOG_TARGET_APP_LOGIC, "Activated {} version {version} (was {previous:?})", binary.name());
+            }
+            changed
+        })
+        .await
+    }
+
+    /// The active version started up healthy, so it is no longer a rollback candidate
+    pub async fn confirm_active(binary: Binaries) -> Result<(), anyhow::Error> {
+        Self::_update(binary, |state| state.pending_upgrade_since.take().is_some()).await
+    }
+
+    /// Explicit pins replace any pin left by a rollback
+    pub async fn set_pinned(
+        binary: Binaries,
+        version: Option<String>,
+    ) -> Result<(), anyhow::Error> {
+        Self::_update(binary, |state| {
+            let changed = state.pinned != version || state.rolled_back_from.is_some();
+            state.pinned = version;
+            state.rolled_back_from = None;
+            changed
+        })
+        .await
+    }
+
+    pub async fn record_rollback(
+        binary: Binaries,
+        from: String,
+        to: String,
+    ) -> Result<(), anyhow::Error> {
+        Self::_update(binary, |state| {
+            state.pinned = Some(to);
+            state.rolled_back_from = Some(from);
+            state.pending_upgrade_since = None;
+            true
+        })
+        .await
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Tests for binary version ordering, activation and rollback selection
+
+use std::cmp::Ordering;
+
+use crate::binaries::version_history::{
+    BinaryVersionState, apply_activation, compare_versions, rollback_target,
+};
+
+fn installed(versions: &[&str]) -> Vec<String> {
+    versions.iter().map(|v| v.to_string()).collect()
+}
+
+// ==================== Ordering ====================
+
+#[test]
+fn semver_versions_are_ordered_numerically() {
+    assert_eq!(compare_versions("4.10.0", "4.9.1"), Ordering::Greater);
+    assert_eq!(compare_versions("4.1.0-pre.1", "4.1.0"), Ordering::Less);
+    assert_eq!(compare_versions("v6.22.2", "6.22.2"), Ordering::Equal);
+}
+
+#[test]
+fn non_semver_versions_fall_back_to_numeric_segments() {
+    assert_eq!(compare_versions("1.98", "1.97"), Ordering::Greater);
+    assert_eq!(compare_versions("1.98a", "1.98"), Ordering::Greater);
+    assert_eq!(compare_versions("14.5.1", "15.0"), Ordering::Less);
+}
+
+// ==================== Activation ====================
+
+#[test]
+fn first_activation_is_not_an_upgrade() {
+    let mut state = BinaryVersionState::default();
+
+    assert!(apply_activation(&mut state, "4.1.0", 100));
+
+    assert_eq!(state.active.as_deref(), Some("4.1.0"));
+    assert_eq!(state.previous, None);
+    assert_eq!(state.pending_upgrade_since, None);
+}
+
+#[test]
+fn newer_version_starts_upgrade_grace_period() {
+    let mut state = BinaryVersionState::default();
+    apply_activation(&mut state, "4.1.0", 100);
+
+    assert!(apply_activation(&mut state, "4.2.0", 200));
+    assert!(!apply_activation(&mut state, "4.2.0", 300));
+
+    assert_eq!(state.previous.as_deref(), Some("4.1.0"));
+    assert_eq!(state.pending_upgrade_since, Some(200));
+}
+
+#[test]
+fn downgrade_is_not_an_upgrade() {
+    let mut state = BinaryVersionState::default();
+    apply_activation(&mut state, "4.2.0", 100);
+
+    apply_activation(&mut state, "4.1.0", 200);
+
+    assert_eq!(state.previous.as_deref(), Some("4.2.0"));
+    assert_eq!(state.pending_upgrade_since, None);
+}
+
+// ==================== Rollback ====================
+
+#[test]
+fn rollback_prefers_previously_active_version() {
+    let state = BinaryVersionState {
+        active: Some("4.2.0".to_string()),
+        previous: Some("4.0.0".to_string()),
+        ..Default::default()
+    };
+
+    let target = rollback_target(&state, &installed(&["4.0.0", "4.1.0", "4.2.0"]));
+
+    assert_eq!(target.as_deref(), Some("4.0.0"));
+}
+
+#[test]
+fn rollback_falls_back_to_newest_older_install() {
+    let state = BinaryVersionState {
+        active: Some("4.2.0".to_string()),
+        previous: Some("4.0.0".to_string()),
+        ..Default::default()
+    };
+
+    let target = rollback_target(&state, &installed(&["3.9.0", "4.1.0", "4.2.0", "4.3.0"]));
+
+    assert_eq!(target.as_deref(), Some("4.1.0"));
+}
+
+#[test]
+fn rollback_never_moves_forward() {
+    let state = BinaryVersionState {
+        active: Some("4.1.0".to_string()),
+        previous: Some("4.2.0".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        rollback_target(&state, &installed(&["4.1.0", "4.2.0"])),
+        None
+    );
+}
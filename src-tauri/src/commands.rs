@@ -20,9 +20,12 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::airdrop::{get_der_encode_pub_key, get_websocket_key};
+use crate::airdrop::{get_websocket_key, sign_with_key};
 use crate::app_in_memory_config::{AppInMemoryConfig, DEFAULT_EXCHANGE_ID, ExchangeMiner};
 use crate::auto_launcher::AutoLauncher;
+use crate::binaries::binary_overrides::BinaryOverride;
+use crate::binaries::signature_verification::{SignatureAuditRecord, read_signature_audit};
+use crate::binaries::version_history::BinaryVersionsInfo;
 use crate::binaries::{Binaries, BinaryResolver};
 use crate::configs::config_core::{AirdropTokens, ConfigCore, ConfigCoreContent};
 use crate::configs::config_mining::{
@@ -34,11 +37,15 @@ use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent, WalletId}
 use crate::configs::pools::BasePoolData;
 use crate::configs::pools::{cpu_pools::CpuPool, gpu_pools::GpuPool};
 use crate::configs::trait_config::ConfigImpl;
+use crate::credential_manager::CredentialBackend;
 use crate::event_scheduler::{EventScheduler, SchedulerEventTiming, SchedulerEventType};
 use crate::events::ConnectionStatusPayload;
 use crate::events_emitter::EventsEmitter;
 use crate::events_manager::EventsManager;
 use crate::internal_wallet::{InternalWallet, PaperWalletConfig, mnemonic_to_tari_cipher_seed};
+use crate::mining::block_ledger::{
+    BlockLedger, BlockLedgerSummary, MinedBlockEntry, MinedBlockStatus,
+};
 use crate::mining::cpu::manager::CpuManager;
 use crate::mining::gpu::manager::GpuManager;
 use crate::mining::pools::PoolManagerInterfaceTrait;
@@ -47,7 +54,15 @@ use crate::mining::pools::gpu_pool_manager::GpuPoolManager;
 use crate::node::node_adapter::BaseNodeStatus;
 use crate::node::node_manager::NodeType;
 use crate::pin::PinManager;
+use crate::port_registry::{
+    PortAssignment, ServicePort, ServicePortInfo, service_port_map, validate_pin,
+};
+use crate::process_inventory::{ProcessControlAction, ProcessInventoryEntry, process_inventory};
+use crate::process_output::{OutputFilter, OutputLine, tail_process_output};
+use crate::process_resource_limits::ResourceLimits;
+use crate::process_restart_policy::close_circuit;
 use crate::release_notes::ReleaseNotes;
+use crate::requests::connectivity::{self, OutboundConnectivity};
 use crate::setup::setup_manager::{SetupManager, SetupPhase};
 use crate::shutdown_manager::{ShutdownManager, ShutdownMode};
 use crate::system_dependencies::system_dependencies_manager::SystemDependenciesManager;
@@ -55,15 +70,20 @@ use crate::systemtray_manager::{SystemTrayEvents, SystemTrayManager};
 use crate::tapplets::interface::ActiveTapplet;
 use crate::tapplets::tapplet_server::start_tapplet;
 use crate::tasks_tracker::TasksTrackers;
+use crate::telemetry_sink::{TelemetryInspection, TelemetrySink};
 use crate::tor_adapter::TorConfig;
+use crate::tor_control_client::TorStatus;
+use crate::tor_manager::TorConfigUpdate;
 use crate::utils::address_utils::verify_send;
 use crate::utils::app_flow_utils::FrontendReadyChannel;
-use crate::wallet::wallet_manager::WalletManagerError;
+use crate::wallet::payment_requests::{
+    Invoice, InvoiceBook, InvoiceStatus, PaymentRequestUri, render_qr_svg,
+};
+use crate::wallet::transaction_export::{ExportFilter, ExportFormat, ExportSummary};
+use crate::wallet::wallet_manager::{WalletManagerError, parse_minotari_amount};
 use crate::wallet::wallet_types::{TariAddressVariants, TransactionInfo};
 use crate::{LOG_TARGET_APP_LOGIC, UniverseAppState, airdrop};
 
-use base64::prelude::*;
-
 use crate::node::data_location::update_data_location;
 use log::{debug, error, info, warn};
 use regex::Regex;
@@ -113,6 +133,12 @@ pub struct SignWsDataResponse {
     pub pub_key: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct PaymentRequestResponse {
+    pub invoice: Invoice,
+    pub qr_svg: Option<String>,
+}
+
 #[tauri::command]
 pub async fn select_exchange_miner(
     app_handle: tauri::AppHandle,
@@ -223,6 +249,9 @@ pub async fn exit_application(
     crate::mcp::server::McpServerManager::stop().await;
     info!(target: LOG_TARGET_APP_LOGIC, "MCP server stopped.");
 
+    crate::fleet::stop().await;
+    info!(target: LOG_TARGET_APP_LOGIC, "Fleet mode stopped.");
+
     let _unused = GpuManager::write().await.stop_mining().await;
     info!(target: LOG_TARGET_APP_LOGIC, "GPU Mining stopped.");
 
@@ -243,7 +272,10 @@ pub async fn exit_application(
 #[tauri::command]
 pub async fn fetch_tor_bridges() -> Result<Vec<String>, String> {
     let timer = Instant::now();
-    let res_html = reqwest::get("https://bridges.torproject.org/bridges?transport=obfs4")
+    let res_html = connectivity::client()
+        .map_err(|e| e.to_string())?
+        .get("https://bridges.torproject.org/bridges?transport=obfs4")
+        .send()
         .await
         .map_err(|e| e.to_string())?
         .text()
@@ -545,6 +577,18 @@ pub async fn get_tor_config(
     Ok(tor_config)
 }
 
+#[tauri::command]
+pub async fn get_tor_status(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<TorStatus, String> {
+    let timer = Instant::now();
+    let tor_status = state.tor_manager.get_status();
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_tor_status took too long: {:?}", timer.elapsed());
+    }
+    Ok(tor_status)
+}
+
 #[tauri::command]
 pub async fn get_airdrop_tokens(
     _window: tauri::Window,
@@ -584,9 +628,92 @@ pub async fn get_transactions(
     Ok(transactions)
 }
 
+#[tauri::command]
+pub async fn export_transaction_history(
+    state: tauri::State<'_, UniverseAppState>,
+    app_handle: tauri::AppHandle,
+    format: ExportFormat,
+    from_timestamp: Option<u64>,
+    to_timestamp: Option<u64>,
+    destination: Option<String>,
+) -> Result<ExportSummary, String> {
+    let timer = Instant::now();
+    let path = match destination {
+        Some(destination) => std::path::PathBuf::from(destination),
+        None => {
+            let export_dir = app_handle
+                .path()
+                .download_dir()
+                .or_else(|_| app_handle.path().app_local_data_dir())
+                .map_err(|e| e.to_string())?;
+            export_dir.join(format!(
+                "tari-universe-transactions-{}.{}",
+                chrono::Local::now().format("%Y%m%d-%H%M%S"),
+                format.extension()
+            ))
+        }
+    };
+    info!(target: LOG_TARGET_APP_LOGIC, "[export_transaction_history] exporting {format:?} to {}", path.display());
+
+    let summary = state
+        .wallet_manager
+        .export_transaction_history(
+            &path,
+            format,
+            ExportFilter {
+                from_timestamp,
+                to_timestamp,
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "export_transaction_history took too long: {:?}", timer.elapsed());
+    }
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn get_mined_blocks(
+    offset: Option<usize>,
+    limit: Option<usize>,
+    status: Option<MinedBlockStatus>,
+) -> Result<Vec<MinedBlockEntry>, String> {
+    let timer = Instant::now();
+    let blocks =
+        BlockLedger::get_blocks(offset.unwrap_or(0), limit.unwrap_or(usize::MAX), status).await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_mined_blocks took too long: {:?}", timer.elapsed());
+    }
+    Ok(blocks)
+}
+
+#[tauri::command]
+pub async fn get_mined_blocks_summary() -> Result<BlockLedgerSummary, String> {
+    let timer = Instant::now();
+    let summary = BlockLedger::get_summary().await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_mined_blocks_summary took too long: {:?}", timer.elapsed());
+    }
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn get_binary_signature_audit()
+-> Result<HashMap<String, Vec<SignatureAuditRecord>>, String> {
+    let timer = Instant::now();
+    let audit = read_signature_audit().await;
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_binary_signature_audit took too long: {:?}", timer.elapsed());
+    }
+    Ok(audit)
+}
+
 #[tauri::command]
 pub async fn forgot_pin(
     seed_words: Vec<String>,
+    reset_credential_vault: Option<bool>,
     app_handle: tauri::AppHandle,
 ) -> Result<(), String> {
     let tari_cipher_seed = mnemonic_to_tari_cipher_seed(seed_words)
@@ -605,9 +732,13 @@ pub async fn forgot_pin(
         return Err("Seed words do not match".to_string());
     }
 
-    InternalWallet::recover_forgotten_pin(&app_handle, tari_cipher_seed)
-        .await
-        .map_err(|e| e.to_string())?;
+    InternalWallet::recover_forgotten_pin(
+        &app_handle,
+        tari_cipher_seed,
+        reset_credential_vault.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     info!(target: LOG_TARGET_APP_LOGIC, "PIN recovery completed successfully");
     Ok(())
@@ -916,6 +1047,47 @@ pub async fn set_allow_telemetry(allow_telemetry: bool) -> Result<(), InvokeErro
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_telemetry_sink(telemetry_sink: TelemetrySink) -> Result<(), InvokeError> {
+    telemetry_sink
+        .validate()
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigCore::update_field(ConfigCoreContent::set_telemetry_sink, telemetry_sink)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_telemetry_redacted_fields(
+    telemetry_redacted_fields: Vec<String>,
+) -> Result<(), InvokeError> {
+    let mut fields: Vec<String> = telemetry_redacted_fields
+        .into_iter()
+        .map(|field| field.trim().to_string())
+        .filter(|field| !field.is_empty())
+        .collect();
+    fields.sort();
+    fields.dedup();
+    ConfigCore::update_field(ConfigCoreContent::set_telemetry_redacted_fields, fields)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn inspect_telemetry_data(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<TelemetryInspection, InvokeError> {
+    state
+        .telemetry_service
+        .read()
+        .await
+        .inspect()
+        .await
+        .map_err(|e| InvokeError::from(e.to_string()))
+}
+
 #[tauri::command]
 pub async fn set_allow_notifications(allow_notifications: bool) -> Result<(), InvokeError> {
     ConfigCore::update_field(
@@ -979,19 +1151,13 @@ pub async fn sign_ws_data(data: String) -> Result<SignWsDataResponse, String> {
         );
         "sign_ws_data: error ws key handling"
     })?;
-    let pub_key = get_der_encode_pub_key(&key).map_err(|e| {
+
+    sign_with_key(&key, &data).map_err(|e| {
         warn!(target: LOG_TARGET_APP_LOGIC,
             "error ws pub key handling:{:?}",
             e.to_string()
         );
-        "sign_ws_data: error ws pub key handling"
-    })?;
-
-    let signature = key.sign(data.as_bytes());
-
-    Ok(SignWsDataResponse {
-        signature: BASE64_STANDARD.encode(signature.as_ref()),
-        pub_key,
+        "sign_ws_data: error ws pub key handling".to_string()
     })
 }
 
@@ -1147,6 +1313,277 @@ pub async fn set_monero_address(monero_address: String) -> Result<(), InvokeErro
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_binary_override(
+    binary: String,
+    binary_override: Option<BinaryOverride>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_binary_override] called with binary: {binary}, override: {binary_override:?}");
+    let binary = Binaries::try_from_name(&binary)
+        .ok_or_else(|| InvokeError::from_anyhow(anyhow::anyhow!("Unknown binary: {binary}")))?;
+    if let Some(binary_override) = &binary_override {
+        binary_override
+            .validate()
+            .map_err(InvokeError::from_anyhow)?;
+    }
+
+    let mut binary_overrides = ConfigCore::content().await.binary_overrides().clone();
+    match binary_override {
+        Some(binary_override) => {
+            binary_overrides.insert(binary.name().to_string(), binary_override);
+        }
+        None => {
+            binary_overrides.remove(binary.name());
+        }
+    }
+
+    ConfigCore::update_field_requires_restart(
+        ConfigCoreContent::set_binary_overrides,
+        binary_overrides,
+        vec![binary.setup_phase()],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue()
+        .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_binary_override took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_process_resource_limits(
+    binary: String,
+    resource_limits: Option<ResourceLimits>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_process_resource_limits] called with binary: {binary}, limits: {resource_limits:?}");
+    let binary = Binaries::try_from_name(&binary)
+        .ok_or_else(|| InvokeError::from_anyhow(anyhow::anyhow!("Unknown binary: {binary}")))?;
+    if let Some(resource_limits) = &resource_limits {
+        resource_limits
+            .validate()
+            .map_err(InvokeError::from_anyhow)?;
+    }
+
+    let mut process_resource_limits = ConfigCore::content()
+        .await
+        .process_resource_limits()
+        .clone();
+    match resource_limits.filter(|limits| !limits.is_empty()) {
+        Some(resource_limits) => {
+            process_resource_limits.insert(binary.name().to_string(), resource_limits);
+        }
+        None => {
+            process_resource_limits.remove(binary.name());
+        }
+    }
+
+    ConfigCore::update_field_requires_restart(
+        ConfigCoreContent::set_process_resource_limits,
+        process_resource_limits,
+        vec![binary.setup_phase()],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue()
+        .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_process_resource_limits took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_service_ports() -> Result<Vec<ServicePortInfo>, InvokeError> {
+    let timer = Instant::now();
+    let port_map = service_port_map().await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_service_ports took too long: {:?}", timer.elapsed());
+    }
+    Ok(port_map)
+}
+
+#[tauri::command]
+pub async fn set_service_port(service: String, port: Option<u16>) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_service_port] called with service: {service}, port: {port:?}");
+    let service = ServicePort::try_from_name(&service)
+        .ok_or_else(|| InvokeError::from_anyhow(anyhow::anyhow!("Unknown service: {service}")))?;
+
+    let mut service_ports = ConfigCore::content().await.service_ports().clone();
+    match port {
+        Some(port) => {
+            validate_pin(&service_ports, service, port).map_err(InvokeError::from_anyhow)?;
+            service_ports.insert(
+                service.name().to_string(),
+                PortAssignment { port, pinned: true },
+            );
+        }
+        None => {
+            // The current port is kept until it's taken by something else
+            if let Some(assignment) = service_ports.get_mut(service.name()) {
+                assignment.pinned = false;
+            }
+        }
+    }
+
+    ConfigCore::update_field_requires_restart(
+        ConfigCoreContent::set_service_ports,
+        service_ports,
+        vec![service.binary().setup_phase()],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue()
+        .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_service_port took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_process_inventory() -> Result<Vec<ProcessInventoryEntry>, InvokeError> {
+    let timer = Instant::now();
+    let inventory = process_inventory().await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_process_inventory took too long: {:?}", timer.elapsed());
+    }
+    Ok(inventory)
+}
+
+#[tauri::command]
+pub async fn control_process(
+    app: tauri::AppHandle,
+    process: String,
+    action: ProcessControlAction,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[control_process] called with process: {process}, action: {action}");
+    crate::process_inventory::control_process(&app, &process, action)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "control_process took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_binary_versions() -> Result<Vec<BinaryVersionsInfo>, InvokeError> {
+    let timer = Instant::now();
+    let resolver = BinaryResolver::current();
+    let mut versions = Vec::new();
+    for binary in Binaries::all() {
+        versions.push(
+            resolver
+                .get_versions_info(binary)
+                .await
+                .map_err(InvokeError::from_anyhow)?,
+        );
+    }
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_binary_versions took too long: {:?}", timer.elapsed());
+    }
+    Ok(versions)
+}
+
+#[tauri::command]
+pub async fn pin_binary_version(
+    binary: String,
+    version: Option<String>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[pin_binary_version] called with binary: {binary}, version: {version:?}");
+    let binary = Binaries::try_from_name(&binary)
+        .ok_or_else(|| InvokeError::from_anyhow(anyhow::anyhow!("Unknown binary: {binary}")))?;
+    BinaryResolver::current()
+        .pin_version(binary, version)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases(vec![binary.setup_phase()])
+        .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "pin_binary_version took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn rollback_binary_version(binary: String) -> Result<String, InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[rollback_binary_version] called with binary: {binary}");
+    let binary = Binaries::try_from_name(&binary)
+        .ok_or_else(|| InvokeError::from_anyhow(anyhow::anyhow!("Unknown binary: {binary}")))?;
+    let version = BinaryResolver::current()
+        .rollback(binary)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases(vec![binary.setup_phase()])
+        .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "rollback_binary_version took too long: {:?}", timer.elapsed());
+    }
+    Ok(version)
+}
+
+#[tauri::command]
+pub async fn reset_process_circuit_breaker(binary: String) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[reset_process_circuit_breaker] called with binary: {binary}");
+    let binary = Binaries::try_from_name(&binary)
+        .ok_or_else(|| InvokeError::from_anyhow(anyhow::anyhow!("Unknown binary: {binary}")))?;
+    if close_circuit(binary).await {
+        SetupManager::get_instance()
+            .restart_phases(vec![binary.setup_phase()])
+            .await;
+    } else {
+        info!(target: LOG_TARGET_APP_LOGIC, "Circuit breaker for {} is not open", binary.name());
+    }
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "reset_process_circuit_breaker took too long: {:?}", timer.elapsed());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_process_output(
+    process: String,
+    filter: Option<OutputFilter>,
+) -> Result<Vec<OutputLine>, InvokeError> {
+    let timer = Instant::now();
+    let output = tail_process_output(&process, &filter.unwrap_or_default())
+        .await
+        .ok_or_else(|| {
+            InvokeError::from_anyhow(anyhow::anyhow!("No captured output for process: {process}"))
+        })?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "get_process_output took too long: {:?}", timer.elapsed());
+    }
+    Ok(output)
+}
+
 #[tauri::command]
 pub async fn set_monerod_config(
     use_monero_fail: bool,
@@ -1234,15 +1671,24 @@ pub async fn set_tor_config(
 ) -> Result<TorConfig, String> {
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[set_tor_config] called with config: {config:?}");
-    let tor_config = state
+    let (tor_config, update) = state
         .tor_manager
         .set_tor_config(config)
         .await
         .map_err(|e| e.to_string())?;
 
-    SetupManager::get_instance()
-        .restart_phases(vec![SetupPhase::Node, SetupPhase::Wallet])
-        .await;
+    match update {
+        TorConfigUpdate::Saved => {}
+        TorConfigUpdate::AppliedLive => {
+            state.node_manager.on_tor_reconfigured().await;
+            state.wallet_manager.on_tor_reconfigured().await;
+        }
+        TorConfigUpdate::RestartRequired => {
+            SetupManager::get_instance()
+                .restart_phases(vec![SetupPhase::Node, SetupPhase::Wallet])
+                .await;
+        }
+    }
 
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
         warn!(target: LOG_TARGET_APP_LOGIC, "set_tor_config took too long: {:?}", timer.elapsed());
@@ -1283,6 +1729,55 @@ pub async fn set_use_tor(use_tor: bool, app_handle: tauri::AppHandle) -> Result<
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_outbound_connectivity(
+    outbound_connectivity: OutboundConnectivity,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    outbound_connectivity
+        .validate()
+        .map_err(InvokeError::from_anyhow)?;
+    if outbound_connectivity == OutboundConnectivity::Tor {
+        let uses_external_tor = state
+            .tor_manager
+            .get_tor_config()
+            .await
+            .external()
+            .is_some();
+        if cfg!(target_os = "macos") && !uses_external_tor {
+            return Err(InvokeError::from(
+                "Tor has no SOCKS port on macOS without a system Tor".to_string(),
+            ));
+        }
+        if !*ConfigCore::content().await.use_tor() {
+            return Err(InvokeError::from(
+                "Tor must be enabled to route traffic through it".to_string(),
+            ));
+        }
+    }
+
+    // The CPU miner takes its proxy on the command line and the GPU miner can't run through Tor
+    ConfigCore::update_field_requires_restart(
+        ConfigCoreContent::set_outbound_connectivity,
+        outbound_connectivity.clone(),
+        vec![SetupPhase::CpuMining, SetupPhase::GpuMining],
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    connectivity::set_connectivity(outbound_connectivity);
+
+    SetupManager::get_instance()
+        .restart_phases_from_queue()
+        .await;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "set_outbound_connectivity took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_visual_mode(enabled: bool) -> Result<(), InvokeError> {
     let timer = Instant::now();
@@ -1800,6 +2295,19 @@ pub async fn create_pin(app_handle: tauri::AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_credential_backend(
+    app_handle: tauri::AppHandle,
+    backend: CredentialBackend,
+) -> Result<usize, String> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_credential_backend] called with backend: {backend:?}");
+    let migrated = InternalWallet::migrate_credential_backend(&app_handle, backend)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_wallet_config_loaded(&ConfigWallet::content().await).await;
+    Ok(migrated)
+}
+
 #[tauri::command]
 pub async fn set_seed_backed_up() -> Result<(), String> {
     ConfigWallet::update_field(ConfigWalletContent::set_seed_backed_up, true)
@@ -1987,6 +2495,57 @@ pub async fn encode_payment_id_to_address(
     Ok(address_base58)
 }
 
+#[tauri::command]
+pub async fn create_payment_request(
+    amount: String,
+    payment_id: Option<String>,
+    description: Option<String>,
+    required_confirmations: Option<u64>,
+    include_qr: Option<bool>,
+) -> Result<PaymentRequestResponse, String> {
+    let timer = Instant::now();
+    info!(target: LOG_TARGET_APP_LOGIC, "[create_payment_request] called with args: (amount: {amount:?}, payment_id: {payment_id:?})");
+    let amount = parse_minotari_amount(&amount).map_err(|e| e.to_string())?;
+    let tari_address = InternalWallet::tari_address().await;
+    let invoice = InvoiceBook::create_invoice(
+        &tari_address,
+        amount,
+        payment_id,
+        description,
+        required_confirmations,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let qr_svg = if include_qr.unwrap_or(false) {
+        Some(render_qr_svg(&invoice.uri).map_err(|e| e.to_string())?)
+    } else {
+        None
+    };
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET_APP_LOGIC, "create_payment_request took too long: {:?}", timer.elapsed());
+    }
+    Ok(PaymentRequestResponse { invoice, qr_svg })
+}
+
+#[tauri::command]
+pub async fn parse_payment_request_uri(uri: String) -> Result<PaymentRequestUri, String> {
+    PaymentRequestUri::parse(&uri).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_invoices(status: Option<InvoiceStatus>) -> Result<Vec<Invoice>, String> {
+    Ok(InvoiceBook::get_invoices(status).await)
+}
+
+#[tauri::command]
+pub async fn cancel_invoice(id: String) -> Result<Invoice, String> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[cancel_invoice] called with id: {id:?}");
+    InvoiceBook::cancel_invoice(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn save_wxtm_address(address: String, exchange_id: String) -> Result<(), String> {
     ConfigWallet::update_field(
@@ -34,9 +34,14 @@ use tokio::sync::RwLock;
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::ab_test_selector::ABTestSelector;
 use crate::app_in_memory_config::{DEFAULT_EXCHANGE_ID, MinerType};
+use crate::binaries::binary_overrides::BinaryOverride;
 use crate::event_scheduler::ScheduledEventInfo;
 use crate::node::node_manager::NodeType;
+use crate::port_registry::PortAssignment;
+use crate::process_resource_limits::ResourceLimits;
+use crate::requests::connectivity::OutboundConnectivity;
 use crate::shutdown_manager::ShutdownMode;
+use crate::telemetry_sink::{DEFAULT_SPOOL_MAX_BYTES, TelemetrySink};
 use crate::utils::rand_utils;
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
@@ -77,6 +82,17 @@ pub struct ConfigCoreContent {
     scheduler_events: HashMap<String, ScheduledEventInfo>,
     shutdown_mode: ShutdownMode,
     node_data_directory: Option<PathBuf>,
+    /// Keyed by `Binaries::name()`
+    binary_overrides: HashMap<String, BinaryOverride>,
+    /// Keyed by `Binaries::name()`
+    process_resource_limits: HashMap<String, ResourceLimits>,
+    /// Keyed by `ServicePort::name()`
+    service_ports: HashMap<String, PortAssignment>,
+    outbound_connectivity: OutboundConnectivity,
+    telemetry_sink: TelemetrySink,
+    /// Dotted field paths nulled out before telemetry leaves the app
+    telemetry_redacted_fields: Vec<String>,
+    telemetry_spool_max_bytes: u64,
 }
 
 fn default_monero_nodes() -> Vec<String> {
@@ -132,6 +148,13 @@ impl Default for ConfigCoreContent {
             scheduler_events: HashMap::new(),
             shutdown_mode: ShutdownMode::Tasktray,
             node_data_directory: None,
+            binary_overrides: HashMap::new(),
+            process_resource_limits: HashMap::new(),
+            service_ports: HashMap::new(),
+            outbound_connectivity: OutboundConnectivity::default(),
+            telemetry_sink: TelemetrySink::default(),
+            telemetry_redacted_fields: Vec::new(),
+            telemetry_spool_max_bytes: DEFAULT_SPOOL_MAX_BYTES,
         }
     }
 }
@@ -0,0 +1,138 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Settings for fleet mode, where rigs report to and take commands from a self-hosted controller.
+
+use super::trait_config::{ConfigContentImpl, ConfigImpl};
+use getset::{Getters, Setters};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use std::time::SystemTime;
+use tauri::AppHandle;
+use tokio::sync::RwLock;
+
+static INSTANCE: LazyLock<RwLock<ConfigFleet>> = LazyLock::new(|| RwLock::new(ConfigFleet::new()));
+
+pub const DEFAULT_FLEET_LISTEN_ADDRESS: SocketAddr =
+    SocketAddr::new(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST), 18190);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FleetRole {
+    #[default]
+    Disabled,
+    Rig,
+    Controller,
+}
+
+/// A rig the controller accepts connections from, identified by its app id and pinned public key
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FleetRig {
+    pub rig_id: String,
+    pub name: String,
+    pub pub_key: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+#[serde(default)]
+#[derive(Getters, Setters)]
+#[getset(get = "pub", set = "pub")]
+pub struct ConfigFleetContent {
+    created_at: SystemTime,
+    role: FleetRole,
+    // Rig role: where to connect and which controller key to accept commands from
+    controller_url: Option<String>,
+    controller_pub_key: Option<String>,
+    // Controller role: where to listen and which rigs are enrolled
+    listen_address: SocketAddr,
+    rigs: Vec<FleetRig>,
+}
+
+impl Default for ConfigFleetContent {
+    fn default() -> Self {
+        Self {
+            created_at: SystemTime::now(),
+            role: FleetRole::Disabled,
+            controller_url: None,
+            controller_pub_key: None,
+            listen_address: DEFAULT_FLEET_LISTEN_ADDRESS,
+            rigs: Vec::new(),
+        }
+    }
+}
+
+impl ConfigContentImpl for ConfigFleetContent {}
+
+impl ConfigFleetContent {
+    pub fn find_rig(&self, rig_id: &str) -> Option<&FleetRig> {
+        self.rigs.iter().find(|rig| rig.rig_id == rig_id)
+    }
+}
+
+pub struct ConfigFleet {
+    content: ConfigFleetContent,
+    app_handle: RwLock<Option<AppHandle>>,
+}
+
+impl ConfigFleet {
+    pub async fn initialize(app_handle: AppHandle) {
+        let mut config = Self::current().write().await;
+        config.load_app_handle(app_handle).await;
+    }
+}
+
+impl ConfigImpl for ConfigFleet {
+    type Config = ConfigFleetContent;
+
+    fn new() -> Self {
+        Self {
+            content: ConfigFleet::_load_or_create(),
+            app_handle: RwLock::new(None),
+        }
+    }
+
+    fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    async fn _get_app_handle(&self) -> Option<AppHandle> {
+        self.app_handle.read().await.clone()
+    }
+
+    fn _get_name() -> String {
+        "config_fleet".to_string()
+    }
+
+    fn _get_content(&self) -> &Self::Config {
+        &self.content
+    }
+
+    fn _get_content_mut(&mut self) -> &mut Self::Config {
+        &mut self.content
+    }
+
+    async fn load_app_handle(&mut self, app_handle: AppHandle) {
+        *self.app_handle.write().await = Some(app_handle);
+    }
+}
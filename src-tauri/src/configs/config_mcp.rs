@@ -133,6 +133,197 @@ pub(crate) mod token_cipher {
     }
 }
 
+const MAX_CLIENT_NAME_LEN: usize = 64;
+
+fn redact_token(token: &str) -> String {
+    if token.len() > 7 {
+        format!("{}••••••••", &token[..3])
+    } else {
+        "tu_••••••••".to_string()
+    }
+}
+
+/// Amount a client sent at a point in time, kept for a day to enforce its daily limit
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct McpClientSpend {
+    pub at: SystemTime,
+    pub amount: u64,
+}
+
+/// Client settings editable from the frontend
+#[derive(Deserialize, Clone, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct McpClientSettings {
+    pub name: String,
+    pub read_tier_enabled: bool,
+    pub control_tier_enabled: bool,
+    pub transactions_enabled: bool,
+    /// Tools the client may call, every tool of its tiers when empty
+    #[serde(default)]
+    pub allowed_tools: Vec<String>,
+    /// Largest single transaction in µT
+    pub max_transaction_amount: Option<u64>,
+    /// Most the client may send within 24 hours in µT
+    pub daily_spend_limit: Option<u64>,
+    /// Days until the token expires, counted from the last save. Never expires when None
+    pub expiry_days: Option<u32>,
+}
+
+impl McpClientSettings {
+    pub fn validate(&self, known_tools: &[String]) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Client name is required".to_string());
+        }
+        if name.chars().count() > MAX_CLIENT_NAME_LEN {
+            return Err(format!(
+                "Client name must be at most {MAX_CLIENT_NAME_LEN} characters"
+            ));
+        }
+        if !self.read_tier_enabled && !self.control_tier_enabled && !self.transactions_enabled {
+            return Err("Client must have at least one tier enabled".to_string());
+        }
+        if let Some(unknown) = self
+            .allowed_tools
+            .iter()
+            .find(|tool| !known_tools.contains(tool))
+        {
+            return Err(format!("Unknown MCP tool: {unknown}"));
+        }
+        if self.max_transaction_amount == Some(0) || self.daily_spend_limit == Some(0) {
+            return Err("Spending limits must be greater than zero".to_string());
+        }
+        if self.expiry_days == Some(0) {
+            return Err("Token expiry must be at least one day".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Named MCP client with its own token, tiers, tool allowlist and spending limits.
+/// The global tier switches still apply on top of the client's own.
+#[derive(Serialize, Deserialize, Clone, Getters)]
+#[getset(get = "pub")]
+#[allow(clippy::struct_excessive_bools)]
+pub struct McpClient {
+    id: String,
+    name: String,
+    #[serde(
+        serialize_with = "token_cipher::serialize_token",
+        deserialize_with = "token_cipher::deserialize_token"
+    )]
+    token: Option<String>,
+    created_at: SystemTime,
+    expires_at: Option<SystemTime>,
+    read_tier_enabled: bool,
+    control_tier_enabled: bool,
+    transactions_enabled: bool,
+    #[serde(default)]
+    allowed_tools: Vec<String>,
+    #[serde(default)]
+    max_transaction_amount: Option<u64>,
+    #[serde(default)]
+    daily_spend_limit: Option<u64>,
+    #[serde(default)]
+    spent: Vec<McpClientSpend>,
+}
+
+impl McpClient {
+    pub fn new(settings: McpClientSettings, now: SystemTime) -> Self {
+        let mut client = Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: String::new(),
+            token: Some(ConfigMcpContent::generate_token()),
+            created_at: now,
+            expires_at: None,
+            read_tier_enabled: false,
+            control_tier_enabled: false,
+            transactions_enabled: false,
+            allowed_tools: Vec::new(),
+            max_transaction_amount: None,
+            daily_spend_limit: None,
+            spent: Vec::new(),
+        };
+        client.apply_settings(settings, now);
+        client
+    }
+
+    pub fn apply_settings(&mut self, settings: McpClientSettings, now: SystemTime) {
+        self.name = settings.name.trim().to_string();
+        self.read_tier_enabled = settings.read_tier_enabled;
+        self.control_tier_enabled = settings.control_tier_enabled;
+        self.transactions_enabled = settings.transactions_enabled;
+        self.allowed_tools = settings.allowed_tools;
+        self.allowed_tools.sort();
+        self.allowed_tools.dedup();
+        self.max_transaction_amount = settings.max_transaction_amount;
+        self.daily_spend_limit = settings.daily_spend_limit;
+        self.expires_at = settings
+            .expiry_days
+            .map(|days| now + std::time::Duration::from_secs(u64::from(days) * SECONDS_PER_DAY));
+    }
+
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at.is_some_and(|expiry| now > expiry)
+    }
+
+    pub fn is_tier_enabled(&self, tier: &str) -> bool {
+        match tier {
+            "read" => self.read_tier_enabled,
+            "control" => self.control_tier_enabled,
+            "transaction" => self.transactions_enabled,
+            _ => false,
+        }
+    }
+
+    pub fn allows_tool(&self, tool_name: &str) -> bool {
+        self.allowed_tools.is_empty() || self.allowed_tools.iter().any(|t| t == tool_name)
+    }
+
+    /// Total sent within the 24 hours before `now`
+    pub fn spent_in_last_day(&self, now: SystemTime) -> u64 {
+        self.spent
+            .iter()
+            .filter(|spend| {
+                now.duration_since(spend.at)
+                    .map_or(true, |age| age.as_secs() < SECONDS_PER_DAY)
+            })
+            .map(|spend| spend.amount)
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn check_spend(&self, amount: u64, now: SystemTime) -> Result<(), String> {
+        if let Some(max_amount) = self.max_transaction_amount
+            && amount > max_amount
+        {
+            return Err(format!(
+                "Amount {amount} µT exceeds this client's maximum of {max_amount} µT"
+            ));
+        }
+        if let Some(limit) = self.daily_spend_limit {
+            let spent = self.spent_in_last_day(now);
+            if spent.saturating_add(amount) > limit {
+                return Err(format!(
+                    "Amount {amount} µT would exceed this client's daily limit of {limit} µT ({spent} µT already sent)"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record_spend(&mut self, amount: u64, now: SystemTime) {
+        self.spent.retain(|spend| {
+            now.duration_since(spend.at)
+                .map_or(true, |age| age.as_secs() < SECONDS_PER_DAY)
+        });
+        self.spent.push(McpClientSpend { at: now, amount });
+    }
+
+    pub fn redacted_token(&self) -> Option<String> {
+        self.token.as_deref().map(redact_token)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 #[serde(default)]
@@ -157,6 +348,8 @@ pub struct ConfigMcpContent {
     read_tier_enabled: bool,
     control_tier_enabled: bool,
     rate_limit_transaction: u32,
+    clients: Vec<McpClient>,
+    unix_socket_enabled: bool,
 }
 
 impl Default for ConfigMcpContent {
@@ -175,6 +368,8 @@ impl Default for ConfigMcpContent {
             read_tier_enabled: true,
             control_tier_enabled: true,
             rate_limit_transaction: 5,
+            clients: Vec::new(),
+            unix_socket_enabled: false,
         }
     }
 }
@@ -235,13 +430,15 @@ impl ConfigMcpContent {
 
     /// Returns a redacted version of the token for safe display
     pub fn redacted_token(&self) -> Option<String> {
-        self.bearer_token.as_ref().map(|t| {
-            if t.len() > 7 {
-                format!("{}••••••••", &t[..3])
-            } else {
-                "tu_••••••••".to_string()
-            }
-        })
+        self.bearer_token.as_deref().map(redact_token)
+    }
+
+    pub fn find_client(&self, id: &str) -> Option<&McpClient> {
+        self.clients.iter().find(|c| c.id == id)
+    }
+
+    pub fn find_client_mut(&mut self, id: &str) -> Option<&mut McpClient> {
+        self.clients.iter_mut().find(|c| c.id == id)
     }
 
     /// Returns a JSON value with the bearer token replaced by a redacted version,
@@ -256,6 +453,22 @@ impl ConfigMcpContent {
                 );
             }
             obj.remove("bearer_token");
+            if let Some(clients) = obj.get_mut("clients").and_then(|v| v.as_array_mut()) {
+                for (client, content) in clients.iter_mut().zip(&self.clients) {
+                    if let Some(client) = client.as_object_mut() {
+                        client.remove("token");
+                        client.remove("spent");
+                        client.insert(
+                            "token_redacted".to_string(),
+                            serde_json::Value::String(content.redacted_token().unwrap_or_default()),
+                        );
+                        client.insert(
+                            "spent_last_day".to_string(),
+                            serde_json::Value::from(content.spent_in_last_day(SystemTime::now())),
+                        );
+                    }
+                }
+            }
         }
         Ok(value)
     }
@@ -20,7 +20,9 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use super::config_mcp::ConfigMcpContent;
+use std::time::{Duration, SystemTime};
+
+use super::config_mcp::{ConfigMcpContent, McpClient, McpClientSettings};
 
 fn ensure_test_identity() {
     super::config_mcp::token_cipher::set_anon_id("test-anon-id".to_string());
@@ -84,6 +86,12 @@ fn default_config_no_max_transaction_amount() {
     assert!(config.max_transaction_amount().is_none());
 }
 
+#[test]
+fn default_config_unix_socket_disabled() {
+    let config = ConfigMcpContent::default();
+    assert!(!*config.unix_socket_enabled());
+}
+
 // =============================================================================
 // Token generation
 // =============================================================================
@@ -308,3 +316,155 @@ fn config_serialization_with_token() {
 
     assert_eq!(config.bearer_token(), deserialized.bearer_token());
 }
+
+// =============================================================================
+// Named clients
+// =============================================================================
+
+fn client_settings() -> McpClientSettings {
+    McpClientSettings {
+        name: " ops agent ".to_string(),
+        read_tier_enabled: true,
+        control_tier_enabled: true,
+        transactions_enabled: false,
+        allowed_tools: vec!["stop_mining".to_string(), "start_mining".to_string()],
+        max_transaction_amount: None,
+        daily_spend_limit: None,
+        expiry_days: Some(7),
+    }
+}
+
+fn known_tools() -> Vec<String> {
+    vec!["start_mining".to_string(), "stop_mining".to_string()]
+}
+
+#[test]
+fn new_client_applies_settings() {
+    let now = SystemTime::now();
+    let client = McpClient::new(client_settings(), now);
+    assert_eq!(client.name(), "ops agent");
+    assert!(
+        client
+            .token()
+            .as_deref()
+            .is_some_and(|t| t.starts_with("tu_"))
+    );
+    assert_eq!(
+        *client.expires_at(),
+        Some(now + Duration::from_secs(7 * 24 * 60 * 60))
+    );
+    assert_eq!(
+        client.allowed_tools(),
+        &vec!["start_mining".to_string(), "stop_mining".to_string()]
+    );
+    assert!(client.is_tier_enabled("control"));
+    assert!(!client.is_tier_enabled("transaction"));
+}
+
+#[test]
+fn new_clients_get_distinct_tokens() {
+    let now = SystemTime::now();
+    let a = McpClient::new(client_settings(), now);
+    let b = McpClient::new(client_settings(), now);
+    assert_ne!(a.token(), b.token());
+    assert_ne!(a.id(), b.id());
+}
+
+#[test]
+fn client_settings_validation() {
+    assert!(client_settings().validate(&known_tools()).is_ok());
+
+    let mut settings = client_settings();
+    settings.name = "  ".to_string();
+    assert!(settings.validate(&known_tools()).is_err());
+
+    let mut settings = client_settings();
+    settings.read_tier_enabled = false;
+    settings.control_tier_enabled = false;
+    assert!(settings.validate(&known_tools()).is_err());
+
+    let mut settings = client_settings();
+    settings.allowed_tools = vec!["format_disk".to_string()];
+    assert!(
+        settings
+            .validate(&known_tools())
+            .is_err_and(|e| e.contains("format_disk"))
+    );
+
+    let mut settings = client_settings();
+    settings.daily_spend_limit = Some(0);
+    assert!(settings.validate(&known_tools()).is_err());
+}
+
+#[test]
+fn client_spend_limits() {
+    let now = SystemTime::now();
+    let mut settings = client_settings();
+    settings.max_transaction_amount = Some(1_000_000);
+    settings.daily_spend_limit = Some(1_500_000);
+    let mut client = McpClient::new(settings, now);
+
+    assert!(client.check_spend(1_000_000, now).is_ok());
+    assert!(client.check_spend(1_000_001, now).is_err());
+
+    client.record_spend(1_000_000, now);
+    assert_eq!(client.spent_in_last_day(now), 1_000_000);
+    assert!(client.check_spend(500_000, now).is_ok());
+    assert!(
+        client
+            .check_spend(500_001, now)
+            .is_err_and(|e| e.contains("daily limit"))
+    );
+
+    let tomorrow = now + Duration::from_secs(24 * 60 * 60 + 1);
+    assert_eq!(client.spent_in_last_day(tomorrow), 0);
+    assert!(client.check_spend(1_000_000, tomorrow).is_ok());
+}
+
+#[test]
+fn record_spend_prunes_old_entries() {
+    let now = SystemTime::now();
+    let mut client = McpClient::new(client_settings(), now);
+    client.record_spend(10, now);
+    let later = now + Duration::from_secs(2 * 24 * 60 * 60);
+    client.record_spend(20, later);
+    assert_eq!(client.spent().len(), 1);
+    assert_eq!(client.spent_in_last_day(later), 20);
+}
+
+#[test]
+fn redacted_value_hides_client_tokens() {
+    let mut config = ConfigMcpContent::default();
+    let client = McpClient::new(client_settings(), SystemTime::now());
+    let token = client.token().clone().unwrap();
+    config.set_clients(vec![client]);
+
+    ensure_test_identity();
+    let value = config.to_redacted_value().unwrap();
+    let client_value = &value["clients"][0];
+    assert!(client_value.get("token").is_none());
+    assert!(client_value.get("spent").is_none());
+    assert_eq!(client_value["spent_last_day"], 0);
+    assert!(
+        client_value["token_redacted"]
+            .as_str()
+            .unwrap()
+            .starts_with("tu_")
+    );
+    assert!(!value.to_string().contains(&token));
+}
+
+#[test]
+fn client_serialization_roundtrip_keeps_token() {
+    ensure_test_identity();
+    let mut config = ConfigMcpContent::default();
+    let client = McpClient::new(client_settings(), SystemTime::now());
+    config.set_clients(vec![client.clone()]);
+
+    let serialized = serde_json::to_string(&config).unwrap();
+    assert!(!serialized.contains(client.token().as_deref().unwrap()));
+    let deserialized: ConfigMcpContent = serde_json::from_str(&serialized).unwrap();
+    let restored = deserialized.find_client(client.id()).unwrap();
+    assert_eq!(restored.token(), client.token());
+    assert_eq!(restored.allowed_tools(), client.allowed_tools());
+}
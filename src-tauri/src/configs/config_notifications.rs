@@ -0,0 +1,399 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Settings for notification channels.
+
+use super::config_mcp::token_cipher;
+use super::trait_config::{ConfigContentImpl, ConfigImpl};
+use anyhow::{anyhow, bail};
+use getset::{Getters, Setters};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::LazyLock;
+use std::time::SystemTime;
+use tauri::AppHandle;
+use tokio::sync::RwLock;
+use url::Url;
+
+static INSTANCE: LazyLock<RwLock<ConfigNotifications>> =
+    LazyLock::new(|| RwLock::new(ConfigNotifications::new()));
+
+pub const DEFAULT_HASHRATE_DROP_PERCENT: u8 = 30;
+pub const DEFAULT_MIN_INTERVAL_SECS: u64 = 300;
+/// Sent to the frontend instead of a stored secret. Sending it back keeps the stored secret.
+pub const REDACTED_SECRET: &str = "••••••••";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEventType {
+    MinerCrashed,
+    PoolFailover,
+    HashrateDropped,
+    NodeOutOfSync,
+    IncomingTransaction,
+    BlockWon,
+    PinLockout,
+}
+
+impl NotificationEventType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NotificationEventType::MinerCrashed => "miner_crashed",
+            NotificationEventType::PoolFailover => "pool_failover",
+            NotificationEventType::HashrateDropped => "hashrate_dropped",
+            NotificationEventType::NodeOutOfSync => "node_out_of_sync",
+            NotificationEventType::IncomingTransaction => "incoming_transaction",
+            NotificationEventType::BlockWon => "block_won",
+            NotificationEventType::PinLockout => "pin_lockout",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS, usually on port 587
+    #[default]
+    StartTls,
+    /// TLS from the first byte, usually on port 465
+    Tls,
+    /// No encryption, only accepted for servers on this machine
+    None,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotificationTransport {
+    /// JSON POST, signed with HMAC-SHA256 when a secret is set
+    Webhook {
+        url: String,
+        #[serde(default)]
+        secret: Option<String>,
+    },
+    Email {
+        host: String,
+        port: u16,
+        #[serde(default)]
+        security: SmtpSecurity,
+        #[serde(default)]
+        username: Option<String>,
+        #[serde(default)]
+        password: Option<String>,
+        from: String,
+        to: Vec<String>,
+    },
+    Ntfy {
+        server_url: String,
+        topic: String,
+        #[serde(default)]
+        token: Option<String>,
+    },
+    Matrix {
+        homeserver_url: String,
+        room_id: String,
+        access_token: String,
+    },
+}
+
+impl NotificationTransport {
+    /// Password, token or signing secret of the transport, if it has one
+    fn secret_mut(&mut self) -> Option<&mut String> {
+        match self {
+            NotificationTransport::Webhook { secret, .. } => secret.as_mut(),
+            NotificationTransport::Email { password, .. } => password.as_mut(),
+            NotificationTransport::Ntfy { token, .. } => token.as_mut(),
+            NotificationTransport::Matrix { access_token, .. } => Some(access_token),
+        }
+    }
+
+    pub fn redact_secret(&mut self) {
+        if let Some(secret) = self.secret_mut() {
+            *secret = REDACTED_SECRET.to_string();
+        }
+    }
+
+    /// Replaces a redacted secret sent back by the frontend with the secret stored for the
+    /// channel, as long as the channel still uses the same kind of transport
+    pub fn restore_secret(
+        &mut self,
+        stored: Option<&NotificationTransport>,
+    ) -> Result<(), anyhow::Error> {
+        let is_same_kind = stored
+            .is_some_and(|stored| std::mem::discriminant(stored) == std::mem::discriminant(self));
+        let stored_secret = stored
+            .filter(|_| is_same_kind)
+            .and_then(|stored| stored.clone().secret_mut().cloned());
+        match (self.secret_mut(), stored_secret) {
+            (Some(secret), Some(stored_secret)) if secret == REDACTED_SECRET => {
+                *secret = stored_secret;
+                Ok(())
+            }
+            (Some(secret), None) if secret == REDACTED_SECRET => {
+                bail!("The channel has no stored secret to keep, enter it again")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        match self {
+            NotificationTransport::Webhook { url, .. } => validate_http_url(url),
+            NotificationTransport::Email {
+                host,
+                port,
+                security,
+                username,
+                password,
+                from,
+                to,
+            } => {
+                if host.trim().is_empty() || *port == 0 {
+                    bail!("SMTP server host and port are required");
+                }
+                if *security == SmtpSecurity::None && !is_local_host(host) {
+                    bail!("Unencrypted SMTP is only allowed for a server on this machine");
+                }
+                if username.is_some() != password.is_some() {
+                    bail!("SMTP username and password must be set together");
+                }
+                for address in std::iter::once(from).chain(to) {
+                    validate_email_address(address)?;
+                }
+                if to.is_empty() {
+                    bail!("At least one recipient is required");
+                }
+                Ok(())
+            }
+            NotificationTransport::Ntfy {
+                server_url, topic, ..
+            } => {
+                validate_http_url(server_url)?;
+                if topic.is_empty() || topic.contains(|c: char| c == '/' || c.is_whitespace()) {
+                    bail!("Invalid ntfy topic: {topic:?}");
+                }
+                Ok(())
+            }
+            NotificationTransport::Matrix {
+                homeserver_url,
+                room_id,
+                access_token,
+            } => {
+                validate_http_url(homeserver_url)?;
+                if !room_id.starts_with('!') || !room_id.contains(':') {
+                    bail!("Matrix room id must look like !room:server");
+                }
+                if access_token.is_empty() {
+                    bail!("Matrix access token is required");
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn validate_http_url(url: &str) -> Result<(), anyhow::Error> {
+    let parsed = Url::parse(url).map_err(|e| anyhow!("Invalid URL {url}: {e}"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        bail!("URL must be http:// or https://: {url}");
+    }
+    Ok(())
+}
+
+fn validate_email_address(address: &str) -> Result<(), anyhow::Error> {
+    // Rejecting line breaks and brackets keeps the address from injecting SMTP commands or headers
+    let valid = address
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty())
+        && !address.contains(|c: char| c.is_whitespace() || c.is_control() || "<>,;".contains(c));
+    if !valid {
+        bail!("Invalid email address: {address:?}");
+    }
+    Ok(())
+}
+
+pub(crate) fn is_local_host(host: &str) -> bool {
+    matches!(host, "localhost" | "127.0.0.1" | "::1" | "[::1]")
+}
+
+/// Where notifications go and which events trigger them. Templates use `{{field}}` placeholders
+/// and fall back to the event's built in text when unset.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NotificationChannel {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub transport: NotificationTransport,
+    pub events: Vec<NotificationEventType>,
+    #[serde(default)]
+    pub title_template: Option<String>,
+    #[serde(default)]
+    pub body_template: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+#[serde(default)]
+#[derive(Getters, Setters)]
+#[getset(get = "pub", set = "pub")]
+pub struct ConfigNotificationsContent {
+    created_at: SystemTime,
+    #[serde(
+        serialize_with = "serialize_channels",
+        deserialize_with = "deserialize_channels"
+    )]
+    channels: Vec<NotificationChannel>,
+    /// Drop below the recent average hashrate, in percent, that counts as a hashrate drop
+    hashrate_drop_percent: u8,
+    /// Shortest time between two notifications of the same event on the same channel
+    min_interval_secs: u64,
+}
+
+impl Default for ConfigNotificationsContent {
+    fn default() -> Self {
+        Self {
+            created_at: SystemTime::now(),
+            channels: Vec::new(),
+            hashrate_drop_percent: DEFAULT_HASHRATE_DROP_PERCENT,
+            min_interval_secs: DEFAULT_MIN_INTERVAL_SECS,
+        }
+    }
+}
+
+fn serialize_channels<S>(channels: &[NotificationChannel], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut channels = channels.to_vec();
+    for channel in &mut channels {
+        if let Some(secret) = channel.transport.secret_mut() {
+            *secret = token_cipher::encrypt_deterministic(secret);
+        }
+    }
+    channels.serialize(serializer)
+}
+
+fn deserialize_channels<'de, D>(deserializer: D) -> Result<Vec<NotificationChannel>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut channels = Vec::<NotificationChannel>::deserialize(deserializer)?;
+    for channel in &mut channels {
+        if let Some(secret) = channel.transport.secret_mut() {
+            *secret = token_cipher::decrypt(secret).map_err(|e| {
+                serde::de::Error::custom(format!("failed to decrypt notification secret: {e}"))
+            })?;
+        }
+    }
+    Ok(channels)
+}
+
+impl ConfigContentImpl for ConfigNotificationsContent {}
+
+impl ConfigNotificationsContent {
+    pub fn find_channel(&self, id: &str) -> Option<&NotificationChannel> {
+        self.channels.iter().find(|channel| channel.id == id)
+    }
+
+    pub fn is_subscribed(&self, event_type: NotificationEventType) -> bool {
+        self.channels
+            .iter()
+            .any(|channel| channel.enabled && channel.events.contains(&event_type))
+    }
+
+    /// Returns a JSON value with channel secrets replaced by [`REDACTED_SECRET`], suitable for
+    /// sending to the frontend.
+    pub fn to_redacted_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        let channels: Vec<NotificationChannel> = self
+            .channels
+            .iter()
+            .cloned()
+            .map(|mut channel| {
+                channel.transport.redact_secret();
+                channel
+            })
+            .collect();
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("channels".to_string(), serde_json::to_value(channels)?);
+        }
+        Ok(value)
+    }
+}
+
+pub struct ConfigNotifications {
+    content: ConfigNotificationsContent,
+    app_handle: RwLock<Option<AppHandle>>,
+}
+
+impl ConfigNotifications {
+    pub async fn initialize(app_handle: AppHandle) {
+        // Secrets are decrypted on load, which needs the key before the config is first read
+        let anon_id = super::config_core::ConfigCore::content()
+            .await
+            .anon_id()
+            .clone();
+        token_cipher::set_anon_id(anon_id);
+        let mut config = Self::current().write().await;
+        config.load_app_handle(app_handle).await;
+    }
+
+    /// Saves the channels without reporting the change to telemetry like `update_field` does,
+    /// as they hold secrets
+    pub async fn save_channels(channels: Vec<NotificationChannel>) -> Result<(), anyhow::Error> {
+        let mut config = Self::current().write().await;
+        config.content.channels = channels;
+        Self::_save_config(config.content.clone())
+    }
+}
+
+impl ConfigImpl for ConfigNotifications {
+    type Config = ConfigNotificationsContent;
+
+    fn new() -> Self {
+        Self {
+            content: ConfigNotifications::_load_or_create(),
+            app_handle: RwLock::new(None),
+        }
+    }
+
+    fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    async fn _get_app_handle(&self) -> Option<AppHandle> {
+        self.app_handle.read().await.clone()
+    }
+
+    fn _get_name() -> String {
+        "config_notifications".to_string()
+    }
+
+    fn _get_content(&self) -> &Self::Config {
+        &self.content
+    }
+
+    fn _get_content_mut(&mut self) -> &mut Self::Config {
+        &mut self.content
+    }
+
+    async fn load_app_handle(&mut self, app_handle: AppHandle) {
+        *self.app_handle.write().await = Some(app_handle);
+    }
+}
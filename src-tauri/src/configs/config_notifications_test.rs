@@ -0,0 +1,122 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::config_notifications::{
+    ConfigNotificationsContent, NotificationChannel, NotificationTransport, REDACTED_SECRET,
+};
+
+fn ensure_test_identity() {
+    super::config_mcp::token_cipher::set_anon_id("test-anon-id".to_string());
+}
+
+fn ntfy(token: Option<&str>) -> NotificationTransport {
+    NotificationTransport::Ntfy {
+        server_url: "https://ntfy.sh".to_string(),
+        topic: "rig".to_string(),
+        token: token.map(str::to_string),
+    }
+}
+
+fn channel(transport: NotificationTransport) -> NotificationChannel {
+    NotificationChannel {
+        id: "ch-1".to_string(),
+        name: "Phone".to_string(),
+        enabled: true,
+        transport,
+        events: Vec::new(),
+        title_template: None,
+        body_template: None,
+    }
+}
+
+// =============================================================================
+// Storage
+// =============================================================================
+
+#[test]
+fn secrets_are_encrypted_on_disk() {
+    ensure_test_identity();
+    let mut config = ConfigNotificationsContent::default();
+    config.set_channels(vec![channel(ntfy(Some("tk_secret")))]);
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert!(!json.contains("tk_secret"));
+
+    let loaded: ConfigNotificationsContent = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.channels()[0].transport, ntfy(Some("tk_secret")));
+}
+
+#[test]
+fn undecryptable_secret_fails_to_load() {
+    ensure_test_identity();
+    let mut value = serde_json::to_value(ConfigNotificationsContent::default()).unwrap();
+    value["channels"] = serde_json::json!([channel(ntfy(Some("plaintext")))]);
+    assert!(serde_json::from_value::<ConfigNotificationsContent>(value).is_err());
+}
+
+// =============================================================================
+// Redaction
+// =============================================================================
+
+#[test]
+fn frontend_value_only_holds_placeholders() {
+    ensure_test_identity();
+    let mut config = ConfigNotificationsContent::default();
+    config.set_channels(vec![channel(ntfy(Some("tk_secret"))), channel(ntfy(None))]);
+
+    let value = config.to_redacted_value().unwrap();
+    assert_eq!(value["channels"][0]["transport"]["token"], REDACTED_SECRET);
+    assert!(value["channels"][1]["transport"]["token"].is_null());
+    assert!(!value.to_string().contains("tk_secret"));
+}
+
+#[test]
+fn placeholder_keeps_stored_secret() {
+    let stored = ntfy(Some("tk_secret"));
+    let mut sent = ntfy(Some(REDACTED_SECRET));
+    sent.restore_secret(Some(&stored)).unwrap();
+    assert_eq!(sent, stored);
+}
+
+#[test]
+fn new_secret_replaces_stored_secret() {
+    let mut sent = ntfy(Some("tk_new"));
+    sent.restore_secret(Some(&ntfy(Some("tk_old")))).unwrap();
+    assert_eq!(sent, ntfy(Some("tk_new")));
+}
+
+#[test]
+fn placeholder_without_stored_secret_is_rejected() {
+    let mut sent = ntfy(Some(REDACTED_SECRET));
+    assert!(sent.restore_secret(None).is_err());
+    assert!(sent.restore_secret(Some(&ntfy(None))).is_err());
+}
+
+#[test]
+fn placeholder_is_not_restored_from_another_transport() {
+    let stored = NotificationTransport::Webhook {
+        url: "https://example.com/hook".to_string(),
+        secret: Some("whsec".to_string()),
+    };
+    let mut sent = ntfy(Some(REDACTED_SECRET));
+    assert!(sent.restore_secret(Some(&stored)).is_err());
+}
@@ -32,6 +32,7 @@ use tokio::sync::RwLock;
 use crate::{
     LOG_TARGET_APP_LOGIC,
     configs::config_ui::{ConfigUI, ConfigUIContent},
+    credential_manager::CredentialBackend,
     internal_wallet::TariWalletDetails,
     pin::PinLockerState,
 };
@@ -97,6 +98,8 @@ pub struct ConfigWalletContent {
     last_known_balance: MicroMinotari,
     #[getset(get = "pub", set = "pub")]
     security_warning_dismissed: bool,
+    #[getset(get = "pub", set = "pub")]
+    credential_backend: CredentialBackend,
 }
 
 impl Default for ConfigWalletContent {
@@ -117,6 +120,7 @@ impl Default for ConfigWalletContent {
             seed_backed_up: false,
             last_known_balance: MicroMinotari(0),
             security_warning_dismissed: false,
+            credential_backend: CredentialBackend::Keyring,
         }
     }
 }
@@ -21,10 +21,14 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod config_core;
+pub mod config_fleet;
 pub mod config_mcp;
 #[cfg(test)]
 mod config_mcp_test;
 pub mod config_mining;
+pub mod config_notifications;
+#[cfg(test)]
+mod config_notifications_test;
 pub mod config_pools;
 pub mod config_ui;
 pub mod config_wallet;
@@ -21,7 +21,9 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::APPLICATION_FOLDER_ID;
-use crate::configs::config_wallet::WalletId;
+use crate::configs::config_wallet::{ConfigWallet, WalletId};
+use crate::configs::trait_config::ConfigImpl;
+use crate::credential_vault::CredentialVault;
 use keyring::{Entry, Error as KeyringError};
 use serde::{Deserialize, Serialize};
 use std::fs::OpenOptions;
@@ -48,6 +50,23 @@ pub enum CredentialError {
     Serialization(#[from] serde_cbor::Error),
     #[error("Keyring had no entry for: {0}")]
     NoEntry(String),
+    #[error("Credential vault is locked")]
+    VaultLocked,
+    #[error("Wrong credential vault passphrase")]
+    WrongPassphrase,
+    #[error("Credential vault error: {0}")]
+    Vault(String),
+}
+
+/// Where wallet credentials are persisted, selected per install in the wallet config
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialBackend {
+    /// OS keyring (Keychain, Credential Manager, Secret Service)
+    #[default]
+    Keyring,
+    /// Passphrase encrypted vault file, for hosts without a keyring daemon
+    EncryptedFile,
 }
 
 const FALLBACK_FILE_PATH: &str = "credentials_backup.bin";
@@ -77,29 +96,100 @@ impl CredentialManager {
         CredentialManager::new(APPLICATION_FOLDER_ID.into(), name)
     }
 
+    pub async fn backend() -> CredentialBackend {
+        *ConfigWallet::content().await.credential_backend()
+    }
+
     pub async fn set_credentials(&self, credential: &Credential) -> Result<(), CredentialError> {
-        match self.save_to_keyring(credential) {
-            Ok(_) => Ok(()),
-            Err(CredentialError::Keyring(e)) => Err(e.into()),
-            Err(err) => Err(err),
-        }
+        self.set_credentials_in(Self::backend().await, credential)
+            .await
     }
 
     pub async fn get_credentials(&self) -> Result<Credential, CredentialError> {
-        match self.load_from_keyring() {
-            Ok(credential) => Ok(credential),
-            Err(CredentialError::Keyring(e)) => Err(e.into()),
-            Err(err) => Err(err),
+        self.get_credentials_from(Self::backend().await).await
+    }
+
+    pub async fn delete_credential(&self) -> Result<(), CredentialError> {
+        self.delete_credential_from(Self::backend().await).await
+    }
+
+    pub async fn set_credentials_in(
+        &self,
+        backend: CredentialBackend,
+        credential: &Credential,
+    ) -> Result<(), CredentialError> {
+        match backend {
+            CredentialBackend::Keyring => match self.save_to_keyring(credential) {
+                Ok(_) => Ok(()),
+                Err(CredentialError::Keyring(e)) => Err(e.into()),
+                Err(err) => Err(err),
+            },
+            CredentialBackend::EncryptedFile => {
+                let serialized = serde_cbor::to_vec(credential)?;
+                CredentialVault::store(&self.username, &serialized).await
+            }
         }
     }
 
-    pub fn delete_credential(&self) -> Result<(), keyring::Error> {
-        let entry = Entry::new(&self.service_name, &self.username)?;
-        entry.delete_credential()
+    pub async fn get_credentials_from(
+        &self,
+        backend: CredentialBackend,
+    ) -> Result<Credential, CredentialError> {
+        match backend {
+            CredentialBackend::Keyring => match self.load_from_keyring() {
+                Ok(credential) => Ok(credential),
+                Err(CredentialError::Keyring(e)) => Err(e.into()),
+                Err(err) => Err(err),
+            },
+            CredentialBackend::EncryptedFile => {
+                let serialized = CredentialVault::load(&self.username).await?;
+                Ok(serde_cbor::from_slice(&serialized)?)
+            }
+        }
+    }
+
+    pub async fn delete_credential_from(
+        &self,
+        backend: CredentialBackend,
+    ) -> Result<(), CredentialError> {
+        match backend {
+            CredentialBackend::Keyring => {
+                let entry = Entry::new(&self.service_name, &self.username)?;
+                entry.delete_credential()?;
+                Ok(())
+            }
+            CredentialBackend::EncryptedFile => CredentialVault::remove(&self.username).await,
+        }
+    }
+
+    /// Copies the credential from one backend to the other and verifies the copy. The source is
+    /// left untouched so the caller can remove it once every credential has been moved. Returns
+    /// false when there was nothing to migrate.
+    pub async fn copy_credential(
+        &self,
+        from: CredentialBackend,
+        to: CredentialBackend,
+    ) -> Result<bool, CredentialError> {
+        let credential = match self.get_credentials_from(from).await {
+            Ok(credential) => credential,
+            Err(CredentialError::NoEntry(_)) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        self.set_credentials_in(to, &credential).await?;
+        let copied = self.get_credentials_from(to).await?;
+        if copied.encrypted_seed != credential.encrypted_seed {
+            return Err(CredentialError::Vault(format!(
+                "Migrated credential for {} does not match the original",
+                self.username
+            )));
+        }
+        Ok(true)
     }
 
     fn save_to_keyring(&self, credential: &Credential) -> Result<(), CredentialError> {
-        let _unused = self.delete_credential();
+        if let Ok(entry) = Entry::new(&self.service_name, &self.username) {
+            let _unused = entry.delete_credential();
+        }
 
         let entry = Entry::new(&self.service_name, &self.username)?;
         let serialized = serde_cbor::to_vec(credential)?;
@@ -0,0 +1,352 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Encrypted file backend for wallet credentials, for hosts without a usable OS keyring.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use dirs::config_dir;
+use ring::aead;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use tari_utilities::{Hidden, SafePassword};
+use tokio::sync::RwLock;
+
+use crate::APPLICATION_FOLDER_ID;
+use crate::credential_manager::CredentialError;
+
+/// Error returned when recovering a forgotten PIN would replace a locked vault, so the UI can ask
+/// the user to confirm before its contents are lost
+pub const CREDENTIAL_VAULT_RESET_REQUIRED: &str = "credential_vault_reset_required";
+
+const VAULT_FILE_NAME: &str = "credential_vault.bin";
+const VAULT_VERSION: u8 = 1;
+const WRAPPED_KEY_ENTRY: &str = "__vault_key__";
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+
+static INSTANCE: LazyLock<RwLock<CredentialVault>> =
+    LazyLock::new(|| RwLock::new(CredentialVault::new()));
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub salt: Vec<u8>,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    /// OWASP recommended Argon2id settings with a fresh random salt
+    pub fn generate() -> Result<Self, CredentialError> {
+        let mut salt = vec![0u8; SALT_LENGTH];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .map_err(|_| CredentialError::Vault("Failed to generate salt".to_string()))?;
+        Ok(Self {
+            salt,
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        })
+    }
+
+    fn derive_key(
+        &self,
+        passphrase: &SafePassword,
+    ) -> Result<Hidden<[u8; KEY_LENGTH]>, CredentialError> {
+        let params = Params::new(
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            Some(KEY_LENGTH),
+        )
+        .map_err(|e| CredentialError::Vault(format!("Invalid KDF parameters: {e}")))?;
+        let mut key = [0u8; KEY_LENGTH];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase.reveal(), &self.salt, &mut key)
+            .map_err(|e| CredentialError::Vault(format!("Key derivation failed: {e}")))?;
+        Ok(Hidden::hide(key))
+    }
+}
+
+/// On-disk vault layout. Every entry is `nonce || ciphertext || tag` with the entry name as
+/// associated data, so ciphertexts can't be swapped between entries. The vault key is stored the
+/// same way, sealed under the passphrase derived key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultFile {
+    version: u8,
+    kdf: KdfParams,
+    wrapped_key: Vec<u8>,
+    entries: HashMap<String, Vec<u8>>,
+}
+
+impl VaultFile {
+    /// Creates an empty vault with a fresh random key and returns it with that key
+    pub fn create(
+        passphrase: &SafePassword,
+        kdf: KdfParams,
+    ) -> Result<(Self, Hidden<[u8; KEY_LENGTH]>), CredentialError> {
+        let mut key = Hidden::hide([0u8; KEY_LENGTH]);
+        SystemRandom::new()
+            .fill(key.reveal_mut())
+            .map_err(|_| CredentialError::Vault("Failed to generate vault key".to_string()))?;
+        let wrapped_key = seal(
+            &kdf.derive_key(passphrase)?,
+            WRAPPED_KEY_ENTRY,
+            key.reveal(),
+        )?;
+        Ok((
+            Self {
+                version: VAULT_VERSION,
+                kdf,
+                wrapped_key,
+                entries: HashMap::new(),
+            },
+            key,
+        ))
+    }
+
+    pub fn unlock(
+        &self,
+        passphrase: &SafePassword,
+    ) -> Result<Hidden<[u8; KEY_LENGTH]>, CredentialError> {
+        let wrapping_key = self.kdf.derive_key(passphrase)?;
+        let key = Hidden::hide(
+            open(&wrapping_key, WRAPPED_KEY_ENTRY, &self.wrapped_key)
+                .map_err(|_| CredentialError::WrongPassphrase)?,
+        );
+        let key: [u8; KEY_LENGTH] =
+            key.reveal().as_slice().try_into().map_err(|_| {
+                CredentialError::Vault("Vault key has the wrong length".to_string())
+            })?;
+        Ok(Hidden::hide(key))
+    }
+
+    pub fn insert(
+        &mut self,
+        key: &Hidden<[u8; KEY_LENGTH]>,
+        name: &str,
+        data: &[u8],
+    ) -> Result<(), CredentialError> {
+        let sealed = seal(key, name, data)?;
+        self.entries.insert(name.to_string(), sealed);
+        Ok(())
+    }
+
+    pub fn get(
+        &self,
+        key: &Hidden<[u8; KEY_LENGTH]>,
+        name: &str,
+    ) -> Result<Vec<u8>, CredentialError> {
+        let sealed = self
+            .entries
+            .get(name)
+            .ok_or_else(|| CredentialError::NoEntry(name.to_string()))?;
+        open(key, name, sealed)
+    }
+
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.entries.remove(name).is_some()
+    }
+
+    /// Wraps the vault key under a new passphrase and fresh KDF salt. Entries stay sealed under
+    /// the same vault key, so nothing else is re-encrypted.
+    pub fn rekey(
+        &self,
+        key: &Hidden<[u8; KEY_LENGTH]>,
+        new_passphrase: &SafePassword,
+        kdf: KdfParams,
+    ) -> Result<Self, CredentialError> {
+        let wrapped_key = seal(
+            &kdf.derive_key(new_passphrase)?,
+            WRAPPED_KEY_ENTRY,
+            key.reveal(),
+        )?;
+        Ok(Self {
+            version: self.version,
+            kdf,
+            wrapped_key,
+            entries: self.entries.clone(),
+        })
+    }
+
+    pub fn read_from(path: &Path) -> Result<Self, CredentialError> {
+        let contents = std::fs::read(path)?;
+        let vault: Self = serde_cbor::from_slice(&contents)?;
+        if vault.version != VAULT_VERSION {
+            return Err(CredentialError::Vault(format!(
+                "Unsupported vault version {}",
+                vault.version
+            )));
+        }
+        Ok(vault)
+    }
+
+    /// Writes to a temporary file first so a crash never leaves a truncated vault behind
+    pub fn write_to(&self, path: &Path) -> Result<(), CredentialError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, serde_cbor::to_vec(self)?)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&temp_path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+}
+
+fn sealing_key(key: &Hidden<[u8; KEY_LENGTH]>) -> Result<aead::LessSafeKey, CredentialError> {
+    let unbound_key = aead::UnboundKey::new(&aead::AES_256_GCM, key.reveal())
+        .map_err(|_| CredentialError::Vault("Failed to create vault key".to_string()))?;
+    Ok(aead::LessSafeKey::new(unbound_key))
+}
+
+fn seal(
+    key: &Hidden<[u8; KEY_LENGTH]>,
+    name: &str,
+    data: &[u8],
+) -> Result<Vec<u8>, CredentialError> {
+    let mut nonce_bytes = [0u8; NONCE_LENGTH];
+    SystemRandom::new()
+        .fill(&mut nonce_bytes)
+        .map_err(|_| CredentialError::Vault("Failed to generate nonce".to_string()))?;
+
+    let mut in_out = data.to_vec();
+    sealing_key(key)?
+        .seal_in_place_append_tag(
+            aead::Nonce::assume_unique_for_key(nonce_bytes),
+            aead::Aad::from(name.as_bytes()),
+            &mut in_out,
+        )
+        .map_err(|_| CredentialError::Vault("Encryption failed".to_string()))?;
+
+    let mut sealed = nonce_bytes.to_vec();
+    sealed.extend_from_slice(&in_out);
+    Ok(sealed)
+}
+
+fn open(
+    key: &Hidden<[u8; KEY_LENGTH]>,
+    name: &str,
+    sealed: &[u8],
+) -> Result<Vec<u8>, CredentialError> {
+    if sealed.len() < NONCE_LENGTH + aead::AES_256_GCM.tag_len() {
+        return Err(CredentialError::Vault(format!("Entry {name} is truncated")));
+    }
+    let (nonce_bytes, ciphertext_and_tag) = sealed.split_at(NONCE_LENGTH);
+    let nonce = aead::Nonce::try_assume_unique_for_key(nonce_bytes)
+        .map_err(|_| CredentialError::Vault("Invalid nonce".to_string()))?;
+
+    let mut in_out = ciphertext_and_tag.to_vec();
+    let plaintext = sealing_key(key)?
+        .open_in_place(nonce, aead::Aad::from(name.as_bytes()), &mut in_out)
+        .map_err(|_| CredentialError::Vault(format!("Entry {name} failed authentication")))?;
+    Ok(plaintext.to_vec())
+}
+
+pub struct CredentialVault {
+    path: PathBuf,
+    key: Option<Hidden<[u8; KEY_LENGTH]>>,
+}
+
+impl CredentialVault {
+    fn new() -> Self {
+        let config_dir = config_dir().unwrap_or_else(std::env::temp_dir);
+        Self {
+            path: config_dir.join(APPLICATION_FOLDER_ID).join(VAULT_FILE_NAME),
+            key: None,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    pub async fn exists() -> bool {
+        Self::current().read().await.path.exists()
+    }
+
+    pub async fn is_unlocked() -> bool {
+        Self::current().read().await.key.is_some()
+    }
+
+    /// Creates a new, empty vault, replacing any existing one. Everything stored in a replaced
+    /// vault is lost, so callers must only do this on first use or after the user confirmed it.
+    pub async fn create(passphrase: &SafePassword) -> Result<(), CredentialError> {
+        let mut vault = Self::current().write().await;
+        let (file, key) = VaultFile::create(passphrase, KdfParams::generate()?)?;
+        file.write_to(&vault.path)?;
+        vault.key = Some(key);
+        Ok(())
+    }
+
+    pub async fn unlock(passphrase: &SafePassword) -> Result<(), CredentialError> {
+        let mut vault = Self::current().write().await;
+        let key = VaultFile::read_from(&vault.path)?.unlock(passphrase)?;
+        vault.key = Some(key);
+        Ok(())
+    }
+
+    /// Moves the vault to a new passphrase, e.g. after the wallet PIN has changed
+    pub async fn rekey(new_passphrase: &SafePassword) -> Result<(), CredentialError> {
+        let vault = Self::current().write().await;
+        let key = vault.key.as_ref().ok_or(CredentialError::VaultLocked)?;
+        VaultFile::read_from(&vault.path)?
+            .rekey(key, new_passphrase, KdfParams::generate()?)?
+            .write_to(&vault.path)
+    }
+
+    pub async fn store(name: &str, data: &[u8]) -> Result<(), CredentialError> {
+        let vault = Self::current().write().await;
+        let key = vault.key.as_ref().ok_or(CredentialError::VaultLocked)?;
+        let mut file = VaultFile::read_from(&vault.path)?;
+        file.insert(key, name, data)?;
+        file.write_to(&vault.path)
+    }
+
+    pub async fn load(name: &str) -> Result<Vec<u8>, CredentialError> {
+        let vault = Self::current().read().await;
+        let key = vault.key.as_ref().ok_or(CredentialError::VaultLocked)?;
+        VaultFile::read_from(&vault.path)?.get(key, name)
+    }
+
+    pub async fn remove(name: &str) -> Result<(), CredentialError> {
+        let vault = Self::current().write().await;
+        if !vault.path.exists() {
+            return Ok(());
+        }
+        let mut file = VaultFile::read_from(&vault.path)?;
+        if file.remove(name) {
+            file.write_to(&vault.path)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Tests for the encrypted credential vault file format
+
+use tari_utilities::SafePassword;
+
+use crate::credential_manager::CredentialError;
+use crate::credential_vault::{KdfParams, VaultFile};
+use crate::testing::test_utils::TestContext;
+
+/// Cheap KDF settings so tests don't spend seconds in Argon2
+fn test_kdf() -> KdfParams {
+    KdfParams {
+        salt: vec![7u8; 16],
+        memory_kib: 64,
+        iterations: 1,
+        parallelism: 1,
+    }
+}
+
+// ==================== Unlocking ====================
+
+#[test]
+fn vault_unlocks_with_the_creating_passphrase() {
+    let passphrase = SafePassword::from("123456");
+    let (mut vault, key) = VaultFile::create(&passphrase, test_kdf()).unwrap();
+    vault.insert(&key, "wallet", b"seed bytes").unwrap();
+
+    let unlocked_key = vault.unlock(&passphrase).unwrap();
+
+    assert_eq!(vault.get(&unlocked_key, "wallet").unwrap(), b"seed bytes");
+}
+
+#[test]
+fn wrong_passphrase_is_rejected() {
+    let (vault, _key) = VaultFile::create(&SafePassword::from("123456"), test_kdf()).unwrap();
+
+    let result = vault.unlock(&SafePassword::from("654321"));
+
+    assert!(matches!(result, Err(CredentialError::WrongPassphrase)));
+}
+
+// ==================== Entries ====================
+
+#[test]
+fn missing_entry_reports_no_entry() {
+    let (vault, key) = VaultFile::create(&SafePassword::from("123456"), test_kdf()).unwrap();
+
+    let result = vault.get(&key, "monero");
+
+    assert!(matches!(result, Err(CredentialError::NoEntry(name)) if name == "monero"));
+}
+
+#[test]
+fn removed_entry_is_gone() {
+    let (mut vault, key) = VaultFile::create(&SafePassword::from("123456"), test_kdf()).unwrap();
+    vault.insert(&key, "wallet", b"seed bytes").unwrap();
+
+    assert!(vault.remove("wallet"));
+    assert!(!vault.remove("wallet"));
+    assert!(matches!(
+        vault.get(&key, "wallet"),
+        Err(CredentialError::NoEntry(_))
+    ));
+}
+
+#[test]
+fn rekey_moves_entries_to_new_passphrase() {
+    let (mut vault, key) = VaultFile::create(&SafePassword::from("1111"), test_kdf()).unwrap();
+    vault.insert(&key, "wallet", b"tari").unwrap();
+    vault.insert(&key, "monero", b"xmr").unwrap();
+
+    let mut new_kdf = test_kdf();
+    new_kdf.salt = vec![9u8; 16];
+    let rekeyed = vault
+        .rekey(&key, &SafePassword::from("2222"), new_kdf)
+        .unwrap();
+
+    assert!(matches!(
+        rekeyed.unlock(&SafePassword::from("1111")),
+        Err(CredentialError::WrongPassphrase)
+    ));
+    let new_key = rekeyed.unlock(&SafePassword::from("2222")).unwrap();
+    assert_eq!(rekeyed.get(&new_key, "wallet").unwrap(), b"tari");
+    assert_eq!(rekeyed.get(&new_key, "monero").unwrap(), b"xmr");
+}
+
+// ==================== Key wrapping ====================
+
+#[test]
+fn vault_key_is_random_not_derived_from_the_passphrase() {
+    let passphrase = SafePassword::from("123456");
+    let (first, first_key) = VaultFile::create(&passphrase, test_kdf()).unwrap();
+    let (_second, second_key) = VaultFile::create(&passphrase, test_kdf()).unwrap();
+
+    assert_ne!(first_key.reveal(), second_key.reveal());
+    assert_eq!(
+        first.unlock(&passphrase).unwrap().reveal(),
+        first_key.reveal()
+    );
+}
+
+#[test]
+fn rekey_only_rewraps_the_vault_key() {
+    let (mut vault, key) = VaultFile::create(&SafePassword::from("1111"), test_kdf()).unwrap();
+    vault.insert(&key, "wallet", b"tari").unwrap();
+
+    let rekeyed = vault
+        .rekey(&key, &SafePassword::from("2222"), test_kdf())
+        .unwrap();
+    let new_key = rekeyed.unlock(&SafePassword::from("2222")).unwrap();
+
+    assert_eq!(new_key.reveal(), key.reveal());
+    assert_eq!(rekeyed.get(&key, "wallet").unwrap(), b"tari");
+}
+
+// ==================== Persistence ====================
+
+#[test]
+fn vault_survives_write_and_read() {
+    let ctx = TestContext::new();
+    let path = ctx.config_dir.join("credential_vault.bin");
+    let passphrase = SafePassword::from("123456");
+    let (mut vault, key) = VaultFile::create(&passphrase, test_kdf()).unwrap();
+    vault.insert(&key, "wallet", b"seed bytes").unwrap();
+
+    vault.write_to(&path).unwrap();
+    let loaded = VaultFile::read_from(&path).unwrap();
+    let loaded_key = loaded.unlock(&passphrase).unwrap();
+
+    assert_eq!(loaded.get(&loaded_key, "wallet").unwrap(), b"seed bytes");
+    assert!(!path.with_extension("tmp").exists());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
+
+#[test]
+fn corrupt_vault_file_fails_to_load() {
+    let ctx = TestContext::new();
+    let path = ctx.config_dir.join("credential_vault.bin");
+    std::fs::write(&path, b"not a vault").unwrap();
+
+    assert!(VaultFile::read_from(&path).is_err());
+}
@@ -64,6 +64,7 @@ pub enum EventType {
     McpServerStatusUpdate,
     McpTransactionConfirmation,
     McpTransactionResult,
+    InvoicePaid,
     BackgroundNodeSyncUpdate,
     InitWalletScanningProgress,
     ConnectionStatus,
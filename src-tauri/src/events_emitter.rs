@@ -35,6 +35,7 @@ use crate::mining::gpu::miners::GpuCommonInformation;
 use crate::mining::pools::PoolStatus;
 #[cfg(target_os = "windows")]
 use crate::system_dependencies::UniversalSystemDependency;
+use crate::wallet::payment_requests::Invoice;
 use crate::wallet::wallet_types::{TransactionInfo, WalletBalance};
 use crate::{
     BaseNodeStatus, LOG_TARGET_APP_LOGIC,
@@ -418,6 +419,20 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_invoice_paid(invoice: Invoice) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::InvoicePaid,
+            payload: invoice,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit InvoicePaid event: {e:?}");
+        }
+    }
+
     pub async fn emit_wallet_balance_update(balance: WalletBalance) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
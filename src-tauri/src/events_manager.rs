@@ -22,16 +22,20 @@
 
 use std::time::Duration;
 
-use log::{error, info};
+use log::{error, info, warn};
 use tari_transaction_components::tari_amount::MicroMinotari;
 use tauri::{AppHandle, Manager};
 
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::airdrop::send_new_block_mined;
 use crate::configs::config_core::ConfigCore;
+use crate::configs::config_notifications::NotificationEventType;
 use crate::configs::trait_config::ConfigImpl;
+use crate::mining::block_ledger::{BlockLedger, RECONCILE_INTERVAL_BLOCKS};
+use crate::notifications::{NotificationEvent, Notifications};
 use crate::setup::listeners::SetupFeature;
 use crate::setup::setup_manager::SetupManager;
+use crate::wallet::payment_requests::InvoiceBook;
 use crate::{
     UniverseAppState, events::NodeTypeUpdatePayload, events_emitter::EventsEmitter,
     tasks_tracker::TasksTrackers,
@@ -56,8 +60,18 @@ impl EventsManager {
         drop(in_memory_config);
         let app_clone = app.clone();
         let wallet_manager = state.wallet_manager.clone();
+        let node_manager = state.node_manager.clone();
+        let telemetry_manager = state.telemetry_manager.clone();
 
         TasksTrackers::current().wallet_phase.get_task_tracker().await.spawn(async move {
+            // The merge mining proxy tags our coinbases with the telemetry id, so won blocks can be
+            // found on the node even when the wallet never sees the reward
+            if node_manager.is_local().await {
+                let coinbase_extra = telemetry_manager.read().await.get_unique_string().await;
+                if let Err(e) = BlockLedger::record_chain_block(&node_manager, block_height, &coinbase_extra).await {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Failed to check block #{block_height} for our coinbase: {e:?}");
+                }
+            }
             // Event does not need to be fired immediately since frontend uses block height from explorer
             match wallet_manager.wait_for_scan_to_height(block_height, Some(Duration::from_secs(20))).await {
                 Ok(scanned_wallet_state) => {
@@ -82,6 +96,49 @@ impl EventsManager {
                         if coinbase_tx.is_some() && allow_notifications {
                             send_new_block_mined(app_clone.clone(), block_height).await;
                         }
+                        if let Some(coinbase_tx) = &coinbase_tx
+                            && let Err(e) = BlockLedger::record_block_won(coinbase_tx, &node_manager).await
+                        {
+                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to record mined block #{block_height}: {e:?}");
+                        }
+                        if let Some(coinbase_tx) = &coinbase_tx {
+                            Notifications::notify(NotificationEvent::BlockWon {
+                                block_height,
+                                reward: coinbase_tx.amount.as_u64(),
+                            })
+                            .await;
+                        }
+                        if Notifications::is_subscribed(NotificationEventType::IncomingTransaction).await {
+                            match wallet_manager.find_incoming_transactions_for_block(block_height).await {
+                                Ok(transactions) => {
+                                    for tx in transactions {
+                                        Notifications::notify(NotificationEvent::IncomingTransaction {
+                                            tx_id: tx.tx_id,
+                                            amount: tx.amount.as_u64(),
+                                            block_height,
+                                            payment_id: tx.payment_id,
+                                        })
+                                        .await;
+                                    }
+                                }
+                                Err(e) => warn!(target: LOG_TARGET_APP_LOGIC, "Failed to look up incoming transactions for block #{block_height}: {e:?}"),
+                            }
+                        }
+                        if InvoiceBook::has_open_invoices().await {
+                            match InvoiceBook::reconcile(&wallet_manager, block_height).await {
+                                Ok(paid) => {
+                                    for invoice in paid {
+                                        EventsEmitter::emit_invoice_paid(invoice).await;
+                                    }
+                                }
+                                Err(e) => warn!(target: LOG_TARGET_APP_LOGIC, "Failed to reconcile invoices: {e}"),
+                            }
+                        }
+                        if (BlockLedger::has_unsettled_entries(block_height).await || block_height % RECONCILE_INTERVAL_BLOCKS == 0)
+                            && let Err(e) = BlockLedger::reconcile(&node_manager, &wallet_manager, block_height).await
+                        {
+                            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to reconcile mined block ledger: {e:?}");
+                        }
                     } else {
                         error!(target: LOG_TARGET_APP_LOGIC, "Wallet balance is None after new block height #{block_height}");
                         EventsEmitter::emit_new_block_mined(
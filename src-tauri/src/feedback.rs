@@ -37,6 +37,7 @@ use crate::LOG_TARGET_APP_LOGIC;
 use crate::app_in_memory_config::AppInMemoryConfig;
 use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
+use crate::requests::connectivity;
 use crate::utils::file_utils::{make_relative_path, path_as_string};
 const MAX_FILE_SIZE: u64 = 100 * 1024 * 1024; // 100MB in bytes
 pub struct Feedback {
@@ -196,7 +197,7 @@ impl Feedback {
         let jwt = airdrop_tokens.map(|tokens| tokens.token);
 
         // Send the POST request
-        let mut req = reqwest::Client::new().post(feedback_url).multipart(form);
+        let mut req = connectivity::client()?.post(feedback_url).multipart(form);
         if let Some(jwt) = jwt {
             req = req.header("Authorization", format!("Bearer {jwt}"));
         }
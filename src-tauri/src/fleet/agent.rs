@@ -0,0 +1,423 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Rig side of fleet mode.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use log::{info, warn};
+use tokio::net::TcpStream;
+use tokio::sync::{RwLock, watch};
+use tokio::task::JoinHandle;
+use tokio::time;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_core::ConfigCore;
+use crate::configs::config_fleet::ConfigFleet;
+use crate::configs::config_mining::ConfigMining;
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::trait_config::ConfigImpl;
+use crate::fleet::audit::{FleetAuditEntry, FleetAuditLog, FleetAuditStatus};
+use crate::fleet::identity::FleetIdentity;
+use crate::fleet::protocol::{
+    CommandGate, ControllerMessage, FLEET_PROTOCOL_VERSION, FleetCommand, RigDeviceStatus,
+    RigMessage, RigMiningStatus, RigNodeStatus, RigStatus, RigWalletStatus, SignedCommand,
+    hello_message, new_nonce, read_message, send_message, verify_signature, welcome_message,
+};
+use crate::hardware::hardware_status_monitor::HardwareStatusMonitor;
+use crate::mcp::tools::scheduler::MiningWindowParams;
+use crate::mcp::tools::{mining, scheduler};
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::gpu::consts::GpuMinerStatus;
+use crate::node::node_adapter::BaseNodeStatus;
+use crate::tasks_tracker::TasksTrackers;
+use crate::wallet::wallet_types::WalletState;
+
+const STATUS_INTERVAL: Duration = Duration::from_secs(15);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const MIN_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(300);
+const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+
+type ControllerStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+static INSTANCE: LazyLock<RwLock<FleetAgent>> = LazyLock::new(|| RwLock::new(FleetAgent::new()));
+
+#[derive(Clone)]
+struct StatusSources {
+    cpu_miner_status: watch::Receiver<CpuMinerStatus>,
+    gpu_miner_status: watch::Receiver<GpuMinerStatus>,
+    node_status: watch::Receiver<BaseNodeStatus>,
+    wallet_state: watch::Receiver<Option<WalletState>>,
+}
+
+impl StatusSources {
+    async fn collect(&self, app_version: &str) -> RigStatus {
+        let cpu_miner_status = self.cpu_miner_status.borrow().clone();
+        let gpu_miner_status = self.gpu_miner_status.borrow().clone();
+        let node_status = *self.node_status.borrow();
+        let available_balance = self
+            .wallet_state
+            .borrow()
+            .as_ref()
+            .and_then(|state| state.balance.as_ref())
+            .map(|balance| balance.available_balance.as_u64());
+
+        let pools = ConfigPools::content().await;
+        let monitor = HardwareStatusMonitor::current();
+        let cpu_devices = monitor
+            .get_cpu_public_properties()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|device| (device.name, device.parameters));
+        let gpu_devices = monitor
+            .get_gpu_public_properties()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|device| (device.name, device.parameters));
+
+        RigStatus {
+            app_version: app_version.to_string(),
+            mining: RigMiningStatus {
+                mode: ConfigMining::content().await.selected_mining_mode().clone(),
+                cpu_mining: cpu_miner_status.is_mining,
+                cpu_hash_rate: cpu_miner_status.hash_rate,
+                cpu_pool: pools.current_cpu_pool().pool_name,
+                gpu_mining: gpu_miner_status.is_mining,
+                gpu_hash_rate: gpu_miner_status.hash_rate,
+                gpu_pool: pools.current_gpu_pool().pool_name,
+            },
+            node: RigNodeStatus {
+                block_height: node_status.block_height,
+                is_synced: node_status.is_synced,
+                num_connections: node_status.num_connections,
+            },
+            wallet: RigWalletStatus { available_balance },
+            hardware: cpu_devices
+                .chain(gpu_devices)
+                .map(|(name, parameters)| RigDeviceStatus {
+                    name,
+                    usage_percentage: parameters.as_ref().map(|p| p.usage_percentage),
+                    temperature: parameters.as_ref().map(|p| p.current_temperature),
+                })
+                .collect(),
+        }
+    }
+}
+
+struct AgentSettings {
+    rig_id: String,
+    controller_url: String,
+    controller_pub_key: String,
+    app_version: String,
+}
+
+pub struct FleetAgent {
+    sources: Option<StatusSources>,
+    handle: Option<JoinHandle<()>>,
+    stop_tx: Option<watch::Sender<bool>>,
+}
+
+impl FleetAgent {
+    fn new() -> Self {
+        Self {
+            sources: None,
+            handle: None,
+            stop_tx: None,
+        }
+    }
+
+    pub async fn initialize(
+        cpu_miner_status: watch::Receiver<CpuMinerStatus>,
+        gpu_miner_status: watch::Receiver<GpuMinerStatus>,
+        node_status: watch::Receiver<BaseNodeStatus>,
+        wallet_state: watch::Receiver<Option<WalletState>>,
+    ) {
+        INSTANCE.write().await.sources = Some(StatusSources {
+            cpu_miner_status,
+            gpu_miner_status,
+            node_status,
+            wallet_state,
+        });
+    }
+
+    pub async fn start(app_version: String) -> anyhow::Result<()> {
+        let config = ConfigFleet::content().await;
+        let controller_url = config
+            .controller_url()
+            .clone()
+            .ok_or_else(|| anyhow!("No fleet controller address is configured"))?;
+        let controller_pub_key = config
+            .controller_pub_key()
+            .clone()
+            .ok_or_else(|| anyhow!("The fleet controller's public key is not pinned"))?;
+        // Surface a broken key file now rather than on every reconnect
+        FleetIdentity::current()?;
+
+        let sources = INSTANCE
+            .read()
+            .await
+            .sources
+            .clone()
+            .ok_or_else(|| anyhow!("Fleet agent not initialized"))?;
+        let settings = AgentSettings {
+            rig_id: ConfigCore::content().await.anon_id().clone(),
+            controller_url,
+            controller_pub_key,
+            app_version,
+        };
+
+        let (stop_tx, stop_rx) = watch::channel(false);
+        let handle = TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(Self::run(settings, sources, stop_rx));
+
+        let mut agent = INSTANCE.write().await;
+        agent.handle = Some(handle);
+        agent.stop_tx = Some(stop_tx);
+        Ok(())
+    }
+
+    pub async fn stop() {
+        let (handle, stop_tx) = {
+            let mut agent = INSTANCE.write().await;
+            (agent.handle.take(), agent.stop_tx.take())
+        };
+        if let Some(stop_tx) = stop_tx {
+            let _unused = stop_tx.send(true);
+        }
+        if let Some(mut handle) = handle
+            && time::timeout(STOP_TIMEOUT, &mut handle).await.is_err()
+        {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Fleet agent did not stop in {STOP_TIMEOUT:?}, aborting it");
+            handle.abort();
+        }
+    }
+
+    async fn run(
+        settings: AgentSettings,
+        sources: StatusSources,
+        mut stop_rx: watch::Receiver<bool>,
+    ) {
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        let mut reconnect_delay = MIN_RECONNECT_DELAY;
+
+        loop {
+            let was_connected = tokio::select! {
+                was_connected = Self::run_session(&settings, &sources) => was_connected,
+                () = shutdown_signal.wait() => return,
+                _ = stop_rx.wait_for(|stop| *stop) => return,
+            };
+            reconnect_delay = if was_connected {
+                MIN_RECONNECT_DELAY
+            } else {
+                (reconnect_delay * 2).min(MAX_RECONNECT_DELAY)
+            };
+            tokio::select! {
+                () = time::sleep(reconnect_delay) => {},
+                () = shutdown_signal.wait() => return,
+                _ = stop_rx.wait_for(|stop| *stop) => return,
+            }
+        }
+    }
+
+    /// Returns whether the controller accepted this rig before the connection ended
+    async fn run_session(settings: &AgentSettings, sources: &StatusSources) -> bool {
+        let (ws, gate) = match time::timeout(HANDSHAKE_TIMEOUT, Self::connect(settings)).await {
+            Ok(Ok(connection)) => connection,
+            Ok(Err(e)) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Could not join fleet controller {}: {e}", settings.controller_url);
+                return false;
+            }
+            Err(_) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Fleet controller {} did not answer in time", settings.controller_url);
+                return false;
+            }
+        };
+        info!(target: LOG_TARGET_APP_LOGIC, "Joined fleet controller {}", settings.controller_url);
+        if let Err(e) = Self::serve(settings, sources, ws, gate).await {
+            info!(target: LOG_TARGET_APP_LOGIC, "Disconnected from fleet controller: {e}");
+        }
+        true
+    }
+
+    async fn connect(settings: &AgentSettings) -> anyhow::Result<(ControllerStream, CommandGate)> {
+        let identity = FleetIdentity::current()?;
+        let mut ws =
+            crate::requests::connectivity::connect_websocket(&settings.controller_url).await?;
+
+        let controller_nonce = match read_message(&mut ws).await? {
+            ControllerMessage::Challenge { nonce, version }
+                if version == FLEET_PROTOCOL_VERSION =>
+            {
+                nonce
+            }
+            ControllerMessage::Challenge { version, .. } => {
+                bail!(
+                    "Controller speaks fleet protocol v{version}, expected v{FLEET_PROTOCOL_VERSION}"
+                )
+            }
+            other => bail!("Expected a challenge, got {other:?}"),
+        };
+
+        let rig_nonce = new_nonce()?;
+        let hello = RigMessage::Hello {
+            rig_id: settings.rig_id.clone(),
+            pub_key: identity.pub_key().to_string(),
+            signature: identity.sign(&hello_message(
+                &settings.rig_id,
+                &controller_nonce,
+                &rig_nonce,
+            ))?,
+            nonce: rig_nonce.clone(),
+        };
+        send_message(&mut ws, &hello).await?;
+
+        match read_message(&mut ws).await? {
+            ControllerMessage::Welcome { pub_key, signature } => {
+                if pub_key != settings.controller_pub_key {
+                    bail!("Controller presented a key other than the pinned one");
+                }
+                verify_signature(
+                    &pub_key,
+                    &welcome_message(&settings.rig_id, &controller_nonce, &rig_nonce),
+                    &signature,
+                )?;
+            }
+            ControllerMessage::Rejected { reason } => {
+                bail!("Controller rejected this rig: {reason}")
+            }
+            other => bail!("Expected a welcome, got {other:?}"),
+        }
+
+        Ok((
+            ws,
+            CommandGate::new(settings.controller_pub_key.clone(), rig_nonce),
+        ))
+    }
+
+    async fn serve(
+        settings: &AgentSettings,
+        sources: &StatusSources,
+        mut ws: ControllerStream,
+        mut gate: CommandGate,
+    ) -> anyhow::Result<()> {
+        let mut interval = time::interval(STATUS_INTERVAL);
+        interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let status = sources.collect(&settings.app_version).await;
+                    send_message(&mut ws, &RigMessage::Status(status)).await?;
+                }
+                message = read_message(&mut ws) => match message? {
+                    ControllerMessage::Command(command) => {
+                        let result = Self::handle_command(&settings.rig_id, &mut gate, command).await;
+                        send_message(&mut ws, &result).await?;
+                    }
+                    other => warn!(target: LOG_TARGET_APP_LOGIC, "Ignoring unexpected fleet message: {other:?}"),
+                }
+            }
+        }
+    }
+
+    async fn handle_command(
+        rig_id: &str,
+        gate: &mut CommandGate,
+        signed: SignedCommand,
+    ) -> RigMessage {
+        let (status, result) = match gate.accept(&signed) {
+            Ok(()) => match execute(signed.command.clone()).await {
+                Ok(message) => (FleetAuditStatus::Succeeded, Ok(message)),
+                Err(message) => (FleetAuditStatus::Failed, Err(message)),
+            },
+            Err(e) => (FleetAuditStatus::Rejected, Err(e.to_string())),
+        };
+        info!(target: LOG_TARGET_APP_LOGIC, "Fleet command {} {:?}: {status:?}", signed.id, signed.command);
+
+        let success = result.is_ok();
+        let message = result.unwrap_or_else(|e| e);
+        FleetAuditLog::record(FleetAuditEntry::new(
+            rig_id,
+            &signed.id,
+            &signed.command,
+            status,
+            Some(message.clone()),
+        ))
+        .await;
+
+        RigMessage::CommandResult {
+            id: signed.id,
+            success,
+            message,
+        }
+    }
+}
+
+async fn execute(command: FleetCommand) -> Result<String, String> {
+    match command {
+        FleetCommand::SetMiningMode { mode } => mining::set_mining_mode(mode).await,
+        FleetCommand::StartMining { cpu, gpu } => mining::start_mining(cpu, gpu).await,
+        FleetCommand::StopMining { cpu, gpu } => mining::stop_mining(cpu, gpu).await,
+        FleetCommand::ChangeCpuPool { pool } => crate::commands::change_cpu_pool(pool.clone())
+            .await
+            .map(|()| format!("CPU pool changed to {pool}"))
+            .map_err(|e| e.0.to_string()),
+        FleetCommand::ChangeGpuPool { pool } => crate::commands::change_gpu_pool(pool.clone())
+            .await
+            .map(|()| format!("GPU pool changed to {pool}"))
+            .map_err(|e| e.0.to_string()),
+        FleetCommand::ScheduleMining {
+            event_id,
+            mining_mode,
+            start_hour,
+            start_minute,
+            start_period,
+            end_hour,
+            end_minute,
+            end_period,
+        } => {
+            scheduler::schedule_mining_window(MiningWindowParams {
+                event_id,
+                mining_mode,
+                start_hour,
+                start_minute,
+                start_period,
+                end_hour,
+                end_minute,
+                end_period,
+            })
+            .await
+        }
+        FleetCommand::CancelSchedule { event_id } => {
+            scheduler::cancel_scheduled_event(event_id).await
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Append-only record of every fleet command, written on both the controller and the rig.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::SystemTime;
+
+use dirs::config_dir;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::RwLock;
+
+use crate::fleet::protocol::FleetCommand;
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+const MAX_BUFFER_SIZE: usize = 500;
+const MAX_LOG_LINES: usize = 10_000;
+const LOG_FILE_NAME: &str = "fleet_audit.jsonl";
+
+static INSTANCE: LazyLock<RwLock<FleetAuditLog>> =
+    LazyLock::new(|| RwLock::new(FleetAuditLog::new()));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetAuditEntry {
+    pub timestamp: SystemTime,
+    pub rig_id: String,
+    pub command_id: String,
+    pub command: FleetCommand,
+    pub status: FleetAuditStatus,
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum FleetAuditStatus {
+    /// Controller signed and sent the command
+    Sent,
+    /// Rig refused the command, e.g. because its signature didn't verify
+    Rejected,
+    Succeeded,
+    Failed,
+}
+
+impl FleetAuditEntry {
+    pub fn new(
+        rig_id: &str,
+        command_id: &str,
+        command: &FleetCommand,
+        status: FleetAuditStatus,
+        details: Option<String>,
+    ) -> Self {
+        Self {
+            timestamp: SystemTime::now(),
+            rig_id: rig_id.to_string(),
+            command_id: command_id.to_string(),
+            command: command.clone(),
+            status,
+            details,
+        }
+    }
+}
+
+pub struct FleetAuditLog {
+    buffer: VecDeque<FleetAuditEntry>,
+    log_path: PathBuf,
+    line_count: usize,
+}
+
+impl FleetAuditLog {
+    fn new() -> Self {
+        let log_path = config_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str())
+            .join(LOG_FILE_NAME);
+        let line_count = Self::count_lines(&log_path);
+        Self {
+            buffer: VecDeque::with_capacity(MAX_BUFFER_SIZE),
+            log_path,
+            line_count,
+        }
+    }
+
+    fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    fn count_lines(path: &Path) -> usize {
+        match File::open(path) {
+            Ok(file) => BufReader::new(file).lines().count(),
+            Err(_) => 0,
+        }
+    }
+
+    pub async fn record(entry: FleetAuditEntry) {
+        let mut log = Self::current().write().await;
+        let serialized = serde_json::to_string(&entry);
+
+        if log.buffer.len() >= MAX_BUFFER_SIZE {
+            log.buffer.pop_front();
+        }
+        log.buffer.push_back(entry);
+
+        if log.line_count >= MAX_LOG_LINES {
+            log.rotate().await;
+        }
+
+        let Ok(serialized) = serialized else {
+            return;
+        };
+        if let Some(parent) = log.log_path.parent() {
+            let _unused = tokio::fs::create_dir_all(parent).await;
+        }
+        match tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log.log_path)
+            .await
+        {
+            Ok(mut file) => {
+                if file
+                    .write_all(format!("{serialized}\n").as_bytes())
+                    .await
+                    .is_ok()
+                {
+                    log.line_count += 1;
+                }
+            }
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to open fleet audit log: {e:?}");
+            }
+        }
+    }
+
+    /// Keeps a single previous log around, the controller's history is in the rigs' logs too
+    async fn rotate(&mut self) {
+        let rotated_path = self.log_path.with_extension("1.jsonl");
+        if let Err(e) = tokio::fs::rename(&self.log_path, &rotated_path).await {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to rotate fleet audit log: {e:?}");
+        } else {
+            info!(target: LOG_TARGET_APP_LOGIC, "Rotated fleet audit log to {rotated_path:?}");
+        }
+        self.line_count = 0;
+    }
+
+    pub async fn get_recent(count: usize) -> Vec<FleetAuditEntry> {
+        let log = Self::current().read().await;
+        log.buffer.iter().rev().take(count).cloned().collect()
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::net::SocketAddr;
+
+use tauri::AppHandle;
+
+use crate::configs::config_fleet::{ConfigFleet, ConfigFleetContent, FleetRig, FleetRole};
+use crate::configs::trait_config::ConfigImpl;
+use crate::fleet::audit::{FleetAuditEntry, FleetAuditLog};
+use crate::fleet::controller::{FleetController, FleetRigView};
+use crate::fleet::identity::FleetIdentity;
+use crate::fleet::protocol::{FleetCommand, parse_pub_key};
+
+async fn restart_fleet(app: &AppHandle) -> Result<(), String> {
+    crate::fleet::restart(app.package_info().version.to_string())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_fleet_config() -> Result<ConfigFleetContent, String> {
+    Ok(ConfigFleet::content().await)
+}
+
+/// Public key other fleet members pin this install by
+#[tauri::command]
+pub async fn get_fleet_identity() -> Result<String, String> {
+    FleetIdentity::current()
+        .map(|identity| identity.pub_key().to_string())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_fleet_role(app: AppHandle, role: FleetRole) -> Result<(), String> {
+    ConfigFleet::update_field(ConfigFleetContent::set_role, role)
+        .await
+        .map_err(|e| e.to_string())?;
+    restart_fleet(&app).await
+}
+
+#[tauri::command]
+pub async fn set_fleet_controller(
+    app: AppHandle,
+    controller_url: String,
+    controller_pub_key: String,
+) -> Result<(), String> {
+    let url = url::Url::parse(controller_url.trim()).map_err(|e| e.to_string())?;
+    if !matches!(url.scheme(), "ws" | "wss") {
+        return Err("Controller address must be a ws:// or wss:// URL".to_string());
+    }
+    parse_pub_key(&controller_pub_key).map_err(|e| e.to_string())?;
+
+    ConfigFleet::update_field(
+        ConfigFleetContent::set_controller_url,
+        Some(url.to_string()),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    ConfigFleet::update_field(
+        ConfigFleetContent::set_controller_pub_key,
+        Some(controller_pub_key.trim().to_string()),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    restart_fleet(&app).await
+}
+
+#[tauri::command]
+pub async fn set_fleet_listen_address(
+    app: AppHandle,
+    listen_address: String,
+) -> Result<(), String> {
+    let listen_address: SocketAddr = listen_address.trim().parse().map_err(|_| {
+        format!("Invalid listen address {listen_address}, expected an IP address and port")
+    })?;
+    ConfigFleet::update_field(ConfigFleetContent::set_listen_address, listen_address)
+        .await
+        .map_err(|e| e.to_string())?;
+    restart_fleet(&app).await
+}
+
+#[tauri::command]
+pub async fn enroll_fleet_rig(rig_id: String, name: String, pub_key: String) -> Result<(), String> {
+    let rig_id = rig_id.trim().to_string();
+    if rig_id.is_empty() {
+        return Err("Rig id cannot be empty".to_string());
+    }
+    parse_pub_key(&pub_key).map_err(|e| e.to_string())?;
+
+    let mut rigs = ConfigFleet::content().await.rigs().clone();
+    rigs.retain(|rig| rig.rig_id != rig_id);
+    rigs.push(FleetRig {
+        name: if name.trim().is_empty() {
+            rig_id.clone()
+        } else {
+            name.trim().to_string()
+        },
+        rig_id,
+        pub_key: pub_key.trim().to_string(),
+    });
+    ConfigFleet::update_field(ConfigFleetContent::set_rigs, rigs)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn remove_fleet_rig(rig_id: String) -> Result<(), String> {
+    let mut rigs = ConfigFleet::content().await.rigs().clone();
+    rigs.retain(|rig| rig.rig_id != rig_id);
+    ConfigFleet::update_field(ConfigFleetContent::set_rigs, rigs)
+        .await
+        .map_err(|e| e.to_string())?;
+    FleetController::disconnect(&rig_id).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_fleet_rigs() -> Result<Vec<FleetRigView>, String> {
+    Ok(FleetController::rigs().await)
+}
+
+/// Returns the id the command is tracked under in the audit log
+#[tauri::command]
+pub async fn send_fleet_command(rig_id: String, command: FleetCommand) -> Result<String, String> {
+    FleetController::send_command(&rig_id, command)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_fleet_audit_log(count: Option<usize>) -> Result<Vec<FleetAuditEntry>, String> {
+    Ok(FleetAuditLog::get_recent(count.unwrap_or(100)).await)
+}
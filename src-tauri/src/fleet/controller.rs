@@ -0,0 +1,374 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Controller side of fleet mode.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, bail};
+use log::{error, info, warn};
+use serde::Serialize;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{RwLock, mpsc, watch};
+use tokio::task::JoinHandle;
+use tokio::time;
+use tokio_tungstenite::WebSocketStream;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_fleet::ConfigFleet;
+use crate::configs::trait_config::ConfigImpl;
+use crate::fleet::audit::{FleetAuditEntry, FleetAuditLog, FleetAuditStatus};
+use crate::fleet::identity::FleetIdentity;
+use crate::fleet::protocol::{
+    ControllerMessage, FLEET_PROTOCOL_VERSION, FleetCommand, RigMessage, RigStatus, SignedCommand,
+    command_message, hello_message, new_nonce, read_message, send_message, verify_signature,
+    welcome_message,
+};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const OUTBOX_SIZE: usize = 16;
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+static INSTANCE: LazyLock<RwLock<FleetController>> =
+    LazyLock::new(|| RwLock::new(FleetController::new()));
+
+struct ConnectedRig {
+    session: String,
+    outbox: mpsc::Sender<ControllerMessage>,
+    connected_at: SystemTime,
+    status: Option<RigStatus>,
+    last_status_at: Option<SystemTime>,
+    pending: HashMap<String, FleetCommand>,
+}
+
+/// An enrolled rig as shown to the controller's operator
+#[derive(Debug, Clone, Serialize)]
+pub struct FleetRigView {
+    pub rig_id: String,
+    pub name: String,
+    pub connected_at: Option<SystemTime>,
+    pub last_status_at: Option<SystemTime>,
+    pub status: Option<RigStatus>,
+}
+
+pub struct FleetController {
+    server_handle: Option<JoinHandle<()>>,
+    shutdown_tx: Option<watch::Sender<bool>>,
+    rigs: HashMap<String, ConnectedRig>,
+}
+
+impl FleetController {
+    fn new() -> Self {
+        Self {
+            server_handle: None,
+            shutdown_tx: None,
+            rigs: HashMap::new(),
+        }
+    }
+
+    pub async fn start() -> anyhow::Result<SocketAddr> {
+        // Rigs pin this key, so create it before anyone can connect
+        FleetIdentity::current()?;
+        let address = *ConfigFleet::content().await.listen_address();
+        let listener = TcpListener::bind(address)
+            .await
+            .map_err(|e| anyhow!("Fleet controller failed to bind to {address}: {e}"))?;
+        let bound_address = listener.local_addr()?;
+        info!(target: LOG_TARGET_APP_LOGIC, "Fleet controller listening on {bound_address}");
+
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let connection_shutdown_rx = shutdown_rx.clone();
+        let handle = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, peer)) => {
+                            tokio::spawn(Self::handle_connection(stream, peer, connection_shutdown_rx.clone()));
+                        }
+                        Err(e) => warn!(target: LOG_TARGET_APP_LOGIC, "Fleet controller failed to accept a connection: {e}"),
+                    },
+                    _ = shutdown_rx.wait_for(|stop| *stop) => break,
+                }
+            }
+            info!(target: LOG_TARGET_APP_LOGIC, "Fleet controller stopped");
+        });
+
+        let mut controller = INSTANCE.write().await;
+        controller.server_handle = Some(handle);
+        controller.shutdown_tx = Some(shutdown_tx);
+        Ok(bound_address)
+    }
+
+    pub async fn stop() {
+        let (handle, shutdown_tx) = {
+            let mut controller = INSTANCE.write().await;
+            controller.rigs.clear();
+            (
+                controller.server_handle.take(),
+                controller.shutdown_tx.take(),
+            )
+        };
+        if let Some(shutdown_tx) = shutdown_tx {
+            let _unused = shutdown_tx.send(true);
+        }
+        if let Some(handle) = handle {
+            match time::timeout(SHUTDOWN_TIMEOUT, handle).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Fleet controller task panicked: {e:?}");
+                }
+                Err(_) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Fleet controller shutdown timed out after {SHUTDOWN_TIMEOUT:?}");
+                }
+            }
+        }
+    }
+
+    /// Every enrolled rig, with the live state of those currently connected
+    pub async fn rigs() -> Vec<FleetRigView> {
+        let enrolled = ConfigFleet::content().await.rigs().clone();
+        let controller = INSTANCE.read().await;
+        enrolled
+            .into_iter()
+            .map(|rig| {
+                let connected = controller.rigs.get(&rig.rig_id);
+                FleetRigView {
+                    connected_at: connected.map(|c| c.connected_at),
+                    last_status_at: connected.and_then(|c| c.last_status_at),
+                    status: connected.and_then(|c| c.status.clone()),
+                    rig_id: rig.rig_id,
+                    name: rig.name,
+                }
+            })
+            .collect()
+    }
+
+    /// Drops the rig's connection, e.g. after it was removed from the enrolled rigs
+    pub async fn disconnect(rig_id: &str) {
+        if INSTANCE.write().await.rigs.remove(rig_id).is_some() {
+            info!(target: LOG_TARGET_APP_LOGIC, "Disconnected rig {rig_id} from the fleet");
+        }
+    }
+
+    /// Signs `command` for the rig's current session and queues it, returning the command id
+    pub async fn send_command(rig_id: &str, command: FleetCommand) -> anyhow::Result<String> {
+        let identity = FleetIdentity::current()?;
+        let id = uuid::Uuid::new_v4().to_string();
+
+        let queued = {
+            let mut controller = INSTANCE.write().await;
+            let rig = controller
+                .rigs
+                .get_mut(rig_id)
+                .ok_or_else(|| anyhow!("Rig {rig_id} is not connected"))?;
+            let signature = identity.sign(&command_message(&rig.session, &id, &command)?)?;
+            rig.pending.insert(id.clone(), command.clone());
+            rig.outbox
+                .try_send(ControllerMessage::Command(SignedCommand {
+                    id: id.clone(),
+                    command: command.clone(),
+                    signature,
+                }))
+        };
+        if let Err(e) = queued {
+            if let Some(rig) = INSTANCE.write().await.rigs.get_mut(rig_id) {
+                rig.pending.remove(&id);
+            }
+            bail!("Could not queue command for rig {rig_id}: {e}");
+        }
+
+        FleetAuditLog::record(FleetAuditEntry::new(
+            rig_id,
+            &id,
+            &command,
+            FleetAuditStatus::Sent,
+            None,
+        ))
+        .await;
+        Ok(id)
+    }
+
+    async fn handle_connection(
+        stream: TcpStream,
+        peer: SocketAddr,
+        mut shutdown_rx: watch::Receiver<bool>,
+    ) {
+        let mut ws = match tokio_tungstenite::accept_async(stream).await {
+            Ok(ws) => ws,
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Fleet websocket handshake with {peer} failed: {e}");
+                return;
+            }
+        };
+
+        let (rig_id, session) = match time::timeout(HANDSHAKE_TIMEOUT, Self::authenticate(&mut ws))
+            .await
+        {
+            Ok(Ok(rig)) => rig,
+            Ok(Err(e)) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Rejected fleet connection from {peer}: {e}");
+                let reason = e.to_string();
+                let _unused = send_message(&mut ws, &ControllerMessage::Rejected { reason }).await;
+                return;
+            }
+            Err(_) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Fleet connection from {peer} did not authenticate in time");
+                return;
+            }
+        };
+        info!(target: LOG_TARGET_APP_LOGIC, "Rig {rig_id} joined the fleet from {peer}");
+
+        // A reconnecting rig replaces its previous connection, whose outbox then closes
+        let (outbox, mut outgoing) = mpsc::channel(OUTBOX_SIZE);
+        INSTANCE.write().await.rigs.insert(
+            rig_id.clone(),
+            ConnectedRig {
+                session: session.clone(),
+                outbox,
+                connected_at: SystemTime::now(),
+                status: None,
+                last_status_at: None,
+                pending: HashMap::new(),
+            },
+        );
+
+        let shutdown = async move {
+            let _unused = shutdown_rx.wait_for(|stop| *stop).await;
+        };
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                message = read_message(&mut ws) => match message {
+                    Ok(RigMessage::Status(status)) => Self::update_status(&rig_id, status).await,
+                    Ok(RigMessage::CommandResult { id, success, message }) => {
+                        Self::record_result(&rig_id, &id, success, message).await;
+                    }
+                    Ok(RigMessage::Hello { .. }) => {
+                        warn!(target: LOG_TARGET_APP_LOGIC, "Rig {rig_id} sent a second hello, ignoring it");
+                    }
+                    Err(e) => {
+                        info!(target: LOG_TARGET_APP_LOGIC, "Rig {rig_id} left the fleet: {e}");
+                        break;
+                    }
+                },
+                message = outgoing.recv() => {
+                    let Some(message) = message else { break };
+                    if let Err(e) = send_message(&mut ws, &message).await {
+                        warn!(target: LOG_TARGET_APP_LOGIC, "Could not send to rig {rig_id}: {e}");
+                        break;
+                    }
+                }
+                () = &mut shutdown => break,
+            }
+        }
+
+        let mut controller = INSTANCE.write().await;
+        if controller
+            .rigs
+            .get(&rig_id)
+            .is_some_and(|rig| rig.session == session)
+        {
+            controller.rigs.remove(&rig_id);
+        }
+    }
+
+    /// Challenges the peer to prove it holds an enrolled rig key, then proves our own identity.
+    /// Returns the rig id and the session its commands will be bound to.
+    async fn authenticate<S>(ws: &mut WebSocketStream<S>) -> anyhow::Result<(String, String)>
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        let identity = FleetIdentity::current()?;
+        let controller_nonce = new_nonce()?;
+        let challenge = ControllerMessage::Challenge {
+            nonce: controller_nonce.clone(),
+            version: FLEET_PROTOCOL_VERSION,
+        };
+        send_message(ws, &challenge).await?;
+
+        let RigMessage::Hello {
+            rig_id,
+            pub_key,
+            nonce,
+            signature,
+        } = read_message(ws).await?
+        else {
+            bail!("Expected a hello");
+        };
+        let config = ConfigFleet::content().await;
+        let rig = config
+            .find_rig(&rig_id)
+            .ok_or_else(|| anyhow!("Rig {rig_id} is not enrolled"))?;
+        if rig.pub_key != pub_key {
+            bail!("Rig {rig_id} presented a key other than the enrolled one");
+        }
+        verify_signature(
+            &pub_key,
+            &hello_message(&rig_id, &controller_nonce, &nonce),
+            &signature,
+        )?;
+
+        let welcome = ControllerMessage::Welcome {
+            pub_key: identity.pub_key().to_string(),
+            signature: identity.sign(&welcome_message(&rig_id, &controller_nonce, &nonce))?,
+        };
+        send_message(ws, &welcome).await?;
+        Ok((rig_id, nonce))
+    }
+
+    async fn update_status(rig_id: &str, status: RigStatus) {
+        if let Some(rig) = INSTANCE.write().await.rigs.get_mut(rig_id) {
+            rig.status = Some(status);
+            rig.last_status_at = Some(SystemTime::now());
+        }
+    }
+
+    async fn record_result(rig_id: &str, id: &str, success: bool, message: String) {
+        let command = INSTANCE
+            .write()
+            .await
+            .rigs
+            .get_mut(rig_id)
+            .and_then(|rig| rig.pending.remove(id));
+        let Some(command) = command else {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Rig {rig_id} reported on unknown command {id}");
+            return;
+        };
+        let status = if success {
+            FleetAuditStatus::Succeeded
+        } else {
+            FleetAuditStatus::Failed
+        };
+        FleetAuditLog::record(FleetAuditEntry::new(
+            rig_id,
+            id,
+            &command,
+            status,
+            Some(message),
+        ))
+        .await;
+    }
+}
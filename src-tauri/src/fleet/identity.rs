@@ -0,0 +1,100 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! The Ed25519 key this install signs fleet traffic with.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::anyhow;
+use dirs::config_dir;
+use log::info;
+use ring::rand::SystemRandom;
+use ring::signature::Ed25519KeyPair;
+
+use crate::airdrop::{get_der_encode_pub_key, sign_with_key};
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+static INSTANCE: OnceLock<FleetIdentity> = OnceLock::new();
+
+pub struct FleetIdentity {
+    key_pair: Ed25519KeyPair,
+    pub_key: String,
+}
+
+impl FleetIdentity {
+    pub fn current() -> anyhow::Result<&'static Self> {
+        if let Some(identity) = INSTANCE.get() {
+            return Ok(identity);
+        }
+        let identity = Self::load_or_create(&Self::key_path())?;
+        Ok(INSTANCE.get_or_init(|| identity))
+    }
+
+    fn key_path() -> PathBuf {
+        config_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(APPLICATION_FOLDER_ID)
+            .join("fleet_identity.pk8")
+    }
+
+    fn load_or_create(path: &Path) -> anyhow::Result<Self> {
+        let pkcs8 = if path.exists() {
+            std::fs::read(path)?
+        } else {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+                .map_err(|_| anyhow!("Failed to generate fleet identity"))?;
+            Self::write_key(path, pkcs8.as_ref())?;
+            info!(target: LOG_TARGET_APP_LOGIC, "Generated fleet identity at {path:?}");
+            pkcs8.as_ref().to_vec()
+        };
+        let key_pair = Ed25519KeyPair::from_pkcs8(&pkcs8)
+            .map_err(|e| anyhow!("Invalid fleet identity at {path:?}: {e}"))?;
+        let pub_key = get_der_encode_pub_key(&key_pair)?;
+        Ok(Self { key_pair, pub_key })
+    }
+
+    fn write_key(path: &Path, pkcs8: &[u8]) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("tmp");
+        std::fs::write(&temp_path, pkcs8)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&temp_path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// Hex DER encoded public key, the form controllers pin rigs by and rigs pin controllers by
+    pub fn pub_key(&self) -> &str {
+        &self.pub_key
+    }
+
+    /// Base64 signature over `message`
+    pub fn sign(&self, message: &str) -> anyhow::Result<String> {
+        Ok(sign_with_key(&self.key_pair, message)?.signature)
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Fleet mode: installs reporting to and taking commands from one self-hosted controller.
+
+pub mod agent;
+pub mod audit;
+pub mod commands;
+pub mod controller;
+pub mod identity;
+pub mod protocol;
+#[cfg(test)]
+mod protocol_test;
+
+use crate::configs::config_fleet::{ConfigFleet, FleetRole};
+use crate::configs::trait_config::ConfigImpl;
+
+/// Stops whichever side of fleet mode is running and starts the one the config asks for
+pub async fn restart(app_version: String) -> anyhow::Result<()> {
+    stop().await;
+    match ConfigFleet::content().await.role() {
+        FleetRole::Disabled => {}
+        FleetRole::Rig => agent::FleetAgent::start(app_version).await?,
+        FleetRole::Controller => {
+            controller::FleetController::start().await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn stop() {
+    agent::FleetAgent::stop().await;
+    controller::FleetController::stop().await;
+}
@@ -0,0 +1,248 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Wire format spoken between rigs and the fleet controller.
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, bail};
+use base64::prelude::*;
+use futures::{SinkExt, StreamExt};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::signature::{ED25519, UnparsedPublicKey};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::WebSocketStream;
+use tungstenite::{Message, Utf8Bytes};
+
+pub const FLEET_PROTOCOL_VERSION: u32 = 1;
+
+const NONCE_LEN: usize = 32;
+// DER prefix of an Ed25519 SubjectPublicKeyInfo, as produced by `get_der_encode_pub_key`
+const ED25519_SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ControllerMessage {
+    Challenge { nonce: String, version: u32 },
+    Welcome { pub_key: String, signature: String },
+    Command(SignedCommand),
+    Rejected { reason: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RigMessage {
+    Hello {
+        rig_id: String,
+        pub_key: String,
+        nonce: String,
+        signature: String,
+    },
+    Status(RigStatus),
+    CommandResult {
+        id: String,
+        success: bool,
+        message: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum FleetCommand {
+    SetMiningMode {
+        mode: String,
+    },
+    StartMining {
+        cpu: Option<bool>,
+        gpu: Option<bool>,
+    },
+    StopMining {
+        cpu: Option<bool>,
+        gpu: Option<bool>,
+    },
+    ChangeCpuPool {
+        pool: String,
+    },
+    ChangeGpuPool {
+        pool: String,
+    },
+    ScheduleMining {
+        event_id: String,
+        mining_mode: String,
+        start_hour: i64,
+        start_minute: Option<i64>,
+        start_period: String,
+        end_hour: i64,
+        end_minute: Option<i64>,
+        end_period: String,
+    },
+    CancelSchedule {
+        event_id: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SignedCommand {
+    pub id: String,
+    pub command: FleetCommand,
+    pub signature: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RigStatus {
+    pub app_version: String,
+    pub mining: RigMiningStatus,
+    pub node: RigNodeStatus,
+    pub wallet: RigWalletStatus,
+    pub hardware: Vec<RigDeviceStatus>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RigMiningStatus {
+    pub mode: String,
+    pub cpu_mining: bool,
+    pub cpu_hash_rate: f64,
+    pub cpu_pool: String,
+    pub gpu_mining: bool,
+    pub gpu_hash_rate: f64,
+    pub gpu_pool: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RigNodeStatus {
+    pub block_height: u64,
+    pub is_synced: bool,
+    pub num_connections: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RigWalletStatus {
+    /// Available balance in micro minotari, unknown until the wallet has scanned
+    pub available_balance: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RigDeviceStatus {
+    pub name: String,
+    pub usage_percentage: Option<f32>,
+    pub temperature: Option<f32>,
+}
+
+pub fn new_nonce() -> anyhow::Result<String> {
+    let mut bytes = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| anyhow!("Failed to generate fleet nonce"))?;
+    Ok(hex::encode(bytes))
+}
+
+pub fn hello_message(rig_id: &str, controller_nonce: &str, rig_nonce: &str) -> String {
+    format!("tari-fleet-hello,{rig_id},{controller_nonce},{rig_nonce}")
+}
+
+pub fn welcome_message(rig_id: &str, controller_nonce: &str, rig_nonce: &str) -> String {
+    format!("tari-fleet-welcome,{rig_id},{controller_nonce},{rig_nonce}")
+}
+
+pub fn command_message(session: &str, id: &str, command: &FleetCommand) -> anyhow::Result<String> {
+    Ok(format!(
+        "tari-fleet-command,{session},{id},{}",
+        serde_json::to_string(command)?
+    ))
+}
+
+/// Extracts the raw key from a hex DER encoded Ed25519 public key
+pub fn parse_pub_key(pub_key: &str) -> anyhow::Result<Vec<u8>> {
+    let der = hex::decode(pub_key.trim())?;
+    der.strip_prefix(ED25519_SPKI_PREFIX.as_slice())
+        .filter(|key| key.len() == ED25519_PUBLIC_KEY_LEN)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| anyhow!("Not an Ed25519 public key"))
+}
+
+/// Checks a base64 signature made by the holder of a hex DER encoded Ed25519 public key
+pub fn verify_signature(pub_key: &str, message: &str, signature: &str) -> anyhow::Result<()> {
+    let raw_key = parse_pub_key(pub_key)?;
+    let signature = BASE64_STANDARD.decode(signature)?;
+    UnparsedPublicKey::new(&ED25519, raw_key)
+        .verify(message.as_bytes(), &signature)
+        .map_err(|_| anyhow!("Invalid signature"))
+}
+
+/// Waits for the next text frame and parses it, skipping pings and other control frames
+pub async fn read_message<T, S>(ws: &mut WebSocketStream<S>) -> anyhow::Result<T>
+where
+    T: DeserializeOwned,
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    while let Some(message) = ws.next().await {
+        match message? {
+            Message::Text(text) => return Ok(serde_json::from_str(text.as_str())?),
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+    bail!("Connection closed")
+}
+
+pub async fn send_message<T, S>(ws: &mut WebSocketStream<S>, message: &T) -> anyhow::Result<()>
+where
+    T: Serialize,
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    ws.send(Message::Text(Utf8Bytes::from(serde_json::to_string(
+        message,
+    )?)))
+    .await?;
+    Ok(())
+}
+
+/// Decides which commands a rig executes within one controller session
+pub struct CommandGate {
+    controller_pub_key: String,
+    session: String,
+    seen: HashSet<String>,
+}
+
+impl CommandGate {
+    pub fn new(controller_pub_key: String, session: String) -> Self {
+        Self {
+            controller_pub_key,
+            session,
+            seen: HashSet::new(),
+        }
+    }
+
+    pub fn accept(&mut self, command: &SignedCommand) -> anyhow::Result<()> {
+        let message = command_message(&self.session, &command.id, &command.command)?;
+        verify_signature(&self.controller_pub_key, &message, &command.signature)?;
+        if !self.seen.insert(command.id.clone()) {
+            bail!("Command {} was already executed", command.id);
+        }
+        Ok(())
+    }
+}
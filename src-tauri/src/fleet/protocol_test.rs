@@ -0,0 +1,254 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for fleet protocol
+
+use ring::rand::SystemRandom;
+use ring::signature::Ed25519KeyPair;
+
+use crate::airdrop::{get_der_encode_pub_key, sign_with_key};
+
+use super::protocol::*;
+
+// RFC 8410 example Ed25519 public key
+const RFC_8410_PUB_KEY: &str =
+    "302a300506032b657003210019bf44096984cdfe8541bac167dc3b96c85086aa30b6b6cb0c5c38ad703166e1";
+
+fn key_pair() -> Ed25519KeyPair {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+    Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap()
+}
+
+fn sign_command(
+    key_pair: &Ed25519KeyPair,
+    session: &str,
+    id: &str,
+    command: FleetCommand,
+) -> SignedCommand {
+    let message = command_message(session, id, &command).unwrap();
+    SignedCommand {
+        id: id.to_string(),
+        command,
+        signature: sign_with_key(key_pair, &message).unwrap().signature,
+    }
+}
+
+// ==================== Keys and signatures ====================
+
+#[test]
+fn parses_standard_ed25519_public_keys() {
+    let raw_key = parse_pub_key(RFC_8410_PUB_KEY).unwrap();
+    assert_eq!(raw_key.len(), 32);
+    assert_eq!(raw_key[0], 0x19);
+}
+
+#[test]
+fn parses_keys_encoded_by_the_airdrop_signer() {
+    let key_pair = key_pair();
+    let pub_key = get_der_encode_pub_key(&key_pair).unwrap();
+    assert_eq!(
+        parse_pub_key(&pub_key).unwrap(),
+        ring::signature::KeyPair::public_key(&key_pair).as_ref()
+    );
+}
+
+#[test]
+fn rejects_keys_that_are_not_ed25519() {
+    assert!(parse_pub_key("not hex").is_err());
+    assert!(parse_pub_key(&RFC_8410_PUB_KEY[..RFC_8410_PUB_KEY.len() - 2]).is_err());
+    assert!(parse_pub_key(&RFC_8410_PUB_KEY.replace("2b6570", "2b6571")).is_err());
+}
+
+#[test]
+fn verifies_signatures_from_the_matching_key_only() {
+    let signer = key_pair();
+    let signed = sign_with_key(&signer, "hello").unwrap();
+
+    assert!(verify_signature(&signed.pub_key, "hello", &signed.signature).is_ok());
+    assert!(verify_signature(&signed.pub_key, "hello!", &signed.signature).is_err());
+
+    let other = get_der_encode_pub_key(&key_pair()).unwrap();
+    assert!(verify_signature(&other, "hello", &signed.signature).is_err());
+}
+
+// ==================== Handshake ====================
+
+#[test]
+fn hello_and_welcome_signatures_are_not_interchangeable() {
+    assert_ne!(
+        hello_message("rig", "controller-nonce", "rig-nonce"),
+        welcome_message("rig", "controller-nonce", "rig-nonce")
+    );
+}
+
+#[test]
+fn nonces_are_unique() {
+    let nonce = new_nonce().unwrap();
+    assert_eq!(nonce.len(), 64);
+    assert_ne!(nonce, new_nonce().unwrap());
+}
+
+// ==================== Commands ====================
+
+#[test]
+fn gate_accepts_commands_signed_for_its_session() {
+    let controller = key_pair();
+    let pub_key = get_der_encode_pub_key(&controller).unwrap();
+    let mut gate = CommandGate::new(pub_key, "session".to_string());
+
+    let command = sign_command(
+        &controller,
+        "session",
+        "1",
+        FleetCommand::StopMining {
+            cpu: None,
+            gpu: Some(true),
+        },
+    );
+    assert!(gate.accept(&command).is_ok());
+}
+
+#[test]
+fn gate_rejects_replayed_commands() {
+    let controller = key_pair();
+    let pub_key = get_der_encode_pub_key(&controller).unwrap();
+    let mut gate = CommandGate::new(pub_key, "session".to_string());
+
+    let command = sign_command(
+        &controller,
+        "session",
+        "1",
+        FleetCommand::StartMining {
+            cpu: Some(true),
+            gpu: None,
+        },
+    );
+    assert!(gate.accept(&command).is_ok());
+    assert!(gate.accept(&command).is_err());
+}
+
+#[test]
+fn gate_rejects_commands_from_another_session() {
+    let controller = key_pair();
+    let pub_key = get_der_encode_pub_key(&controller).unwrap();
+    let mut gate = CommandGate::new(pub_key, "session".to_string());
+
+    let command = sign_command(
+        &controller,
+        "old-session",
+        "1",
+        FleetCommand::StopMining {
+            cpu: None,
+            gpu: None,
+        },
+    );
+    assert!(gate.accept(&command).is_err());
+}
+
+#[test]
+fn gate_rejects_commands_signed_by_another_controller() {
+    let pub_key = get_der_encode_pub_key(&key_pair()).unwrap();
+    let mut gate = CommandGate::new(pub_key, "session".to_string());
+
+    let command = sign_command(
+        &key_pair(),
+        "session",
+        "1",
+        FleetCommand::StopMining {
+            cpu: None,
+            gpu: None,
+        },
+    );
+    assert!(gate.accept(&command).is_err());
+}
+
+#[test]
+fn gate_rejects_tampered_commands() {
+    let controller = key_pair();
+    let pub_key = get_der_encode_pub_key(&controller).unwrap();
+    let mut gate = CommandGate::new(pub_key, "session".to_string());
+
+    let mut command = sign_command(
+        &controller,
+        "session",
+        "1",
+        FleetCommand::ChangeCpuPool {
+            pool: "SupportXTMPool".to_string(),
+        },
+    );
+    command.command = FleetCommand::ChangeCpuPool {
+        pool: "EvilPool".to_string(),
+    };
+    assert!(gate.accept(&command).is_err());
+}
+
+// ==================== Wire format ====================
+
+#[test]
+fn commands_are_tagged_by_action() {
+    let message = ControllerMessage::Command(SignedCommand {
+        id: "1".to_string(),
+        command: FleetCommand::SetMiningMode {
+            mode: "Turbo".to_string(),
+        },
+        signature: "sig".to_string(),
+    });
+    let value = serde_json::to_value(&message).unwrap();
+
+    assert_eq!(value["type"], "command");
+    assert_eq!(value["command"]["action"], "set_mining_mode");
+    assert_eq!(value["command"]["mode"], "Turbo");
+    assert_eq!(
+        serde_json::from_value::<ControllerMessage>(value).unwrap(),
+        message
+    );
+}
+
+#[test]
+fn rig_status_round_trips() {
+    let message = RigMessage::Status(RigStatus {
+        app_version: "1.0.0".to_string(),
+        mining: RigMiningStatus {
+            mode: "Eco".to_string(),
+            cpu_mining: true,
+            cpu_hash_rate: 1500.0,
+            ..Default::default()
+        },
+        node: RigNodeStatus {
+            block_height: 1000,
+            is_synced: true,
+            num_connections: 8,
+        },
+        wallet: RigWalletStatus {
+            available_balance: Some(42),
+        },
+        hardware: vec![RigDeviceStatus {
+            name: "GPU".to_string(),
+            usage_percentage: Some(90.0),
+            temperature: None,
+        }],
+    });
+    let json = serde_json::to_string(&message).unwrap();
+
+    assert!(json.contains(r#""type":"status""#));
+    assert_eq!(serde_json::from_str::<RigMessage>(&json).unwrap(), message);
+}
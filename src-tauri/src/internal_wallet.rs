@@ -48,8 +48,10 @@ use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent, WALLET_VE
 use crate::configs::trait_config::ConfigImpl;
 use crate::consts::DEFAULT_MONERO_ADDRESS;
 use crate::credential_manager::{
-    Credential, CredentialError, CredentialManager, LegacyCredential, LegacyCredentialManager,
+    Credential, CredentialBackend, CredentialError, CredentialManager, LegacyCredential,
+    LegacyCredentialManager,
 };
+use crate::credential_vault::{CREDENTIAL_VAULT_RESET_REQUIRED, CredentialVault};
 use crate::events::CriticalProblemPayload;
 use crate::events_emitter::EventsEmitter;
 use crate::mining::pools::PoolManagerInterfaceTrait;
@@ -136,7 +138,8 @@ impl InternalWallet {
         let mut monero_seed_binary = None;
         if monero_address.is_empty() {
             let monero_seed = MoneroSeed::generate()?;
-            monero_seed_binary = Some(InternalWallet::add_monero_wallet(monero_seed).await?);
+            monero_seed_binary =
+                Some(InternalWallet::add_monero_wallet(app_handle, monero_seed).await?);
         };
 
         let internal_wallet = InternalWallet {
@@ -257,7 +260,7 @@ impl InternalWallet {
                     if monero_address.is_empty() {
                         let monero_seed = MoneroSeed::generate()?;
                         monero_seed_binary =
-                            Some(InternalWallet::add_monero_wallet(monero_seed).await?);
+                            Some(InternalWallet::add_monero_wallet(app_handle, monero_seed).await?);
                     };
 
                     InternalWallet {
@@ -401,17 +404,19 @@ impl InternalWallet {
         Ok((wallet_details, encrypted_seed))
     }
 
-    fn remove_tari_wallet(wallet_id: WalletId) -> Result<(), anyhow::Error> {
+    async fn remove_tari_wallet(wallet_id: WalletId) -> Result<(), anyhow::Error> {
         log::info!(target: LOG_TARGET_APP_LOGIC, "Removing Tari Wallet with id: {wallet_id:?}");
         let cm = CredentialManager::new_default(wallet_id);
-        cm.delete_credential()?;
+        cm.delete_credential().await?;
 
         Ok(())
     }
 
-    async fn add_monero_wallet(monero_seed: MoneroSeed) -> Result<Vec<u8>, anyhow::Error> {
+    async fn add_monero_wallet(
+        app_handle: &AppHandle,
+        monero_seed: MoneroSeed,
+    ) -> Result<Vec<u8>, anyhow::Error> {
         log::info!(target: LOG_TARGET_APP_LOGIC, "Adding new Monero Wallet");
-        let cm = CredentialManager::new_default(WalletId::new("monero".to_string()));
         let monero_seed_binary = (*monero_seed.inner())
             .to_binary()
             .expect("Failed to convert monero seed to binary");
@@ -419,7 +424,13 @@ impl InternalWallet {
         let credentials = Credential {
             encrypted_seed: monero_seed_binary.clone(),
         };
-        cm.set_credentials(&credentials).await?;
+        InternalWallet::set_credentials(
+            app_handle,
+            WalletId::new("monero".to_string()),
+            &credentials,
+            false,
+        )
+        .await?;
 
         let monero_address = monero_seed
             .to_address::<Mainnet>()
@@ -433,10 +444,10 @@ impl InternalWallet {
         Ok(monero_seed_binary)
     }
 
-    fn remove_monero_wallet() -> Result<(), anyhow::Error> {
+    async fn remove_monero_wallet() -> Result<(), anyhow::Error> {
         log::info!(target: LOG_TARGET_APP_LOGIC, "Removing Monero Wallet");
         let cm = CredentialManager::new_default(WalletId::new("monero".to_string()));
-        cm.delete_credential()?;
+        cm.delete_credential().await?;
 
         Ok(())
     }
@@ -444,8 +455,24 @@ impl InternalWallet {
     pub async fn recover_forgotten_pin(
         app_handle: &AppHandle,
         tari_seed: CipherSeed,
+        reset_credential_vault: bool,
     ) -> Result<(), anyhow::Error> {
+        let uses_vault = CredentialManager::backend().await == CredentialBackend::EncryptedFile;
+        // An unlocked vault just gets its key re-wrapped under the new PIN. A locked one is sealed
+        // under the forgotten PIN and can only be replaced, which loses everything stored in it.
+        let replaces_vault =
+            uses_vault && CredentialVault::exists().await && !CredentialVault::is_unlocked().await;
+        if replaces_vault && !reset_credential_vault {
+            return Err(anyhow!(CREDENTIAL_VAULT_RESET_REQUIRED));
+        }
+
         let pin_password = PinManager::create_pin(app_handle).await?;
+        if uses_vault && !CredentialVault::is_unlocked().await {
+            if replaces_vault {
+                log::warn!(target: LOG_TARGET_APP_LOGIC, "Replacing the credential vault sealed under the forgotten PIN");
+            }
+            CredentialVault::create(&pin_password).await?;
+        }
 
         let encrypted_monero_seed = if *ConfigWallet::content().await.monero_address_is_generated()
         {
@@ -482,7 +509,7 @@ impl InternalWallet {
                 .await
                 .ok_or_else(|| anyhow!("Seedless Wallet does not support PIN enciphering"))?
                 .id;
-            let encrypted_tari_seed = tari_seed.encipher(Some(pin_password))?;
+            let encrypted_tari_seed = tari_seed.encipher(Some(pin_password.clone()))?;
             InternalWallet::set_credentials(
                 app_handle,
                 wallet_id.clone(),
@@ -494,6 +521,9 @@ impl InternalWallet {
             .await?;
             encrypted_tari_seed
         };
+        if CredentialManager::backend().await == CredentialBackend::EncryptedFile {
+            CredentialVault::rekey(&pin_password).await?;
+        }
         PinManager::set_pin_locked().await?;
 
         if InternalWallet::is_initialized() {
@@ -507,7 +537,16 @@ impl InternalWallet {
     }
 
     pub async fn create_pin(app_handle: &AppHandle) -> Result<(), anyhow::Error> {
+        let uses_vault = CredentialManager::backend().await == CredentialBackend::EncryptedFile;
+        // Open an existing vault before asking for the PIN and create a missing one with it, so
+        // reading the seeds below doesn't prompt a second time
+        if uses_vault && CredentialVault::exists().await {
+            PinManager::unlock_credential_vault(app_handle).await?;
+        }
         let pin_password = PinManager::create_pin(app_handle).await?;
+        if uses_vault && !CredentialVault::exists().await {
+            CredentialVault::create(&pin_password).await?;
+        }
 
         let encrypted_monero_seed = if *ConfigWallet::content().await.monero_address_is_generated()
         {
@@ -540,7 +579,7 @@ impl InternalWallet {
                 .await
                 .ok_or_else(|| anyhow!("Seedless Wallet does not support PIN enciphering"))?
                 .id;
-            let encrypted_tari_seed = tari_seed.encipher(Some(pin_password))?;
+            let encrypted_tari_seed = tari_seed.encipher(Some(pin_password.clone()))?;
             InternalWallet::set_credentials(
                 app_handle,
                 wallet_id,
@@ -552,6 +591,9 @@ impl InternalWallet {
             .await?;
             encrypted_tari_seed
         };
+        if CredentialManager::backend().await == CredentialBackend::EncryptedFile {
+            CredentialVault::rekey(&pin_password).await?;
+        }
         PinManager::set_pin_locked().await?;
 
         if InternalWallet::is_initialized() {
@@ -569,6 +611,9 @@ impl InternalWallet {
         id: WalletId,
         forced: bool,
     ) -> Result<Credential, anyhow::Error> {
+        if CredentialManager::backend().await == CredentialBackend::EncryptedFile {
+            PinManager::unlock_credential_vault(app_handle).await?;
+        }
         let cm = CredentialManager::new_default(id);
         let seed = if forced {
             // Infinitely retry until the user proceeds with keyring
@@ -594,6 +639,9 @@ impl InternalWallet {
         credential: &Credential,
         forced: bool,
     ) -> Result<(), anyhow::Error> {
+        if CredentialManager::backend().await == CredentialBackend::EncryptedFile {
+            PinManager::unlock_credential_vault(app_handle).await?;
+        }
         let cm = CredentialManager::new_default(id);
         if forced {
             // Infinitely retry until the user proceed with keyring
@@ -933,11 +981,53 @@ impl InternalWallet {
     pub async fn clear_all_wallets() -> Result<(), anyhow::Error> {
         let wallet_config = ConfigWallet::content().await;
         for wallet_id in wallet_config.tari_wallets() {
-            InternalWallet::remove_tari_wallet(wallet_id.clone())?
+            InternalWallet::remove_tari_wallet(wallet_id.clone()).await?
         }
-        InternalWallet::remove_monero_wallet()?;
+        InternalWallet::remove_monero_wallet().await?;
         Ok(())
     }
+
+    /// Moves every wallet credential to another backend. Sources are only removed once all
+    /// credentials have been copied and verified, so a failure leaves the old backend intact.
+    pub async fn migrate_credential_backend(
+        app_handle: &AppHandle,
+        target: CredentialBackend,
+    ) -> Result<usize, anyhow::Error> {
+        let wallet_config = ConfigWallet::content().await;
+        let current = *wallet_config.credential_backend();
+        if current == target {
+            return Ok(0);
+        }
+        if current == CredentialBackend::EncryptedFile || target == CredentialBackend::EncryptedFile
+        {
+            PinManager::unlock_credential_vault(app_handle).await?;
+        }
+
+        let wallet_ids: Vec<WalletId> = wallet_config
+            .tari_wallets()
+            .iter()
+            .cloned()
+            .chain(std::iter::once(WalletId::new("monero".to_string())))
+            .collect();
+        drop(wallet_config);
+
+        let mut migrated = Vec::new();
+        for wallet_id in wallet_ids {
+            let cm = CredentialManager::new_default(wallet_id.clone());
+            if cm.copy_credential(current, target).await? {
+                migrated.push(cm);
+            }
+        }
+        ConfigWallet::update_field(ConfigWalletContent::set_credential_backend, target).await?;
+
+        for cm in &migrated {
+            if let Err(e) = cm.delete_credential_from(current).await {
+                log::warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove migrated credential from {current:?}: {e}");
+            }
+        }
+        log::info!(target: LOG_TARGET_APP_LOGIC, "Migrated {} credentials from {current:?} to {target:?}", migrated.len());
+        Ok(migrated.len())
+    }
 }
 
 // ** Utils **
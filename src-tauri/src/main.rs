@@ -60,12 +60,15 @@ use app_in_memory_config::EXCHANGE_ID;
 use telemetry_manager::TelemetryManager;
 
 use crate::feedback::Feedback;
+use crate::fleet::agent::FleetAgent;
 use crate::mining::cpu::CpuMinerStatus;
 use crate::mining::cpu::manager::CpuManager;
 use crate::mining::gpu::consts::GpuMinerStatus;
 use crate::mining::gpu::manager::GpuManager;
 use crate::mm_proxy_manager::MmProxyManager;
 use crate::node::node_manager::NodeManager;
+use crate::notifications::Notifications;
+use crate::requests::connectivity;
 use crate::shutdown_manager::ShutdownManager;
 use crate::systemtray_manager::SystemTrayManager;
 use crate::tor_manager::TorManager;
@@ -82,6 +85,9 @@ mod commands;
 mod configs;
 mod consts;
 mod credential_manager;
+mod credential_vault;
+#[cfg(test)]
+mod credential_vault_test;
 mod download_utils;
 mod event_scheduler;
 #[cfg(test)]
@@ -90,6 +96,7 @@ mod events;
 mod events_emitter;
 mod events_manager;
 mod feedback;
+mod fleet;
 mod hardware;
 mod internal_wallet;
 #[cfg(test)]
@@ -101,11 +108,30 @@ mod mm_proxy_adapter;
 mod mm_proxy_manager;
 mod network_utils;
 mod node;
+mod notifications;
 mod pin;
 mod port_allocator;
+mod port_registry;
+#[cfg(test)]
+mod port_registry_test;
 mod process_adapter;
 mod process_adapter_utils;
+mod process_exit_status;
+#[cfg(test)]
+mod process_exit_status_test;
+mod process_inventory;
+#[cfg(test)]
+mod process_inventory_test;
 mod process_killer;
+mod process_output;
+#[cfg(test)]
+mod process_output_test;
+mod process_resource_limits;
+#[cfg(test)]
+mod process_resource_limits_test;
+mod process_restart_policy;
+#[cfg(test)]
+mod process_restart_policy_test;
 mod process_stats_collector;
 mod process_utils;
 mod process_watcher;
@@ -123,12 +149,22 @@ mod tapplets;
 mod tasks_tracker;
 mod telemetry_manager;
 mod telemetry_service;
+mod telemetry_sink;
+#[cfg(test)]
+mod telemetry_sink_test;
 #[cfg(test)]
 mod testing;
 mod tests;
 mod tor_adapter;
+#[cfg(test)]
+mod tor_adapter_test;
 mod tor_control_client;
+#[cfg(test)]
+mod tor_control_client_test;
 mod tor_manager;
+mod tor_transports;
+#[cfg(test)]
+mod tor_transports_test;
 mod updates_manager;
 mod utils;
 mod wallet;
@@ -180,6 +216,11 @@ struct UniverseAppState {
 
 #[allow(clippy::too_many_lines)]
 fn main() {
+    // The stdio bridge owns stdout for the MCP protocol, so it must run before anything logs
+    if std::env::args().nth(1).as_deref() == Some(mcp::stdio_bridge::STDIO_BRIDGE_ARG) {
+        std::process::exit(mcp::stdio_bridge::run());
+    }
+
     #[cfg(target_os = "linux")]
     {
         if std::path::Path::new("/dev/dri").exists()
@@ -276,6 +317,19 @@ fn main() {
         Some(base_node_watch_rx.clone()),
     ));
 
+    block_on(FleetAgent::initialize(
+        cpu_miner_status_watch_rx.clone(),
+        gpu_status_rx.clone(),
+        base_node_watch_rx.clone(),
+        wallet_state_watch_rx.clone(),
+    ));
+
+    block_on(Notifications::initialize(
+        cpu_miner_status_watch_rx.clone(),
+        gpu_status_rx.clone(),
+        base_node_watch_rx.clone(),
+    ));
+
     let (tor_watch_tx, tor_watch_rx) = watch::channel(TorStatus::default());
     let tor_manager = TorManager::new(tor_watch_tx, &mut stats_collector);
     let mm_proxy_manager = MmProxyManager::new(&mut stats_collector);
@@ -420,7 +474,12 @@ fn main() {
             commands::get_paper_wallet_details,
             commands::get_seed_words,
             commands::get_tor_config,
+            commands::get_tor_status,
             commands::get_transactions,
+            commands::export_transaction_history,
+            commands::get_mined_blocks,
+            commands::get_mined_blocks_summary,
+            commands::get_binary_signature_audit,
             commands::import_seed_words,
             commands::revert_to_internal_wallet,
             commands::log_web_message,
@@ -429,6 +488,9 @@ fn main() {
             commands::restart_application,
             commands::send_feedback,
             commands::set_allow_telemetry,
+            commands::set_telemetry_sink,
+            commands::set_telemetry_redacted_fields,
+            commands::inspect_telemetry_data,
             commands::set_application_language,
             commands::set_auto_update,
             commands::set_cpu_mining_enabled,
@@ -437,6 +499,17 @@ fn main() {
             commands::set_mine_on_app_start,
             commands::set_monero_address,
             commands::set_monerod_config,
+            commands::set_binary_override,
+            commands::set_process_resource_limits,
+            commands::get_service_ports,
+            commands::set_service_port,
+            commands::get_process_inventory,
+            commands::control_process,
+            commands::get_binary_versions,
+            commands::pin_binary_version,
+            commands::rollback_binary_version,
+            commands::reset_process_circuit_breaker,
+            commands::get_process_output,
             commands::set_external_tari_address,
             commands::confirm_exchange_address,
             commands::select_exchange_miner,
@@ -445,6 +518,7 @@ fn main() {
             commands::set_should_auto_launch,
             commands::set_tor_config,
             commands::set_use_tor,
+            commands::set_outbound_connectivity,
             commands::set_visual_mode,
             commands::start_cpu_mining,
             commands::start_gpu_mining,
@@ -474,11 +548,16 @@ fn main() {
             commands::refresh_wallet_history,
             commands::get_base_node_status,
             commands::create_pin,
+            commands::set_credential_backend,
             commands::forgot_pin,
             commands::set_seed_backed_up,
             commands::select_mining_mode,
             commands::update_custom_mining_mode,
             commands::encode_payment_id_to_address,
+            commands::create_payment_request,
+            commands::parse_payment_request_uri,
+            commands::get_invoices,
+            commands::cancel_invoice,
             commands::save_wxtm_address,
             commands::set_security_warning_dismissed,
             commands::change_cpu_pool,
@@ -516,6 +595,28 @@ fn main() {
             mcp::commands::export_mcp_audit_log,
             mcp::commands::set_mcp_transactions_enabled,
             mcp::commands::mcp_transaction_dialog_response,
+            mcp::commands::list_mcp_tools,
+            mcp::commands::create_mcp_client,
+            mcp::commands::update_mcp_client,
+            mcp::commands::revoke_mcp_client,
+            mcp::commands::get_mcp_client_token,
+            mcp::commands::set_mcp_unix_socket_enabled,
+            mcp::commands::get_mcp_stdio_bridge,
+            fleet::commands::get_fleet_config,
+            fleet::commands::get_fleet_identity,
+            fleet::commands::set_fleet_role,
+            fleet::commands::set_fleet_controller,
+            fleet::commands::set_fleet_listen_address,
+            fleet::commands::enroll_fleet_rig,
+            fleet::commands::remove_fleet_rig,
+            fleet::commands::get_fleet_rigs,
+            fleet::commands::send_fleet_command,
+            fleet::commands::get_fleet_audit_log,
+            notifications::commands::get_notification_config,
+            notifications::commands::save_notification_channel,
+            notifications::commands::remove_notification_channel,
+            notifications::commands::set_notification_limits,
+            notifications::commands::test_notification_channel,
         ])
         .build(tauri::generate_context!())
         .inspect_err(|e| {
@@ -557,6 +658,7 @@ fn main() {
                     warn!(target: LOG_TARGET_APP_LOGIC, "Failed to initialize process wrapper sidecar: {}. Processes will spawn without orphan protection.", e);
                 }
 
+                block_on(connectivity::load_from_config());
                 block_on(state.updates_manager.initial_try_update(&handle_clone));
 
                 tauri::async_runtime::spawn(async move {
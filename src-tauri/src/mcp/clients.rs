@@ -0,0 +1,148 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Identifies which MCP client made a request and what it may do.
+
+use std::time::SystemTime;
+
+use axum08::http::request::Parts;
+use rmcp::RoleServer;
+use rmcp::service::RequestContext;
+use sha2::{Digest, Sha256};
+
+use crate::configs::config_mcp::ConfigMcpContent;
+
+/// Authenticated caller, attached to each HTTP request by the auth middleware
+/// or fixed per connection for the local socket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum McpCaller {
+    /// Holder of the legacy bearer token
+    Default,
+    Client {
+        id: String,
+        name: String,
+    },
+    /// Process of the same user connected over the Unix domain socket
+    #[cfg_attr(not(unix), allow(dead_code))]
+    LocalSocket {
+        pid: Option<i32>,
+    },
+}
+
+impl McpCaller {
+    /// Reads the caller from the HTTP request parts rmcp forwards with each call
+    pub fn from_context(context: &RequestContext<RoleServer>) -> Option<Self> {
+        context
+            .extensions
+            .get::<Parts>()
+            .and_then(|parts| parts.extensions.get::<McpCaller>())
+            .cloned()
+    }
+
+    pub fn client_id(&self) -> Option<&str> {
+        match self {
+            McpCaller::Default | McpCaller::LocalSocket { .. } => None,
+            McpCaller::Client { id, .. } => Some(id),
+        }
+    }
+
+    /// Identity recorded in the audit log
+    pub fn client_info(&self) -> String {
+        match self {
+            McpCaller::Default => "default".to_string(),
+            McpCaller::Client { id, name } => format!("{name} ({id})"),
+            McpCaller::LocalSocket { pid: Some(pid) } => format!("local socket (pid {pid})"),
+            McpCaller::LocalSocket { pid: None } => "local socket".to_string(),
+        }
+    }
+}
+
+/// Matches a presented token against the legacy token and every named client
+pub fn authenticate(
+    config: &ConfigMcpContent,
+    provided: &str,
+    now: SystemTime,
+) -> Option<McpCaller> {
+    // Constant-time comparison via hashing to avoid timing side-channels
+    let provided_hash = Sha256::digest(provided.as_bytes());
+    let matches = |token: &str| Sha256::digest(token.as_bytes()) == provided_hash;
+
+    if config.bearer_token().as_deref().is_some_and(matches) {
+        return (!config.is_token_expired()).then_some(McpCaller::Default);
+    }
+    config
+        .clients()
+        .iter()
+        .find(|client| client.token().as_deref().is_some_and(matches))
+        .filter(|client| !client.is_expired(now))
+        .map(|client| McpCaller::Client {
+            id: client.id().clone(),
+            name: client.name().clone(),
+        })
+}
+
+/// Checks the global tier switch, then the caller's own tiers and tool allowlist
+pub fn authorize(
+    config: &ConfigMcpContent,
+    caller: &McpCaller,
+    tool_name: &str,
+    tier: &str,
+    now: SystemTime,
+) -> Result<(), String> {
+    let globally_enabled = match tier {
+        "read" => *config.read_tier_enabled(),
+        "control" => *config.control_tier_enabled(),
+        "transaction" => *config.transactions_enabled(),
+        _ => false,
+    };
+    if !globally_enabled {
+        return Err(format!("{} is disabled", tier_label(tier)));
+    }
+
+    let McpCaller::Client { id, .. } = caller else {
+        return Ok(());
+    };
+    let client = config
+        .find_client(id)
+        .ok_or_else(|| "MCP client has been revoked".to_string())?;
+    if client.is_expired(now) {
+        return Err("MCP client token has expired".to_string());
+    }
+    if !client.is_tier_enabled(tier) {
+        return Err(format!(
+            "{} is not allowed for this client",
+            tier_label(tier)
+        ));
+    }
+    if !client.allows_tool(tool_name) {
+        return Err(format!("Tool {tool_name} is not allowed for this client"));
+    }
+    Ok(())
+}
+
+fn tier_label(tier: &str) -> &'static str {
+    match tier {
+        "read" => "Read tier",
+        "control" => "Control tier",
+        "transaction" => "Transaction tier",
+        _ => "Unknown tier",
+    }
+}
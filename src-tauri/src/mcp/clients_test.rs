@@ -0,0 +1,222 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Unit tests for MCP client authentication and authorization
+
+use std::time::{Duration, SystemTime};
+
+use super::clients::{McpCaller, authenticate, authorize};
+use crate::configs::config_mcp::{ConfigMcpContent, McpClient, McpClientSettings};
+
+fn settings(name: &str, read: bool, control: bool) -> McpClientSettings {
+    McpClientSettings {
+        name: name.to_string(),
+        read_tier_enabled: read,
+        control_tier_enabled: control,
+        transactions_enabled: false,
+        allowed_tools: Vec::new(),
+        max_transaction_amount: None,
+        daily_spend_limit: None,
+        expiry_days: None,
+    }
+}
+
+fn config_with(clients: Vec<McpClient>) -> ConfigMcpContent {
+    let mut config = ConfigMcpContent::default();
+    config.set_clients(clients);
+    config
+}
+
+fn token_of(client: &McpClient) -> String {
+    client.token().clone().expect("client has a token")
+}
+
+fn caller_of(client: &McpClient) -> McpCaller {
+    McpCaller::Client {
+        id: client.id().clone(),
+        name: client.name().clone(),
+    }
+}
+
+// ==================== authenticate ====================
+
+#[test]
+fn test_authenticate_legacy_token_as_default() {
+    let mut config = ConfigMcpContent::default();
+    let token = config.ensure_token().to_string();
+    assert_eq!(
+        authenticate(&config, &token, SystemTime::now()),
+        Some(McpCaller::Default)
+    );
+}
+
+#[test]
+fn test_authenticate_rejects_expired_legacy_token() {
+    let mut config = ConfigMcpContent::default();
+    let token = config.ensure_token().to_string();
+    config.set_token_expires_at(Some(SystemTime::now() - Duration::from_secs(1)));
+    assert_eq!(authenticate(&config, &token, SystemTime::now()), None);
+}
+
+#[test]
+fn test_authenticate_named_client() {
+    let now = SystemTime::now();
+    let monitor = McpClient::new(settings("monitor", true, false), now);
+    let ops = McpClient::new(settings("ops", true, true), now);
+    let config = config_with(vec![monitor.clone(), ops.clone()]);
+
+    assert_eq!(
+        authenticate(&config, &token_of(&ops), now),
+        Some(caller_of(&ops))
+    );
+    assert_eq!(
+        authenticate(&config, &token_of(&monitor), now),
+        Some(caller_of(&monitor))
+    );
+}
+
+#[test]
+fn test_authenticate_rejects_unknown_token() {
+    let now = SystemTime::now();
+    let config = config_with(vec![McpClient::new(settings("ops", true, true), now)]);
+    assert_eq!(authenticate(&config, "tu_not-a-token", now), None);
+}
+
+#[test]
+fn test_authenticate_rejects_expired_client() {
+    let now = SystemTime::now();
+    let mut client_settings = settings("ops", true, true);
+    client_settings.expiry_days = Some(1);
+    let client = McpClient::new(client_settings, now);
+    let config = config_with(vec![client.clone()]);
+
+    assert!(authenticate(&config, &token_of(&client), now).is_some());
+    let later = now + Duration::from_secs(2 * 24 * 60 * 60);
+    assert_eq!(authenticate(&config, &token_of(&client), later), None);
+}
+
+#[test]
+fn test_revoking_one_client_keeps_others_working() {
+    let now = SystemTime::now();
+    let monitor = McpClient::new(settings("monitor", true, false), now);
+    let ops = McpClient::new(settings("ops", true, true), now);
+    let config = config_with(vec![ops.clone()]);
+
+    assert_eq!(authenticate(&config, &token_of(&monitor), now), None);
+    assert_eq!(
+        authenticate(&config, &token_of(&ops), now),
+        Some(caller_of(&ops))
+    );
+}
+
+// ==================== authorize ====================
+
+#[test]
+fn test_authorize_default_follows_global_tiers() {
+    let mut config = ConfigMcpContent::default();
+    let now = SystemTime::now();
+    assert!(authorize(&config, &McpCaller::Default, "start_mining", "control", now).is_ok());
+
+    config.set_control_tier_enabled(false);
+    let err = authorize(&config, &McpCaller::Default, "start_mining", "control", now)
+        .expect_err("control tier disabled");
+    assert_eq!(err, "Control tier is disabled");
+}
+
+#[test]
+fn test_authorize_read_only_client() {
+    let now = SystemTime::now();
+    let monitor = McpClient::new(settings("monitor", true, false), now);
+    let config = config_with(vec![monitor.clone()]);
+    let caller = caller_of(&monitor);
+
+    assert!(authorize(&config, &caller, "get_wallet_balance", "read", now).is_ok());
+    let err = authorize(&config, &caller, "start_mining", "control", now)
+        .expect_err("control tier not granted");
+    assert_eq!(err, "Control tier is not allowed for this client");
+}
+
+#[test]
+fn test_authorize_global_switch_overrides_client() {
+    let now = SystemTime::now();
+    let ops = McpClient::new(settings("ops", true, true), now);
+    let mut config = config_with(vec![ops.clone()]);
+    config.set_control_tier_enabled(false);
+
+    assert!(authorize(&config, &caller_of(&ops), "start_mining", "control", now).is_err());
+}
+
+#[test]
+fn test_authorize_tool_allowlist() {
+    let now = SystemTime::now();
+    let mut client_settings = settings("ops", true, true);
+    client_settings.allowed_tools = vec!["get_mining_status".to_string()];
+    let ops = McpClient::new(client_settings, now);
+    let config = config_with(vec![ops.clone()]);
+    let caller = caller_of(&ops);
+
+    assert!(authorize(&config, &caller, "get_mining_status", "control", now).is_ok());
+    let err = authorize(&config, &caller, "stop_mining", "control", now)
+        .expect_err("tool not on allowlist");
+    assert!(err.contains("stop_mining"));
+}
+
+#[test]
+fn test_authorize_revoked_client() {
+    let now = SystemTime::now();
+    let ops = McpClient::new(settings("ops", true, true), now);
+    let config = config_with(Vec::new());
+
+    let err = authorize(
+        &config,
+        &caller_of(&ops),
+        "get_mining_status",
+        "control",
+        now,
+    )
+    .expect_err("client removed");
+    assert_eq!(err, "MCP client has been revoked");
+}
+
+#[test]
+fn test_client_info_identifies_caller() {
+    assert_eq!(McpCaller::Default.client_info(), "default");
+    let caller = McpCaller::Client {
+        id: "abc".to_string(),
+        name: "ops".to_string(),
+    };
+    assert_eq!(caller.client_info(), "ops (abc)");
+    assert_eq!(caller.client_id(), Some("abc"));
+}
+
+#[test]
+fn test_local_socket_caller_follows_global_tiers() {
+    let now = SystemTime::now();
+    let mut config = config_with(Vec::new());
+    let caller = McpCaller::LocalSocket { pid: Some(4242) };
+
+    assert_eq!(caller.client_info(), "local socket (pid 4242)");
+    assert_eq!(caller.client_id(), None);
+    assert!(authorize(&config, &caller, "start_mining", "control", now).is_ok());
+
+    config.set_control_tier_enabled(false);
+    assert!(authorize(&config, &caller, "start_mining", "control", now).is_err());
+}
@@ -22,11 +22,14 @@
 
 use std::time::SystemTime;
 
-use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent, SECONDS_PER_DAY};
+use crate::configs::config_mcp::{
+    ConfigMcp, ConfigMcpContent, McpClient, McpClientSettings, SECONDS_PER_DAY,
+};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
 use crate::mcp::audit::AuditLog;
 use crate::mcp::server::McpServerManager;
+use crate::mcp::tools::TariMcpHandler;
 
 #[tauri::command]
 pub async fn get_mcp_config() -> Result<serde_json::Value, String> {
@@ -152,6 +155,142 @@ pub async fn set_mcp_tier_enabled(tier: String, enabled: bool) -> Result<(), Str
     Ok(())
 }
 
+#[tauri::command]
+pub async fn list_mcp_tools() -> Result<Vec<String>, String> {
+    Ok(TariMcpHandler::tool_names())
+}
+
+#[tauri::command]
+pub async fn set_mcp_unix_socket_enabled(enabled: bool) -> Result<(), String> {
+    if enabled && cfg!(not(unix)) {
+        return Err("The MCP local socket is only available on macOS and Linux".to_string());
+    }
+    ConfigMcp::update_field(ConfigMcpContent::set_unix_socket_enabled, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    // Restart if currently running so the socket is opened or closed
+    if McpServerManager::current().read().await.is_running() {
+        McpServerManager::restart()
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+/// Command line for launching the stdio bridge, or `None` where the local socket is unavailable
+#[tauri::command]
+pub async fn get_mcp_stdio_bridge() -> Result<Option<serde_json::Value>, String> {
+    #[cfg(unix)]
+    {
+        let info =
+            crate::mcp::local_socket::StdioBridgeInfo::current().map_err(|e| e.to_string())?;
+        serde_json::to_value(info)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+    #[cfg(not(unix))]
+    Ok(None)
+}
+
+/// Granting the transaction tier to a client needs the same PIN as enabling it globally
+async fn require_pin_for_transactions(
+    settings: &McpClientSettings,
+    pin: Option<String>,
+) -> Result<(), String> {
+    if !settings.transactions_enabled {
+        return Ok(());
+    }
+    if !crate::pin::PinManager::pin_locked().await {
+        return Err(
+            "Cannot grant MCP transactions without a PIN configured. Please set up a PIN first."
+                .to_string(),
+        );
+    }
+    let pin_str = pin.ok_or("PIN is required to grant the transaction tier")?;
+    crate::pin::PinManager::validate_pin(tari_utilities::SafePassword::from(pin_str))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Creates a named client and returns its token
+#[tauri::command]
+pub async fn create_mcp_client(
+    settings: McpClientSettings,
+    pin: Option<String>,
+) -> Result<String, String> {
+    settings.validate(&TariMcpHandler::tool_names())?;
+    require_pin_for_transactions(&settings, pin).await?;
+    let client = McpClient::new(settings, SystemTime::now());
+    let token = client.token().clone().unwrap_or_default();
+    {
+        let mut config = ConfigMcp::current().write().await;
+        let content = config._get_content_mut();
+        let mut clients = content.clients().clone();
+        clients.push(client);
+        content.set_clients(clients);
+        ConfigMcp::_save_config(content.clone()).map_err(|e| e.to_string())?;
+    }
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(token)
+}
+
+#[tauri::command]
+pub async fn update_mcp_client(
+    id: String,
+    settings: McpClientSettings,
+    pin: Option<String>,
+) -> Result<(), String> {
+    settings.validate(&TariMcpHandler::tool_names())?;
+    require_pin_for_transactions(&settings, pin).await?;
+    {
+        let mut config = ConfigMcp::current().write().await;
+        let content = config._get_content_mut();
+        let client = content
+            .find_client_mut(&id)
+            .ok_or_else(|| format!("Unknown MCP client: {id}"))?;
+        client.apply_settings(settings, SystemTime::now());
+        ConfigMcp::_save_config(content.clone()).map_err(|e| e.to_string())?;
+    }
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+/// Removes a client; its token stops working on the next request
+#[tauri::command]
+pub async fn revoke_mcp_client(id: String) -> Result<(), String> {
+    {
+        let mut config = ConfigMcp::current().write().await;
+        let content = config._get_content_mut();
+        let mut clients = content.clients().clone();
+        let before = clients.len();
+        clients.retain(|client| client.id() != &id);
+        if clients.len() == before {
+            return Err(format!("Unknown MCP client: {id}"));
+        }
+        content.set_clients(clients);
+        ConfigMcp::_save_config(content.clone()).map_err(|e| e.to_string())?;
+    }
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_mcp_client_token(id: String, pin: Option<String>) -> Result<String, String> {
+    if crate::pin::PinManager::pin_locked().await {
+        let pin_str = pin.ok_or("PIN is required to reveal the token")?;
+        let pin_password = tari_utilities::SafePassword::from(pin_str);
+        crate::pin::PinManager::validate_pin(pin_password)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    ConfigMcp::content()
+        .await
+        .find_client(&id)
+        .and_then(|client| client.token().clone())
+        .ok_or_else(|| format!("Unknown MCP client: {id}"))
+}
+
 #[tauri::command]
 pub async fn get_mcp_audit_log(count: usize) -> Result<Vec<serde_json::Value>, String> {
     let entries = AuditLog::get_recent(count).await;
@@ -0,0 +1,151 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Unix domain socket transport for local agents.
+
+use std::fs::{DirBuilder, Permissions};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::path::PathBuf;
+
+use log::{info, warn};
+use rmcp::ServiceExt;
+use serde::Serialize;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+
+use crate::mcp::clients::McpCaller;
+use crate::mcp::stdio_bridge::STDIO_BRIDGE_ARG;
+use crate::mcp::tools::TariMcpHandler;
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+const SOCKET_DIR_NAME: &str = "mcp";
+const SOCKET_FILE_NAME: &str = "mcp.sock";
+
+/// How an agent launches the stdio bridge, shown in the agent setup snippets
+#[derive(Debug, Clone, Serialize)]
+pub struct StdioBridgeInfo {
+    pub socket_path: String,
+    pub command: String,
+    pub args: Vec<String>,
+}
+
+impl StdioBridgeInfo {
+    pub fn current() -> Result<Self, anyhow::Error> {
+        // AppImages run from a temporary mount, so point agents at the image itself
+        let command = match std::env::var_os("APPIMAGE") {
+            Some(appimage) => PathBuf::from(appimage),
+            None => std::env::current_exe()?,
+        };
+        Ok(Self {
+            socket_path: socket_path().display().to_string(),
+            command: command.display().to_string(),
+            args: vec![STDIO_BRIDGE_ARG.to_string()],
+        })
+    }
+}
+
+/// Fixed location shared by the app and the stdio bridge, which has no app handle to ask
+pub fn socket_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(APPLICATION_FOLDER_ID)
+        .join(SOCKET_DIR_NAME)
+        .join(SOCKET_FILE_NAME)
+}
+
+/// Binds the socket inside an owner-only directory, replacing a stale socket file
+pub fn bind() -> Result<UnixListener, anyhow::Error> {
+    let path = socket_path();
+    let dir = path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("MCP socket path has no parent directory"))?;
+    DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+    // The directory may predate this version with looser permissions
+    std::fs::set_permissions(dir, Permissions::from_mode(0o700))?;
+
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    let listener = UnixListener::bind(&path)?;
+    std::fs::set_permissions(&path, Permissions::from_mode(0o600))?;
+    info!(target: LOG_TARGET_APP_LOGIC, "MCP socket listening on {}", path.display());
+    Ok(listener)
+}
+
+/// Accepts connections until shutdown, then closes open sessions and removes the socket file
+pub async fn serve(
+    listener: UnixListener,
+    make_handler: impl Fn() -> TariMcpHandler,
+    mut shutdown_rx: watch::Receiver<bool>,
+) {
+    let sessions = CancellationToken::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let Some(caller) = peer_caller(&stream) else {
+                        continue;
+                    };
+                    let handler = make_handler().with_caller(caller.clone());
+                    let session = sessions.child_token();
+                    tokio::spawn(async move {
+                        match handler.serve_with_ct(stream, session).await {
+                            Ok(service) => {
+                                let _unused = service.waiting().await;
+                            }
+                            Err(e) => {
+                                warn!(target: LOG_TARGET_APP_LOGIC, "MCP socket session for {} failed: {e}", caller.client_info());
+                            }
+                        }
+                    });
+                }
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "MCP socket failed to accept a connection: {e}");
+                }
+            },
+            _ = shutdown_rx.wait_for(|v| *v) => break,
+        }
+    }
+
+    sessions.cancel();
+    if let Err(e) = std::fs::remove_file(socket_path()) {
+        warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove MCP socket file: {e}");
+    }
+    info!(target: LOG_TARGET_APP_LOGIC, "MCP socket stopped");
+}
+
+/// Only processes running as the same user may connect
+fn peer_caller(stream: &UnixStream) -> Option<McpCaller> {
+    let credentials = stream
+        .peer_cred()
+        .inspect_err(|e| {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Rejected MCP socket connection without peer credentials: {e}");
+        })
+        .ok()?;
+    if credentials.uid() != nix::unistd::getuid().as_raw() {
+        warn!(target: LOG_TARGET_APP_LOGIC, "Rejected MCP socket connection from uid {}", credentials.uid());
+        return None;
+    }
+    Some(McpCaller::LocalSocket {
+        pid: credentials.pid(),
+    })
+}
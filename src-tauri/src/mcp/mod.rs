@@ -21,7 +21,13 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod audit;
+pub mod clients;
+#[cfg(test)]
+mod clients_test;
 pub mod commands;
+#[cfg(unix)]
+pub mod local_socket;
 pub mod rate_limiter;
 pub mod server;
+pub mod stdio_bridge;
 pub mod tools;
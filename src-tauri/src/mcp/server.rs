@@ -28,7 +28,6 @@ use log::{error, info, warn};
 use rmcp::transport::StreamableHttpServerConfig;
 use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
 use rmcp::transport::streamable_http_server::tower::StreamableHttpService;
-use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 
@@ -36,6 +35,9 @@ use crate::LOG_TARGET_APP_LOGIC;
 use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent, SECONDS_PER_DAY};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
+use crate::mcp::clients::{self, McpCaller};
+#[cfg(unix)]
+use crate::mcp::local_socket;
 use crate::mcp::tools::TariMcpHandler;
 use crate::node::node_adapter::BaseNodeStatus;
 use crate::wallet::wallet_manager::WalletManager;
@@ -47,6 +49,7 @@ const SHUTDOWN_TIMEOUT_SECS: u64 = 5;
 
 pub struct McpServerManager {
     server_handle: Option<JoinHandle<()>>,
+    socket_handle: Option<JoinHandle<()>>,
     shutdown_tx: Option<tokio::sync::watch::Sender<bool>>,
     bound_port: Option<u16>,
     node_status_rx: Option<Arc<tokio::sync::watch::Receiver<BaseNodeStatus>>>,
@@ -57,6 +60,7 @@ impl McpServerManager {
     fn new() -> Self {
         Self {
             server_handle: None,
+            socket_handle: None,
             shutdown_tx: None,
             bound_port: None,
             node_status_rx: None,
@@ -103,10 +107,9 @@ impl McpServerManager {
             anyhow::bail!("MCP server is not enabled");
         }
 
-        let token = config
-            .bearer_token()
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("MCP server has no bearer token configured"))?;
+        if config.bearer_token().is_none() && config.clients().is_empty() {
+            anyhow::bail!("MCP server has no bearer token or clients configured");
+        }
 
         let configured_port = *config.port();
 
@@ -140,31 +143,49 @@ impl McpServerManager {
             })?
         };
 
+        // Every transport builds its handlers the same way
+        let make_handler =
+            move || TariMcpHandler::new(node_status_rx.clone(), wallet_manager.clone());
+
         // Build the rmcp StreamableHttpService
         let mcp_service: StreamableHttpService<TariMcpHandler, LocalSessionManager> =
             StreamableHttpService::new(
-                move || {
-                    Ok(TariMcpHandler::new(
-                        node_status_rx.clone(),
-                        wallet_manager.clone(),
-                    ))
+                {
+                    let make_handler = make_handler.clone();
+                    move || Ok(make_handler())
                 },
                 LocalSessionManager::default().into(),
                 StreamableHttpServerConfig::default(),
             );
 
         // Build axum 0.8 router with bearer auth middleware
-        let protected_router =
-            axum::Router::new()
-                .nest_service("/mcp", mcp_service)
-                .layer(axum::middleware::from_fn(move |req, next| {
-                    let token = token.clone();
-                    auth_middleware(token, req, next)
-                }));
+        let protected_router = axum::Router::new()
+            .nest_service("/mcp", mcp_service)
+            .layer(axum::middleware::from_fn(auth_middleware));
 
         // Shutdown channel
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
 
+        // The local socket is optional; the HTTP server keeps running if it can't be bound
+        #[cfg(unix)]
+        let socket_handle = if *config.unix_socket_enabled() {
+            match local_socket::bind() {
+                Ok(listener) => Some(tokio::spawn(local_socket::serve(
+                    listener,
+                    make_handler,
+                    shutdown_rx.clone(),
+                ))),
+                Err(e) => {
+                    error!(target: LOG_TARGET_APP_LOGIC, "MCP socket failed to start: {e}");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        #[cfg(not(unix))]
+        let socket_handle: Option<JoinHandle<()>> = None;
+
         let handle = tokio::spawn(async move {
             if let Err(e) = axum::serve(listener, protected_router)
                 .with_graceful_shutdown(async move {
@@ -181,6 +202,7 @@ impl McpServerManager {
         {
             let mut manager = Self::current().write().await;
             manager.server_handle = Some(handle);
+            manager.socket_handle = socket_handle;
             manager.shutdown_tx = Some(shutdown_tx);
             manager.bound_port = Some(bound_port);
         }
@@ -191,12 +213,13 @@ impl McpServerManager {
     }
 
     pub async fn stop() {
-        let (handle, shutdown_tx) = {
+        let (handle, socket_handle, shutdown_tx) = {
             let mut manager = Self::current().write().await;
             let handle = manager.server_handle.take();
+            let socket_handle = manager.socket_handle.take();
             let tx = manager.shutdown_tx.take();
             manager.bound_port = None;
-            (handle, tx)
+            (handle, socket_handle, tx)
         };
 
         if let Some(tx) = shutdown_tx {
@@ -221,6 +244,16 @@ impl McpServerManager {
             }
         }
 
+        if let Some(handle) = socket_handle {
+            let timeout = tokio::time::timeout(
+                std::time::Duration::from_secs(SHUTDOWN_TIMEOUT_SECS),
+                handle,
+            );
+            if timeout.await.is_err() {
+                warn!(target: LOG_TARGET_APP_LOGIC, "MCP socket shutdown timed out after {SHUTDOWN_TIMEOUT_SECS}s");
+            }
+        }
+
         EventsEmitter::emit_mcp_server_status_update(false, None).await;
 
         crate::mcp::tools::transaction::clear_inflight().await;
@@ -233,43 +266,34 @@ impl McpServerManager {
 }
 
 async fn auth_middleware(
-    expected_token: String,
-    req: axum::http::Request<axum::body::Body>,
+    mut req: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
 ) -> Result<axum::response::Response, axum::http::StatusCode> {
-    let auth_header = req
+    let provided = req
         .headers()
         .get("Authorization")
-        .and_then(|v: &axum::http::HeaderValue| v.to_str().ok());
-
-    match auth_header {
-        Some(header) if header.starts_with("Bearer ") => {
-            let provided = &header[7..];
-            // Constant-time comparison via hashing to avoid timing side-channels
-            let provided_hash = Sha256::digest(provided.as_bytes());
-            let expected_hash = Sha256::digest(expected_token.as_bytes());
-            if provided_hash == expected_hash {
-                let config = ConfigMcp::content().await;
-                if config.is_token_expired() {
-                    return Err(axum::http::StatusCode::UNAUTHORIZED);
-                }
-                // Sliding-window refresh: bump expiry on each successful request
-                // so the token only expires after `token_expiry_days` of inactivity.
-                let _unused = ConfigMcp::update_field(
-                    ConfigMcpContent::set_token_expires_at,
-                    Some(
-                        SystemTime::now()
-                            + std::time::Duration::from_secs(
-                                u64::from(*config.token_expiry_days()) * SECONDS_PER_DAY,
-                            ),
+        .and_then(|v: &axum::http::HeaderValue| v.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or(axum::http::StatusCode::UNAUTHORIZED)?;
+
+    let config = ConfigMcp::content().await;
+    let caller = clients::authenticate(&config, provided, SystemTime::now())
+        .ok_or(axum::http::StatusCode::UNAUTHORIZED)?;
+
+    if caller == McpCaller::Default {
+        // Sliding-window refresh: bump expiry on each successful request
+        // so the token only expires after `token_expiry_days` of inactivity.
+        let _unused = ConfigMcp::update_field(
+            ConfigMcpContent::set_token_expires_at,
+            Some(
+                SystemTime::now()
+                    + std::time::Duration::from_secs(
+                        u64::from(*config.token_expiry_days()) * SECONDS_PER_DAY,
                     ),
-                )
-                .await;
-                Ok(next.run(req).await)
-            } else {
-                Err(axum::http::StatusCode::UNAUTHORIZED)
-            }
-        }
-        _ => Err(axum::http::StatusCode::UNAUTHORIZED),
+            ),
+        )
+        .await;
     }
+    req.extensions_mut().insert(caller);
+    Ok(next.run(req).await)
 }
@@ -0,0 +1,84 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! `mcp-stdio` entry point for desktop agents that only speak MCP over stdio.
+
+/// First command line argument that starts the bridge instead of the app
+pub const STDIO_BRIDGE_ARG: &str = "mcp-stdio";
+
+/// Runs the bridge to completion and returns the process exit code
+pub fn run() -> i32 {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("tari-universe {STDIO_BRIDGE_ARG}: failed to start runtime: {e}");
+            return 1;
+        }
+    };
+    let result = runtime.block_on(relay());
+    // A pending stdin read would otherwise keep the runtime from shutting down
+    runtime.shutdown_background();
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("tari-universe {STDIO_BRIDGE_ARG}: {e}");
+            1
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn relay() -> Result<(), anyhow::Error> {
+    use tokio::io::AsyncWriteExt;
+
+    let path = crate::mcp::local_socket::socket_path();
+    let stream = tokio::net::UnixStream::connect(&path).await.map_err(|e| {
+        anyhow::anyhow!(
+            "could not connect to {}: {e}. Make sure Tari Universe is running \
+             with the MCP local socket enabled.",
+            path.display()
+        )
+    })?;
+    let (mut socket_reader, mut socket_writer) = stream.into_split();
+
+    // Closing stdin ends the session; the app closes the socket once it is done replying
+    let upstream = tokio::spawn(async move {
+        tokio::io::copy(&mut tokio::io::stdin(), &mut socket_writer).await?;
+        socket_writer.shutdown().await
+    });
+
+    let mut stdout = tokio::io::stdout();
+    let downstream = tokio::io::copy(&mut socket_reader, &mut stdout).await;
+    upstream.abort();
+    downstream?;
+    stdout.flush().await?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn relay() -> Result<(), anyhow::Error> {
+    anyhow::bail!(
+        "the MCP stdio bridge needs the local socket transport, which is only available on macOS and Linux"
+    )
+}
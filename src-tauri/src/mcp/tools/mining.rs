@@ -24,6 +24,7 @@ use crate::configs::config_mining::{ConfigMining, ConfigMiningContent, MiningMod
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
 use crate::hardware::hardware_status_monitor::HardwareStatusMonitor;
+use crate::mining::block_ledger::{BlockLedger, MinedBlockStatus};
 use crate::mining::cpu::manager::CpuManager;
 use crate::mining::gpu::manager::GpuManager;
 use crate::systemtray_manager::{SystemTrayEvents, SystemTrayManager};
@@ -213,3 +214,43 @@ pub async fn get_gpu_devices() -> Result<String, String> {
 
     serde_json::to_string(&result).map_err(|e| e.to_string())
 }
+
+pub async fn get_mined_blocks(
+    limit: Option<u32>,
+    status: Option<String>,
+) -> Result<String, String> {
+    let limit = usize::try_from(limit.unwrap_or(20)).unwrap_or(usize::MAX);
+    let status = status
+        .map(|s| serde_json::from_value::<MinedBlockStatus>(json!(s)))
+        .transpose()
+        .map_err(|_| "Invalid status, expected pending, confirmed, orphaned or reward_missing")?;
+
+    let summary = BlockLedger::get_summary().await;
+    let blocks = BlockLedger::get_blocks(0, limit, status).await;
+
+    let result = json!({
+        "summary": {
+            "total_blocks": summary.total_blocks,
+            "pending": summary.pending,
+            "confirmed": summary.confirmed,
+            "orphaned": summary.orphaned,
+            "reward_missing": summary.reward_missing,
+            "confirmed_reward": summary.confirmed_reward.as_u64(),
+            "cpu_blocks": summary.cpu_blocks,
+            "gpu_blocks": summary.gpu_blocks,
+        },
+        "blocks": blocks
+            .iter()
+            .map(|block| json!({
+                "height": block.height,
+                "hash": block.hash,
+                "reward": block.reward.as_u64(),
+                "mined_at": block.mined_at,
+                "miner": block.miner,
+                "status": block.status,
+            }))
+            .collect::<Vec<_>>(),
+    });
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
@@ -20,10 +20,12 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod binaries;
 pub mod chain;
 #[cfg(test)]
 mod chain_test;
 pub mod mining;
+pub mod processes;
 pub mod scheduler;
 pub mod transaction;
 pub mod wallet;
@@ -35,7 +37,8 @@ use log::info;
 use rmcp::handler::server::router::tool::ToolRouter;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::*;
-use rmcp::{ServerHandler, tool, tool_handler, tool_router};
+use rmcp::service::RequestContext;
+use rmcp::{RoleServer, ServerHandler, tool, tool_handler, tool_router};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use tokio::sync::watch;
@@ -44,6 +47,7 @@ use crate::LOG_TARGET_APP_LOGIC;
 use crate::configs::config_mcp::ConfigMcp;
 use crate::configs::trait_config::ConfigImpl;
 use crate::mcp::audit::{AuditEntry, AuditLog, AuditStatus};
+use crate::mcp::clients::{self, McpCaller};
 use crate::node::node_adapter::BaseNodeStatus;
 use crate::wallet::wallet_manager::WalletManager;
 
@@ -52,6 +56,8 @@ pub struct TariMcpHandler {
     tool_router: ToolRouter<Self>,
     node_status_rx: Arc<watch::Receiver<BaseNodeStatus>>,
     wallet_manager: WalletManager,
+    /// Caller for transports without per-request authentication, such as the local socket
+    transport_caller: Option<McpCaller>,
 }
 
 #[tool_handler]
@@ -72,7 +78,7 @@ impl ServerHandler for TariMcpHandler {
                 icons: None,
             },
             instructions: Some(
-                "Tari Universe MCP server. Available tool categories: mining (start/stop/mode), wallet (address/balance), chain (block height/sync status), and scheduler (scheduled mining events). Use get_mining_status, get_wallet_address, and get_chain_status to get an overview."
+                "Tari Universe MCP server. Available tool categories: mining (start/stop/mode), wallet (address/balance/outputs), chain (block height/sync status), and scheduler (scheduled mining events). Use get_mining_status, get_wallet_address, and get_chain_status to get an overview."
                     .to_string(),
             ),
         }
@@ -97,6 +103,44 @@ struct SetMiningModeParams {
 struct GetTransactionHistoryParams {
     /// Maximum number of transactions to return. Defaults to 20.
     limit: Option<u32>,
+    /// Only include transactions at or after this unix timestamp (seconds)
+    from_timestamp: Option<u64>,
+    /// Only include transactions at or before this unix timestamp (seconds)
+    to_timestamp: Option<u64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetMinedBlocksParams {
+    /// Maximum number of blocks to return, newest first. Defaults to 20.
+    limit: Option<u32>,
+    /// Only return blocks with this status: pending, confirmed, orphaned or reward_missing.
+    status: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetBinaryVersionsParams {
+    /// Only return this binary: minotari_node, wallet, mmproxy, xmrig, lolminer, tor or bridge.
+    binary: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TailProcessOutputParams {
+    /// Process to tail, e.g. xmrig, lolminer, tor or local_minotari_node. Lists captured processes when omitted.
+    process: Option<String>,
+    /// Number of most recent matching lines (default: 100)
+    lines: Option<usize>,
+    /// Only return lines containing this text, case-insensitive
+    contains: Option<String>,
+    /// Only return lines from this stream: stdout or stderr
+    stream: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ControlProcessParams {
+    /// Process to control, as listed by list_processes, e.g. xmrig, tor or local_minotari_node
+    process: String,
+    /// Action to take: start, stop or restart
+    action: String,
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -144,11 +188,31 @@ impl TariMcpHandler {
             tool_router: Self::tool_router(),
             node_status_rx,
             wallet_manager,
+            transport_caller: None,
         }
     }
 
+    /// Binds every call on this handler to `caller`, for connections authenticated up front
+    #[cfg_attr(not(unix), allow(dead_code))]
+    pub fn with_caller(mut self, caller: McpCaller) -> Self {
+        self.transport_caller = Some(caller);
+        self
+    }
+
+    /// Names of every tool the server exposes, sorted
+    pub fn tool_names() -> Vec<String> {
+        let mut names: Vec<String> = Self::tool_router()
+            .list_all()
+            .into_iter()
+            .map(|tool| tool.name.to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
     async fn audit_tool_call(
         &self,
+        caller: &McpCaller,
         tool_name: &str,
         tier: &str,
         status: AuditStatus,
@@ -160,20 +224,45 @@ impl TariMcpHandler {
             tier: tier.to_string(),
             status,
             duration_ms,
-            client_info: None,
+            client_info: Some(caller.client_info()),
             details: None,
         };
         AuditLog::record(entry).await;
     }
 
-    async fn is_tier_enabled(tier: &str) -> bool {
+    /// Resolves the calling client and checks it may use the tool, recording the attempt
+    async fn authorize(
+        &self,
+        context: &RequestContext<RoleServer>,
+        tool_name: &str,
+        tier: &str,
+    ) -> Result<McpCaller, String> {
+        let caller = McpCaller::from_context(context)
+            .or_else(|| self.transport_caller.clone())
+            .ok_or_else(|| "MCP request is not authenticated".to_string())?;
         let config = ConfigMcp::content().await;
-        match tier {
-            "read" => *config.read_tier_enabled(),
-            "control" => *config.control_tier_enabled(),
-            "transaction" => *config.transactions_enabled(),
-            _ => false,
+        if let Err(e) = clients::authorize(
+            &config,
+            &caller,
+            tool_name,
+            tier,
+            std::time::SystemTime::now(),
+        ) {
+            AuditLog::record(AuditEntry {
+                timestamp: std::time::SystemTime::now(),
+                tool_name: tool_name.to_string(),
+                tier: tier.to_string(),
+                status: AuditStatus::Denied,
+                duration_ms: None,
+                client_info: Some(caller.client_info()),
+                details: Some(e.clone()),
+            })
+            .await;
+            return Err(e);
         }
+        self.audit_tool_call(&caller, tool_name, tier, AuditStatus::Started, None)
+            .await;
+        Ok(caller)
     }
 }
 
@@ -186,13 +275,14 @@ impl TariMcpHandler {
         name = "get_mining_status",
         description = "Get current mining status: CPU/GPU running state, mining mode, and enabled settings"
     )]
-    async fn get_mining_status(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_mining_status(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_mining_status", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_mining_status", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::get_mining_status().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -200,6 +290,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_mining_status",
             "control",
             status,
@@ -214,13 +305,11 @@ impl TariMcpHandler {
         name = "get_mining_mode",
         description = "Get the currently selected mining mode and its CPU/GPU usage percentages"
     )]
-    async fn get_mining_mode(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_mining_mode(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_mining_mode", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_mining_mode", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::get_mining_mode().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -228,6 +317,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_mining_mode",
             "control",
             status,
@@ -242,13 +332,14 @@ impl TariMcpHandler {
         name = "list_mining_modes",
         description = "List all available mining modes (Eco, Turbo, Ludicrous, Custom) with their CPU/GPU usage settings"
     )]
-    async fn list_mining_modes(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn list_mining_modes(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "list_mining_modes", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("list_mining_modes", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::list_mining_modes().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -256,6 +347,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "list_mining_modes",
             "control",
             status,
@@ -272,15 +364,12 @@ impl TariMcpHandler {
     )]
     async fn start_mining(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<StartStopMiningParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let caller = self.authorize(&context, "start_mining", "control").await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: start_mining called (cpu={:?}, gpu={:?})", params.cpu, params.gpu);
-        self.audit_tool_call("start_mining", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::start_mining(params.cpu, params.gpu).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -288,6 +377,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "start_mining",
             "control",
             status,
@@ -304,15 +394,12 @@ impl TariMcpHandler {
     )]
     async fn stop_mining(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<StartStopMiningParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let caller = self.authorize(&context, "stop_mining", "control").await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: stop_mining called (cpu={:?}, gpu={:?})", params.cpu, params.gpu);
-        self.audit_tool_call("stop_mining", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::stop_mining(params.cpu, params.gpu).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -320,6 +407,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "stop_mining",
             "control",
             status,
@@ -336,15 +424,14 @@ impl TariMcpHandler {
     )]
     async fn set_mining_mode(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<SetMiningModeParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let caller = self
+            .authorize(&context, "set_mining_mode", "control")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: set_mining_mode called (mode={})", params.mode);
-        self.audit_tool_call("set_mining_mode", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::set_mining_mode(params.mode).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -352,6 +439,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "set_mining_mode",
             "control",
             status,
@@ -366,13 +454,11 @@ impl TariMcpHandler {
         name = "get_gpu_devices",
         description = "List GPU devices with vendor, name, availability, and current parameters (temperature, usage)"
     )]
-    async fn get_gpu_devices(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_gpu_devices(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_gpu_devices", "control")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_gpu_devices", "control", AuditStatus::Started, None)
-            .await;
         let result = mining::get_gpu_devices().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -380,6 +466,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_gpu_devices",
             "control",
             status,
@@ -396,13 +483,14 @@ impl TariMcpHandler {
         name = "get_wallet_address",
         description = "Get the wallet's Tari address in emoji, base58, and hex formats"
     )]
-    async fn get_wallet_address(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_wallet_address(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_wallet_address", "read")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_wallet_address", "read", AuditStatus::Started, None)
-            .await;
         let result = wallet::get_wallet_address().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -410,6 +498,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_wallet_address",
             "read",
             status,
@@ -424,13 +513,14 @@ impl TariMcpHandler {
         name = "get_wallet_balance",
         description = "Get the wallet balance including available, pending, and timelocked amounts"
     )]
-    async fn get_wallet_balance(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_wallet_balance(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_wallet_balance", "read")
+            .await?;
         let start = Instant::now();
-        self.audit_tool_call("get_wallet_balance", "read", AuditStatus::Started, None)
-            .await;
         let result = wallet::get_wallet_balance(&self.wallet_manager).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -438,6 +528,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_wallet_balance",
             "read",
             status,
@@ -450,31 +541,61 @@ impl TariMcpHandler {
     /// Get recent transaction history.
     #[tool(
         name = "get_transaction_history",
-        description = "Get recent transaction history with configurable limit"
+        description = "Get recent transaction history with configurable limit and optional date range. Each entry is classified as coinbase, pool_payout or transfer"
     )]
     async fn get_transaction_history(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<GetTransactionHistoryParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+        let caller = self
+            .authorize(&context, "get_transaction_history", "read")
+            .await?;
         let start = Instant::now();
+        let result = wallet::get_transaction_history(
+            &self.wallet_manager,
+            params.limit,
+            params.from_timestamp,
+            params.to_timestamp,
+        )
+        .await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
         self.audit_tool_call(
+            &caller,
             "get_transaction_history",
             "read",
-            AuditStatus::Started,
-            None,
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
         )
         .await;
-        let result = wallet::get_transaction_history(&self.wallet_manager, params.limit).await;
+        result
+    }
+
+    /// List blocks won by this machine's solo miners and their reconciliation status.
+    #[tool(
+        name = "get_mined_blocks",
+        description = "List blocks won by solo mining with reward, miner and status (pending/confirmed/orphaned/reward_missing), plus totals"
+    )]
+    async fn get_mined_blocks(
+        &self,
+        context: RequestContext<RoleServer>,
+        Parameters(params): Parameters<GetMinedBlocksParams>,
+    ) -> Result<String, String> {
+        let caller = self.authorize(&context, "get_mined_blocks", "read").await?;
+        let start = Instant::now();
+        let result = mining::get_mined_blocks(params.limit, params.status).await;
         let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "get_transaction_history",
+            &caller,
+            "get_mined_blocks",
             "read",
             status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
@@ -490,13 +611,12 @@ impl TariMcpHandler {
         name = "get_chain_status",
         description = "Get chain status: block height, block time, block reward, sync status, peer count"
     )]
-    async fn get_chain_status(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_chain_status(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self.authorize(&context, "get_chain_status", "read").await?;
         let start = Instant::now();
-        self.audit_tool_call("get_chain_status", "read", AuditStatus::Started, None)
-            .await;
         let status = *self.node_status_rx.borrow();
         let result = chain::get_chain_status(&status);
         let audit_status = if result.is_ok() {
@@ -505,6 +625,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_chain_status",
             "read",
             audit_status,
@@ -519,13 +640,12 @@ impl TariMcpHandler {
         name = "get_network_info",
         description = "Get network info: network name, sync status, connection count"
     )]
-    async fn get_network_info(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
-        }
+    async fn get_network_info(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self.authorize(&context, "get_network_info", "read").await?;
         let start = Instant::now();
-        self.audit_tool_call("get_network_info", "read", AuditStatus::Started, None)
-            .await;
         let status = *self.node_status_rx.borrow();
         let result = chain::get_network_info(&status);
         let audit_status = if result.is_ok() {
@@ -534,6 +654,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "get_network_info",
             "read",
             audit_status,
@@ -543,25 +664,183 @@ impl TariMcpHandler {
         result
     }
 
-    // ==================== Scheduler Tools (Control tier) ====================
+    // ==================== Binaries Tools (Read tier) ====================
 
-    /// List all scheduled mining events.
+    /// List installed, active, pinned and release versions of the managed binaries.
     #[tool(
-        name = "list_scheduled_events",
-        description = "List all scheduled mining events with their timing and state"
+        name = "get_binary_versions",
+        description = "List managed binaries with release, active, pinned and installed versions, the version they replaced and any pending upgrade or rollback"
     )]
-    async fn list_scheduled_events(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+    async fn get_binary_versions(
+        &self,
+        context: RequestContext<RoleServer>,
+        Parameters(params): Parameters<GetBinaryVersionsParams>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_binary_versions", "read")
+            .await?;
         let start = Instant::now();
+        let result = binaries::get_binary_versions(params.binary).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
         self.audit_tool_call(
-            "list_scheduled_events",
+            &caller,
+            "get_binary_versions",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Process Tools (Read tier) ====================
+
+    /// Tail the captured stdout and stderr of a managed process.
+    #[tool(
+        name = "tail_process_output",
+        description = "Tail the captured stdout/stderr of a managed process, optionally filtered by text and stream. Without a process, lists the processes with captured output"
+    )]
+    async fn tail_process_output(
+        &self,
+        context: RequestContext<RoleServer>,
+        Parameters(params): Parameters<TailProcessOutputParams>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "tail_process_output", "read")
+            .await?;
+        let start = Instant::now();
+        let result = processes::tail_process_output_lines(
+            params.process,
+            params.lines,
+            params.contains,
+            params.stream,
+        )
+        .await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &caller,
+            "tail_process_output",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Get the port assigned to each managed service.
+    #[tool(
+        name = "get_service_ports",
+        description = "Get the port map of the managed services (node gRPC/P2P/HTTP API, Tor SOCKS/control, wallet gRPC, mmproxy, xmrig and lolMiner APIs), including which ports are pinned"
+    )]
+    async fn get_service_ports(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "get_service_ports", "read")
+            .await?;
+        let start = Instant::now();
+        let result = processes::get_service_ports().await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &caller,
+            "get_service_ports",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Process Tools (Control tier) ====================
+
+    /// List the managed processes with their runtime details.
+    #[tool(
+        name = "list_processes",
+        description = "List every managed process with its PID, binary path and version, command line (secrets redacted), ports, uptime, health status, restart count and last exit code"
+    )]
+    async fn list_processes(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "list_processes", "control")
+            .await?;
+        let start = Instant::now();
+        let result = processes::list_processes().await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &caller,
+            "list_processes",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Start, stop or restart a managed process.
+    #[tool(
+        name = "control_process",
+        description = "Start, stop or restart a managed process. Node, Tor, wallet and mmproxy start and restart by restarting their setup phase"
+    )]
+    async fn control_process(
+        &self,
+        context: RequestContext<RoleServer>,
+        Parameters(params): Parameters<ControlProcessParams>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "control_process", "control")
+            .await?;
+        let start = Instant::now();
+        let result = processes::control_process(params.process, params.action).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            &caller,
+            "control_process",
             "control",
-            AuditStatus::Started,
-            None,
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
         )
         .await;
+        result
+    }
+
+    // ==================== Scheduler Tools (Control tier) ====================
+
+    /// List all scheduled mining events.
+    #[tool(
+        name = "list_scheduled_events",
+        description = "List all scheduled mining events with their timing and state"
+    )]
+    async fn list_scheduled_events(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let caller = self
+            .authorize(&context, "list_scheduled_events", "control")
+            .await?;
+        let start = Instant::now();
         let result = scheduler::list_scheduled_events().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -569,6 +848,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "list_scheduled_events",
             "control",
             status,
@@ -585,23 +865,17 @@ impl TariMcpHandler {
     )]
     async fn schedule_mining_window(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<ScheduleMiningWindowParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let caller = self
+            .authorize(&context, "schedule_mining_window", "control")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: schedule_mining_window called (id={}, mode={}, start={}:{:02} {}, end={}:{:02} {})",
             params.event_id, params.mining_mode,
             params.start_hour, params.start_minute.unwrap_or(0), params.start_period,
             params.end_hour, params.end_minute.unwrap_or(0), params.end_period);
-        self.audit_tool_call(
-            "schedule_mining_window",
-            "control",
-            AuditStatus::Started,
-            None,
-        )
-        .await;
         let result = scheduler::schedule_mining_window(scheduler::MiningWindowParams {
             event_id: params.event_id,
             mining_mode: params.mining_mode,
@@ -619,6 +893,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "schedule_mining_window",
             "control",
             status,
@@ -635,20 +910,14 @@ impl TariMcpHandler {
     )]
     async fn cancel_scheduled_event(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<CancelScheduledEventParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
-        }
+        let caller = self
+            .authorize(&context, "cancel_scheduled_event", "control")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: cancel_scheduled_event called (id={})", params.event_id);
-        self.audit_tool_call(
-            "cancel_scheduled_event",
-            "control",
-            AuditStatus::Started,
-            None,
-        )
-        .await;
         let result = scheduler::cancel_scheduled_event(params.event_id).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -656,6 +925,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            &caller,
             "cancel_scheduled_event",
             "control",
             status,
@@ -674,26 +944,21 @@ impl TariMcpHandler {
     )]
     async fn send_transaction(
         &self,
+        context: RequestContext<RoleServer>,
         Parameters(params): Parameters<SendTransactionParams>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("transaction").await {
-            return Err("Transaction tier is disabled".to_string());
-        }
+        let caller = self
+            .authorize(&context, "send_transaction", "transaction")
+            .await?;
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: send_transaction called (destination={}, amount={})", params.destination, params.amount);
-        self.audit_tool_call(
-            "send_transaction",
-            "transaction",
-            AuditStatus::Started,
-            None,
-        )
-        .await;
 
         let app_handle = crate::events_emitter::EventsEmitter::get_app_handle_public().await;
         let result = transaction::send_transaction(
             params.destination,
             params.amount,
             params.payment_id,
+            &caller,
             &self.wallet_manager,
             &app_handle,
         )
@@ -706,6 +971,7 @@ impl TariMcpHandler {
             Err(_) => AuditStatus::Error,
         };
         self.audit_tool_call(
+            &caller,
             "send_transaction",
             "transaction",
             status,
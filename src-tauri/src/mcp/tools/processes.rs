@@ -0,0 +1,87 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::events_emitter::EventsEmitter;
+use crate::port_registry::service_port_map;
+use crate::process_inventory::{ProcessControlAction, process_inventory};
+use crate::process_output::{
+    OutputFilter, OutputStream, captured_process_names, tail_process_output,
+};
+
+pub async fn tail_process_output_lines(
+    process: Option<String>,
+    lines: Option<usize>,
+    contains: Option<String>,
+    stream: Option<String>,
+) -> Result<String, String> {
+    let Some(process) = process else {
+        return serde_json::to_string(&captured_process_names().await).map_err(|e| e.to_string());
+    };
+
+    let stream = match stream.as_deref() {
+        None => None,
+        Some("stdout") => Some(OutputStream::Stdout),
+        Some("stderr") => Some(OutputStream::Stderr),
+        Some(other) => {
+            return Err(format!(
+                "Unknown stream: {other}, expected stdout or stderr"
+            ));
+        }
+    };
+    let filter = OutputFilter {
+        lines,
+        contains,
+        stream,
+    };
+    let output = tail_process_output(&process, &filter)
+        .await
+        .ok_or_else(|| format!("No captured output for process: {process}"))?;
+
+    serde_json::to_string(&output).map_err(|e| e.to_string())
+}
+
+pub async fn get_service_ports() -> Result<String, String> {
+    serde_json::to_string(&service_port_map().await).map_err(|e| e.to_string())
+}
+
+pub async fn list_processes() -> Result<String, String> {
+    serde_json::to_string(&process_inventory().await).map_err(|e| e.to_string())
+}
+
+pub async fn control_process(process: String, action: String) -> Result<String, String> {
+    let action = match action.as_str() {
+        "start" => ProcessControlAction::Start,
+        "stop" => ProcessControlAction::Stop,
+        "restart" => ProcessControlAction::Restart,
+        other => {
+            return Err(format!(
+                "Unknown action: {other}, expected start, stop or restart"
+            ));
+        }
+    };
+    let app_handle = EventsEmitter::get_app_handle_public().await;
+    crate::process_inventory::control_process(&app_handle, &process, action)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(format!("{process}: {action} requested"))
+}
@@ -23,12 +23,13 @@
 use std::fmt;
 use std::str::FromStr;
 use std::sync::LazyLock;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::configs::config_mcp::ConfigMcp;
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
+use crate::mcp::clients::McpCaller;
 use crate::mcp::rate_limiter::TransactionRateLimiter;
 use crate::pin::PinManager;
 use crate::wallet::wallet_manager::WalletManager;
@@ -119,6 +120,7 @@ pub async fn send_transaction(
     destination: String,
     amount: String,
     payment_id: Option<String>,
+    caller: &McpCaller,
     wallet_manager: &WalletManager,
     app_handle: &tauri::AppHandle,
 ) -> Result<String, TransactionError> {
@@ -158,6 +160,17 @@ pub async fn send_transaction(
         ));
     }
 
+    // 5b. Per-client limits, checked under the gate so concurrent sends can't both pass
+    if let Some(client_id) = caller.client_id() {
+        let config = ConfigMcp::content().await;
+        let client = config
+            .find_client(client_id)
+            .ok_or_else(|| TransactionError::Denied("MCP client has been revoked".to_string()))?;
+        client
+            .check_spend(amount_u64, SystemTime::now())
+            .map_err(TransactionError::InvalidAmount)?;
+    }
+
     // 6. Generate request ID
     let request_id = format!("mcp_tx_{}", uuid::Uuid::new_v4());
 
@@ -203,6 +216,9 @@ pub async fn send_transaction(
 
     match tx_result {
         Ok(()) => {
+            if let Some(client_id) = caller.client_id() {
+                record_client_spend(client_id, amount_u64).await;
+            }
             EventsEmitter::emit_mcp_transaction_result(
                 crate::events::McpTransactionResultPayload {
                     request_id,
@@ -240,6 +256,17 @@ pub async fn send_transaction(
     }
 }
 
+async fn record_client_spend(client_id: &str, amount: u64) {
+    let mut config = ConfigMcp::current().write().await;
+    let content = config._get_content_mut();
+    if let Some(client) = content.find_client_mut(client_id) {
+        client.record_spend(amount, SystemTime::now());
+        if let Err(e) = ConfigMcp::_save_config(content.clone()) {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP: failed to record spend for client {client_id}: {e:?}");
+        }
+    }
+}
+
 async fn await_confirmation(
     rx: tokio::sync::oneshot::Receiver<TxnDialogResponse>,
 ) -> Result<(), TransactionError> {
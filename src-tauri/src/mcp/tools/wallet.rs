@@ -21,6 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::internal_wallet::InternalWallet;
+use crate::wallet::transaction_export::{ExportFilter, classify_transaction};
 use crate::wallet::wallet_manager::WalletManager;
 
 pub async fn get_wallet_address() -> Result<String, String> {
@@ -55,12 +56,24 @@ pub async fn get_wallet_balance(wallet_manager: &WalletManager) -> Result<String
 pub async fn get_transaction_history(
     wallet_manager: &WalletManager,
     limit: Option<u32>,
+    from_timestamp: Option<u64>,
+    to_timestamp: Option<u64>,
 ) -> Result<String, String> {
     let limit = limit.unwrap_or(20);
-    let transactions = wallet_manager
-        .get_transactions(None, Some(limit), None)
-        .await
-        .map_err(|e| format!("Failed to get transaction history: {e}"))?;
+    let transactions = if from_timestamp.is_some() || to_timestamp.is_some() {
+        let filter = ExportFilter {
+            from_timestamp,
+            to_timestamp,
+        };
+        wallet_manager
+            .get_filtered_transactions(filter, usize::try_from(limit).unwrap_or(usize::MAX))
+            .await
+    } else {
+        wallet_manager
+            .get_transactions(None, Some(limit), None)
+            .await
+    }
+    .map_err(|e| format!("Failed to get transaction history: {e}"))?;
 
     let result: Vec<serde_json::Value> = transactions
         .iter()
@@ -77,6 +90,8 @@ pub async fn get_transaction_history(
                 "timestamp": tx.timestamp,
                 "mined_in_block_height": tx.mined_in_block_height,
                 "payment_id": tx.payment_id,
+                "payment_reference": tx.payment_reference,
+                "category": classify_transaction(tx),
             })
         })
         .collect();
@@ -0,0 +1,496 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Persistent ledger of blocks won by our solo miners.
+
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use dirs::config_dir;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tari_transaction_components::tari_amount::MicroMinotari;
+use tokio::sync::RwLock;
+
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::trait_config::ConfigImpl;
+use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::manager::GpuManager;
+use crate::node::node_adapter::BlockCoinbases;
+use crate::node::node_manager::NodeManager;
+use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus};
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+/// Number of blocks a coinbase output stays time-locked after being mined
+pub const COINBASE_MATURITY_BLOCKS: u64 = 720;
+/// Blocks on top of a won block before its entry is considered settled
+pub const CONFIRMATION_DEPTH: u64 = 6;
+/// Blocks to wait for the wallet to pick up a coinbase before flagging the reward as missing
+pub const MISSING_REWARD_GRACE_BLOCKS: u64 = 30;
+/// Full reconciliation (including backfill from wallet coinbases) runs every N blocks
+pub const RECONCILE_INTERVAL_BLOCKS: u64 = 10;
+
+static INSTANCE: LazyLock<RwLock<BlockLedger>> = LazyLock::new(|| RwLock::new(BlockLedger::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinerKind {
+    /// CPU merge mining through the local mm-proxy
+    Cpu,
+    /// GPU solo mining against the local node
+    Gpu,
+    /// Both solo miners were running, or the block was backfilled from the wallet
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinedBlockStatus {
+    Pending,
+    Confirmed,
+    Orphaned,
+    RewardMissing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinedBlockEntry {
+    pub height: u64,
+    pub hash: Option<String>,
+    pub reward: MicroMinotari,
+    /// Unix timestamp (seconds) of when the block was found
+    pub mined_at: u64,
+    pub miner: MinerKind,
+    pub status: MinedBlockStatus,
+    pub coinbase_tx_id: Option<String>,
+}
+
+impl MinedBlockEntry {
+    pub fn from_coinbase(
+        coinbase: &TransactionInfo,
+        hash: Option<String>,
+        miner: MinerKind,
+    ) -> Self {
+        Self {
+            height: coinbase.mined_in_block_height,
+            hash,
+            reward: coinbase.amount,
+            mined_at: coinbase.timestamp,
+            miner,
+            status: MinedBlockStatus::Pending,
+            coinbase_tx_id: Some(coinbase.tx_id.clone()),
+        }
+    }
+
+    /// Builds an entry for a block on the node whose coinbase carries `coinbase_extra`, or None
+    /// when somebody else won it
+    pub fn from_chain_block(
+        height: u64,
+        block: &BlockCoinbases,
+        coinbase_extra: &[u8],
+        miner: MinerKind,
+    ) -> Option<Self> {
+        if coinbase_extra.is_empty() {
+            return None;
+        }
+        let reward = block
+            .coinbases
+            .iter()
+            .filter(|coinbase| coinbase.extra == coinbase_extra)
+            .map(|coinbase| coinbase.value.as_u64())
+            .reduce(|total, value| total.saturating_add(value))?;
+        Some(Self {
+            height,
+            hash: Some(block.hash.clone()),
+            reward: MicroMinotari(reward),
+            mined_at: block.timestamp,
+            miner,
+            status: MinedBlockStatus::Pending,
+            coinbase_tx_id: None,
+        })
+    }
+
+    /// Confirmed and orphaned blocks are final. A missing reward stays open until the coinbase
+    /// would have matured, in case the wallet picks it up late, and is final after that.
+    pub fn is_settled(&self, tip_height: u64) -> bool {
+        match self.status {
+            MinedBlockStatus::Confirmed | MinedBlockStatus::Orphaned => true,
+            MinedBlockStatus::RewardMissing => {
+                tip_height >= self.height.saturating_add(COINBASE_MATURITY_BLOCKS)
+            }
+            MinedBlockStatus::Pending => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BlockLedgerSummary {
+    pub total_blocks: usize,
+    pub pending: usize,
+    pub confirmed: usize,
+    pub orphaned: usize,
+    pub reward_missing: usize,
+    pub confirmed_reward: MicroMinotari,
+    pub cpu_blocks: usize,
+    pub gpu_blocks: usize,
+}
+
+fn is_coinbase_status(status: TransactionStatus) -> bool {
+    matches!(
+        status,
+        TransactionStatus::Coinbase
+            | TransactionStatus::CoinbaseConfirmed
+            | TransactionStatus::CoinbaseUnconfirmed
+            | TransactionStatus::CoinbaseNotInBlockChain
+    )
+}
+
+/// Decides the status of an entry from the node's current hash at its height and the wallet's
+/// coinbase for it
+pub fn evaluate_block_status(
+    entry: &MinedBlockEntry,
+    chain_hash: Option<&str>,
+    coinbase: Option<&TransactionInfo>,
+    tip_height: u64,
+) -> MinedBlockStatus {
+    if let (Some(recorded), Some(chain)) = (entry.hash.as_deref(), chain_hash)
+        && recorded != chain
+    {
+        return MinedBlockStatus::Orphaned;
+    }
+
+    let depth = tip_height.saturating_sub(entry.height);
+    match coinbase {
+        Some(tx) if tx.is_cancelled || tx.status == TransactionStatus::CoinbaseNotInBlockChain => {
+            MinedBlockStatus::Orphaned
+        }
+        Some(tx)
+            if tx.status == TransactionStatus::CoinbaseConfirmed && depth >= CONFIRMATION_DEPTH =>
+        {
+            MinedBlockStatus::Confirmed
+        }
+        Some(_) => MinedBlockStatus::Pending,
+        None if depth >= MISSING_REWARD_GRACE_BLOCKS => MinedBlockStatus::RewardMissing,
+        None => MinedBlockStatus::Pending,
+    }
+}
+
+/// Inserts the entry or fills in missing fields of the existing one at the same height, keeping
+/// the ledger sorted by height. Returns true when a new entry was added.
+pub fn upsert_entry(entries: &mut Vec<MinedBlockEntry>, entry: MinedBlockEntry) -> bool {
+    match entries.binary_search_by_key(&entry.height, |e| e.height) {
+        Ok(index) => {
+            let existing = &mut entries[index];
+            // Never overwrite a recorded hash, it's what orphan detection compares against
+            if existing.hash.is_none() {
+                existing.hash = entry.hash;
+            }
+            if existing.coinbase_tx_id.is_none() && entry.coinbase_tx_id.is_some() {
+                existing.coinbase_tx_id = entry.coinbase_tx_id;
+                existing.reward = entry.reward;
+            }
+            if existing.miner == MinerKind::Unknown {
+                existing.miner = entry.miner;
+            }
+            false
+        }
+        Err(index) => {
+            entries.insert(index, entry);
+            true
+        }
+    }
+}
+
+/// Applies the latest chain and wallet data to every unsettled entry and adds wallet coinbases
+/// that are missing from the ledger. Returns the number of entries added or changed.
+pub fn reconcile_entries(
+    entries: &mut Vec<MinedBlockEntry>,
+    chain_hashes: &[(u64, String)],
+    coinbases: &[TransactionInfo],
+    tip_height: u64,
+) -> usize {
+    let mut changed = 0;
+    for coinbase in coinbases
+        .iter()
+        .filter(|tx| is_coinbase_status(tx.status) && tx.mined_in_block_height > 0)
+    {
+        let hash = chain_hashes
+            .iter()
+            .find(|(height, _)| *height == coinbase.mined_in_block_height)
+            .map(|(_, hash)| hash.clone());
+        if upsert_entry(
+            entries,
+            MinedBlockEntry::from_coinbase(coinbase, hash, MinerKind::Unknown),
+        ) {
+            changed += 1;
+        }
+    }
+
+    for entry in entries.iter_mut().filter(|e| !e.is_settled(tip_height)) {
+        let chain_hash = chain_hashes
+            .iter()
+            .find(|(height, _)| *height == entry.height)
+            .map(|(_, hash)| hash.as_str());
+        if entry.hash.is_none() {
+            entry.hash = chain_hash.map(str::to_string);
+        }
+        let coinbase = coinbases.iter().find(|tx| {
+            tx.mined_in_block_height == entry.height
+                && entry
+                    .coinbase_tx_id
+                    .as_ref()
+                    .is_none_or(|tx_id| *tx_id == tx.tx_id)
+        });
+        let status = evaluate_block_status(entry, chain_hash, coinbase, tip_height);
+        if status != entry.status {
+            info!(target: LOG_TARGET_APP_LOGIC, "Mined block #{} status changed: {:?} -> {:?}", entry.height, entry.status, status);
+            entry.status = status;
+            changed += 1;
+        }
+    }
+
+    changed
+}
+
+pub fn summarize(entries: &[MinedBlockEntry]) -> BlockLedgerSummary {
+    let mut summary = BlockLedgerSummary {
+        total_blocks: entries.len(),
+        ..Default::default()
+    };
+    let mut confirmed_reward = 0u64;
+    for entry in entries {
+        match entry.status {
+            MinedBlockStatus::Pending => summary.pending += 1,
+            MinedBlockStatus::Confirmed => {
+                summary.confirmed += 1;
+                confirmed_reward += entry.reward.as_u64();
+            }
+            MinedBlockStatus::Orphaned => summary.orphaned += 1,
+            MinedBlockStatus::RewardMissing => summary.reward_missing += 1,
+        }
+        match entry.miner {
+            MinerKind::Cpu => summary.cpu_blocks += 1,
+            MinerKind::Gpu => summary.gpu_blocks += 1,
+            MinerKind::Unknown => {}
+        }
+    }
+    summary.confirmed_reward = MicroMinotari(confirmed_reward);
+    summary
+}
+
+pub struct BlockLedger {
+    entries: Vec<MinedBlockEntry>,
+    ledger_path: PathBuf,
+}
+
+impl BlockLedger {
+    fn new() -> Self {
+        let ledger_path = Self::_get_ledger_path();
+        let entries = Self::_load(&ledger_path);
+        Self {
+            entries,
+            ledger_path,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    fn _get_ledger_path() -> PathBuf {
+        let config_dir = config_dir().unwrap_or_else(std::env::temp_dir);
+        config_dir
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str())
+            .join("mined_blocks.json")
+    }
+
+    fn _load(path: &PathBuf) -> Vec<MinedBlockEntry> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to parse mined block ledger: {e:?}");
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    async fn _save(&self) -> Result<(), anyhow::Error> {
+        if let Some(parent) = self.ledger_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let contents = serde_json::to_string_pretty(&self.entries)?;
+        tokio::fs::write(&self.ledger_path, contents).await?;
+        Ok(())
+    }
+
+    /// Works out which solo miner could have found a block. Pool mining never pays a coinbase to
+    /// our wallet, so only miners that are running without a pool are considered.
+    pub async fn detect_solo_miner() -> MinerKind {
+        let pools_config = ConfigPools::content().await;
+        let cpu_solo = !*pools_config.cpu_pool_enabled() && CpuManager::read().await.is_running();
+        let gpu_solo = !*pools_config.gpu_pool_enabled() && GpuManager::read().await.is_running();
+        match (cpu_solo, gpu_solo) {
+            (true, false) => MinerKind::Cpu,
+            (false, true) => MinerKind::Gpu,
+            _ => MinerKind::Unknown,
+        }
+    }
+
+    pub async fn record_block_won(
+        coinbase: &TransactionInfo,
+        node_manager: &NodeManager,
+    ) -> Result<(), anyhow::Error> {
+        let height = coinbase.mined_in_block_height;
+        let hash = match node_manager.get_current_service().await {
+            Ok(service) => service
+                .get_historical_blocks(vec![height])
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .next()
+                .map(|(_, hash)| hash),
+            Err(_) => None,
+        };
+        let miner = Self::detect_solo_miner().await;
+        info!(target: LOG_TARGET_APP_LOGIC, "Recording mined block #{height} ({miner:?}) with reward {}", coinbase.amount);
+
+        let mut ledger = Self::current().write().await;
+        upsert_entry(
+            &mut ledger.entries,
+            MinedBlockEntry::from_coinbase(coinbase, hash, miner),
+        );
+        ledger._save().await
+    }
+
+    /// Checks whether the node's block at `height` pays our coinbase and records it if so.
+    /// Blocks are found from chain data so a reward the wallet never sees still gets an entry.
+    pub async fn record_chain_block(
+        node_manager: &NodeManager,
+        height: u64,
+        coinbase_extra: &str,
+    ) -> Result<bool, anyhow::Error> {
+        let Some(block) = node_manager
+            .get_current_service()
+            .await?
+            .get_block_coinbases(height)
+            .await?
+        else {
+            return Ok(false);
+        };
+        let miner = Self::detect_solo_miner().await;
+        let Some(entry) =
+            MinedBlockEntry::from_chain_block(height, &block, coinbase_extra.as_bytes(), miner)
+        else {
+            return Ok(false);
+        };
+        info!(target: LOG_TARGET_APP_LOGIC, "Recording mined block #{height} ({miner:?}) from chain with reward {}", entry.reward);
+
+        let mut ledger = Self::current().write().await;
+        upsert_entry(&mut ledger.entries, entry);
+        ledger._save().await?;
+        Ok(true)
+    }
+
+    pub async fn has_unsettled_entries(tip_height: u64) -> bool {
+        Self::current()
+            .read()
+            .await
+            .entries
+            .iter()
+            .any(|e| !e.is_settled(tip_height))
+    }
+
+    /// Cross-checks unsettled entries with the node and wallet, and backfills coinbases the
+    /// wallet knows about but the ledger doesn't
+    pub async fn reconcile(
+        node_manager: &NodeManager,
+        wallet_manager: &WalletManager,
+        tip_height: u64,
+    ) -> Result<usize, anyhow::Error> {
+        const COINBASE_STATUSES_BITFLAG: u32 = (1 << TransactionStatus::CoinbaseConfirmed as u32)
+            | (1 << TransactionStatus::CoinbaseUnconfirmed as u32)
+            | (1 << TransactionStatus::CoinbaseNotInBlockChain as u32);
+
+        let coinbases = wallet_manager
+            .get_transactions(None, None, Some(COINBASE_STATUSES_BITFLAG))
+            .await?;
+
+        // Only look up hashes for unsettled entries and coinbases not in the ledger yet
+        let mut heights: Vec<u64> = {
+            let ledger = Self::current().read().await;
+            let known = |height: u64| ledger.entries.iter().any(|e| e.height == height);
+            ledger
+                .entries
+                .iter()
+                .filter(|e| !e.is_settled(tip_height))
+                .map(|e| e.height)
+                .chain(
+                    coinbases
+                        .iter()
+                        .map(|tx| tx.mined_in_block_height)
+                        .filter(|height| !known(*height)),
+                )
+                .collect()
+        };
+        heights.sort_unstable();
+        heights.dedup();
+        heights.retain(|height| *height > 0 && *height <= tip_height);
+
+        let chain_hashes = node_manager
+            .get_current_service()
+            .await?
+            .get_historical_blocks(heights)
+            .await?;
+
+        let mut ledger = Self::current().write().await;
+        let changed = reconcile_entries(&mut ledger.entries, &chain_hashes, &coinbases, tip_height);
+        if changed > 0 {
+            ledger._save().await?;
+        }
+        Ok(changed)
+    }
+
+    /// Entries newest first, optionally restricted to one status
+    pub async fn get_blocks(
+        offset: usize,
+        limit: usize,
+        status: Option<MinedBlockStatus>,
+    ) -> Vec<MinedBlockEntry> {
+        let ledger = Self::current().read().await;
+        ledger
+            .entries
+            .iter()
+            .rev()
+            .filter(|e| status.is_none_or(|s| e.status == s))
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub async fn get_summary() -> BlockLedgerSummary {
+        summarize(&Self::current().read().await.entries)
+    }
+}
@@ -0,0 +1,307 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Tests for mined block ledger reconciliation
+
+use tari_transaction_components::tari_amount::MicroMinotari;
+
+use super::block_ledger::{
+    COINBASE_MATURITY_BLOCKS, CONFIRMATION_DEPTH, MISSING_REWARD_GRACE_BLOCKS, MinedBlockEntry,
+    MinedBlockStatus, MinerKind, evaluate_block_status, reconcile_entries, summarize, upsert_entry,
+};
+use crate::node::node_adapter::{BlockCoinbase, BlockCoinbases};
+use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus};
+
+fn create_coinbase(height: u64, status: TransactionStatus) -> TransactionInfo {
+    TransactionInfo {
+        tx_id: format!("cb_{height}"),
+        source_address: "source".to_string(),
+        dest_address: "dest".to_string(),
+        status,
+        amount: MicroMinotari(13_000_000_000),
+        is_cancelled: false,
+        direction: 1,
+        excess_sig: vec![],
+        fee: 0,
+        timestamp: 1_700_000_000,
+        payment_id: "".to_string(),
+        mined_in_block_height: height,
+        payment_reference: None,
+    }
+}
+
+fn create_entry(height: u64, hash: Option<&str>, miner: MinerKind) -> MinedBlockEntry {
+    MinedBlockEntry::from_coinbase(
+        &create_coinbase(height, TransactionStatus::CoinbaseUnconfirmed),
+        hash.map(str::to_string),
+        miner,
+    )
+}
+
+fn create_chain_block(coinbases: &[(&str, u64)]) -> BlockCoinbases {
+    BlockCoinbases {
+        hash: "cccc".to_string(),
+        timestamp: 1_700_000_500,
+        coinbases: coinbases
+            .iter()
+            .map(|(extra, value)| BlockCoinbase {
+                extra: extra.as_bytes().to_vec(),
+                value: MicroMinotari(*value),
+            })
+            .collect(),
+    }
+}
+
+// ==================== Chain blocks ====================
+
+#[test]
+fn chain_block_with_our_coinbase_extra_is_recorded() {
+    let block = create_chain_block(&[("ours", 10_000), ("ours", 3_000), ("theirs", 99)]);
+
+    let entry = MinedBlockEntry::from_chain_block(200, &block, b"ours", MinerKind::Cpu).unwrap();
+
+    assert_eq!(entry.height, 200);
+    assert_eq!(entry.hash.as_deref(), Some("cccc"));
+    assert_eq!(entry.reward, MicroMinotari(13_000));
+    assert_eq!(entry.mined_at, 1_700_000_500);
+    assert_eq!(entry.status, MinedBlockStatus::Pending);
+    assert!(entry.coinbase_tx_id.is_none());
+}
+
+#[test]
+fn chain_block_won_by_someone_else_is_ignored() {
+    let block = create_chain_block(&[("theirs", 13_000)]);
+
+    assert!(MinedBlockEntry::from_chain_block(200, &block, b"ours", MinerKind::Cpu).is_none());
+    assert!(MinedBlockEntry::from_chain_block(200, &block, b"", MinerKind::Cpu).is_none());
+}
+
+// ==================== Status evaluation ====================
+
+#[test]
+fn hash_mismatch_marks_block_orphaned() {
+    let entry = create_entry(100, Some("aaaa"), MinerKind::Cpu);
+    let coinbase = create_coinbase(100, TransactionStatus::CoinbaseConfirmed);
+
+    let status = evaluate_block_status(&entry, Some("bbbb"), Some(&coinbase), 1_000);
+
+    assert_eq!(status, MinedBlockStatus::Orphaned);
+}
+
+#[test]
+fn coinbase_not_in_blockchain_marks_block_orphaned() {
+    let entry = create_entry(100, Some("aaaa"), MinerKind::Cpu);
+    let coinbase = create_coinbase(100, TransactionStatus::CoinbaseNotInBlockChain);
+
+    let status = evaluate_block_status(&entry, Some("aaaa"), Some(&coinbase), 101);
+
+    assert_eq!(status, MinedBlockStatus::Orphaned);
+}
+
+#[test]
+fn confirmed_coinbase_needs_confirmation_depth() {
+    let entry = create_entry(100, Some("aaaa"), MinerKind::Gpu);
+    let coinbase = create_coinbase(100, TransactionStatus::CoinbaseConfirmed);
+
+    let shallow = evaluate_block_status(
+        &entry,
+        Some("aaaa"),
+        Some(&coinbase),
+        100 + CONFIRMATION_DEPTH - 1,
+    );
+    let deep = evaluate_block_status(
+        &entry,
+        Some("aaaa"),
+        Some(&coinbase),
+        100 + CONFIRMATION_DEPTH,
+    );
+
+    assert_eq!(shallow, MinedBlockStatus::Pending);
+    assert_eq!(deep, MinedBlockStatus::Confirmed);
+}
+
+#[test]
+fn missing_coinbase_flagged_after_grace_period() {
+    let entry = create_entry(100, Some("aaaa"), MinerKind::Cpu);
+
+    let early = evaluate_block_status(&entry, Some("aaaa"), None, 101);
+    let late = evaluate_block_status(
+        &entry,
+        Some("aaaa"),
+        None,
+        100 + MISSING_REWARD_GRACE_BLOCKS,
+    );
+
+    assert_eq!(early, MinedBlockStatus::Pending);
+    assert_eq!(late, MinedBlockStatus::RewardMissing);
+}
+
+// ==================== Upsert ====================
+
+#[test]
+fn upsert_keeps_entries_sorted_and_deduplicated() {
+    let mut entries = Vec::new();
+
+    assert!(upsert_entry(
+        &mut entries,
+        create_entry(200, None, MinerKind::Cpu)
+    ));
+    assert!(upsert_entry(
+        &mut entries,
+        create_entry(100, None, MinerKind::Gpu)
+    ));
+    assert!(!upsert_entry(
+        &mut entries,
+        create_entry(200, Some("cccc"), MinerKind::Gpu)
+    ));
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].height, 100);
+    assert_eq!(entries[1].hash.as_deref(), Some("cccc"));
+    // A known miner is not replaced
+    assert_eq!(entries[1].miner, MinerKind::Cpu);
+}
+
+#[test]
+fn upsert_never_overwrites_recorded_hash() {
+    let mut entries = vec![create_entry(100, Some("aaaa"), MinerKind::Unknown)];
+
+    upsert_entry(
+        &mut entries,
+        create_entry(100, Some("bbbb"), MinerKind::Cpu),
+    );
+
+    assert_eq!(entries[0].hash.as_deref(), Some("aaaa"));
+    assert_eq!(entries[0].miner, MinerKind::Cpu);
+}
+
+// ==================== Reconciliation ====================
+
+#[test]
+fn reconcile_backfills_wallet_coinbases() {
+    let mut entries = Vec::new();
+    let coinbases = vec![create_coinbase(150, TransactionStatus::CoinbaseConfirmed)];
+    let chain_hashes = vec![(150, "ffff".to_string())];
+
+    let changed = reconcile_entries(&mut entries, &chain_hashes, &coinbases, 1_000);
+
+    assert_eq!(changed, 2);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].miner, MinerKind::Unknown);
+    assert_eq!(entries[0].hash.as_deref(), Some("ffff"));
+    assert_eq!(entries[0].status, MinedBlockStatus::Confirmed);
+}
+
+#[test]
+fn reconcile_detects_reorged_block() {
+    let mut entries = vec![create_entry(100, Some("aaaa"), MinerKind::Cpu)];
+    let coinbases = vec![create_coinbase(100, TransactionStatus::CoinbaseUnconfirmed)];
+    let chain_hashes = vec![(100, "bbbb".to_string())];
+
+    reconcile_entries(&mut entries, &chain_hashes, &coinbases, 103);
+
+    assert_eq!(entries[0].status, MinedBlockStatus::Orphaned);
+    assert_eq!(entries[0].hash.as_deref(), Some("aaaa"));
+}
+
+#[test]
+fn reconcile_leaves_settled_entries_alone() {
+    let mut entry = create_entry(100, Some("aaaa"), MinerKind::Cpu);
+    entry.status = MinedBlockStatus::Confirmed;
+    let mut entries = vec![entry];
+
+    let changed = reconcile_entries(&mut entries, &[(100, "bbbb".to_string())], &[], 1_000);
+
+    assert_eq!(changed, 0);
+    assert_eq!(entries[0].status, MinedBlockStatus::Confirmed);
+}
+
+#[test]
+fn reconcile_flags_chain_block_the_wallet_never_saw() {
+    let block = create_chain_block(&[("ours", 13_000)]);
+    let entry = MinedBlockEntry::from_chain_block(100, &block, b"ours", MinerKind::Cpu).unwrap();
+    let mut entries = vec![entry];
+    let chain_hashes = vec![(100, "cccc".to_string())];
+    let tip_height = 100 + MISSING_REWARD_GRACE_BLOCKS;
+
+    let changed = reconcile_entries(&mut entries, &chain_hashes, &[], tip_height);
+
+    assert_eq!(changed, 1);
+    assert_eq!(entries[0].status, MinedBlockStatus::RewardMissing);
+    assert!(!entries[0].is_settled(tip_height));
+}
+
+#[test]
+fn late_coinbase_clears_missing_reward_before_maturity() {
+    let mut entry = create_entry(100, Some("aaaa"), MinerKind::Cpu);
+    entry.coinbase_tx_id = None;
+    entry.status = MinedBlockStatus::RewardMissing;
+    let mut entries = vec![entry];
+    let coinbases = vec![create_coinbase(100, TransactionStatus::CoinbaseConfirmed)];
+
+    reconcile_entries(&mut entries, &[(100, "aaaa".to_string())], &coinbases, 200);
+
+    assert_eq!(entries[0].status, MinedBlockStatus::Confirmed);
+}
+
+#[test]
+fn missing_reward_is_settled_after_maturity_window() {
+    let mut entry = create_entry(100, Some("aaaa"), MinerKind::Cpu);
+    entry.status = MinedBlockStatus::RewardMissing;
+    let matured = 100 + COINBASE_MATURITY_BLOCKS;
+    let mut entries = vec![entry];
+
+    assert!(!entries[0].is_settled(matured - 1));
+    assert!(entries[0].is_settled(matured));
+
+    let coinbases = vec![create_coinbase(100, TransactionStatus::CoinbaseConfirmed)];
+    let changed = reconcile_entries(
+        &mut entries,
+        &[(100, "aaaa".to_string())],
+        &coinbases,
+        matured,
+    );
+
+    assert_eq!(changed, 0);
+    assert_eq!(entries[0].status, MinedBlockStatus::RewardMissing);
+}
+
+// ==================== Summary ====================
+
+#[test]
+fn summary_counts_statuses_and_confirmed_rewards() {
+    let mut confirmed = create_entry(100, None, MinerKind::Cpu);
+    confirmed.status = MinedBlockStatus::Confirmed;
+    let mut orphaned = create_entry(101, None, MinerKind::Gpu);
+    orphaned.status = MinedBlockStatus::Orphaned;
+    let pending = create_entry(102, None, MinerKind::Unknown);
+
+    let summary = summarize(&[confirmed, orphaned, pending]);
+
+    assert_eq!(summary.total_blocks, 3);
+    assert_eq!(summary.confirmed, 1);
+    assert_eq!(summary.orphaned, 1);
+    assert_eq!(summary.pending, 1);
+    assert_eq!(summary.cpu_blocks, 1);
+    assert_eq!(summary.gpu_blocks, 1);
+    assert_eq!(summary.confirmed_reward, MicroMinotari(13_000_000_000));
+}
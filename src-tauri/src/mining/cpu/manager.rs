@@ -51,6 +51,7 @@ use crate::{
         pools::{PoolManagerInterfaceTrait, cpu_pool_manager::CpuPoolManager},
     },
     node::node_adapter::BaseNodeStatus,
+    port_registry::{ServicePort, assign_port},
     process_adapter::ProcessAdapter,
     process_watcher::{ProcessWatcher, ProcessWatcherStats},
     systemtray_manager::{SystemTrayEvents, SystemTrayManager},
@@ -225,6 +226,7 @@ impl CpuManager {
             }
 
             let binary = crate::binaries::Binaries::Xmrig;
+            self.process_watcher.adapter.http_api_port = assign_port(ServicePort::XmrigApi).await?;
 
             let cpu_usage_percentage = ConfigMining::content()
                 .await
@@ -24,7 +24,7 @@ use anyhow::Error;
 use async_trait::async_trait;
 use log::{info, warn};
 use serde::Deserialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tari_shutdown::Shutdown;
@@ -33,11 +33,13 @@ use uuid::Uuid;
 
 use crate::mining::CpuConnectionType;
 use crate::mining::cpu::{CpuMinerConnectionStatus, CpuMinerStatus};
-use crate::port_allocator::PortAllocator;
+use crate::port_registry::ServicePort;
 use crate::process_adapter::{
     HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec,
     StatusMonitor,
 };
+use crate::process_restart_policy::RestartPolicy;
+use crate::requests::connectivity;
 use crate::setup::setup_manager::SetupManager;
 use crate::{LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 
@@ -53,13 +55,13 @@ pub struct XmrigAdapter {
 
 impl XmrigAdapter {
     pub fn new(summary_broadcast: Sender<CpuMinerStatus>) -> Self {
-        let http_api_port = PortAllocator::new().assign_port_with_fallback();
         let http_api_token = Uuid::new_v4().to_string();
         Self {
             connection_type: CpuConnectionType::default(),
             address: String::new(),
             http_api_token: http_api_token.clone(),
-            http_api_port,
+            // Taken from the port registry before each start
+            http_api_port: 0,
             cpu_threads: None,
             extra_options: Vec::new(),
             summary_broadcast,
@@ -107,6 +109,9 @@ impl ProcessAdapter for XmrigAdapter {
                 extra_args.push(format!("--url={}", pool_url));
                 extra_args.push("--user".to_string());
                 extra_args.push(extended_user_address);
+                if let Some(proxy) = connectivity::miner_socks_proxy()? {
+                    extra_args.push(format!("--proxy={proxy}"));
+                }
                 extra_args
             }
         };
@@ -161,6 +166,7 @@ impl ProcessAdapter for XmrigAdapter {
                     args,
                     data_dir,
                     pid_file_name: self.pid_file_name().to_string(),
+                    log_dir,
                     name: self.name().to_string(),
                 },
             },
@@ -179,6 +185,18 @@ impl ProcessAdapter for XmrigAdapter {
     fn pid_file_name(&self) -> &str {
         "xmrig_pid"
     }
+
+    fn log_file_path(&self, log_folder: &Path) -> Option<PathBuf> {
+        Some(log_folder.join("xmrig").join("xmrig.log"))
+    }
+
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        vec![(ServicePort::XmrigApi, self.http_api_port)]
+    }
+
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::for_miner()
+    }
 }
 
 // This is a flag to indicate if the fallback to solo mining has been triggered
@@ -22,10 +22,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    configs::pools::gpu_pools::GpuPool,
-    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
-};
+use crate::{binaries::binaries_list::BinaryPlatformAssets, configs::pools::gpu_pools::GpuPool};
 
 #[derive(Debug, Serialize, Clone, Default)]
 pub(crate) struct GpuMinerStatus {
@@ -73,11 +70,11 @@ impl GpuMinerType {
         }
     }
 
-    pub fn supported_platforms(&self) -> Vec<CurrentOperatingSystem> {
+    pub fn supported_platforms(&self) -> Vec<BinaryPlatformAssets> {
         match self {
             GpuMinerType::LolMiner => vec![
-                CurrentOperatingSystem::Windows,
-                CurrentOperatingSystem::Linux,
+                BinaryPlatformAssets::WindowsX64,
+                BinaryPlatformAssets::LinuxX64,
             ],
         }
     }
@@ -99,8 +96,8 @@ impl GpuMinerType {
     }
 
     pub fn is_supported_on_current_platform(&self) -> bool {
-        let current_os = PlatformUtils::detect_current_os();
-        self.supported_platforms().contains(&current_os)
+        self.supported_platforms()
+            .contains(&BinaryPlatformAssets::current())
     }
     pub fn is_pool_mining_supported(&self) -> bool {
         self.get_expected_features()
@@ -29,9 +29,11 @@ use crate::{
         GpuConnectionType,
         gpu::miners::lolminer::{LolMinerGpuMiner, LolMinerGpuMinerStatusMonitor},
     },
+    port_registry::ServicePort,
     process_adapter::{
         HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, StatusMonitor,
     },
+    process_restart_policy::RestartPolicy,
 };
 
 pub trait GpuMinerInterfaceTrait: Send + Sync {
@@ -45,6 +47,7 @@ pub trait GpuMinerInterfaceTrait: Send + Sync {
         &mut self,
         connection_type: GpuConnectionType,
     ) -> Result<(), anyhow::Error>;
+    async fn load_api_port(&mut self, api_port: u16) -> Result<(), anyhow::Error>;
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error>;
     async fn load_excluded_devices(
         &mut self,
@@ -88,6 +91,12 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
         }
     }
 
+    async fn load_api_port(&mut self, api_port: u16) -> Result<(), anyhow::Error> {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => miner.load_api_port(api_port).await,
+        }
+    }
+
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.detect_devices().await,
@@ -165,4 +174,19 @@ impl ProcessAdapter for GpuMinerInterface {
             GpuMinerInterface::LolMiner(miner) => miner.pid_file_name(),
         }
     }
+    fn log_file_path(&self, log_folder: &std::path::Path) -> Option<std::path::PathBuf> {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => miner.log_file_path(log_folder),
+        }
+    }
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => miner.service_ports(),
+        }
+    }
+    fn restart_policy(&self) -> RestartPolicy {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => miner.restart_policy(),
+        }
+    }
 }
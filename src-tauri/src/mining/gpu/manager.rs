@@ -55,6 +55,7 @@ use crate::{
         pools::{PoolManagerInterfaceTrait, gpu_pool_manager::GpuPoolManager},
     },
     node::node_adapter::BaseNodeStatus,
+    port_registry::{ServicePort, assign_port},
     process_adapter::ProcessAdapter,
     process_watcher::{ProcessWatcher, ProcessWatcherStats},
     systemtray_manager::{SystemTrayEvents, SystemTrayManager},
@@ -380,6 +381,10 @@ impl GpuManager {
                     .adapter
                     .load_excluded_devices(excluded_devices)
                     .await?;
+                self.process_watcher
+                    .adapter
+                    .load_api_port(assign_port(ServicePort::LolMinerApi).await?)
+                    .await?;
 
                 info!(target: LOG_TARGET_APP_LOGIC, "Starting gpu miner process watcher with binary: {:?}", binary);
 
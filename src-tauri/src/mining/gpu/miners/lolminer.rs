@@ -51,12 +51,14 @@ use crate::{
             miners::GpuCommonInformation,
         },
     },
-    port_allocator::PortAllocator,
+    port_registry::ServicePort,
     process_adapter::{
         HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec,
         StatusMonitor,
     },
+    process_restart_policy::RestartPolicy,
     process_utils::launch_child_process,
+    requests::connectivity,
 };
 
 #[derive(Default)]
@@ -68,6 +70,8 @@ pub struct LolMinerGpuMiner {
     pub gpu_status_sender: Sender<GpuMinerStatus>,
     pub gpu_devices: Vec<GpuCommonInformation>,
     pub excluded_devices: Vec<u32>,
+    /// Taken from the port registry before each start
+    pub api_port: u16,
 }
 
 impl LolMinerGpuMiner {
@@ -80,6 +84,7 @@ impl LolMinerGpuMiner {
             gpu_status_sender,
             gpu_devices: vec![],
             excluded_devices: vec![],
+            api_port: 0,
         }
     }
 }
@@ -116,6 +121,11 @@ impl GpuMinerInterfaceTrait for LolMinerGpuMiner {
         Ok(())
     }
 
+    async fn load_api_port(&mut self, api_port: u16) -> Result<(), anyhow::Error> {
+        self.api_port = api_port;
+        Ok(())
+    }
+
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
         let config_path =
             dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Failed to get config directory"))?;
@@ -129,7 +139,7 @@ impl GpuMinerInterfaceTrait for LolMinerGpuMiner {
         let args = vec!["--list-devices".to_string()];
 
         crate::download_utils::set_permissions(&gpu_miner_binary).await?;
-        let result = launch_child_process(&gpu_miner_binary, &config_dir, None, &args, true)?;
+        let result = launch_child_process(&gpu_miner_binary, &config_dir, None, &args, None, None)?;
 
         let mut gpu_devices: Vec<GpuCommonInformation> = vec![];
 
@@ -181,8 +191,14 @@ impl ProcessAdapter for LolMinerGpuMiner {
         binary_version_path: std::path::PathBuf,
         _is_first_start: bool,
     ) -> Result<(Self::ProcessInstance, Self::StatusMonitor), anyhow::Error> {
+        // lolMiner has no proxy support, so its pool connection would bypass Tor
+        if connectivity::routes_through_tor() {
+            return Err(anyhow::anyhow!(
+                "Lolminer can't connect to its pool through Tor, GPU mining is unavailable while traffic is routed through Tor"
+            ));
+        }
         let inner_shutdown = Shutdown::new();
-        let api_port = PortAllocator::new().assign_port_with_fallback();
+        let api_port = self.api_port;
 
         let mut args: Vec<String> = vec![
             "--algo".to_string(),
@@ -277,6 +293,7 @@ impl ProcessAdapter for LolMinerGpuMiner {
                     args,
                     data_dir: base_folder,
                     pid_file_name: self.pid_file_name().to_string(),
+                    log_dir: log_folder,
                     name: self.name().to_string(),
                 },
                 handle: None,
@@ -295,6 +312,18 @@ impl ProcessAdapter for LolMinerGpuMiner {
     fn pid_file_name(&self) -> &str {
         "lolminer_pid"
     }
+
+    fn log_file_path(&self, log_folder: &std::path::Path) -> Option<std::path::PathBuf> {
+        Some(log_folder.join("lolminer.txt"))
+    }
+
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        vec![(ServicePort::LolMinerApi, self.api_port)]
+    }
+
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::for_miner()
+    }
 }
 
 #[derive(Clone)]
@@ -22,6 +22,9 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod block_ledger;
+#[cfg(test)]
+mod block_ledger_test;
 pub mod cpu;
 pub mod gpu;
 pub mod pools;
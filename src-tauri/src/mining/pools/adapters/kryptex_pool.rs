@@ -71,7 +71,7 @@ impl PoolApiAdapter for KryptexPoolAdapter {
             .stats_url
             .replace("%TARI_ADDRESS%", &address.to_string());
         info!(target: LOG_TARGET_STATUSES, "Requesting Kryptex pool status from: {url}");
-        let pool_status_response = HttpClient::with_retries(3).send_get_request(&url).await?;
+        let pool_status_response = HttpClient::with_retries(3)?.send_get_request(&url).await?;
         let response_text = pool_status_response.text().await?;
         let pool_status = self.convert_api_data(response_text.as_str())?;
         Ok(pool_status)
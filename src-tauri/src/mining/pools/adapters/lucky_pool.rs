@@ -168,7 +168,7 @@ impl PoolApiAdapter for LuckyPoolAdapter {
             .stats_url
             .replace("%TARI_ADDRESS%", &address.to_string());
         info!(target: LOG_TARGET_STATUSES, "Requesting lucky pool status from: {url}");
-        let pool_status_response = HttpClient::with_retries(3).send_get_request(&url).await?;
+        let pool_status_response = HttpClient::with_retries(3)?.send_get_request(&url).await?;
         let response_text = pool_status_response.text().await?;
         let pool_status = self.convert_api_data(response_text.as_str())?;
         Ok(pool_status)
@@ -130,6 +130,15 @@ impl GpuPoolManager {
                     .await;
 
                 let default_pool_content = ConfigPools::content().await.current_gpu_pool().clone();
+                Notifications::notify(NotificationEvent::PoolFailover {
+                    from_pool: current_pool_content.pool_name.clone(),
+                    to_pool: default_pool_content.pool_name.clone(),
+                    reason: format!(
+                        "{} does not support {miner:?}",
+                        current_pool_content.pool_name
+                    ),
+                })
+                .await;
 
                 INSTANCE
                     .pool_status_manager
@@ -40,6 +40,7 @@ use crate::{
     commands::{SignWsDataResponse, sign_ws_data},
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
     mining::cpu::CpuMinerStatus,
+    requests::connectivity,
     tasks_tracker::TasksTrackers,
 };
 static INTERVAL_DURATION: std::time::Duration = Duration::from_secs(15);
@@ -137,9 +138,10 @@ impl MiningStatusManager {
                                 _ = interval.tick() => {
                                 if let (Some(jwt), Some(app_version))= (jwt_token, app_version_option)
                                     && let Some(message) = MiningStatusManager::assemble_mining_status(cpu_miner_status_watch_rx.clone(),gpu_latest_miner_stats.clone(),node_latest_status.clone(),app_id.clone(),app_version.clone(),jwt.clone(),).await {
-                                        let client = reqwest::Client::new();
                                         let url = format!("{base_url}/miner/mining-status");
-                                        if let Ok(response) = client.post(url).header(AUTHORIZATION, &format!("Bearer {jwt}")).json(&message).send().await.inspect_err(|e|{
+                                        if let Ok(client) = connectivity::client().inspect_err(|e| {
+                                            error!("error at building mining status client {e}");
+                                        }) && let Ok(response) = client.post(url).header(AUTHORIZATION, &format!("Bearer {jwt}")).json(&message).send().await.inspect_err(|e|{
                                             error!("error at sending mining status {e}");
                                         }){
                                             let status = response.status();
@@ -20,10 +20,11 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use crate::LOG_TARGET_STATUSES;
+use crate::port_registry::ServicePort;
 use crate::process_adapter::{
     HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec, StatusMonitor,
 };
@@ -162,6 +163,7 @@ impl ProcessAdapter for MergeMiningProxyAdapter {
                     args,
                     data_dir,
                     pid_file_name: self.pid_file_name().to_string(),
+                    log_dir,
                     name: self.name().to_string(),
                 },
             },
@@ -178,6 +180,17 @@ impl ProcessAdapter for MergeMiningProxyAdapter {
     fn pid_file_name(&self) -> &str {
         "mmproxy_pid"
     }
+
+    fn log_file_path(&self, log_folder: &Path) -> Option<PathBuf> {
+        Some(log_folder.join("proxy").join("log").join("proxy.log"))
+    }
+
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        self.config
+            .as_ref()
+            .map(|config| vec![(ServicePort::MmProxy, config.port)])
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Clone)]
@@ -31,7 +31,7 @@ use tokio::sync::RwLock;
 use tokio::time::sleep;
 
 use crate::mm_proxy_adapter::{MergeMiningProxyAdapter, MergeMiningProxyConfig};
-use crate::port_allocator::PortAllocator;
+use crate::port_registry::{ServicePort, assign_port};
 use crate::process_adapter::{HealthStatus, StatusMonitor};
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
@@ -109,7 +109,7 @@ impl MmProxyManager {
             tari_address: config.tari_address.clone(),
             base_node_grpc_address: config.base_node_grpc_address.clone(),
             coinbase_extra: config.coinbase_extra.clone(),
-            port: PortAllocator::new().assign_port_with_fallback(),
+            port: assign_port(ServicePort::MmProxy).await?,
             monero_nodes: config.monero_nodes.clone(),
             use_monero_fail: config.use_monero_fail,
         };
@@ -169,7 +169,6 @@ impl MmProxyManager {
             None => Err(anyhow!("MM proxy not started")),
         }
     }
-    #[allow(dead_code)]
     pub async fn stop(&self) -> Result<(), anyhow::Error> {
         let mut process_watcher = self.watcher.write().await;
         process_watcher.stop().await?;
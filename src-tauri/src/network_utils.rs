@@ -20,7 +20,7 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::requests::utils::create_user_agent;
+use crate::requests::{connectivity, utils::create_user_agent};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Deserialize;
 use tari_common::configuration::Network;
@@ -33,7 +33,7 @@ fn create_client() -> Result<reqwest::Client, anyhow::Error> {
         HeaderValue::from_str(&agent).unwrap_or(HeaderValue::from_static("tari-universe")),
     );
 
-    let client = reqwest::Client::builder()
+    let client = connectivity::client_builder()?
         .user_agent(agent)
         .default_headers(headers)
         .build()?;
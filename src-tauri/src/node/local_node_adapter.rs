@@ -26,8 +26,10 @@ use crate::node::node_adapter::{
     BaseNodeStatus, NodeAdapter, NodeAdapterService, NodeStatusMonitor,
 };
 use crate::node::node_manager::NodeType;
-use crate::port_allocator::PortAllocator;
+use crate::port_registry::{ServicePort, assign_port};
 use crate::process_adapter::{ProcessAdapter, ProcessInstance, ProcessStartupSpec};
+use crate::process_restart_policy::RestartPolicy;
+use crate::tor_adapter::TorControlEndpoint;
 use crate::utils::file_utils::convert_to_string;
 use crate::utils::logging_utils::setup_logging;
 #[cfg(target_os = "windows")]
@@ -36,7 +38,9 @@ use async_trait::async_trait;
 use log::{info, warn};
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
@@ -46,6 +50,10 @@ use tari_shutdown::Shutdown;
 use tari_transaction_components::consensus::ConsensusManager;
 use tokio::sync::watch;
 
+/// Config override for `base_node.p2p.transport.tor.control_auth`. Tari applications read
+/// `TARI_` environment variables as overrides, with `__` between the sections of the key.
+const TOR_CONTROL_AUTH_ENV: &str = "TARI_BASE_NODE__P2P__TRANSPORT__TOR__CONTROL_AUTH";
+
 #[derive(Serialize, Deserialize, Default)]
 struct MinotariNodeMigrationInfo {
     version: u32,
@@ -71,6 +79,14 @@ impl MinotariNodeMigrationInfo {
     }
 }
 
+/// Formats an address the way the node's transport config expects it
+fn to_multiaddr(address: SocketAddr) -> String {
+    match address {
+        SocketAddr::V4(address) => format!("/ip4/{}/tcp/{}", address.ip(), address.port()),
+        SocketAddr::V6(address) => format!("/ip6/{}/tcp/{}", address.ip(), address.port()),
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct LocalNodeAdapter {
     pub(crate) grpc_address: Option<(String, u16)>,
@@ -78,7 +94,7 @@ pub(crate) struct LocalNodeAdapter {
     pub(crate) use_tor: bool,
     pub(crate) tcp_listener_port: u16,
     pub(crate) use_pruned_mode: bool,
-    pub(crate) tor_control_port: Option<u16>,
+    pub(crate) tor_control: Option<TorControlEndpoint>,
     required_initial_peers: u32,
     pub(crate) ab_test_group: ABTestSelector,
     pub(crate) http_api_port: u16,
@@ -90,24 +106,29 @@ impl LocalNodeAdapter {
         status_broadcast: watch::Sender<BaseNodeStatus>,
         consensus_manager: ConsensusManager,
     ) -> Self {
-        let grpc_port = PortAllocator::new().assign_port_with_fallback();
-        let tcp_listener_port = PortAllocator::new().assign_port_with_fallback();
-        let http_api_port = PortAllocator::new().assign_port_with_fallback();
-
+        // Ports are taken from the port registry before each start, see `assign_ports`
         Self {
-            grpc_address: Some(("127.0.0.1".to_string(), grpc_port)),
+            grpc_address: Some(("127.0.0.1".to_string(), 0)),
             status_broadcast,
-            tcp_listener_port,
+            tcp_listener_port: 0,
             use_pruned_mode: false,
             required_initial_peers: 3,
             use_tor: false,
-            tor_control_port: None,
+            tor_control: None,
             ab_test_group: ABTestSelector::GroupA,
-            http_api_port,
+            http_api_port: 0,
             consensus_manager,
         }
     }
 
+    pub async fn assign_ports(&mut self) -> Result<(), anyhow::Error> {
+        let grpc_port = assign_port(ServicePort::NodeGrpc).await?;
+        self.grpc_address = Some(("127.0.0.1".to_string(), grpc_port));
+        self.tcp_listener_port = assign_port(ServicePort::NodeP2p).await?;
+        self.http_api_port = assign_port(ServicePort::NodeHttpApi).await?;
+        Ok(())
+    }
+
     pub fn get_grpc_address(&self) -> Option<(String, u16)> {
         self.grpc_address.clone()
     }
@@ -166,8 +187,8 @@ impl NodeAdapter for LocalNodeAdapter {
         self.use_tor = use_tor;
     }
 
-    fn set_tor_control_port(&mut self, tor_control_port: Option<u16>) {
-        self.tor_control_port = tor_control_port;
+    fn set_tor_control(&mut self, tor_control: Option<TorControlEndpoint>) {
+        self.tor_control = tor_control;
     }
 
     fn set_ab_group(&mut self, ab_test_group: ABTestSelector) {
@@ -250,6 +271,7 @@ impl ProcessAdapter for LocalNodeAdapter {
             .get_grpc_address()
             .expect("Local node grpc address not defined");
 
+        let mut envs = HashMap::new();
         let mut args: Vec<String> = vec![
             "-b".to_string(),
             working_dir_string,
@@ -289,7 +311,12 @@ impl ProcessAdapter for LocalNodeAdapter {
         if self.use_tor {
             args.push("-p".to_string());
             args.push("base_node.p2p.transport.type=tor".to_string());
-            if !cfg!(target_os = "macos") {
+            let uses_external_tor = self
+                .tor_control
+                .as_ref()
+                .is_some_and(TorControlEndpoint::is_external);
+            // A system Tor replaces libtor on macOS
+            if !cfg!(target_os = "macos") || uses_external_tor {
                 args.push("-p".to_string());
                 args.push("use_libtor=false".to_string());
             }
@@ -300,15 +327,31 @@ impl ProcessAdapter for LocalNodeAdapter {
             ));
             args.push("-p".to_string());
             args.push("base_node.p2p.transport.tor.proxy_bypass_for_outbound_tcp=true".to_string());
-            if let Some(mut tor_control_port) = self.tor_control_port {
+            if let Some(tor_control) = &self.tor_control {
+                let mut control_address = tor_control.control_address;
                 // macos uses libtor, so will be 9051
-                if cfg!(target_os = "macos") {
-                    tor_control_port = 9051;
+                if cfg!(target_os = "macos") && !uses_external_tor {
+                    control_address.set_port(9051);
                 }
                 args.push("-p".to_string());
                 args.push(format!(
-                    "base_node.p2p.transport.tor.control_address=/ip4/127.0.0.1/tcp/{tor_control_port}"
+                    "base_node.p2p.transport.tor.control_address={}",
+                    to_multiaddr(control_address)
                 ));
+                // Passed in the environment, as other local users can read the command line
+                if let Some(password) = &tor_control.control_password {
+                    envs.insert(
+                        TOR_CONTROL_AUTH_ENV.to_string(),
+                        format!("password={password}"),
+                    );
+                }
+                if let Some(socks_address) = tor_control.socks_address {
+                    args.push("-p".to_string());
+                    args.push(format!(
+                        "base_node.p2p.transport.tor.socks_address_override={}",
+                        to_multiaddr(socks_address)
+                    ));
+                }
             }
             let network = Network::get_current_or_user_setting_or_default();
             args.push("-p".to_string());
@@ -372,10 +415,11 @@ impl ProcessAdapter for LocalNodeAdapter {
                 handle: None,
                 startup_spec: ProcessStartupSpec {
                     file_path: binary_version_path,
-                    envs: None,
+                    envs: (!envs.is_empty()).then_some(envs),
                     args,
                     data_dir: data_dir.clone(),
                     pid_file_name: self.pid_file_name().to_string(),
+                    log_dir,
                     name: self.name().to_string(),
                 },
             },
@@ -401,4 +445,28 @@ impl ProcessAdapter for LocalNodeAdapter {
     fn pid_file_name(&self) -> &str {
         "node_pid"
     }
+
+    fn log_file_path(&self, log_folder: &Path) -> Option<PathBuf> {
+        Some(
+            log_folder
+                .join("base_node")
+                .join("log")
+                .join("base_layer.log"),
+        )
+    }
+
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        let mut ports = vec![
+            (ServicePort::NodeP2p, self.tcp_listener_port),
+            (ServicePort::NodeHttpApi, self.http_api_port),
+        ];
+        if let Some((_, grpc_port)) = self.grpc_address {
+            ports.push((ServicePort::NodeGrpc, grpc_port));
+        }
+        ports
+    }
+
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::for_node()
+    }
 }
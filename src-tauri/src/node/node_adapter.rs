@@ -24,6 +24,7 @@ use crate::ab_test_selector::ABTestSelector;
 use crate::node::node_manager::NodeType;
 use crate::node::utils::SyncProgressInfo;
 use crate::process_adapter::{HandleUnhealthyResult, HealthStatus, StatusMonitor};
+use crate::tor_adapter::TorControlEndpoint;
 use crate::{LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 use anyhow::{Error, anyhow};
 use async_trait::async_trait;
@@ -31,7 +32,7 @@ use chrono::{NaiveDateTime, TimeZone, Utc};
 use log::{error, info, warn};
 use minotari_node_grpc_client::BaseNodeGrpcClient;
 use minotari_node_grpc_client::grpc::{
-    Empty, GetNetworkStateRequest, SyncProgressResponse, SyncState,
+    Empty, GetBlocksRequest, GetNetworkStateRequest, SyncProgressResponse, SyncState,
 };
 use minotari_node_wallet_client::BaseNodeWalletClient;
 use serde::Serialize;
@@ -67,7 +68,7 @@ pub trait NodeAdapter {
     ) -> Result<(Option<RistrettoPublicKey>, String), anyhow::Error>;
     fn get_http_api_url(&self) -> String;
     fn use_tor(&mut self, use_tor: bool);
-    fn set_tor_control_port(&mut self, tor_control_port: Option<u16>);
+    fn set_tor_control(&mut self, tor_control: Option<TorControlEndpoint>);
     fn set_ab_group(&mut self, ab_group: ABTestSelector);
 }
 
@@ -165,6 +166,46 @@ impl NodeAdapterService {
         Ok(blocks)
     }
 
+    /// Reads the block at `height` with the extra data and value of each of its coinbase outputs
+    pub async fn get_block_coinbases(&self, height: u64) -> Result<Option<BlockCoinbases>, Error> {
+        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let mut stream = client
+            .get_blocks(GetBlocksRequest {
+                heights: vec![height],
+            })
+            .await?
+            .into_inner();
+        let Some(block) = stream
+            .message()
+            .await?
+            .and_then(|historical| historical.block)
+        else {
+            return Ok(None);
+        };
+        let header = block
+            .header
+            .ok_or_else(|| anyhow!("Block #{height} has no header"))?;
+        let coinbases = block
+            .body
+            .map(|body| body.outputs)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|output| {
+                let features = output.features?;
+                (features.output_type == COINBASE_OUTPUT_TYPE).then(|| BlockCoinbase {
+                    extra: features.coinbase_extra,
+                    value: MicroMinotari(output.minimum_value_promise),
+                })
+            })
+            .collect();
+
+        Ok(Some(BlockCoinbases {
+            hash: header.hash.to_hex(),
+            timestamp: header.timestamp,
+            coinbases,
+        }))
+    }
+
     pub async fn get_identity(&self) -> Result<NodeIdentity, Error> {
         let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
         let id = client.identify(Empty {}).await?;
@@ -673,6 +714,24 @@ impl std::fmt::Display for ReadinessStatus {
     }
 }
 
+/// `OutputType::Coinbase` as encoded in the node's gRPC output features
+const COINBASE_OUTPUT_TYPE: u32 = 1;
+
+#[derive(Clone, Debug)]
+pub(crate) struct BlockCoinbase {
+    /// Data the miner embedded in the coinbase, the merge mining proxy puts our unique id here
+    pub extra: Vec<u8>,
+    /// Coinbase values are revealed, so the minimum value promise is the exact amount
+    pub value: MicroMinotari,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct BlockCoinbases {
+    pub hash: String,
+    pub timestamp: u64,
+    pub coinbases: Vec<BlockCoinbase>,
+}
+
 #[derive(Clone, Copy, Debug, Serialize)]
 pub(crate) struct BaseNodeStatus {
     pub block_reward: MicroMinotari,
@@ -50,6 +50,7 @@ use crate::process_watcher::ProcessWatcherStats;
 use crate::progress_trackers::progress_stepper::IncrementalProgressTracker;
 use crate::setup::setup_manager::SetupManager;
 use crate::tasks_tracker::TasksTrackers;
+use crate::tor_adapter::TorControlEndpoint;
 use crate::{BaseNodeStatus, LOG_TARGET_APP_LOGIC, LocalNodeAdapter, RemoteNodeAdapter};
 
 #[derive(Debug, thiserror::Error)]
@@ -156,19 +157,22 @@ impl NodeManager {
         config_path: PathBuf,
         log_path: PathBuf,
         use_tor: bool,
-        tor_control_port: Option<u16>,
+        tor_control: Option<TorControlEndpoint>,
         remote_grpc_address: Option<String>,
     ) -> Result<(), NodeManagerError> {
         let shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
         let task_tracker = TasksTrackers::current().node_phase.get_task_tracker().await;
 
         if self.is_local().await {
+            if let Some(local_node_watcher) = self.local_node_watcher.write().await.as_mut() {
+                local_node_watcher.adapter.assign_ports().await?;
+            }
             self.configure_adapter(
                 self.local_node_watcher.clone(),
                 self.is_local_current().await,
                 None, // always 127.0.0.1
                 use_tor,
-                tor_control_port,
+                tor_control,
             )
             .await?;
             start_watcher(
@@ -224,7 +228,7 @@ impl NodeManager {
         is_current: bool,
         remote_grpc_address: Option<String>,
         use_tor: bool,
-        tor_control_port: Option<u16>,
+        tor_control: Option<TorControlEndpoint>,
     ) -> Result<(), anyhow::Error>
     where
         T: NodeAdapter + ProcessAdapter + Send + Sync + Clone + 'static,
@@ -232,7 +236,7 @@ impl NodeManager {
         let mut node_watcher = node_watcher.write().await;
         if let Some(node_watcher) = node_watcher.as_mut() {
             node_watcher.adapter.use_tor(use_tor);
-            node_watcher.adapter.set_tor_control_port(tor_control_port);
+            node_watcher.adapter.set_tor_control(tor_control);
             let ab_group = *ConfigCore::content().await.ab_group();
             node_watcher.adapter.set_ab_group(ab_group);
 
@@ -483,6 +487,25 @@ impl NodeManager {
         }
     }
 
+    /// Stops the local node. The remote node has no process to stop.
+    pub async fn stop(&self) -> Result<(), anyhow::Error> {
+        if let Some(local_node_watcher) = self.local_node_watcher.write().await.as_mut() {
+            local_node_watcher.stop().await?;
+        }
+        Ok(())
+    }
+
+    /// Restarts the local node when its p2p traffic goes through Tor, so it reconnects over
+    /// the new Tor route. The remote node is reached directly.
+    pub async fn on_tor_reconfigured(&self) {
+        if let Some(local_node_watcher) = self.local_node_watcher.read().await.as_ref()
+            && local_node_watcher.adapter.use_tor
+        {
+            info!(target: LOG_TARGET_APP_LOGIC, "Tor route changed, restarting the local node");
+            local_node_watcher.request_restart();
+        }
+    }
+
     pub async fn get_grpc_port(&self) -> Result<u16, anyhow::Error> {
         let current_adapter = self.current_adapter.read().await;
         let grpc_address = current_adapter.get_grpc_address();
@@ -35,6 +35,8 @@ use crate::{
         node_manager::NodeType,
     },
     process_adapter::{ProcessAdapter, ProcessInstanceTrait},
+    process_restart_policy::RestartPolicy,
+    tor_adapter::TorControlEndpoint,
 };
 use anyhow::Error;
 use std::{
@@ -144,8 +146,8 @@ impl NodeAdapter for RemoteNodeAdapter {
         self.ab_group = ab_group;
     }
 
-    fn set_tor_control_port(&mut self, _tor_control_port: Option<u16>) {
-        log::info!(target: LOG_TARGET_APP_LOGIC, "RemoteNodeAdapter doesn't use tor_control_port");
+    fn set_tor_control(&mut self, _tor_control: Option<TorControlEndpoint>) {
+        log::info!(target: LOG_TARGET_APP_LOGIC, "RemoteNodeAdapter doesn't use tor_control");
     }
 
     async fn get_connection_details(
@@ -202,6 +204,10 @@ impl ProcessAdapter for RemoteNodeAdapter {
     fn pid_file_name(&self) -> &str {
         "remote_node_pid"
     }
+
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::for_node()
+    }
 }
 
 pub struct NullProcessInstance {
@@ -0,0 +1,201 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Rendering of notifications and their delivery to webhooks, email, ntfy and Matrix.
+
+use std::time::Duration;
+
+use anyhow::{Error, bail};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use serde_json::{Value, json};
+use sha2::Sha256;
+
+use crate::configs::config_notifications::{
+    NotificationChannel, NotificationEventType, NotificationTransport,
+};
+use crate::notifications::events::NotificationEvent;
+use crate::notifications::smtp::{self, SmtpMessage, SmtpServer};
+use crate::notifications::template;
+use crate::requests::connectivity;
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+pub const SIGNATURE_HEADER: &str = "X-Universe-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Universe-Timestamp";
+pub const EVENT_HEADER: &str = "X-Universe-Event";
+pub const DELIVERY_HEADER: &str = "X-Universe-Delivery";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A notification rendered for one channel. The id stays the same across retries so receivers
+/// can drop duplicates.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NotificationMessage {
+    pub id: String,
+    pub event: NotificationEventType,
+    pub title: String,
+    pub body: String,
+    pub timestamp: i64,
+    pub data: Value,
+}
+
+impl NotificationMessage {
+    pub fn render(channel: &NotificationChannel, event: &NotificationEvent) -> Self {
+        let timestamp = Utc::now().timestamp();
+        let mut fields = event.fields();
+        let event_type = event.event_type();
+        fields.insert("event", event_type.as_str().to_string());
+        fields.insert("timestamp", timestamp.to_string());
+        let title_template = channel
+            .title_template
+            .as_deref()
+            .filter(|template| !template.trim().is_empty())
+            .unwrap_or(event.default_title());
+        let body_template = channel
+            .body_template
+            .as_deref()
+            .filter(|template| !template.trim().is_empty())
+            .unwrap_or(event.default_body());
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            event: event_type,
+            title: template::render(title_template, &fields),
+            body: template::render(body_template, &fields),
+            timestamp,
+            data: serde_json::to_value(event).unwrap_or(Value::Null),
+        }
+    }
+}
+
+/// `sha256=<hex>` HMAC of `<timestamp>.<body>`, so a captured request can't be replayed later with
+/// a different timestamp
+pub fn sign_webhook(secret: &str, timestamp: i64, body: &[u8]) -> Result<String, Error> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())?;
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    Ok(format!(
+        "sha256={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+fn check_status(response: &reqwest::Response, channel: &str) -> Result<(), Error> {
+    if !response.status().is_success() {
+        bail!("{channel} responded with {}", response.status());
+    }
+    Ok(())
+}
+
+pub async fn deliver(
+    transport: &NotificationTransport,
+    message: &NotificationMessage,
+) -> Result<(), Error> {
+    let user_agent = format!("tari-universe/{}", env!("CARGO_PKG_VERSION"));
+    match transport {
+        NotificationTransport::Webhook { url, secret } => {
+            let body = serde_json::to_vec(message)?;
+            let mut request = connectivity::client()?
+                .post(url)
+                .timeout(DELIVERY_TIMEOUT)
+                .header("User-Agent", user_agent)
+                .header("Content-Type", "application/json")
+                .header(EVENT_HEADER, message.event.as_str())
+                .header(DELIVERY_HEADER, &message.id)
+                .header(TIMESTAMP_HEADER, message.timestamp.to_string());
+            if let Some(secret) = secret.as_deref().filter(|secret| !secret.is_empty()) {
+                request = request.header(
+                    SIGNATURE_HEADER,
+                    sign_webhook(secret, message.timestamp, &body)?,
+                );
+            }
+            let response = request.body(body).send().await?;
+            check_status(&response, "Webhook")
+        }
+        NotificationTransport::Ntfy {
+            server_url,
+            topic,
+            token,
+        } => {
+            let url = format!("{}/{topic}", server_url.trim_end_matches('/'));
+            let mut request = connectivity::client()?
+                .post(url)
+                .timeout(DELIVERY_TIMEOUT)
+                .header("User-Agent", user_agent)
+                .header("Title", message.title.as_str())
+                .header("Tags", message.event.as_str())
+                .body(message.body.clone());
+            if let Some(token) = token.as_deref().filter(|token| !token.is_empty()) {
+                request = request.bearer_auth(token);
+            }
+            let response = request.send().await?;
+            check_status(&response, "ntfy")
+        }
+        NotificationTransport::Matrix {
+            homeserver_url,
+            room_id,
+            access_token,
+        } => {
+            let url = format!(
+                "{}/_matrix/client/v3/rooms/{}/send/m.room.message/{}",
+                homeserver_url.trim_end_matches('/'),
+                urlencoding::encode(room_id),
+                message.id
+            );
+            let response = connectivity::client()?
+                .put(url)
+                .timeout(DELIVERY_TIMEOUT)
+                .header("User-Agent", user_agent)
+                .bearer_auth(access_token)
+                .json(&json!({
+                    "msgtype": "m.text",
+                    "body": format!("{}\n\n{}", message.title, message.body),
+                }))
+                .send()
+                .await?;
+            check_status(&response, "Matrix homeserver")
+        }
+        NotificationTransport::Email {
+            host,
+            port,
+            security,
+            username,
+            password,
+            from,
+            to,
+        } => {
+            let server = SmtpServer {
+                host,
+                port: *port,
+                security: *security,
+                credentials: username.as_deref().zip(password.as_deref()),
+            };
+            let email = SmtpMessage {
+                from,
+                to,
+                subject: &message.title,
+                body: &message.body,
+            };
+            smtp::send(&server, &email).await
+        }
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Unit tests for notification channels
+
+use hmac::{Hmac, Mac};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use wiremock::matchers::{body_json, body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::configs::config_notifications::{
+    NotificationChannel, NotificationEventType, NotificationTransport, SmtpSecurity,
+};
+
+use super::channels::{
+    DELIVERY_HEADER, EVENT_HEADER, NotificationMessage, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+    deliver, sign_webhook,
+};
+use super::events::NotificationEvent;
+
+fn channel(transport: NotificationTransport) -> NotificationChannel {
+    NotificationChannel {
+        id: "channel-1".to_string(),
+        name: "Test".to_string(),
+        enabled: true,
+        transport,
+        events: vec![NotificationEventType::BlockWon],
+        title_template: None,
+        body_template: None,
+    }
+}
+
+fn message() -> NotificationMessage {
+    NotificationMessage {
+        id: "delivery-1".to_string(),
+        event: NotificationEventType::BlockWon,
+        title: "Block 42 won".to_string(),
+        body: "Your rig won block 42.".to_string(),
+        timestamp: 1_700_000_000,
+        data: json!({ "block_height": 42, "reward": 1 }),
+    }
+}
+
+fn email(host: &str, port: u16, security: SmtpSecurity) -> NotificationTransport {
+    NotificationTransport::Email {
+        host: host.to_string(),
+        port,
+        security,
+        username: None,
+        password: None,
+        from: "rig@example.com".to_string(),
+        to: vec!["me@example.com".to_string()],
+    }
+}
+
+// ==================== Rendering ====================
+
+#[test]
+fn test_render_uses_default_templates() {
+    let event = NotificationEvent::BlockWon {
+        block_height: 42,
+        reward: 1_000_000,
+    };
+    let message = NotificationMessage::render(
+        &channel(NotificationTransport::Webhook {
+            url: "http://localhost".to_string(),
+            secret: None,
+        }),
+        &event,
+    );
+    assert_eq!(message.event, NotificationEventType::BlockWon);
+    assert_eq!(message.title, "Block 42 won");
+    assert_eq!(
+        message.data,
+        json!({ "block_height": 42, "reward": 1_000_000 })
+    );
+}
+
+#[test]
+fn test_render_uses_channel_templates_and_common_fields() {
+    let mut channel = channel(NotificationTransport::Webhook {
+        url: "http://localhost".to_string(),
+        secret: None,
+    });
+    channel.title_template = Some("[{{event}}] #{{block_height}}".to_string());
+    channel.body_template = Some("  ".to_string());
+    let event = NotificationEvent::BlockWon {
+        block_height: 42,
+        reward: 1_000_000,
+    };
+    let message = NotificationMessage::render(&channel, &event);
+    assert_eq!(message.title, "[block_won] #42");
+    // A blank template falls back to the built in one
+    assert_eq!(
+        message.body,
+        "Your rig won block 42 for a reward of 1.000000 XTM."
+    );
+}
+
+#[test]
+fn test_render_gives_each_message_its_own_id() {
+    let channel = channel(NotificationTransport::Webhook {
+        url: "http://localhost".to_string(),
+        secret: None,
+    });
+    let event = NotificationEvent::NodeOutOfSync { block_height: 1 };
+    assert_ne!(
+        NotificationMessage::render(&channel, &event).id,
+        NotificationMessage::render(&channel, &event).id
+    );
+}
+
+// ==================== Webhook ====================
+
+#[test]
+fn test_signature_covers_timestamp_and_body() {
+    let signature = sign_webhook("secret", 1_700_000_000, b"{}").unwrap();
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+    mac.update(b"1700000000.{}");
+    assert_eq!(
+        signature,
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    );
+    assert_ne!(
+        signature,
+        sign_webhook("secret", 1_700_000_001, b"{}").unwrap()
+    );
+    assert_ne!(
+        signature,
+        sign_webhook("other", 1_700_000_000, b"{}").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_posts_signed_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(header(EVENT_HEADER, "block_won"))
+        .and(header(DELIVERY_HEADER, "delivery-1"))
+        .and(header(TIMESTAMP_HEADER, "1700000000"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let transport = NotificationTransport::Webhook {
+        url: format!("{}/hook", server.uri()),
+        secret: Some("secret".to_string()),
+    };
+    deliver(&transport, &message()).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let request = &requests[0];
+    let body: Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["event"], "block_won");
+    assert_eq!(body["title"], "Block 42 won");
+    assert_eq!(body["data"]["block_height"], 42);
+    let signature = request
+        .headers
+        .get(SIGNATURE_HEADER)
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert_eq!(
+        signature,
+        sign_webhook("secret", 1_700_000_000, &request.body).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_without_secret_is_unsigned() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let transport = NotificationTransport::Webhook {
+        url: server.uri(),
+        secret: Some(String::new()),
+    };
+    deliver(&transport, &message()).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests[0].headers.get(SIGNATURE_HEADER).is_none());
+}
+
+#[tokio::test]
+async fn test_webhook_error_status_fails_delivery() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let transport = NotificationTransport::Webhook {
+        url: server.uri(),
+        secret: None,
+    };
+    let error = deliver(&transport, &message()).await.unwrap_err();
+    assert!(error.to_string().contains("500"), "{error}");
+}
+
+// ==================== ntfy ====================
+
+#[tokio::test]
+async fn test_ntfy_posts_body_to_topic() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rig-alerts"))
+        .and(header("Title", "Block 42 won"))
+        .and(header("Tags", "block_won"))
+        .and(header("Authorization", "Bearer tk_123"))
+        .and(body_string("Your rig won block 42."))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let transport = NotificationTransport::Ntfy {
+        server_url: format!("{}/", server.uri()),
+        topic: "rig-alerts".to_string(),
+        token: Some("tk_123".to_string()),
+    };
+    deliver(&transport, &message()).await.unwrap();
+}
+
+// ==================== Matrix ====================
+
+#[tokio::test]
+async fn test_matrix_puts_message_with_delivery_id_as_transaction() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(
+            "/_matrix/client/v3/rooms/%21room%3Aexample.org/send/m.room.message/delivery-1",
+        ))
+        .and(header("Authorization", "Bearer syt_token"))
+        .and(body_json(json!({
+            "msgtype": "m.text",
+            "body": "Block 42 won\n\nYour rig won block 42.",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "event_id": "$1" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let transport = NotificationTransport::Matrix {
+        homeserver_url: server.uri(),
+        room_id: "!room:example.org".to_string(),
+        access_token: "syt_token".to_string(),
+    };
+    deliver(&transport, &message()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_matrix_rejected_token_fails_delivery() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let transport = NotificationTransport::Matrix {
+        homeserver_url: server.uri(),
+        room_id: "!room:example.org".to_string(),
+        access_token: "expired".to_string(),
+    };
+    assert!(deliver(&transport, &message()).await.is_err());
+}
+
+// ==================== Validation ====================
+
+#[test]
+fn test_validate_accepts_complete_transports() {
+    let transports = [
+        NotificationTransport::Webhook {
+            url: "https://example.com/hook".to_string(),
+            secret: None,
+        },
+        NotificationTransport::Ntfy {
+            server_url: "https://ntfy.sh".to_string(),
+            topic: "rig".to_string(),
+            token: None,
+        },
+        NotificationTransport::Matrix {
+            homeserver_url: "https://matrix.org".to_string(),
+            room_id: "!abc:matrix.org".to_string(),
+            access_token: "token".to_string(),
+        },
+        email("smtp.example.com", 587, SmtpSecurity::StartTls),
+        email("localhost", 25, SmtpSecurity::None),
+    ];
+    for transport in transports {
+        assert!(transport.validate().is_ok(), "{transport:?}");
+    }
+}
+
+#[test]
+fn test_validate_rejects_bad_urls_and_topics() {
+    let transports = [
+        NotificationTransport::Webhook {
+            url: "ftp://example.com".to_string(),
+            secret: None,
+        },
+        NotificationTransport::Ntfy {
+            server_url: "https://ntfy.sh".to_string(),
+            topic: "a/b".to_string(),
+            token: None,
+        },
+        NotificationTransport::Matrix {
+            homeserver_url: "https://matrix.org".to_string(),
+            room_id: "general".to_string(),
+            access_token: "token".to_string(),
+        },
+    ];
+    for transport in transports {
+        assert!(transport.validate().is_err(), "{transport:?}");
+    }
+}
+
+#[test]
+fn test_validate_rejects_plain_smtp_to_remote_server() {
+    assert!(
+        email("smtp.example.com", 25, SmtpSecurity::None)
+            .validate()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_validate_rejects_header_injection_in_addresses() {
+    let transport = NotificationTransport::Email {
+        host: "smtp.example.com".to_string(),
+        port: 587,
+        security: SmtpSecurity::StartTls,
+        username: None,
+        password: None,
+        from: "rig@example.com".to_string(),
+        to: vec!["me@example.com\r\nBcc: x@evil.com".to_string()],
+    };
+    assert!(transport.validate().is_err());
+}
+
+#[test]
+fn test_validate_requires_username_and_password_together() {
+    let transport = NotificationTransport::Email {
+        host: "smtp.example.com".to_string(),
+        port: 587,
+        security: SmtpSecurity::StartTls,
+        username: Some("rig".to_string()),
+        password: None,
+        from: "rig@example.com".to_string(),
+        to: vec!["me@example.com".to_string()],
+    };
+    assert!(transport.validate().is_err());
+}
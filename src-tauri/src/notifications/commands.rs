@@ -0,0 +1,128 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::configs::config_notifications::{
+    ConfigNotifications, ConfigNotificationsContent, NotificationChannel,
+};
+use crate::configs::trait_config::ConfigImpl;
+use crate::notifications::Notifications;
+
+const MAX_HASHRATE_DROP_PERCENT: u8 = 95;
+
+fn validate_channel(channel: &NotificationChannel) -> Result<(), String> {
+    if channel.name.trim().is_empty() {
+        return Err("Channel name cannot be empty".to_string());
+    }
+    channel.transport.validate().map_err(|e| e.to_string())
+}
+
+/// Puts the stored secret back in place of the redacted one the frontend was given
+async fn restore_channel_secret(channel: &mut NotificationChannel) -> Result<(), String> {
+    let config = ConfigNotifications::content().await;
+    let stored = config
+        .find_channel(&channel.id)
+        .map(|stored| &stored.transport);
+    channel
+        .transport
+        .restore_secret(stored)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_notification_config() -> Result<serde_json::Value, String> {
+    ConfigNotifications::content()
+        .await
+        .to_redacted_value()
+        .map_err(|e| e.to_string())
+}
+
+/// Adds the channel, or replaces the one with the same id. New channels get their id here.
+#[tauri::command]
+pub async fn save_notification_channel(
+    mut channel: NotificationChannel,
+) -> Result<NotificationChannel, String> {
+    restore_channel_secret(&mut channel).await?;
+    validate_channel(&channel)?;
+    channel.name = channel.name.trim().to_string();
+    if channel.id.trim().is_empty() {
+        channel.id = uuid::Uuid::new_v4().to_string();
+    }
+    channel.events.sort();
+    channel.events.dedup();
+
+    let mut channels = ConfigNotifications::content().await.channels().clone();
+    match channels
+        .iter_mut()
+        .find(|existing| existing.id == channel.id)
+    {
+        Some(existing) => *existing = channel.clone(),
+        None => channels.push(channel.clone()),
+    }
+    ConfigNotifications::save_channels(channels)
+        .await
+        .map_err(|e| e.to_string())?;
+    channel.transport.redact_secret();
+    Ok(channel)
+}
+
+#[tauri::command]
+pub async fn remove_notification_channel(id: String) -> Result<(), String> {
+    let mut channels = ConfigNotifications::content().await.channels().clone();
+    channels.retain(|channel| channel.id != id);
+    ConfigNotifications::save_channels(channels)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_notification_limits(
+    hashrate_drop_percent: u8,
+    min_interval_secs: u64,
+) -> Result<(), String> {
+    if hashrate_drop_percent == 0 || hashrate_drop_percent > MAX_HASHRATE_DROP_PERCENT {
+        return Err(format!(
+            "Hashrate drop must be between 1 and {MAX_HASHRATE_DROP_PERCENT} percent"
+        ));
+    }
+    ConfigNotifications::update_field(
+        ConfigNotificationsContent::set_hashrate_drop_percent,
+        hashrate_drop_percent,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    ConfigNotifications::update_field(
+        ConfigNotificationsContent::set_min_interval_secs,
+        min_interval_secs,
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Sends a sample notification through a channel, saved or not, and reports why it failed
+#[tauri::command]
+pub async fn test_notification_channel(mut channel: NotificationChannel) -> Result<(), String> {
+    restore_channel_secret(&mut channel).await?;
+    validate_channel(&channel)?;
+    Notifications::send_test(&channel)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,408 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Background worker that delivers events to the subscribed notification channels.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use log::{debug, info, warn};
+use tokio::sync::{RwLock, mpsc, watch};
+use tokio::time::{self, Instant, MissedTickBehavior};
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_notifications::{
+    ConfigNotifications, NotificationChannel, NotificationEventType,
+};
+use crate::configs::trait_config::ConfigImpl;
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::gpu::consts::GpuMinerStatus;
+use crate::node::node_adapter::BaseNodeStatus;
+use crate::notifications::channels::{self, NotificationMessage};
+use crate::notifications::events::NotificationEvent;
+use crate::tasks_tracker::TasksTrackers;
+
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(30);
+const MAX_QUEUED_RETRIES: usize = 100;
+const RETRY_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+const MONITOR_INTERVAL: Duration = Duration::from_secs(30);
+/// Samples taken before the hashrate average is trusted, so ramping up after a start isn't a drop
+const HASHRATE_WARMUP_SAMPLES: u32 = 4;
+/// Weight of the newest sample in the hashrate average
+const HASHRATE_SMOOTHING: f64 = 0.2;
+
+static INSTANCE: LazyLock<RwLock<Notifications>> =
+    LazyLock::new(|| RwLock::new(Notifications::new()));
+
+/// Allows one notification per channel and event type within the configured interval
+#[derive(Default)]
+pub struct RateLimiter {
+    last_sent: HashMap<(String, NotificationEventType), Instant>,
+}
+
+impl RateLimiter {
+    pub fn allow(
+        &mut self,
+        channel_id: &str,
+        event_type: NotificationEventType,
+        min_interval: Duration,
+        now: Instant,
+    ) -> bool {
+        let key = (channel_id.to_string(), event_type);
+        if self
+            .last_sent
+            .get(&key)
+            .is_some_and(|last| now.duration_since(*last) < min_interval)
+        {
+            return false;
+        }
+        self.last_sent.insert(key, now);
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PendingDelivery {
+    pub channel_id: String,
+    pub message: NotificationMessage,
+    /// Attempts made so far
+    pub attempts: u32,
+    pub next_attempt: Instant,
+}
+
+/// Delay before the next attempt, doubling with every failed one
+pub fn retry_delay(attempts: u32) -> Duration {
+    RETRY_BASE_DELAY * 2u32.saturating_pow(attempts.saturating_sub(1))
+}
+
+/// Failed deliveries waiting for their next attempt. The oldest entry is dropped when full.
+#[derive(Default)]
+pub struct RetryQueue {
+    entries: VecDeque<PendingDelivery>,
+}
+
+impl RetryQueue {
+    /// Queues another attempt after a failed one. Returns false when the delivery is given up on.
+    pub fn schedule(
+        &mut self,
+        channel_id: String,
+        message: NotificationMessage,
+        attempts: u32,
+        now: Instant,
+    ) -> bool {
+        if attempts >= MAX_DELIVERY_ATTEMPTS {
+            return false;
+        }
+        if self.entries.len() >= MAX_QUEUED_RETRIES
+            && let Some(dropped) = self.entries.pop_front()
+        {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Notification retry queue is full, dropping {} for channel {}", dropped.message.id, dropped.channel_id);
+        }
+        self.entries.push_back(PendingDelivery {
+            channel_id,
+            message,
+            attempts,
+            next_attempt: now + retry_delay(attempts),
+        });
+        true
+    }
+
+    pub fn take_due(&mut self, now: Instant) -> Vec<PendingDelivery> {
+        let (due, waiting): (VecDeque<_>, VecDeque<_>) = self
+            .entries
+            .drain(..)
+            .partition(|entry| entry.next_attempt <= now);
+        self.entries = waiting;
+        due.into()
+    }
+}
+
+/// Tracks a smoothed hashrate and reports once when it falls the configured percentage below it
+#[derive(Default)]
+pub struct HashrateMonitor {
+    average: f64,
+    samples: u32,
+    dropped: bool,
+}
+
+impl HashrateMonitor {
+    /// Returns the average before the drop and the current hashrate when a new drop starts
+    pub fn sample(
+        &mut self,
+        is_mining: bool,
+        hashrate: f64,
+        drop_percent: u8,
+    ) -> Option<(f64, f64)> {
+        if !is_mining {
+            *self = Self::default();
+            return None;
+        }
+        let threshold = self.average * (1.0 - f64::from(drop_percent.min(100)) / 100.0);
+        if self.samples >= HASHRATE_WARMUP_SAMPLES && hashrate < threshold {
+            // Keep the average from before the drop so recovery is measured against it
+            if self.dropped {
+                return None;
+            }
+            self.dropped = true;
+            return Some((self.average, hashrate));
+        }
+        self.dropped = false;
+        self.average = if self.samples == 0 {
+            hashrate
+        } else {
+            self.average * (1.0 - HASHRATE_SMOOTHING) + hashrate * HASHRATE_SMOOTHING
+        };
+        self.samples = self.samples.saturating_add(1);
+        None
+    }
+}
+
+/// Reports when a synced node falls behind
+#[derive(Default)]
+pub struct SyncMonitor {
+    was_synced: bool,
+}
+
+impl SyncMonitor {
+    pub fn sample(&mut self, is_synced: bool, block_height: u64) -> bool {
+        // A height of 0 means the node hasn't reported yet, e.g. while it restarts
+        if block_height == 0 {
+            return false;
+        }
+        let lost_sync = self.was_synced && !is_synced;
+        self.was_synced = is_synced;
+        lost_sync
+    }
+}
+
+#[derive(Clone)]
+struct StatusSources {
+    cpu_miner_status: watch::Receiver<CpuMinerStatus>,
+    gpu_miner_status: watch::Receiver<GpuMinerStatus>,
+    node_status: watch::Receiver<BaseNodeStatus>,
+}
+
+#[derive(Default)]
+struct Monitors {
+    cpu_hashrate: HashrateMonitor,
+    gpu_hashrate: HashrateMonitor,
+    sync: SyncMonitor,
+}
+
+impl Monitors {
+    fn check(&mut self, sources: &StatusSources, drop_percent: u8) -> Vec<NotificationEvent> {
+        let mut events = Vec::new();
+        let cpu = sources.cpu_miner_status.borrow().clone();
+        let gpu = sources.gpu_miner_status.borrow().clone();
+        let node = *sources.node_status.borrow();
+        for (device, monitor, is_mining, hashrate) in [
+            ("CPU", &mut self.cpu_hashrate, cpu.is_mining, cpu.hash_rate),
+            ("GPU", &mut self.gpu_hashrate, gpu.is_mining, gpu.hash_rate),
+        ] {
+            if let Some((previous, current)) = monitor.sample(is_mining, hashrate, drop_percent) {
+                events.push(NotificationEvent::HashrateDropped {
+                    device: device.to_string(),
+                    previous_hashrate: previous,
+                    current_hashrate: current,
+                    drop_percent: (1.0 - current / previous) * 100.0,
+                });
+            }
+        }
+        if self.sync.sample(node.is_synced, node.block_height) {
+            events.push(NotificationEvent::NodeOutOfSync {
+                block_height: node.block_height,
+            });
+        }
+        events
+    }
+}
+
+pub struct Notifications {
+    sources: Option<StatusSources>,
+    sender: Option<mpsc::UnboundedSender<NotificationEvent>>,
+}
+
+impl Notifications {
+    fn new() -> Self {
+        Self {
+            sources: None,
+            sender: None,
+        }
+    }
+
+    pub async fn initialize(
+        cpu_miner_status: watch::Receiver<CpuMinerStatus>,
+        gpu_miner_status: watch::Receiver<GpuMinerStatus>,
+        node_status: watch::Receiver<BaseNodeStatus>,
+    ) {
+        INSTANCE.write().await.sources = Some(StatusSources {
+            cpu_miner_status,
+            gpu_miner_status,
+            node_status,
+        });
+    }
+
+    pub async fn start() -> anyhow::Result<()> {
+        let mut notifications = INSTANCE.write().await;
+        let sources = notifications
+            .sources
+            .clone()
+            .ok_or_else(|| anyhow!("Notifications not initialized"))?;
+        let (sender, receiver) = mpsc::unbounded_channel();
+        notifications.sender = Some(sender);
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(Self::run(sources, receiver));
+        Ok(())
+    }
+
+    /// Whether any enabled channel wants this event, for callers that need extra work to build it
+    pub async fn is_subscribed(event_type: NotificationEventType) -> bool {
+        ConfigNotifications::content()
+            .await
+            .is_subscribed(event_type)
+    }
+
+    /// Queues an event for the channels subscribed to it. Never blocks on delivery.
+    pub async fn notify(event: NotificationEvent) {
+        if !Self::is_subscribed(event.event_type()).await {
+            return;
+        }
+        let sender = INSTANCE.read().await.sender.clone();
+        match sender {
+            Some(sender) => {
+                if sender.send(event).is_err() {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Notification worker has stopped, dropping event");
+                }
+            }
+            None => {
+                debug!(target: LOG_TARGET_APP_LOGIC, "Notifications not started, dropping {event:?}")
+            }
+        }
+    }
+
+    /// Delivers a sample notification right away, bypassing rate limits and retries
+    pub async fn send_test(channel: &NotificationChannel) -> anyhow::Result<()> {
+        let event = NotificationEvent::BlockWon {
+            block_height: 1,
+            reward: 0,
+        };
+        let mut message = NotificationMessage::render(channel, &event);
+        message.title = format!("Test: {}", message.title);
+        channels::deliver(&channel.transport, &message).await
+    }
+
+    async fn run(sources: StatusSources, mut events: mpsc::UnboundedReceiver<NotificationEvent>) {
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        let (failed_tx, mut failed_rx) = mpsc::unbounded_channel::<PendingDelivery>();
+        let mut rate_limiter = RateLimiter::default();
+        let mut retries = RetryQueue::default();
+        let mut monitors = Monitors::default();
+        let mut retry_timer = time::interval(RETRY_CHECK_INTERVAL);
+        let mut monitor_timer = time::interval(MONITOR_INTERVAL);
+        monitor_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        info!(target: LOG_TARGET_APP_LOGIC, "Notification worker started");
+
+        loop {
+            tokio::select! {
+                event = events.recv() => {
+                    let Some(event) = event else { return };
+                    Self::dispatch(&event, &mut rate_limiter, &failed_tx).await;
+                }
+                Some(failed) = failed_rx.recv() => {
+                    let id = failed.message.id.clone();
+                    if !retries.schedule(failed.channel_id, failed.message, failed.attempts, Instant::now()) {
+                        warn!(target: LOG_TARGET_APP_LOGIC, "Giving up on notification {id} after {} attempts", failed.attempts);
+                    }
+                }
+                _ = retry_timer.tick() => {
+                    let config = ConfigNotifications::content().await;
+                    for pending in retries.take_due(Instant::now()) {
+                        // Channels removed or disabled since the first attempt are skipped
+                        match config.find_channel(&pending.channel_id).filter(|channel| channel.enabled) {
+                            Some(channel) => Self::spawn_delivery(channel.clone(), pending.message, pending.attempts, &failed_tx).await,
+                            None => debug!(target: LOG_TARGET_APP_LOGIC, "Dropping retry for removed notification channel {}", pending.channel_id),
+                        }
+                    }
+                }
+                _ = monitor_timer.tick() => {
+                    let drop_percent = *ConfigNotifications::content().await.hashrate_drop_percent();
+                    for event in monitors.check(&sources, drop_percent) {
+                        Self::dispatch(&event, &mut rate_limiter, &failed_tx).await;
+                    }
+                }
+                () = shutdown_signal.wait() => return,
+            }
+        }
+    }
+
+    async fn dispatch(
+        event: &NotificationEvent,
+        rate_limiter: &mut RateLimiter,
+        failed_tx: &mpsc::UnboundedSender<PendingDelivery>,
+    ) {
+        let config = ConfigNotifications::content().await;
+        let min_interval = Duration::from_secs(*config.min_interval_secs());
+        let event_type = event.event_type();
+        for channel in config
+            .channels()
+            .iter()
+            .filter(|channel| channel.enabled && channel.events.contains(&event_type))
+        {
+            if !rate_limiter.allow(&channel.id, event_type, min_interval, Instant::now()) {
+                debug!(target: LOG_TARGET_APP_LOGIC, "Rate limited {} notification for channel {}", event_type.as_str(), channel.name);
+                continue;
+            }
+            let message = NotificationMessage::render(channel, event);
+            Self::spawn_delivery(channel.clone(), message, 0, failed_tx).await;
+        }
+    }
+
+    async fn spawn_delivery(
+        channel: NotificationChannel,
+        message: NotificationMessage,
+        previous_attempts: u32,
+        failed_tx: &mpsc::UnboundedSender<PendingDelivery>,
+    ) {
+        let failed_tx = failed_tx.clone();
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let attempts = previous_attempts + 1;
+                if let Err(e) = channels::deliver(&channel.transport, &message).await {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Notification to channel {} failed (attempt {attempts}): {e}", channel.name);
+                    let _unused = failed_tx.send(PendingDelivery {
+                        channel_id: channel.id,
+                        message,
+                        attempts,
+                        next_attempt: Instant::now() + retry_delay(attempts),
+                    });
+                }
+            });
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Unit tests for notification rate limiting, retries and status monitors
+
+use std::time::Duration;
+
+use serde_json::Value;
+use tokio::time::Instant;
+
+use crate::configs::config_notifications::NotificationEventType;
+
+use super::channels::NotificationMessage;
+use super::dispatcher::{
+    HashrateMonitor, MAX_DELIVERY_ATTEMPTS, RateLimiter, RetryQueue, SyncMonitor, retry_delay,
+};
+
+fn message(id: &str) -> NotificationMessage {
+    NotificationMessage {
+        id: id.to_string(),
+        event: NotificationEventType::MinerCrashed,
+        title: "title".to_string(),
+        body: "body".to_string(),
+        timestamp: 0,
+        data: Value::Null,
+    }
+}
+
+// ==================== Rate limiting ====================
+
+#[test]
+fn test_rate_limiter_blocks_repeats_within_interval() {
+    let mut limiter = RateLimiter::default();
+    let now = Instant::now();
+    let interval = Duration::from_secs(300);
+    let event = NotificationEventType::MinerCrashed;
+
+    assert!(limiter.allow("a", event, interval, now));
+    assert!(!limiter.allow("a", event, interval, now + Duration::from_secs(299)));
+    assert!(limiter.allow("a", event, interval, now + Duration::from_secs(300)));
+}
+
+#[test]
+fn test_rate_limiter_is_per_channel_and_event() {
+    let mut limiter = RateLimiter::default();
+    let now = Instant::now();
+    let interval = Duration::from_secs(300);
+
+    assert!(limiter.allow("a", NotificationEventType::MinerCrashed, interval, now));
+    assert!(limiter.allow("b", NotificationEventType::MinerCrashed, interval, now));
+    assert!(limiter.allow("a", NotificationEventType::BlockWon, interval, now));
+}
+
+#[test]
+fn test_rate_limiter_with_zero_interval_allows_everything() {
+    let mut limiter = RateLimiter::default();
+    let now = Instant::now();
+    for _ in 0..3 {
+        assert!(limiter.allow("a", NotificationEventType::BlockWon, Duration::ZERO, now));
+    }
+}
+
+// ==================== Retry queue ====================
+
+#[test]
+fn test_retry_delay_doubles() {
+    assert_eq!(retry_delay(1), Duration::from_secs(30));
+    assert_eq!(retry_delay(2), Duration::from_secs(60));
+    assert_eq!(retry_delay(4), Duration::from_secs(240));
+}
+
+#[test]
+fn test_retry_queue_releases_entries_when_due() {
+    let mut queue = RetryQueue::default();
+    let now = Instant::now();
+    assert!(queue.schedule("a".to_string(), message("1"), 1, now));
+    assert!(queue.schedule("a".to_string(), message("2"), 2, now));
+
+    assert!(queue.take_due(now + Duration::from_secs(29)).is_empty());
+    let due = queue.take_due(now + Duration::from_secs(30));
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].message.id, "1");
+    assert_eq!(due[0].attempts, 1);
+
+    let due = queue.take_due(now + Duration::from_secs(60));
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].message.id, "2");
+    assert!(queue.take_due(now + Duration::from_secs(3600)).is_empty());
+}
+
+#[test]
+fn test_retry_queue_gives_up_after_max_attempts() {
+    let mut queue = RetryQueue::default();
+    let now = Instant::now();
+    assert!(queue.schedule(
+        "a".to_string(),
+        message("1"),
+        MAX_DELIVERY_ATTEMPTS - 1,
+        now
+    ));
+    assert!(!queue.schedule("a".to_string(), message("2"), MAX_DELIVERY_ATTEMPTS, now));
+
+    let due = queue.take_due(now + Duration::from_secs(24 * 3600));
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].message.id, "1");
+}
+
+#[test]
+fn test_retry_queue_drops_oldest_when_full() {
+    let mut queue = RetryQueue::default();
+    let now = Instant::now();
+    for i in 0..101 {
+        queue.schedule("a".to_string(), message(&i.to_string()), 1, now);
+    }
+    let due = queue.take_due(now + Duration::from_secs(30));
+    assert_eq!(due.len(), 100);
+    assert_eq!(due[0].message.id, "1");
+}
+
+// ==================== Hashrate monitor ====================
+
+fn warm_up(monitor: &mut HashrateMonitor, hashrate: f64) {
+    for _ in 0..4 {
+        assert!(monitor.sample(true, hashrate, 30).is_none());
+    }
+}
+
+#[test]
+fn test_hashrate_drop_is_reported_once() {
+    let mut monitor = HashrateMonitor::default();
+    warm_up(&mut monitor, 1000.0);
+
+    assert_eq!(monitor.sample(true, 600.0, 30), Some((1000.0, 600.0)));
+    assert!(monitor.sample(true, 500.0, 30).is_none());
+}
+
+#[test]
+fn test_hashrate_small_dip_is_ignored() {
+    let mut monitor = HashrateMonitor::default();
+    warm_up(&mut monitor, 1000.0);
+    assert!(monitor.sample(true, 750.0, 30).is_none());
+}
+
+#[test]
+fn test_hashrate_drop_reported_again_after_recovery() {
+    let mut monitor = HashrateMonitor::default();
+    warm_up(&mut monitor, 1000.0);
+
+    assert!(monitor.sample(true, 100.0, 30).is_some());
+    assert!(monitor.sample(true, 1000.0, 30).is_none());
+    assert!(monitor.sample(true, 100.0, 30).is_some());
+}
+
+#[test]
+fn test_hashrate_not_reported_during_warm_up() {
+    let mut monitor = HashrateMonitor::default();
+    assert!(monitor.sample(true, 1000.0, 30).is_none());
+    assert!(monitor.sample(true, 10.0, 30).is_none());
+}
+
+#[test]
+fn test_hashrate_monitor_resets_when_mining_stops() {
+    let mut monitor = HashrateMonitor::default();
+    warm_up(&mut monitor, 1000.0);
+    assert!(monitor.sample(false, 0.0, 30).is_none());
+    // Starting again ramps up from zero without counting as a drop
+    assert!(monitor.sample(true, 10.0, 30).is_none());
+}
+
+// ==================== Sync monitor ====================
+
+#[test]
+fn test_sync_loss_reported_on_transition() {
+    let mut monitor = SyncMonitor::default();
+    assert!(!monitor.sample(false, 100));
+    assert!(!monitor.sample(true, 100));
+    assert!(monitor.sample(false, 101));
+    assert!(!monitor.sample(false, 102));
+    assert!(!monitor.sample(true, 103));
+    assert!(monitor.sample(false, 104));
+}
+
+#[test]
+fn test_sync_ignores_node_without_height() {
+    let mut monitor = SyncMonitor::default();
+    assert!(!monitor.sample(true, 100));
+    // A restarting node reports the default status first
+    assert!(!monitor.sample(false, 0));
+    assert!(monitor.sample(false, 100));
+}
@@ -0,0 +1,218 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Events notification channels can subscribe to, with the fields their templates can use.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::configs::config_notifications::NotificationEventType;
+
+const MICRO_PER_XTM: u64 = 1_000_000;
+
+/// Serializes to the event's own fields, which webhooks receive as `data`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum NotificationEvent {
+    MinerCrashed {
+        miner: String,
+        exit_code: Option<i32>,
+        restarting: bool,
+    },
+    PoolFailover {
+        from_pool: String,
+        to_pool: String,
+        reason: String,
+    },
+    HashrateDropped {
+        device: String,
+        previous_hashrate: f64,
+        current_hashrate: f64,
+        drop_percent: f64,
+    },
+    NodeOutOfSync {
+        block_height: u64,
+    },
+    IncomingTransaction {
+        tx_id: String,
+        /// In µT
+        amount: u64,
+        block_height: u64,
+        payment_id: String,
+    },
+    BlockWon {
+        block_height: u64,
+        /// In µT
+        reward: u64,
+    },
+    PinLockout {
+        failed_attempts: u32,
+        lockout_secs: u64,
+    },
+}
+
+impl NotificationEvent {
+    pub fn event_type(&self) -> NotificationEventType {
+        match self {
+            NotificationEvent::MinerCrashed { .. } => NotificationEventType::MinerCrashed,
+            NotificationEvent::PoolFailover { .. } => NotificationEventType::PoolFailover,
+            NotificationEvent::HashrateDropped { .. } => NotificationEventType::HashrateDropped,
+            NotificationEvent::NodeOutOfSync { .. } => NotificationEventType::NodeOutOfSync,
+            NotificationEvent::IncomingTransaction { .. } => {
+                NotificationEventType::IncomingTransaction
+            }
+            NotificationEvent::BlockWon { .. } => NotificationEventType::BlockWon,
+            NotificationEvent::PinLockout { .. } => NotificationEventType::PinLockout,
+        }
+    }
+
+    /// Values for `{{field}}` placeholders, formatted for people rather than machines
+    pub fn fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::new();
+        match self {
+            NotificationEvent::MinerCrashed {
+                miner,
+                exit_code,
+                restarting,
+            } => {
+                fields.insert("miner", miner.clone());
+                fields.insert(
+                    "exit_code",
+                    exit_code.map_or_else(|| "unknown".to_string(), |code| code.to_string()),
+                );
+                fields.insert(
+                    "action",
+                    if *restarting {
+                        "It is being restarted."
+                    } else {
+                        "It will not be restarted automatically."
+                    }
+                    .to_string(),
+                );
+            }
+            NotificationEvent::PoolFailover {
+                from_pool,
+                to_pool,
+                reason,
+            } => {
+                fields.insert("from_pool", from_pool.clone());
+                fields.insert("to_pool", to_pool.clone());
+                fields.insert("reason", reason.clone());
+            }
+            NotificationEvent::HashrateDropped {
+                device,
+                previous_hashrate,
+                current_hashrate,
+                drop_percent,
+            } => {
+                fields.insert("device", device.clone());
+                fields.insert("previous_hashrate", format_hashrate(*previous_hashrate));
+                fields.insert("current_hashrate", format_hashrate(*current_hashrate));
+                fields.insert("drop_percent", format!("{drop_percent:.0}"));
+            }
+            NotificationEvent::NodeOutOfSync { block_height } => {
+                fields.insert("block_height", block_height.to_string());
+            }
+            NotificationEvent::IncomingTransaction {
+                tx_id,
+                amount,
+                block_height,
+                payment_id,
+            } => {
+                fields.insert("tx_id", tx_id.clone());
+                fields.insert("amount", format_xtm(*amount));
+                fields.insert("block_height", block_height.to_string());
+                fields.insert("payment_id", payment_id.clone());
+            }
+            NotificationEvent::BlockWon {
+                block_height,
+                reward,
+            } => {
+                fields.insert("block_height", block_height.to_string());
+                fields.insert("reward", format_xtm(*reward));
+            }
+            NotificationEvent::PinLockout {
+                failed_attempts,
+                lockout_secs,
+            } => {
+                fields.insert("failed_attempts", failed_attempts.to_string());
+                fields.insert("lockout_secs", lockout_secs.to_string());
+            }
+        }
+        fields
+    }
+
+    pub fn default_title(&self) -> &'static str {
+        match self {
+            NotificationEvent::MinerCrashed { .. } => "{{miner}} crashed",
+            NotificationEvent::PoolFailover { .. } => "Switched to pool {{to_pool}}",
+            NotificationEvent::HashrateDropped { .. } => {
+                "{{device}} hashrate dropped {{drop_percent}}%"
+            }
+            NotificationEvent::NodeOutOfSync { .. } => "Node out of sync",
+            NotificationEvent::IncomingTransaction { .. } => "Received {{amount}}",
+            NotificationEvent::BlockWon { .. } => "Block {{block_height}} won",
+            NotificationEvent::PinLockout { .. } => "Wallet PIN locked",
+        }
+    }
+
+    pub fn default_body(&self) -> &'static str {
+        match self {
+            NotificationEvent::MinerCrashed { .. } => {
+                "{{miner}} stopped unexpectedly with exit code {{exit_code}}. {{action}}"
+            }
+            NotificationEvent::PoolFailover { .. } => {
+                "Mining moved from {{from_pool}} to {{to_pool}}: {{reason}}."
+            }
+            NotificationEvent::HashrateDropped { .. } => {
+                "{{device}} hashrate fell from {{previous_hashrate}} to {{current_hashrate}}."
+            }
+            NotificationEvent::NodeOutOfSync { .. } => {
+                "The base node fell out of sync at block {{block_height}}."
+            }
+            NotificationEvent::IncomingTransaction { .. } => {
+                "Transaction {{tx_id}} for {{amount}} was mined in block {{block_height}}."
+            }
+            NotificationEvent::BlockWon { .. } => {
+                "Your rig won block {{block_height}} for a reward of {{reward}}."
+            }
+            NotificationEvent::PinLockout { .. } => {
+                "{{failed_attempts}} wrong PIN attempts. PIN entry is locked for {{lockout_secs}} seconds."
+            }
+        }
+    }
+}
+
+pub fn format_xtm(micro: u64) -> String {
+    format!("{}.{:06} XTM", micro / MICRO_PER_XTM, micro % MICRO_PER_XTM)
+}
+
+pub fn format_hashrate(hashrate: f64) -> String {
+    const UNITS: [&str; 5] = ["H/s", "kH/s", "MH/s", "GH/s", "TH/s"];
+    let mut value = hashrate;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
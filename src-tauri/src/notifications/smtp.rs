@@ -0,0 +1,304 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Minimal SMTP client for email notifications.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use chrono::{DateTime, Utc};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::time;
+use tokio_native_tls::{TlsConnector, native_tls};
+
+use crate::configs::config_notifications::SmtpSecurity;
+use crate::requests::connectivity;
+
+const SESSION_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_REPLY_LINE_LENGTH: u64 = 4096;
+const MAX_REPLY_LINES: usize = 100;
+const BODY_LINE_LENGTH: usize = 76;
+/// Name sent with EHLO. The machine's host name is left out so it doesn't leak to the server.
+const EHLO_NAME: &str = "localhost";
+
+pub struct SmtpServer<'a> {
+    pub host: &'a str,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub credentials: Option<(&'a str, &'a str)>,
+}
+
+pub struct SmtpMessage<'a> {
+    pub from: &'a str,
+    pub to: &'a [String],
+    pub subject: &'a str,
+    pub body: &'a str,
+}
+
+#[derive(Debug)]
+struct Reply {
+    code: u16,
+    lines: Vec<String>,
+}
+
+impl Reply {
+    fn text(&self) -> String {
+        self.lines.join(" ")
+    }
+}
+
+struct Connection<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    async fn read_reply(&mut self) -> Result<Reply, anyhow::Error> {
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            let read = (&mut self.stream)
+                .take(MAX_REPLY_LINE_LENGTH)
+                .read_line(&mut line)
+                .await?;
+            if read == 0 {
+                bail!("SMTP server closed the connection");
+            }
+            if !line.ends_with('\n') {
+                bail!("SMTP reply line is too long");
+            }
+            let line = line.trim_end();
+            let code = line
+                .get(..3)
+                .and_then(|code| code.parse::<u16>().ok())
+                .ok_or_else(|| anyhow!("Malformed SMTP reply: {line:?}"))?;
+            let is_last = line.as_bytes().get(3) != Some(&b'-');
+            lines.push(line.get(4..).unwrap_or_default().to_string());
+            if is_last {
+                return Ok(Reply { code, lines });
+            }
+            if lines.len() >= MAX_REPLY_LINES {
+                bail!("SMTP reply has too many lines");
+            }
+        }
+    }
+
+    async fn write_line(&mut self, line: &str) -> Result<(), anyhow::Error> {
+        let stream = self.stream.get_mut();
+        stream.write_all(line.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.flush().await?;
+        Ok(())
+    }
+
+    /// Sends a command and fails unless the reply has one of the expected codes. `shown` replaces
+    /// the command in errors, so credentials don't end up in logs.
+    async fn command(
+        &mut self,
+        line: &str,
+        expected: &[u16],
+        shown: Option<&str>,
+    ) -> Result<Reply, anyhow::Error> {
+        self.write_line(line).await?;
+        let reply = self.read_reply().await?;
+        if !expected.contains(&reply.code) {
+            bail!(
+                "SMTP server rejected {}: {} {}",
+                shown.unwrap_or(line),
+                reply.code,
+                reply.text()
+            );
+        }
+        Ok(reply)
+    }
+
+    async fn greeting(&mut self) -> Result<(), anyhow::Error> {
+        let reply = self.read_reply().await?;
+        if reply.code != 220 {
+            bail!(
+                "SMTP server refused the connection: {} {}",
+                reply.code,
+                reply.text()
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the extensions the server advertises, upper cased
+    async fn ehlo(&mut self) -> Result<Vec<String>, anyhow::Error> {
+        let reply = self
+            .command(&format!("EHLO {EHLO_NAME}"), &[250], None)
+            .await?;
+        Ok(reply
+            .lines
+            .iter()
+            .skip(1)
+            .map(|line| line.to_ascii_uppercase())
+            .collect())
+    }
+
+    async fn authenticate(
+        &mut self,
+        extensions: &[String],
+        (username, password): (&str, &str),
+    ) -> Result<(), anyhow::Error> {
+        let mechanisms: Vec<&str> = extensions
+            .iter()
+            .filter_map(|extension| extension.strip_prefix("AUTH "))
+            .flat_map(str::split_whitespace)
+            .collect();
+        if mechanisms.contains(&"PLAIN") {
+            let token = BASE64_STANDARD.encode(format!("\0{username}\0{password}"));
+            self.command(&format!("AUTH PLAIN {token}"), &[235], Some("AUTH PLAIN"))
+                .await?;
+        } else if mechanisms.contains(&"LOGIN") {
+            self.command("AUTH LOGIN", &[334], None).await?;
+            self.command(
+                &BASE64_STANDARD.encode(username),
+                &[334],
+                Some("AUTH LOGIN"),
+            )
+            .await?;
+            self.command(
+                &BASE64_STANDARD.encode(password),
+                &[235],
+                Some("AUTH LOGIN"),
+            )
+            .await?;
+        } else {
+            bail!("SMTP server offers no supported authentication method");
+        }
+        Ok(())
+    }
+
+    async fn send_message(
+        &mut self,
+        extensions: &[String],
+        server: &SmtpServer<'_>,
+        message: &SmtpMessage<'_>,
+    ) -> Result<(), anyhow::Error> {
+        if let Some(credentials) = server.credentials {
+            self.authenticate(extensions, credentials).await?;
+        }
+        self.command(&format!("MAIL FROM:<{}>", message.from), &[250], None)
+            .await?;
+        for recipient in message.to {
+            self.command(&format!("RCPT TO:<{recipient}>"), &[250, 251], None)
+                .await?;
+        }
+        self.command("DATA", &[354], None).await?;
+        let stream = self.stream.get_mut();
+        stream
+            .write_all(format_message(message, Utc::now()).as_bytes())
+            .await?;
+        self.command(".", &[250], Some("message")).await?;
+        // The message is accepted at this point, so a failing QUIT doesn't matter
+        let _unused = self.command("QUIT", &[221], None).await;
+        Ok(())
+    }
+}
+
+async fn tls_handshake<S: AsyncRead + AsyncWrite + Unpin>(
+    host: &str,
+    stream: S,
+) -> Result<tokio_native_tls::TlsStream<S>, anyhow::Error> {
+    let connector = TlsConnector::from(native_tls::TlsConnector::new()?);
+    Ok(connector.connect(host, stream).await?)
+}
+
+async fn session(server: &SmtpServer<'_>, message: &SmtpMessage<'_>) -> Result<(), anyhow::Error> {
+    let stream = connectivity::connect_tcp(server.host, server.port).await?;
+    match server.security {
+        SmtpSecurity::Tls => {
+            let mut connection = Connection::new(tls_handshake(server.host, stream).await?);
+            connection.greeting().await?;
+            let extensions = connection.ehlo().await?;
+            connection.send_message(&extensions, server, message).await
+        }
+        SmtpSecurity::StartTls => {
+            let mut connection = Connection::new(stream);
+            connection.greeting().await?;
+            let extensions = connection.ehlo().await?;
+            if !extensions.iter().any(|extension| extension == "STARTTLS") {
+                bail!("SMTP server does not support STARTTLS");
+            }
+            connection.command("STARTTLS", &[220], None).await?;
+            // Anything already buffered was sent before encryption and could be injected
+            if !connection.stream.buffer().is_empty() {
+                bail!("SMTP server sent data before the TLS handshake");
+            }
+            let stream = connection.stream.into_inner();
+            let mut connection = Connection::new(tls_handshake(server.host, stream).await?);
+            let extensions = connection.ehlo().await?;
+            connection.send_message(&extensions, server, message).await
+        }
+        SmtpSecurity::None => {
+            let mut connection = Connection::new(stream);
+            connection.greeting().await?;
+            let extensions = connection.ehlo().await?;
+            connection.send_message(&extensions, server, message).await
+        }
+    }
+}
+
+pub async fn send(server: &SmtpServer<'_>, message: &SmtpMessage<'_>) -> Result<(), anyhow::Error> {
+    time::timeout(SESSION_TIMEOUT, session(server, message))
+        .await
+        .map_err(|_| anyhow!("SMTP server {}:{} timed out", server.host, server.port))?
+}
+
+fn encode_header(value: &str) -> String {
+    let value: String = value.chars().filter(|c| !c.is_control()).collect();
+    if value.is_ascii() {
+        value
+    } else {
+        format!("=?UTF-8?B?{}?=", BASE64_STANDARD.encode(value))
+    }
+}
+
+/// Headers and base64 body of the DATA section, without the terminating `.` line
+pub(crate) fn format_message(message: &SmtpMessage<'_>, date: DateTime<Utc>) -> String {
+    let domain = message
+        .from
+        .rsplit_once('@')
+        .map_or("localhost", |(_, domain)| domain);
+    let recipients: Vec<String> = message.to.iter().map(|to| format!("<{to}>")).collect();
+    let mut data = format!(
+        "Date: {}\r\nFrom: <{}>\r\nTo: {}\r\nSubject: {}\r\nMessage-ID: <{}@{domain}>\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n",
+        date.to_rfc2822(),
+        message.from,
+        recipients.join(", "),
+        encode_header(message.subject),
+        uuid::Uuid::new_v4(),
+    );
+    let body = BASE64_STANDARD.encode(message.body.replace("\r\n", "\n").replace('\n', "\r\n"));
+    for chunk in body.as_bytes().chunks(BODY_LINE_LENGTH) {
+        data.push_str(&String::from_utf8_lossy(chunk));
+        data.push_str("\r\n");
+    }
+    data
+}
@@ -0,0 +1,293 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Unit tests for the SMTP client, against a scripted server on localhost
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use chrono::{TimeZone, Utc};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+use crate::configs::config_notifications::SmtpSecurity;
+
+use super::smtp::{SmtpMessage, SmtpServer, format_message, send};
+
+#[derive(Clone, Default)]
+struct Script {
+    extensions: Vec<&'static str>,
+    reject_recipients: bool,
+    reject_auth: bool,
+}
+
+/// Serves one SMTP session and returns every line the client sent
+async fn fake_smtp(script: Script) -> (u16, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (read, mut write) = stream.into_split();
+        let mut lines = BufReader::new(read).lines();
+        let mut transcript = Vec::new();
+        let mut in_data = false;
+        let mut login_step = 0;
+        write.write_all(b"220 fake ESMTP ready\r\n").await.unwrap();
+        while let Ok(Some(line)) = lines.next_line().await {
+            transcript.push(line.clone());
+            if in_data {
+                if line == "." {
+                    in_data = false;
+                    write.write_all(b"250 2.0.0 queued\r\n").await.unwrap();
+                }
+                continue;
+            }
+            let upper = line.to_ascii_uppercase();
+            let reply = if login_step > 0 {
+                login_step += 1;
+                if login_step == 2 {
+                    "334 UGFzc3dvcmQ6".to_string()
+                } else {
+                    login_step = 0;
+                    "235 2.7.0 accepted".to_string()
+                }
+            } else if upper.starts_with("EHLO") {
+                let mut reply = vec!["250-fake greets you".to_string()];
+                reply.extend(script.extensions.iter().map(|e| format!("250-{e}")));
+                reply.push("250 SIZE 1000000".to_string());
+                reply.join("\r\n")
+            } else if upper.starts_with("AUTH PLAIN") {
+                if script.reject_auth {
+                    "535 5.7.8 bad credentials".to_string()
+                } else {
+                    "235 2.7.0 accepted".to_string()
+                }
+            } else if upper == "AUTH LOGIN" {
+                login_step = 1;
+                "334 VXNlcm5hbWU6".to_string()
+            } else if upper.starts_with("MAIL FROM") {
+                "250 ok".to_string()
+            } else if upper.starts_with("RCPT TO") {
+                if script.reject_recipients {
+                    "550 5.1.1 no such user".to_string()
+                } else {
+                    "250 ok".to_string()
+                }
+            } else if upper == "DATA" {
+                in_data = true;
+                "354 end with .".to_string()
+            } else if upper == "QUIT" {
+                write.write_all(b"221 bye\r\n").await.unwrap();
+                break;
+            } else {
+                "502 not implemented".to_string()
+            };
+            write
+                .write_all(format!("{reply}\r\n").as_bytes())
+                .await
+                .unwrap();
+        }
+        transcript
+    });
+    (port, handle)
+}
+
+fn server(port: u16, credentials: Option<(&'static str, &'static str)>) -> SmtpServer<'static> {
+    SmtpServer {
+        host: "127.0.0.1",
+        port,
+        security: SmtpSecurity::None,
+        credentials,
+    }
+}
+
+fn recipients() -> Vec<String> {
+    vec!["me@example.com".to_string(), "ops@example.com".to_string()]
+}
+
+fn message(to: &[String]) -> SmtpMessage<'_> {
+    SmtpMessage {
+        from: "rig@example.com",
+        to,
+        subject: "Block 42 won",
+        body: "Your rig won block 42.",
+    }
+}
+
+fn decoded_body(transcript: &[String]) -> String {
+    let start = transcript.iter().position(|l| l.is_empty()).unwrap() + 1;
+    let end = transcript.iter().position(|l| l == ".").unwrap();
+    let encoded: String = transcript[start..end].concat();
+    String::from_utf8(BASE64_STANDARD.decode(encoded).unwrap()).unwrap()
+}
+
+// ==================== Sessions ====================
+
+#[tokio::test]
+async fn test_send_delivers_to_every_recipient() {
+    let (port, handle) = fake_smtp(Script::default()).await;
+    let to = recipients();
+    send(&server(port, None), &message(&to)).await.unwrap();
+
+    let transcript = handle.await.unwrap();
+    assert_eq!(transcript[0], "EHLO localhost");
+    assert!(transcript.contains(&"MAIL FROM:<rig@example.com>".to_string()));
+    assert!(transcript.contains(&"RCPT TO:<me@example.com>".to_string()));
+    assert!(transcript.contains(&"RCPT TO:<ops@example.com>".to_string()));
+    assert!(transcript.contains(&"Subject: Block 42 won".to_string()));
+    assert!(!transcript.iter().any(|l| l.starts_with("AUTH")));
+    assert_eq!(decoded_body(&transcript), "Your rig won block 42.");
+    assert_eq!(transcript.last().unwrap(), "QUIT");
+}
+
+#[tokio::test]
+async fn test_send_prefers_auth_plain() {
+    let (port, handle) = fake_smtp(Script {
+        extensions: vec!["AUTH LOGIN PLAIN"],
+        ..Script::default()
+    })
+    .await;
+    let to = recipients();
+    send(&server(port, Some(("rig", "pass"))), &message(&to))
+        .await
+        .unwrap();
+
+    let transcript = handle.await.unwrap();
+    let token = BASE64_STANDARD.encode("\0rig\0pass");
+    assert!(transcript.contains(&format!("AUTH PLAIN {token}")));
+}
+
+#[tokio::test]
+async fn test_send_falls_back_to_auth_login() {
+    let (port, handle) = fake_smtp(Script {
+        extensions: vec!["AUTH LOGIN"],
+        ..Script::default()
+    })
+    .await;
+    let to = recipients();
+    send(&server(port, Some(("rig", "pass"))), &message(&to))
+        .await
+        .unwrap();
+
+    let transcript = handle.await.unwrap();
+    let login = transcript.iter().position(|l| l == "AUTH LOGIN").unwrap();
+    assert_eq!(transcript[login + 1], BASE64_STANDARD.encode("rig"));
+    assert_eq!(transcript[login + 2], BASE64_STANDARD.encode("pass"));
+}
+
+#[tokio::test]
+async fn test_send_fails_without_supported_auth_mechanism() {
+    let (port, _handle) = fake_smtp(Script {
+        extensions: vec!["AUTH CRAM-MD5"],
+        ..Script::default()
+    })
+    .await;
+    let to = recipients();
+    let error = send(&server(port, Some(("rig", "pass"))), &message(&to))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("authentication"), "{error}");
+}
+
+#[tokio::test]
+async fn test_rejected_auth_error_hides_credentials() {
+    let (port, _handle) = fake_smtp(Script {
+        extensions: vec!["AUTH PLAIN"],
+        reject_auth: true,
+        ..Script::default()
+    })
+    .await;
+    let to = recipients();
+    let error = send(&server(port, Some(("rig", "pass"))), &message(&to))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("535"), "{error}");
+    assert!(
+        !error.contains(&BASE64_STANDARD.encode("\0rig\0pass")),
+        "{error}"
+    );
+}
+
+#[tokio::test]
+async fn test_rejected_recipient_fails_send() {
+    let (port, _handle) = fake_smtp(Script {
+        reject_recipients: true,
+        ..Script::default()
+    })
+    .await;
+    let to = recipients();
+    let error = send(&server(port, None), &message(&to)).await.unwrap_err();
+    assert!(error.to_string().contains("550"), "{error}");
+}
+
+#[tokio::test]
+async fn test_starttls_required_when_configured() {
+    let (port, _handle) = fake_smtp(Script::default()).await;
+    let to = recipients();
+    let server = SmtpServer {
+        security: SmtpSecurity::StartTls,
+        ..server(port, None)
+    };
+    let error = send(&server, &message(&to)).await.unwrap_err();
+    assert!(error.to_string().contains("STARTTLS"), "{error}");
+}
+
+// ==================== Message format ====================
+
+#[test]
+fn test_format_message_encodes_headers_and_body() {
+    let to = recipients();
+    let message = SmtpMessage {
+        from: "rig@example.com",
+        to: &to,
+        subject: "Reçu 1 XTM\r\nBcc: x@evil.com",
+        body: "line one\nline two",
+    };
+    let date = Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap();
+    let data = format_message(&message, date);
+
+    assert!(data.contains("Date: Fri, 2 Jan 2026 03:04:05 +0000\r\n"));
+    assert!(data.contains("To: <me@example.com>, <ops@example.com>\r\n"));
+    assert!(!data.contains("\r\nBcc:"));
+    let subject = BASE64_STANDARD.encode("Reçu 1 XTMBcc: x@evil.com");
+    assert!(data.contains(&format!("Subject: =?UTF-8?B?{subject}?=\r\n")));
+    assert!(data.contains("@example.com>\r\n"));
+
+    let (_, body) = data.split_once("\r\n\r\n").unwrap();
+    let decoded = BASE64_STANDARD.decode(body.replace("\r\n", "")).unwrap();
+    assert_eq!(decoded, b"line one\r\nline two");
+}
+
+#[test]
+fn test_format_message_wraps_long_bodies() {
+    let to = recipients();
+    let body = "x".repeat(500);
+    let message = SmtpMessage {
+        from: "rig@example.com",
+        to: &to,
+        subject: "Long",
+        body: &body,
+    };
+    let data = format_message(&message, Utc::now());
+    let (_, encoded) = data.split_once("\r\n\r\n").unwrap();
+    assert!(encoded.ends_with("\r\n"));
+    assert!(encoded.split("\r\n").all(|line| line.len() <= 76));
+}
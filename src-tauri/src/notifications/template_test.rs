@@ -0,0 +1,166 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Unit tests for notification templates and event fields
+
+use std::collections::BTreeMap;
+
+use super::events::{NotificationEvent, format_hashrate, format_xtm};
+use super::template::render;
+
+fn fields(pairs: &[(&'static str, &str)]) -> BTreeMap<&'static str, String> {
+    pairs.iter().map(|(k, v)| (*k, v.to_string())).collect()
+}
+
+fn all_events() -> Vec<NotificationEvent> {
+    vec![
+        NotificationEvent::MinerCrashed {
+            miner: "xmrig".to_string(),
+            exit_code: Some(137),
+            restarting: true,
+        },
+        NotificationEvent::PoolFailover {
+            from_pool: "LuckyPool".to_string(),
+            to_pool: "Kryptex".to_string(),
+            reason: "unsupported miner".to_string(),
+        },
+        NotificationEvent::HashrateDropped {
+            device: "GPU".to_string(),
+            previous_hashrate: 1_500_000.0,
+            current_hashrate: 500_000.0,
+            drop_percent: 66.7,
+        },
+        NotificationEvent::NodeOutOfSync { block_height: 42 },
+        NotificationEvent::IncomingTransaction {
+            tx_id: "123".to_string(),
+            amount: 2_500_000,
+            block_height: 42,
+            payment_id: "invoice-7".to_string(),
+        },
+        NotificationEvent::BlockWon {
+            block_height: 42,
+            reward: 15_000_000_000,
+        },
+        NotificationEvent::PinLockout {
+            failed_attempts: 3,
+            lockout_secs: 30,
+        },
+    ]
+}
+
+// ==================== Rendering ====================
+
+#[test]
+fn test_render_replaces_placeholders() {
+    let output = render(
+        "Block {{block_height}} for {{ reward }}",
+        &fields(&[("block_height", "42"), ("reward", "1 XTM")]),
+    );
+    assert_eq!(output, "Block 42 for 1 XTM");
+}
+
+#[test]
+fn test_render_keeps_unknown_placeholders() {
+    let output = render("Hello {{nobody}}!", &fields(&[]));
+    assert_eq!(output, "Hello {{nobody}}!");
+}
+
+#[test]
+fn test_render_keeps_unclosed_placeholder() {
+    let output = render("{{a}} and {{b", &fields(&[("a", "1"), ("b", "2")]));
+    assert_eq!(output, "1 and {{b");
+}
+
+#[test]
+fn test_render_does_not_expand_values() {
+    let output = render("{{a}}", &fields(&[("a", "{{b}}"), ("b", "nested")]));
+    assert_eq!(output, "{{b}}");
+}
+
+#[test]
+fn test_render_handles_adjacent_and_unicode_text() {
+    let output = render("ⓣ{{a}}{{b}}ⓣ", &fields(&[("a", "1"), ("b", "2")]));
+    assert_eq!(output, "ⓣ12ⓣ");
+}
+
+// ==================== Event defaults ====================
+
+#[test]
+fn test_default_templates_use_only_known_fields() {
+    for event in all_events() {
+        let fields = event.fields();
+        let title = render(event.default_title(), &fields);
+        let body = render(event.default_body(), &fields);
+        assert!(!title.contains("{{"), "{title}");
+        assert!(!body.contains("{{"), "{body}");
+    }
+}
+
+#[test]
+fn test_block_won_body_formats_reward() {
+    let event = NotificationEvent::BlockWon {
+        block_height: 42,
+        reward: 15_000_000_000,
+    };
+    assert_eq!(
+        render(event.default_body(), &event.fields()),
+        "Your rig won block 42 for a reward of 15000.000000 XTM."
+    );
+}
+
+#[test]
+fn test_miner_crash_without_exit_code() {
+    let event = NotificationEvent::MinerCrashed {
+        miner: "lolminer".to_string(),
+        exit_code: None,
+        restarting: false,
+    };
+    let fields = event.fields();
+    assert_eq!(fields["exit_code"], "unknown");
+    assert_eq!(fields["action"], "It will not be restarted automatically.");
+}
+
+#[test]
+fn test_event_serializes_to_its_fields() {
+    let event = NotificationEvent::PinLockout {
+        failed_attempts: 4,
+        lockout_secs: 120,
+    };
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        serde_json::json!({ "failed_attempts": 4, "lockout_secs": 120 })
+    );
+}
+
+// ==================== Formatting ====================
+
+#[test]
+fn test_format_xtm() {
+    assert_eq!(format_xtm(0), "0.000000 XTM");
+    assert_eq!(format_xtm(1_234_567), "1.234567 XTM");
+}
+
+#[test]
+fn test_format_hashrate_picks_unit() {
+    assert_eq!(format_hashrate(950.0), "950.00 H/s");
+    assert_eq!(format_hashrate(1_500.0), "1.50 kH/s");
+    assert_eq!(format_hashrate(2_000_000_000.0), "2.00 GH/s");
+}
@@ -30,6 +30,7 @@ use crate::configs::{
     config_wallet::{ConfigWallet, ConfigWalletContent},
     trait_config::ConfigImpl,
 };
+use crate::notifications::{NotificationEvent, Notifications};
 
 pub struct PinLocker {
     state: PinLockerState,
@@ -50,7 +51,15 @@ impl PinLocker {
             ConfigWalletContent::set_pin_locker_state,
             self.state.clone(),
         )
-        .await
+        .await?;
+        if let Some(lockout) = self.state.pin_lockout_duration() {
+            Notifications::notify(NotificationEvent::PinLockout {
+                failed_attempts: self.state.failed_pin_attempts,
+                lockout_secs: lockout.as_secs(),
+            })
+            .await;
+        }
+        Ok(())
     }
 
     pub async fn reset_pin_attempts(&mut self) -> Result<(), anyhow::Error> {
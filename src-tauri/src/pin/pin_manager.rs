@@ -27,6 +27,8 @@ use tokio::sync::oneshot;
 use crate::{
     LOG_TARGET_APP_LOGIC,
     configs::{config_wallet::ConfigWallet, trait_config::ConfigImpl},
+    credential_manager::{CredentialBackend, CredentialError, CredentialManager},
+    credential_vault::CredentialVault,
     events_emitter::EventsEmitter,
     internal_wallet::InternalWallet,
     pin::pin_locker::PinLocker,
@@ -69,6 +71,15 @@ impl PinManager {
             ));
         }
 
+        // The vault passphrase follows the PIN, so the same entry unlocks it and the seed can be read
+        if CredentialManager::backend().await == CredentialBackend::EncryptedFile
+            && !CredentialVault::is_unlocked().await
+            && CredentialVault::exists().await
+            && let Err(e) = CredentialVault::unlock(&pin_password).await
+        {
+            log::info!(target: LOG_TARGET_APP_LOGIC, "Credential vault could not be unlocked with the entered PIN: {e}");
+        }
+
         let wallet_config = ConfigWallet::content().await;
         // TODO: We can set a flag to validate against monero so user don't need to enter kerying twice
 
@@ -112,6 +123,42 @@ impl PinManager {
         Ok(SafePassword::from(pin))
     }
 
+    /// Makes sure the encrypted credential vault is unlocked, asking for its passphrase through the
+    /// PIN dialog. On first use the vault is created with a new PIN.
+    pub async fn unlock_credential_vault(app_handle: &AppHandle) -> Result<(), anyhow::Error> {
+        if CredentialVault::is_unlocked().await {
+            return Ok(());
+        }
+        if !CredentialVault::exists().await {
+            log::info!(target: LOG_TARGET_APP_LOGIC, "Creating encrypted credential vault");
+            let passphrase = SafePassword::from(create_pin_dialog(app_handle).await?);
+            CredentialVault::create(&passphrase).await?;
+            return Ok(());
+        }
+
+        let pin_locker_state = ConfigWallet::content().await.pin_locker_state().clone();
+        let mut pin_locker = PinLocker::new(pin_locker_state);
+        if let Some(remaining_seconds) = pin_locker.locked_out_seconds().await {
+            return Err(anyhow::anyhow!(
+                "Pin is locked out. Remaining seconds: {}",
+                remaining_seconds
+            ));
+        }
+        let passphrase = SafePassword::from(enter_pin_dialog(app_handle).await?);
+        match CredentialVault::unlock(&passphrase).await {
+            Ok(()) => {
+                pin_locker.reset_pin_attempts().await?;
+                log::info!(target: LOG_TARGET_APP_LOGIC, "Credential vault unlocked");
+                Ok(())
+            }
+            Err(CredentialError::WrongPassphrase) => {
+                pin_locker.register_failed_pin_attempt().await?;
+                Err(anyhow::anyhow!("Wrong PIN entered!"))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn set_pin_locked() -> Result<(), anyhow::Error> {
         let pin_locker_state = ConfigWallet::content().await.pin_locker_state().clone();
         let mut pin_locker = PinLocker::new(pin_locker_state);
@@ -58,7 +58,7 @@ impl PortAllocator {
         }
     }
 
-    fn check_if_port_is_free(&self, port: u16) -> bool {
+    pub fn check_if_port_is_free(&self, port: u16) -> bool {
         TcpListener::bind(format!("{ADDRESS}:{port}")).is_ok()
     }
 
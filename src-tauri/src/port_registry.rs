@@ -0,0 +1,268 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Persistent port assignments for the managed services.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use anyhow::anyhow;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::binaries::Binaries;
+use crate::configs::config_core::{ConfigCore, ConfigCoreContent};
+use crate::configs::trait_config::ConfigImpl;
+use crate::port_allocator::PortAllocator;
+
+/// Attempts at finding a port not assigned to another service before giving up
+const MAX_ALLOCATION_ATTEMPTS: u32 = 10;
+
+/// Serializes assignments so services starting together can't be handed the same port
+static ASSIGNMENT_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServicePort {
+    NodeGrpc,
+    NodeP2p,
+    NodeHttpApi,
+    TorSocks,
+    TorControl,
+    WalletGrpc,
+    MmProxy,
+    XmrigApi,
+    LolMinerApi,
+}
+
+impl ServicePort {
+    pub const ALL: [ServicePort; 9] = [
+        ServicePort::NodeGrpc,
+        ServicePort::NodeP2p,
+        ServicePort::NodeHttpApi,
+        ServicePort::TorSocks,
+        ServicePort::TorControl,
+        ServicePort::WalletGrpc,
+        ServicePort::MmProxy,
+        ServicePort::XmrigApi,
+        ServicePort::LolMinerApi,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ServicePort::NodeGrpc => "node_grpc",
+            ServicePort::NodeP2p => "node_p2p",
+            ServicePort::NodeHttpApi => "node_http_api",
+            ServicePort::TorSocks => "tor_socks",
+            ServicePort::TorControl => "tor_control",
+            ServicePort::WalletGrpc => "wallet_grpc",
+            ServicePort::MmProxy => "mm_proxy",
+            ServicePort::XmrigApi => "xmrig_api",
+            ServicePort::LolMinerApi => "lolminer_api",
+        }
+    }
+
+    pub fn try_from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|service| service.name() == name)
+    }
+
+    /// Binary listening on the port
+    pub fn binary(&self) -> Binaries {
+        match self {
+            ServicePort::NodeGrpc | ServicePort::NodeP2p | ServicePort::NodeHttpApi => {
+                Binaries::MinotariNode
+            }
+            ServicePort::TorSocks | ServicePort::TorControl => Binaries::Tor,
+            ServicePort::WalletGrpc => Binaries::Wallet,
+            ServicePort::MmProxy => Binaries::MergeMiningProxy,
+            ServicePort::XmrigApi => Binaries::Xmrig,
+            ServicePort::LolMinerApi => Binaries::LolMiner,
+        }
+    }
+}
+
+impl Display for ServicePort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PortAssignment {
+    pub port: u16,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// Entry of the port map reported to the frontend and MCP clients
+#[derive(Debug, Clone, Serialize)]
+pub struct ServicePortInfo {
+    pub service: ServicePort,
+    pub binary: String,
+    /// `None` until the service has been started once
+    pub port: Option<u16>,
+    pub pinned: bool,
+}
+
+/// Picks the port for `service` from the persisted `assignments`.
+///
+/// A pinned port is always used, a persisted one is kept while it's free and not assigned to
+/// another service, otherwise `allocate` is asked for a new port.
+pub(crate) fn resolve_port(
+    assignments: &HashMap<String, PortAssignment>,
+    service: ServicePort,
+    is_free: impl Fn(u16) -> bool,
+    mut allocate: impl FnMut() -> u16,
+) -> Result<u16, anyhow::Error> {
+    let taken_by_other = |port: u16| {
+        assignments
+            .iter()
+            .find(|(name, assignment)| name.as_str() != service.name() && assignment.port == port)
+            .map(|(name, _)| name.clone())
+    };
+
+    if let Some(assignment) = assignments.get(service.name()) {
+        if assignment.pinned {
+            if let Some(other) = taken_by_other(assignment.port) {
+                return Err(anyhow!(
+                    "Port {} pinned for {service} is also assigned to {other}",
+                    assignment.port
+                ));
+            }
+            return Ok(assignment.port);
+        }
+        if assignment.port != 0 && taken_by_other(assignment.port).is_none() {
+            if is_free(assignment.port) {
+                return Ok(assignment.port);
+            }
+            warn!(target: LOG_TARGET_APP_LOGIC, "Port {} of {service} is in use, assigning a new one", assignment.port);
+        }
+    }
+
+    for _ in 0..MAX_ALLOCATION_ATTEMPTS {
+        let port = allocate();
+        if port != 0 && taken_by_other(port).is_none() {
+            return Ok(port);
+        }
+    }
+    Err(anyhow!("Failed to assign a port to {service}"))
+}
+
+/// Checks a port requested by the user against the ports pinned by other services
+pub(crate) fn validate_pin(
+    assignments: &HashMap<String, PortAssignment>,
+    service: ServicePort,
+    port: u16,
+) -> Result<(), anyhow::Error> {
+    if port == 0 {
+        return Err(anyhow!("Port must be greater than 0"));
+    }
+    match assignments.iter().find(|(name, assignment)| {
+        name.as_str() != service.name() && assignment.pinned && assignment.port == port
+    }) {
+        Some((other, _)) => Err(anyhow!("Port {port} is already pinned for {other}")),
+        None => Ok(()),
+    }
+}
+
+/// Returns the port `service` should use for its next start, persisting new assignments
+pub async fn assign_port(service: ServicePort) -> Result<u16, anyhow::Error> {
+    let _lock = ASSIGNMENT_LOCK.lock().await;
+    let mut assignments = ConfigCore::content().await.service_ports().clone();
+    let allocator = PortAllocator::new();
+    let port = resolve_port(
+        &assignments,
+        service,
+        |port| allocator.check_if_port_is_free(port),
+        || allocator.assign_port_with_fallback(),
+    )?;
+
+    if assignments
+        .get(service.name())
+        .map(|assignment| assignment.port)
+        != Some(port)
+    {
+        info!(target: LOG_TARGET_APP_LOGIC, "Assigned port {port} to {service}");
+        assignments.insert(
+            service.name().to_string(),
+            PortAssignment {
+                port,
+                pinned: false,
+            },
+        );
+        ConfigCore::update_field(ConfigCoreContent::set_service_ports, assignments).await?;
+    }
+    Ok(port)
+}
+
+/// Records a port configured outside the registry, so the reported port map stays accurate
+pub async fn record_port(service: ServicePort, port: u16) -> Result<(), anyhow::Error> {
+    let _lock = ASSIGNMENT_LOCK.lock().await;
+    let mut assignments = ConfigCore::content().await.service_ports().clone();
+    if assignments
+        .get(service.name())
+        .map(|assignment| assignment.port)
+        == Some(port)
+    {
+        return Ok(());
+    }
+    assignments.insert(
+        service.name().to_string(),
+        PortAssignment {
+            port,
+            pinned: false,
+        },
+    );
+    ConfigCore::update_field(ConfigCoreContent::set_service_ports, assignments).await
+}
+
+/// Fails when one of the ports a process is about to listen on is taken by another program
+pub fn ensure_ports_available(ports: &[(ServicePort, u16)]) -> Result<(), anyhow::Error> {
+    let allocator = PortAllocator::new();
+    for (service, port) in ports {
+        if !allocator.check_if_port_is_free(*port) {
+            return Err(anyhow!(
+                "Port {port} for {service} is already in use by another program"
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub async fn service_port_map() -> Vec<ServicePortInfo> {
+    let assignments = ConfigCore::content().await.service_ports().clone();
+    ServicePort::ALL
+        .into_iter()
+        .map(|service| {
+            let assignment = assignments.get(service.name());
+            ServicePortInfo {
+                service,
+                binary: service.binary().name().to_string(),
+                port: assignment.map(|assignment| assignment.port),
+                pinned: assignment.is_some_and(|assignment| assignment.pinned),
+            }
+        })
+        .collect()
+}
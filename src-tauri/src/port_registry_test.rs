@@ -0,0 +1,143 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for port_registry
+
+use std::collections::HashMap;
+
+use crate::port_registry::{PortAssignment, ServicePort, resolve_port, validate_pin};
+
+fn assignments(entries: &[(ServicePort, u16, bool)]) -> HashMap<String, PortAssignment> {
+    entries
+        .iter()
+        .map(|(service, port, pinned)| {
+            (
+                service.name().to_string(),
+                PortAssignment {
+                    port: *port,
+                    pinned: *pinned,
+                },
+            )
+        })
+        .collect()
+}
+
+// ==================== Resolving ====================
+
+#[test]
+fn reuses_persisted_port_while_free() {
+    let assignments = assignments(&[(ServicePort::NodeGrpc, 18142, false)]);
+
+    let port = resolve_port(&assignments, ServicePort::NodeGrpc, |_| true, || 50000).unwrap();
+
+    assert_eq!(port, 18142);
+}
+
+#[test]
+fn allocates_new_port_when_persisted_one_is_taken() {
+    let assignments = assignments(&[(ServicePort::XmrigApi, 18000, false)]);
+
+    let port = resolve_port(
+        &assignments,
+        ServicePort::XmrigApi,
+        |port| port != 18000,
+        || 50001,
+    )
+    .unwrap();
+
+    assert_eq!(port, 50001);
+}
+
+#[test]
+fn allocates_port_for_unknown_service() {
+    let port = resolve_port(&HashMap::new(), ServicePort::TorSocks, |_| true, || 50002).unwrap();
+
+    assert_eq!(port, 50002);
+}
+
+#[test]
+fn skips_ports_assigned_to_other_services() {
+    let assignments = assignments(&[(ServicePort::WalletGrpc, 50003, false)]);
+    let mut candidates = [50003, 50004].into_iter();
+
+    let port = resolve_port(
+        &assignments,
+        ServicePort::MmProxy,
+        |_| true,
+        || candidates.next().unwrap_or(0),
+    )
+    .unwrap();
+
+    assert_eq!(port, 50004);
+}
+
+#[test]
+fn pinned_port_is_used_even_when_busy() {
+    // Whether it's actually free is checked right before spawning
+    let assignments = assignments(&[(ServicePort::NodeHttpApi, 9000, true)]);
+
+    let port = resolve_port(&assignments, ServicePort::NodeHttpApi, |_| false, || 50005).unwrap();
+
+    assert_eq!(port, 9000);
+}
+
+#[test]
+fn pinned_port_conflicting_with_another_service_fails() {
+    let assignments = assignments(&[
+        (ServicePort::NodeGrpc, 9000, true),
+        (ServicePort::WalletGrpc, 9000, false),
+    ]);
+
+    let result = resolve_port(&assignments, ServicePort::NodeGrpc, |_| true, || 50006);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn gives_up_when_no_port_can_be_allocated() {
+    let result = resolve_port(&HashMap::new(), ServicePort::LolMinerApi, |_| true, || 0);
+
+    assert!(result.is_err());
+}
+
+// ==================== Pinning ====================
+
+#[test]
+fn rejects_port_pinned_by_another_service() {
+    let assignments = assignments(&[
+        (ServicePort::TorControl, 9051, true),
+        (ServicePort::TorSocks, 9050, false),
+    ]);
+
+    assert!(validate_pin(&assignments, ServicePort::NodeGrpc, 9051).is_err());
+    assert!(validate_pin(&assignments, ServicePort::NodeGrpc, 9050).is_ok());
+    assert!(validate_pin(&assignments, ServicePort::TorControl, 9051).is_ok());
+    assert!(validate_pin(&assignments, ServicePort::NodeGrpc, 0).is_err());
+}
+
+#[test]
+fn service_names_round_trip() {
+    for service in ServicePort::ALL {
+        assert_eq!(ServicePort::try_from_name(service.name()), Some(service));
+    }
+    assert_eq!(ServicePort::try_from_name("unknown"), None);
+}
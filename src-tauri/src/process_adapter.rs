@@ -24,6 +24,7 @@ use anyhow::{Error, anyhow};
 use async_trait::async_trait;
 use futures_util::future::FusedFuture;
 use log::{error, info, warn};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs;
@@ -41,7 +42,17 @@ use crate::LOG_TARGET_APP_LOGIC;
 use crate::download_utils::set_permissions;
 use crate::events::CriticalProblemPayload;
 use crate::events_emitter::EventsEmitter;
+use crate::port_registry::ServicePort;
+use crate::process_exit_status::{
+    ProcessExitStatus, exit_status_file, read_exit_status_file, record_exit_status,
+};
+use crate::process_inventory::{record_exit, record_spawn};
 use crate::process_killer::kill_process;
+use crate::process_output::{
+    CRASH_REPORT_LINES, capture_output, last_output_lines, output_log_for, redact_output_line,
+};
+use crate::process_resource_limits::{prepare_limits, release_limits};
+use crate::process_restart_policy::RestartPolicy;
 use crate::process_utils::{graceful_kill, launch_child_process, write_pid_file};
 
 const SPACE_ERROR_MESSAGE: &str = "No space left on device";
@@ -80,6 +91,21 @@ pub(crate) trait ProcessAdapter {
 
     fn pid_file_name(&self) -> &str;
 
+    /// Log file written by the process, its tail is attached to crash reports
+    fn log_file_path(&self, _log_folder: &Path) -> Option<PathBuf> {
+        None
+    }
+
+    /// Ports the process listens on, checked before it is spawned
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        Vec::new()
+    }
+
+    /// How the process watcher backs off and when it stops restarting the process
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::default()
+    }
+
     #[allow(dead_code)]
     fn pid_file_exisits(&self, base_folder: PathBuf) -> bool {
         std::path::Path::new(&base_folder)
@@ -150,7 +176,8 @@ pub(crate) trait ProcessAdapter {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum HealthStatus {
     Healthy,
     Warning,
@@ -196,6 +223,7 @@ pub(crate) struct ProcessStartupSpec {
     pub args: Vec<String>,
     pub pid_file_name: String,
     pub data_dir: PathBuf,
+    pub log_dir: PathBuf,
     pub name: String,
 }
 
@@ -238,13 +266,27 @@ impl ProcessInstanceTrait for ProcessInstance {
             }
             // start
             info!(target: LOG_TARGET_APP_LOGIC, "Launching process for: {}", spec.name);
-            let mut child = launch_child_process(
+            let resource_limits = prepare_limits(&spec.name).await;
+            let status_file = exit_status_file(&spec.data_dir, &spec.name);
+            // A leftover file from a previous run would be mistaken for this one's
+            let _unused = fs::remove_file(&status_file);
+            let mut child = match launch_child_process(
                 &spec.file_path,
                 spec.data_dir.as_path(),
                 spec.envs.as_ref(),
                 &spec.args,
-                false
-            )?;
+                resource_limits.as_ref(),
+                Some(&status_file),
+            ) {
+                Ok(child) => child,
+                Err(e) => {
+                    release_limits(&spec.name).await;
+                    return Err(e);
+                }
+            };
+            let output_log = output_log_for(&spec.name, &spec.log_dir).await;
+            capture_output(&mut child, &output_log);
+            record_spawn(&spec.name, child.id(), &spec.file_path, &spec.args).await;
 
             if let Some(id) = child.id() {
                 let pid_file_res = write_pid_file(&spec, id);
@@ -267,22 +309,37 @@ impl ProcessInstanceTrait for ProcessInstance {
             select! {
                 _res = shutdown_signal =>{
                     graceful_kill(&mut child).await?;
+                    let exit_status = read_exit_status_file(&status_file)
+                        .unwrap_or_else(ProcessExitStatus::terminated);
+                    record_exit(&spec.name, Some(exit_status.clone())).await;
+                    record_exit_status(&spec.name, exit_status).await;
                     exit_code = 0;
                 },
                 res2 = child.wait() => {
                     match res2
                      {
                         Ok(res) => {
-                            exit_code = res.code().unwrap_or(0)
+                            let exit_status = read_exit_status_file(&status_file)
+                                .unwrap_or_else(|| ProcessExitStatus::from_exit_status(&res));
+                            exit_code = exit_status.code();
+                            if exit_code != 0 {
+                                report_crash(&spec.name, &exit_status).await;
+                            }
+                            record_exit(&spec.name, Some(exit_status.clone())).await;
+                            record_exit_status(&spec.name, exit_status).await;
                             },
                         Err(e) => {
                             warn!(target: LOG_TARGET_APP_LOGIC, "Error in process instance {}:  {}", spec.name, e);
+                            record_exit(&spec.name, None).await;
+                            release_limits(&spec.name).await;
                             return Err(e.into());
                         }
                     }
                 },
             }
+            release_limits(&spec.name).await;
             info!(target: LOG_TARGET_APP_LOGIC, "Stopping {} process with exit code: {}", spec.name, exit_code);
+            let _unused = fs::remove_file(&status_file);
 
             if let Err(error) = fs::remove_file(spec.data_dir.join(spec.pid_file_name)) {
                 warn!(target: LOG_TARGET_APP_LOGIC, "Could not clear {}'s pid file: {:?}", spec.name, error);
@@ -316,7 +373,8 @@ impl ProcessInstanceTrait for ProcessInstance {
             spec.data_dir.as_path(),
             spec.envs.as_ref(),
             &spec.args,
-            true,
+            None,
+            None,
         )?;
 
         if let Some(id) = child.id() {
@@ -412,3 +470,21 @@ impl Drop for ProcessInstance {
         }
     }
 }
+
+async fn report_crash(name: &str, exit_status: &ProcessExitStatus) {
+    let message = format!("{name} {exit_status} unexpectedly");
+    let output = last_output_lines(name, CRASH_REPORT_LINES).await;
+    if output.is_empty() {
+        warn!(target: LOG_TARGET_APP_LOGIC, "{message}");
+        sentry::capture_message(&message, sentry::Level::Error);
+        return;
+    }
+
+    warn!(target: LOG_TARGET_APP_LOGIC, "{message}\n\nLast output:\n{}", output.join("\n"));
+    // The report leaves the machine, so addresses, paths and secrets are stripped from the output
+    let redacted: Vec<String> = output.iter().map(|line| redact_output_line(line)).collect();
+    sentry::capture_message(
+        &format!("{message}\n\nLast output:\n{}", redacted.join("\n")),
+        sentry::Level::Error,
+    );
+}
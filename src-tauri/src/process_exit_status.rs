@@ -0,0 +1,163 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Exit status and resource usage of managed processes.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+/// Environment variable telling the wrapper where to write its status file
+pub const WRAPPER_STATUS_FILE_ENV: &str = "PROCESS_WRAPPER_STATUS_FILE";
+
+static EXIT_STATUSES: LazyLock<RwLock<HashMap<String, ProcessExitStatus>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitCause {
+    /// The process exited on its own
+    #[default]
+    Exited,
+    /// The process was killed by a signal
+    Signaled,
+    /// The app died and the wrapper terminated the process
+    ParentDied,
+    /// The process was stopped on request
+    Terminated,
+}
+
+impl Display for ExitCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExitCause::Exited => write!(f, "exited"),
+            ExitCause::Signaled => write!(f, "signaled"),
+            ExitCause::ParentDied => write!(f, "parent_died"),
+            ExitCause::Terminated => write!(f, "terminated"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ProcessExitStatus {
+    pub cause: ExitCause,
+    pub exit_code: Option<i32>,
+    pub signal: Option<i32>,
+    pub user_cpu_ms: Option<u64>,
+    pub system_cpu_ms: Option<u64>,
+    pub max_rss_kb: Option<u64>,
+    pub read_bytes: Option<u64>,
+    pub write_bytes: Option<u64>,
+}
+
+impl ProcessExitStatus {
+    pub fn from_exit_status(status: &std::process::ExitStatus) -> Self {
+        #[cfg(unix)]
+        let signal = std::os::unix::process::ExitStatusExt::signal(status);
+        #[cfg(not(unix))]
+        let signal = None;
+
+        Self {
+            cause: if signal.is_some() {
+                ExitCause::Signaled
+            } else {
+                ExitCause::Exited
+            },
+            exit_code: status.code(),
+            signal,
+            ..Default::default()
+        }
+    }
+
+    pub fn terminated() -> Self {
+        Self {
+            cause: ExitCause::Terminated,
+            ..Default::default()
+        }
+    }
+
+    /// Exit code as reported by a shell, `128 + signal` for processes killed by a signal
+    pub fn code(&self) -> i32 {
+        self.exit_code
+            .or(self.signal.map(|signal| 128 + signal))
+            .unwrap_or(0)
+    }
+
+    /// Combined user and system CPU time, when the wrapper reported it
+    pub fn cpu_time(&self) -> Option<Duration> {
+        match (self.user_cpu_ms, self.system_cpu_ms) {
+            (None, None) => None,
+            (user, system) => Some(Duration::from_millis(
+                user.unwrap_or(0) + system.unwrap_or(0),
+            )),
+        }
+    }
+}
+
+impl Display for ProcessExitStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.cause {
+            ExitCause::Exited => write!(f, "exited with code {}", self.code()),
+            ExitCause::Signaled => match self.signal {
+                Some(signal) => write!(f, "killed by signal {}", signal_name(signal)),
+                None => write!(f, "killed by a signal"),
+            },
+            ExitCause::ParentDied => write!(f, "terminated after the app exited"),
+            ExitCause::Terminated => write!(f, "stopped on request"),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn signal_name(signal: i32) -> String {
+    nix::sys::signal::Signal::try_from(signal)
+        .map_or_else(|_| signal.to_string(), |name| format!("{signal} ({name})"))
+}
+
+#[cfg(not(unix))]
+fn signal_name(signal: i32) -> String {
+    signal.to_string()
+}
+
+pub fn exit_status_file(data_dir: &Path, name: &str) -> PathBuf {
+    data_dir.join(format!("{name}.exit.json"))
+}
+
+pub fn read_exit_status_file(path: &Path) -> Option<ProcessExitStatus> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+pub async fn record_exit_status(name: &str, status: ProcessExitStatus) {
+    EXIT_STATUSES.write().await.insert(name.to_string(), status);
+}
+
+/// Takes the exit status of the last run of a process, so each exit is only accounted once
+pub async fn take_exit_status(name: &str) -> Option<ProcessExitStatus> {
+    EXIT_STATUSES.write().await.remove(name)
+}
@@ -0,0 +1,102 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for process_exit_status
+
+use std::time::Duration;
+
+use crate::process_exit_status::{
+    ExitCause, ProcessExitStatus, exit_status_file, read_exit_status_file,
+};
+use crate::testing::test_utils::TestContext;
+
+// ==================== Wrapper status file ====================
+
+#[test]
+fn reads_wrapper_status_file() {
+    let ctx = TestContext::new();
+    let path = exit_status_file(&ctx.data_dir, "tor");
+    std::fs::write(
+        &path,
+        r#"{"cause":"signaled","exit_code":null,"signal":9,"user_cpu_ms":1200,"system_cpu_ms":300,"max_rss_kb":51200,"read_bytes":4096,"write_bytes":null}"#,
+    )
+    .unwrap();
+
+    let status = read_exit_status_file(&path).unwrap();
+
+    assert_eq!(status.cause, ExitCause::Signaled);
+    assert_eq!(status.signal, Some(9));
+    assert_eq!(status.code(), 137);
+    assert_eq!(status.cpu_time(), Some(Duration::from_millis(1500)));
+    assert_eq!(status.max_rss_kb, Some(51200));
+    assert_eq!(status.write_bytes, None);
+}
+
+#[test]
+fn missing_or_partial_status_file_is_ignored() {
+    let ctx = TestContext::new();
+    let path = exit_status_file(&ctx.data_dir, "wallet");
+
+    assert!(read_exit_status_file(&path).is_none());
+
+    std::fs::write(&path, r#"{"cause":"exi"#).unwrap();
+    assert!(read_exit_status_file(&path).is_none());
+}
+
+// ==================== Exit status ====================
+
+#[test]
+fn describes_cause_of_death() {
+    let exited = ProcessExitStatus {
+        exit_code: Some(3),
+        ..Default::default()
+    };
+    let parent_died = ProcessExitStatus {
+        cause: ExitCause::ParentDied,
+        ..Default::default()
+    };
+
+    assert_eq!(exited.to_string(), "exited with code 3");
+    assert_eq!(parent_died.to_string(), "terminated after the app exited");
+    assert_eq!(
+        ProcessExitStatus::terminated().to_string(),
+        "stopped on request"
+    );
+    assert_eq!(exited.cpu_time(), None);
+}
+
+#[cfg(unix)]
+#[test]
+fn derives_status_from_signal() {
+    use std::os::unix::process::ExitStatusExt;
+
+    let status = ProcessExitStatus::from_exit_status(&std::process::ExitStatus::from_raw(9));
+
+    assert_eq!(status.cause, ExitCause::Signaled);
+    assert_eq!(status.exit_code, None);
+    assert_eq!(status.code(), 137);
+    assert_eq!(status.to_string(), "killed by signal 9 (SIGKILL)");
+
+    let status = ProcessExitStatus::from_exit_status(&std::process::ExitStatus::from_raw(2 << 8));
+    assert_eq!(status.cause, ExitCause::Exited);
+    assert_eq!(status.code(), 2);
+}
@@ -0,0 +1,335 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Inventory of the managed processes.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::SystemTime;
+
+use anyhow::anyhow;
+use log::info;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
+
+use crate::binaries::{Binaries, BinaryResolver};
+use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::manager::GpuManager;
+use crate::port_registry::ServicePort;
+use crate::process_adapter::HealthStatus;
+use crate::process_exit_status::{ExitCause, ProcessExitStatus};
+use crate::process_watcher::ProcessWatcherStats;
+use crate::setup::setup_manager::SetupManager;
+use crate::{LOG_TARGET_APP_LOGIC, UniverseAppState};
+
+const REDACTED: &str = "<redacted>";
+/// Argument name words marking the argument, or the one after it, as a secret
+const SECRET_ARGUMENT_SEGMENTS: [&str; 8] = [
+    "token", "password", "passwd", "pass", "secret", "key", "seed", "auth",
+];
+
+/// Processes listed in the inventory, with the binary they run
+const MANAGED_PROCESSES: [(&str, Binaries); 6] = [
+    ("local_minotari_node", Binaries::MinotariNode),
+    ("tor", Binaries::Tor),
+    ("wallet", Binaries::Wallet),
+    ("minotari_merge_mining_proxy", Binaries::MergeMiningProxy),
+    ("xmrig", Binaries::Xmrig),
+    ("lolminer", Binaries::LolMiner),
+];
+
+static INVENTORY: LazyLock<RwLock<HashMap<String, InventoryRecord>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, Default)]
+struct InventoryRecord {
+    pid: Option<u32>,
+    binary_path: Option<PathBuf>,
+    command_line: Vec<String>,
+    started_at: Option<SystemTime>,
+    ports: Vec<(ServicePort, u16)>,
+    stats: ProcessWatcherStats,
+    last_exit_status: Option<ProcessExitStatus>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessPort {
+    pub service: ServicePort,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessInventoryEntry {
+    pub name: String,
+    pub binary: String,
+    pub running: bool,
+    /// PID of the spawned process, which is the process-wrapper sidecar when it is used
+    pub pid: Option<u32>,
+    pub binary_path: Option<PathBuf>,
+    pub version: Option<String>,
+    /// Arguments with secrets redacted
+    pub command_line: Vec<String>,
+    pub ports: Vec<ProcessPort>,
+    pub uptime_secs: Option<u64>,
+    pub health: Option<HealthStatus>,
+    pub restarts: u64,
+    pub last_exit_code: Option<i32>,
+    pub last_exit_cause: Option<ExitCause>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessControlAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl Display for ProcessControlAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessControlAction::Start => write!(f, "start"),
+            ProcessControlAction::Stop => write!(f, "stop"),
+            ProcessControlAction::Restart => write!(f, "restart"),
+        }
+    }
+}
+
+/// Splits an argument name into lowercase words, on separators and CamelCase boundaries
+/// (tor takes options like `--HashedControlPassword`)
+fn argument_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if (!c.is_ascii_alphanumeric() || (c.is_ascii_uppercase() && previous_lowercase))
+            && !word.is_empty()
+        {
+            words.push(std::mem::take(&mut word));
+        }
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_lowercase());
+        }
+        previous_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn is_secret_argument(name: &str) -> bool {
+    argument_words(name)
+        .iter()
+        .any(|word| SECRET_ARGUMENT_SEGMENTS.contains(&word.as_str()))
+}
+
+/// Redacts everything after the first secret `name=` in `arg`. Values can nest their own
+/// assignments (`control_auth=password=<secret>`), so those are checked by their last word.
+fn redact_assignment(arg: &str) -> Option<String> {
+    arg.match_indices('=')
+        .enumerate()
+        .find_map(|(index, (position, _))| {
+            let name = &arg[..position];
+            let is_secret = if index == 0 {
+                is_secret_argument(name)
+            } else {
+                argument_words(name)
+                    .last()
+                    .is_some_and(|word| SECRET_ARGUMENT_SEGMENTS.contains(&word.as_str()))
+            };
+            is_secret.then(|| format!("{name}={REDACTED}"))
+        })
+}
+
+/// Replaces the values of secret arguments, both `--name=value` and `--name value` forms
+pub(crate) fn redact_command_line(args: &[String]) -> Vec<String> {
+    let mut redact_next = false;
+    args.iter()
+        .map(|arg| {
+            if redact_next {
+                redact_next = false;
+                return REDACTED.to_string();
+            }
+            if let Some(redacted) = redact_assignment(arg) {
+                return redacted;
+            }
+            redact_next = !arg.contains('=') && arg.starts_with('-') && is_secret_argument(arg);
+            arg.clone()
+        })
+        .collect()
+}
+
+pub(crate) async fn record_spawn(
+    name: &str,
+    pid: Option<u32>,
+    binary_path: &Path,
+    args: &[String],
+) {
+    let mut inventory = INVENTORY.write().await;
+    let record = inventory.entry(name.to_string()).or_default();
+    record.pid = pid;
+    record.binary_path = Some(binary_path.to_path_buf());
+    record.command_line = redact_command_line(args);
+    record.started_at = Some(SystemTime::now());
+}
+
+pub(crate) async fn record_exit(name: &str, exit_status: Option<ProcessExitStatus>) {
+    let mut inventory = INVENTORY.write().await;
+    let record = inventory.entry(name.to_string()).or_default();
+    record.pid = None;
+    record.started_at = None;
+    if exit_status.is_some() {
+        record.last_exit_status = exit_status;
+    }
+}
+
+pub(crate) async fn record_ports(name: &str, ports: Vec<(ServicePort, u16)>) {
+    INVENTORY
+        .write()
+        .await
+        .entry(name.to_string())
+        .or_default()
+        .ports = ports;
+}
+
+pub(crate) async fn record_stats(name: &str, stats: &ProcessWatcherStats) {
+    INVENTORY
+        .write()
+        .await
+        .entry(name.to_string())
+        .or_default()
+        .stats = stats.clone();
+}
+
+/// Inventory of the managed processes, without binary versions
+pub(crate) async fn inventory_entries() -> Vec<ProcessInventoryEntry> {
+    let inventory = INVENTORY.read().await;
+    MANAGED_PROCESSES
+        .iter()
+        .map(|(name, binary)| {
+            let record = inventory.get(*name).cloned().unwrap_or_default();
+            let running = record.pid.is_some();
+            ProcessInventoryEntry {
+                name: (*name).to_string(),
+                binary: binary.name().to_string(),
+                running,
+                pid: record.pid,
+                binary_path: record.binary_path,
+                version: None,
+                command_line: record.command_line,
+                ports: record
+                    .ports
+                    .into_iter()
+                    .map(|(service, port)| ProcessPort { service, port })
+                    .collect(),
+                uptime_secs: record
+                    .started_at
+                    .and_then(|started_at| started_at.elapsed().ok())
+                    .map(|uptime| uptime.as_secs()),
+                health: record.stats.last_health_status.filter(|_| running),
+                restarts: record.stats.num_restarts,
+                last_exit_code: record
+                    .last_exit_status
+                    .as_ref()
+                    .map(ProcessExitStatus::code),
+                last_exit_cause: record.last_exit_status.map(|status| status.cause),
+            }
+        })
+        .collect()
+}
+
+pub async fn process_inventory() -> Vec<ProcessInventoryEntry> {
+    let mut entries = inventory_entries().await;
+    for (entry, (_, binary)) in entries.iter_mut().zip(MANAGED_PROCESSES) {
+        if entry.binary_path.is_some() {
+            entry.version = Some(BinaryResolver::current().get_binary_version(binary).await);
+        }
+    }
+    entries
+}
+
+/// Starts, stops or restarts a managed process.
+///
+/// Miners are controlled through their managers directly. The other processes need the state
+/// of their setup phase to start, so starting or restarting them restarts that phase.
+pub async fn control_process(
+    app_handle: &AppHandle,
+    name: &str,
+    action: ProcessControlAction,
+) -> Result<(), anyhow::Error> {
+    let binary = MANAGED_PROCESSES
+        .iter()
+        .find(|(process, _)| *process == name)
+        .map(|(_, binary)| *binary)
+        .ok_or_else(|| anyhow!("Unknown process: {name}"))?;
+    info!(target: LOG_TARGET_APP_LOGIC, "Process control: {action} {name}");
+
+    match (binary, action) {
+        (Binaries::Xmrig, ProcessControlAction::Start) => {
+            CpuManager::write().await.start_mining().await
+        }
+        (Binaries::Xmrig, ProcessControlAction::Stop) => {
+            CpuManager::write().await.stop_mining().await
+        }
+        (Binaries::Xmrig, ProcessControlAction::Restart) => {
+            let mut cpu_manager = CpuManager::write().await;
+            cpu_manager.stop_mining().await?;
+            cpu_manager.start_mining().await
+        }
+        (Binaries::LolMiner, ProcessControlAction::Start) => {
+            GpuManager::write().await.start_mining().await
+        }
+        (Binaries::LolMiner, ProcessControlAction::Stop) => {
+            GpuManager::write().await.stop_mining().await
+        }
+        (Binaries::LolMiner, ProcessControlAction::Restart) => {
+            let mut gpu_manager = GpuManager::write().await;
+            gpu_manager.stop_mining().await?;
+            gpu_manager.start_mining().await
+        }
+        (_, ProcessControlAction::Stop) => {
+            let state = app_handle.state::<UniverseAppState>();
+            match binary {
+                Binaries::MinotariNode => state.node_manager.stop().await,
+                Binaries::Tor => state.tor_manager.stop().await.map(|_| ()),
+                Binaries::Wallet => state
+                    .wallet_manager
+                    .stop()
+                    .await
+                    .map(|_| ())
+                    .map_err(anyhow::Error::from),
+                Binaries::MergeMiningProxy => state.mm_proxy_manager.stop().await,
+                _ => Err(anyhow!("{name} can't be stopped")),
+            }
+        }
+        (_, ProcessControlAction::Start | ProcessControlAction::Restart) => {
+            SetupManager::get_instance()
+                .restart_phases(vec![binary.setup_phase()])
+                .await;
+            Ok(())
+        }
+    }
+}
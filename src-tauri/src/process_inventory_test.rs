@@ -0,0 +1,207 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for process_inventory
+
+use std::path::Path;
+
+use crate::port_registry::ServicePort;
+use crate::process_adapter::HealthStatus;
+use crate::process_exit_status::{ExitCause, ProcessExitStatus};
+use crate::process_inventory::{
+    inventory_entries, record_exit, record_ports, record_spawn, record_stats, redact_command_line,
+};
+use crate::process_watcher::ProcessWatcherStats;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| (*arg).to_string()).collect()
+}
+
+// ==================== Command line redaction ====================
+
+#[test]
+fn redacts_secret_argument_values() {
+    let redacted = redact_command_line(&args(&[
+        "--http-access-token=abc123",
+        "-p",
+        "wallet.password=hunter2",
+        "-p",
+        "base_node.p2p.transport.tor.control_auth=password=hunter2",
+        "--http-port=18000",
+    ]));
+    assert_eq!(
+        redacted,
+        args(&[
+            "--http-access-token=<redacted>",
+            "-p",
+            "wallet.password=<redacted>",
+            "-p",
+            "base_node.p2p.transport.tor.control_auth=<redacted>",
+            "--http-port=18000",
+        ])
+    );
+}
+
+#[test]
+fn redacts_value_following_secret_flag() {
+    let redacted = redact_command_line(&args(&["--password", "hunter2", "--log", "info"]));
+    assert_eq!(
+        redacted,
+        args(&["--password", "<redacted>", "--log", "info"])
+    );
+}
+
+#[test]
+fn redacts_tor_control_password_passed_to_node() {
+    let redacted = redact_command_line(&args(&[
+        "-p",
+        "base_node.p2p.transport.tor.control_auth=password=hunter2",
+    ]));
+    assert_eq!(
+        redacted,
+        args(&["-p", "base_node.p2p.transport.tor.control_auth=<redacted>"])
+    );
+}
+
+#[test]
+fn redacts_secrets_nested_in_values() {
+    let redacted = redact_command_line(&args(&[
+        "--connection=password=hunter2",
+        "-p",
+        "wallet.grpc_authentication=basic:token=abc123",
+        "--url=http://host/?path=/data",
+    ]));
+    assert_eq!(
+        redacted,
+        args(&[
+            "--connection=password=<redacted>",
+            "-p",
+            "wallet.grpc_authentication=basic:token=<redacted>",
+            "--url=http://host/?path=/data",
+        ])
+    );
+}
+
+#[test]
+fn keeps_arguments_that_only_contain_secret_words() {
+    let arguments = args(&[
+        "--keypair-path=/data",
+        "--passive",
+        "base_node.grpc_address=x",
+    ]);
+    assert_eq!(redact_command_line(&arguments), arguments);
+}
+
+// ==================== Inventory ====================
+
+#[tokio::test]
+async fn lists_every_managed_process() {
+    let names: Vec<String> = inventory_entries()
+        .await
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "local_minotari_node",
+            "tor",
+            "wallet",
+            "minotari_merge_mining_proxy",
+            "xmrig",
+            "lolminer"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn running_process_reports_spawn_ports_and_health() {
+    record_spawn(
+        "tor",
+        Some(4242),
+        Path::new("/bin/tor"),
+        &args(&["--HashedControlPassword", "16:abc"]),
+    )
+    .await;
+    record_ports("tor", vec![(ServicePort::TorSocks, 19050)]).await;
+    record_stats(
+        "tor",
+        &ProcessWatcherStats {
+            num_restarts: 2,
+            last_health_status: Some(HealthStatus::Healthy),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let entries = inventory_entries().await;
+    let tor = entries
+        .iter()
+        .find(|entry| entry.name == "tor")
+        .expect("tor entry");
+    assert!(tor.running);
+    assert_eq!(tor.pid, Some(4242));
+    assert_eq!(tor.binary, "tor");
+    assert_eq!(
+        tor.command_line,
+        args(&["--HashedControlPassword", "<redacted>"])
+    );
+    assert_eq!(tor.ports.len(), 1);
+    assert_eq!(tor.ports[0].port, 19050);
+    assert_eq!(tor.health, Some(HealthStatus::Healthy));
+    assert_eq!(tor.restarts, 2);
+    assert!(tor.uptime_secs.is_some());
+}
+
+#[tokio::test]
+async fn exited_process_keeps_last_exit_status() {
+    record_spawn("xmrig", Some(1234), Path::new("/bin/xmrig"), &[]).await;
+    record_stats(
+        "xmrig",
+        &ProcessWatcherStats {
+            last_health_status: Some(HealthStatus::Healthy),
+            ..Default::default()
+        },
+    )
+    .await;
+    record_exit(
+        "xmrig",
+        Some(ProcessExitStatus {
+            cause: ExitCause::Signaled,
+            signal: Some(9),
+            ..Default::default()
+        }),
+    )
+    .await;
+
+    let entries = inventory_entries().await;
+    let xmrig = entries
+        .iter()
+        .find(|entry| entry.name == "xmrig")
+        .expect("xmrig entry");
+    assert!(!xmrig.running);
+    assert_eq!(xmrig.pid, None);
+    assert_eq!(xmrig.uptime_secs, None);
+    assert_eq!(xmrig.health, None);
+    assert_eq!(xmrig.last_exit_code, Some(137));
+    assert_eq!(xmrig.last_exit_cause, Some(ExitCause::Signaled));
+}
@@ -0,0 +1,491 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Optional resource limits for managed processes.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::LOG_TARGET_APP_LOGIC;
+
+/// `cpu.max` period in microseconds
+#[cfg(target_os = "linux")]
+const CPU_MAX_PERIOD: u64 = 100_000;
+/// Niceness used when only a CPU limit is configured and cgroups are unavailable
+#[cfg(unix)]
+const FALLBACK_CPU_LIMIT_NICE: i32 = 10;
+/// Size of `cpu_set_t`, higher cores can't be set in the affinity mask
+#[cfg(unix)]
+const MAX_AFFINITY_CPUS: usize = 1024;
+
+static PROCESS_LIMITS: LazyLock<RwLock<HashMap<String, ProcessLimits>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, Default)]
+struct ProcessLimits {
+    requested: Option<ResourceLimits>,
+    effective: Option<EffectiveResourceLimits>,
+    /// Cgroup created for the running process
+    cgroup: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ResourceLimits {
+    /// CPU time in percent of a single core, 150 allows one and a half cores
+    pub cpu_max_percent: Option<u32>,
+    /// CPU cores the process is allowed to run on
+    pub cpus: Option<Vec<usize>>,
+    pub memory_max_bytes: Option<u64>,
+    /// Relative I/O weight between 1 and 10000, the kernel default is 100
+    pub io_weight: Option<u16>,
+    /// Niceness between -20 and 19, only used when cgroups are unavailable
+    pub nice: Option<i32>,
+}
+
+impl ResourceLimits {
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.cpu_max_percent == Some(0) {
+            return Err(anyhow::anyhow!("CPU limit must be greater than 0"));
+        }
+        if self.cpus.as_ref().is_some_and(Vec::is_empty) {
+            return Err(anyhow::anyhow!("CPU list must not be empty"));
+        }
+        if self.memory_max_bytes == Some(0) {
+            return Err(anyhow::anyhow!("Memory limit must be greater than 0"));
+        }
+        if self
+            .io_weight
+            .is_some_and(|weight| !(1..=10_000).contains(&weight))
+        {
+            return Err(anyhow::anyhow!("I/O weight must be between 1 and 10000"));
+        }
+        if self.nice.is_some_and(|nice| !(-20..=19).contains(&nice)) {
+            return Err(anyhow::anyhow!("Niceness must be between -20 and 19"));
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &ResourceLimits::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitMechanism {
+    #[default]
+    None,
+    Cgroup,
+    Fallback,
+}
+
+impl Display for LimitMechanism {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitMechanism::None => write!(f, "none"),
+            LimitMechanism::Cgroup => write!(f, "cgroup"),
+            LimitMechanism::Fallback => write!(f, "fallback"),
+        }
+    }
+}
+
+/// Limits actually applied to a process, reported in its stats
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct EffectiveResourceLimits {
+    pub mechanism: LimitMechanism,
+    /// `cpu.max` as written to the cgroup
+    pub cpu_max: Option<String>,
+    pub cpus: Option<Vec<usize>>,
+    pub memory_max_bytes: Option<u64>,
+    pub io_weight: Option<u16>,
+    pub nice: Option<i32>,
+    /// Best-effort I/O priority level, 0 is the highest and 7 the lowest
+    pub io_priority: Option<u8>,
+}
+
+/// Limits applied in the forked child before it executes the binary
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(not(unix), allow(dead_code))]
+pub struct PreExecLimits {
+    cgroup_procs: Option<std::ffi::CString>,
+    nice: Option<i32>,
+    io_priority: Option<u8>,
+    cpus: Option<Vec<usize>>,
+}
+
+impl PreExecLimits {
+    /// Runs between fork and exec, so it only makes raw system calls and never allocates.
+    /// Limits are best effort and failures don't prevent the process from starting.
+    #[cfg(unix)]
+    pub fn apply(&self) -> std::io::Result<()> {
+        use nix::libc;
+
+        // SAFETY: only async-signal-safe system calls on data prepared before the fork
+        unsafe {
+            if let Some(procs) = &self.cgroup_procs {
+                let fd = libc::open(procs.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+                if fd >= 0 {
+                    libc::write(fd, b"0".as_ptr().cast(), 1);
+                    libc::close(fd);
+                }
+            }
+            if let Some(nice) = self.nice {
+                libc::setpriority(libc::PRIO_PROCESS, 0, nice);
+            }
+            #[cfg(target_os = "linux")]
+            {
+                if let Some(level) = self.io_priority {
+                    const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+                    const IOPRIO_CLASS_BE: libc::c_int = 2;
+                    const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+                    libc::syscall(
+                        libc::SYS_ioprio_set,
+                        IOPRIO_WHO_PROCESS,
+                        0,
+                        (IOPRIO_CLASS_BE << IOPRIO_CLASS_SHIFT) | libc::c_int::from(level),
+                    );
+                }
+                if let Some(cpus) = &self.cpus {
+                    let mut set: libc::cpu_set_t = std::mem::zeroed();
+                    for cpu in cpus {
+                        libc::CPU_SET(*cpu, &mut set);
+                    }
+                    libc::sched_setaffinity(0, size_of::<libc::cpu_set_t>(), &set);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `cpu.max` value allowing `percent` of a single core per period
+#[cfg(target_os = "linux")]
+pub fn cpu_max_value(percent: u32) -> String {
+    let quota = u64::from(percent) * CPU_MAX_PERIOD / 100;
+    format!("{quota} {CPU_MAX_PERIOD}")
+}
+
+/// `cpuset.cpus` value, consecutive cores are collapsed into ranges
+#[cfg(target_os = "linux")]
+pub fn cpuset_value(cpus: &[usize]) -> String {
+    let mut sorted = cpus.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut ranges: Vec<String> = Vec::new();
+    let mut iter = sorted.into_iter().peekable();
+    while let Some(start) = iter.next() {
+        let mut end = start;
+        while iter.peek() == Some(&(end + 1)) {
+            end += 1;
+            iter.next();
+        }
+        if start == end {
+            ranges.push(start.to_string());
+        } else {
+            ranges.push(format!("{start}-{end}"));
+        }
+    }
+    ranges.join(",")
+}
+
+/// Maps a cgroup I/O weight onto a best-effort I/O priority level, 100 maps to the default 4
+#[cfg(unix)]
+pub fn io_weight_to_priority(weight: u16) -> u8 {
+    match weight {
+        0..=24 => 7,
+        25..=49 => 6,
+        50..=74 => 5,
+        75..=149 => 4,
+        150..=299 => 3,
+        300..=599 => 2,
+        600..=999 => 1,
+        _ => 0,
+    }
+}
+
+/// Path of the process' cgroup v2 group from the contents of `/proc/<pid>/cgroup`
+#[cfg(target_os = "linux")]
+pub fn parse_cgroup_v2_path(contents: &str) -> Option<&str> {
+    contents
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .map(str::trim)
+}
+
+/// Niceness, I/O priority and CPU affinity approximating `limits`, the latter two only exist on Linux
+#[cfg(unix)]
+pub fn fallback_limits(limits: &ResourceLimits) -> (PreExecLimits, EffectiveResourceLimits) {
+    let is_linux = cfg!(target_os = "linux");
+    let nice = limits
+        .nice
+        .or_else(|| limits.cpu_max_percent.map(|_| FALLBACK_CPU_LIMIT_NICE));
+    let io_priority = limits
+        .io_weight
+        .filter(|_| is_linux)
+        .map(io_weight_to_priority);
+    let cpus = limits
+        .cpus
+        .as_ref()
+        .filter(|_| is_linux)
+        .map(|cpus| {
+            cpus.iter()
+                .copied()
+                .filter(|cpu| *cpu < MAX_AFFINITY_CPUS)
+                .collect::<Vec<usize>>()
+        })
+        .filter(|cpus| !cpus.is_empty());
+
+    let pre_exec = PreExecLimits {
+        cgroup_procs: None,
+        nice,
+        io_priority,
+        cpus: cpus.clone(),
+    };
+    let effective = EffectiveResourceLimits {
+        mechanism: LimitMechanism::Fallback,
+        nice,
+        io_priority,
+        cpus,
+        ..Default::default()
+    };
+    (pre_exec, effective)
+}
+
+#[cfg(target_os = "linux")]
+mod cgroup {
+    use std::ffi::CString;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use log::{info, warn};
+
+    use super::{
+        EffectiveResourceLimits, LimitMechanism, PreExecLimits, ResourceLimits, cpu_max_value,
+        cpuset_value, parse_cgroup_v2_path,
+    };
+    use crate::LOG_TARGET_APP_LOGIC;
+
+    const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+    /// Leaf the app and unlimited processes are moved into, cgroup v2 only allows
+    /// enabling controllers for children of a group without processes of its own
+    const APP_LEAF: &str = "universe";
+    const CONTROLLERS: [&str; 4] = ["cpu", "cpuset", "memory", "io"];
+
+    /// Systemd hands a delegated cgroup to the user by chowning the group and the files needed
+    /// to manage its subtree. Root owns every group, so ownership proves nothing when running as root.
+    fn is_delegated(base: &Path) -> bool {
+        use std::os::unix::fs::MetadataExt;
+
+        let uid = nix::unistd::geteuid();
+        if uid.is_root() {
+            return false;
+        }
+        [
+            base.to_path_buf(),
+            base.join("cgroup.procs"),
+            base.join("cgroup.subtree_control"),
+        ]
+        .iter()
+        .all(|path| fs::metadata(path).is_ok_and(|metadata| metadata.uid() == uid.as_raw()))
+    }
+
+    fn delegated_base() -> Option<PathBuf> {
+        let root = Path::new(CGROUP_ROOT);
+        if !root.join("cgroup.controllers").exists() {
+            return None;
+        }
+        let contents = fs::read_to_string("/proc/self/cgroup").ok()?;
+        let own = root.join(parse_cgroup_v2_path(&contents)?.trim_start_matches('/'));
+        let base = if own.file_name().is_some_and(|name| name == APP_LEAF) {
+            own.parent()?.to_path_buf()
+        } else {
+            own
+        };
+        if base == root {
+            // The root group is never delegated
+            return None;
+        }
+        if !is_delegated(&base) {
+            info!(target: LOG_TARGET_APP_LOGIC, "Cgroup {base:?} is not delegated, using fallback limits");
+            return None;
+        }
+        Some(base)
+    }
+
+    fn prepare_base(base: &Path) -> std::io::Result<()> {
+        let leaf = base.join(APP_LEAF);
+        fs::create_dir_all(&leaf)?;
+        for pid in fs::read_to_string(base.join("cgroup.procs"))?.lines() {
+            fs::write(leaf.join("cgroup.procs"), pid)?;
+        }
+
+        let available = fs::read_to_string(base.join("cgroup.controllers"))?;
+        let enable: Vec<String> = CONTROLLERS
+            .iter()
+            .filter(|controller| available.split_whitespace().any(|c| c == **controller))
+            .map(|controller| format!("+{controller}"))
+            .collect();
+        fs::write(base.join("cgroup.subtree_control"), enable.join(" "))
+    }
+
+    fn write_limit(group: &Path, file: &str, value: &str) -> bool {
+        match fs::write(group.join(file), value) {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to set {file} of {group:?}: {e}");
+                false
+            }
+        }
+    }
+
+    pub fn prepare(
+        name: &str,
+        limits: &ResourceLimits,
+    ) -> Option<(PreExecLimits, EffectiveResourceLimits, PathBuf)> {
+        let base = delegated_base()?;
+        if let Err(e) = prepare_base(&base) {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to prepare cgroup {base:?}, falling back: {e}");
+            return None;
+        }
+        let group = base.join(name);
+        if let Err(e) = fs::create_dir_all(&group) {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to create cgroup {group:?}: {e}");
+            return None;
+        }
+
+        let mut effective = EffectiveResourceLimits {
+            mechanism: LimitMechanism::Cgroup,
+            ..Default::default()
+        };
+        let cpu_max = limits
+            .cpu_max_percent
+            .map_or_else(|| "max".to_string(), cpu_max_value);
+        if write_limit(&group, "cpu.max", &cpu_max) && limits.cpu_max_percent.is_some() {
+            effective.cpu_max = Some(cpu_max);
+        }
+        // An empty cpuset inherits the parent's cores
+        let cpuset = limits
+            .cpus
+            .as_deref()
+            .map_or_else(|| "\n".to_string(), cpuset_value);
+        if write_limit(&group, "cpuset.cpus", &cpuset) {
+            effective.cpus = limits.cpus.clone();
+        }
+        let memory_max = limits
+            .memory_max_bytes
+            .map_or_else(|| "max".to_string(), |bytes| bytes.to_string());
+        if write_limit(&group, "memory.max", &memory_max) {
+            effective.memory_max_bytes = limits.memory_max_bytes;
+        }
+        let io_weight = limits.io_weight.unwrap_or(100);
+        if write_limit(&group, "io.weight", &format!("default {io_weight}")) {
+            effective.io_weight = limits.io_weight;
+        }
+
+        let procs = CString::new(group.join("cgroup.procs").to_string_lossy().as_bytes()).ok()?;
+        Some((
+            PreExecLimits {
+                cgroup_procs: Some(procs),
+                ..Default::default()
+            },
+            effective,
+            group,
+        ))
+    }
+
+    /// Removes the group of an exited process, fails while any of its descendants still run
+    pub fn remove(group: &Path) {
+        if let Err(e) = fs::remove_dir(group) {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove cgroup {group:?}: {e}");
+        }
+    }
+}
+
+/// Stores the configured limits of `name`, applied the next time the process is launched
+pub async fn set_requested_limits(name: &str, limits: Option<ResourceLimits>) {
+    let mut process_limits = PROCESS_LIMITS.write().await;
+    let entry = process_limits.entry(name.to_string()).or_default();
+    entry.requested = limits.filter(|limits| !limits.is_empty());
+}
+
+/// Prepares the configured limits of `name` for launching it, `None` when it is not limited
+pub async fn prepare_limits(name: &str) -> Option<PreExecLimits> {
+    let mut process_limits = PROCESS_LIMITS.write().await;
+    let entry = process_limits.get_mut(name)?;
+    let Some(limits) = entry.requested.clone() else {
+        entry.effective = None;
+        return None;
+    };
+
+    #[cfg(target_os = "linux")]
+    let prepared = match cgroup::prepare(name, &limits) {
+        Some((pre_exec, effective, group)) => {
+            entry.cgroup = Some(group);
+            (pre_exec, effective)
+        }
+        None => fallback_limits(&limits),
+    };
+    #[cfg(all(unix, not(target_os = "linux")))]
+    let prepared = fallback_limits(&limits);
+    #[cfg(not(unix))]
+    let prepared = (PreExecLimits::default(), EffectiveResourceLimits::default());
+
+    let (pre_exec, effective) = prepared;
+    if effective.mechanism == LimitMechanism::None {
+        warn!(target: LOG_TARGET_APP_LOGIC, "Resource limits for {name} are not supported on this platform");
+    } else {
+        info!(target: LOG_TARGET_APP_LOGIC, "Applying resource limits to {name}: {effective:?}");
+    }
+    entry.effective = Some(effective);
+    Some(pre_exec)
+}
+
+/// Cleans up after `name` exited, removing the cgroup created for it
+pub async fn release_limits(name: &str) {
+    let group = PROCESS_LIMITS
+        .write()
+        .await
+        .get_mut(name)
+        .and_then(|limits| limits.cgroup.take());
+    #[cfg(target_os = "linux")]
+    if let Some(group) = group {
+        cgroup::remove(&group);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _unused = group;
+}
+
+pub async fn effective_limits(name: &str) -> Option<EffectiveResourceLimits> {
+    PROCESS_LIMITS
+        .read()
+        .await
+        .get(name)
+        .and_then(|limits| limits.effective.clone())
+}
@@ -0,0 +1,146 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for process_resource_limits
+
+use crate::process_resource_limits::{LimitMechanism, ResourceLimits};
+
+// ==================== Validation ====================
+
+#[test]
+fn empty_limits_are_valid() {
+    let limits = ResourceLimits::default();
+
+    assert!(limits.is_empty());
+    assert!(limits.validate().is_ok());
+}
+
+#[test]
+fn rejects_out_of_range_values() {
+    let invalid = [
+        ResourceLimits {
+            cpu_max_percent: Some(0),
+            ..Default::default()
+        },
+        ResourceLimits {
+            cpus: Some(vec![]),
+            ..Default::default()
+        },
+        ResourceLimits {
+            io_weight: Some(10_001),
+            ..Default::default()
+        },
+        ResourceLimits {
+            nice: Some(20),
+            ..Default::default()
+        },
+    ];
+
+    for limits in invalid {
+        assert!(limits.validate().is_err(), "{limits:?} should be invalid");
+    }
+}
+
+#[test]
+fn deserializes_partial_limits() {
+    let limits: ResourceLimits = serde_json::from_str(r#"{"cpu_max_percent": 150}"#).unwrap();
+
+    assert_eq!(limits.cpu_max_percent, Some(150));
+    assert_eq!(limits.cpus, None);
+}
+
+// ==================== Cgroup values ====================
+
+#[cfg(target_os = "linux")]
+#[test]
+fn cpu_max_is_relative_to_one_core() {
+    use crate::process_resource_limits::cpu_max_value;
+
+    assert_eq!(cpu_max_value(50), "50000 100000");
+    assert_eq!(cpu_max_value(250), "250000 100000");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn cpuset_collapses_consecutive_cores() {
+    use crate::process_resource_limits::cpuset_value;
+
+    assert_eq!(cpuset_value(&[3, 0, 1, 2, 6, 8, 9]), "0-3,6,8-9");
+    assert_eq!(cpuset_value(&[4, 4]), "4");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn parses_unified_hierarchy_path() {
+    use crate::process_resource_limits::parse_cgroup_v2_path;
+
+    let contents = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/tari.scope\n";
+    assert_eq!(
+        parse_cgroup_v2_path(contents),
+        Some("/user.slice/user-1000.slice/user@1000.service/app.slice/tari.scope")
+    );
+    assert_eq!(parse_cgroup_v2_path("1:name=systemd:/init.scope\n"), None);
+}
+
+// ==================== Fallback ====================
+
+#[cfg(unix)]
+#[test]
+fn io_weight_maps_to_best_effort_levels() {
+    use crate::process_resource_limits::io_weight_to_priority;
+
+    assert_eq!(io_weight_to_priority(1), 7);
+    assert_eq!(io_weight_to_priority(100), 4);
+    assert_eq!(io_weight_to_priority(10_000), 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn cpu_limit_without_nice_lowers_priority() {
+    use crate::process_resource_limits::fallback_limits;
+
+    let (_, effective) = fallback_limits(&ResourceLimits {
+        cpu_max_percent: Some(100),
+        ..Default::default()
+    });
+
+    assert_eq!(effective.mechanism, LimitMechanism::Fallback);
+    assert_eq!(effective.nice, Some(10));
+    assert_eq!(effective.cpu_max, None);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn fallback_drops_cores_outside_affinity_mask() {
+    use crate::process_resource_limits::fallback_limits;
+
+    let (_, effective) = fallback_limits(&ResourceLimits {
+        cpus: Some(vec![0, 2, 4096]),
+        io_weight: Some(50),
+        nice: Some(5),
+        ..Default::default()
+    });
+
+    assert_eq!(effective.cpus, Some(vec![0, 2]));
+    assert_eq!(effective.io_priority, Some(5));
+    assert_eq!(effective.nice, Some(5));
+}
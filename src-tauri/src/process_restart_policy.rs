@@ -0,0 +1,244 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Restart policy for processes supervised by the process watcher.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use log::{error, info};
+use rand::Rng;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::LOG_TARGET_STATUSES;
+use crate::binaries::Binaries;
+use crate::events::CriticalProblemPayload;
+use crate::events_emitter::EventsEmitter;
+use crate::process_output::last_output_lines;
+
+const MAX_RECORDED_EXIT_CODES: usize = 5;
+const LOG_TAIL_LINES: usize = 20;
+const LOG_TAIL_MAX_BYTES: u64 = 16 * 1024;
+
+static OPEN_CIRCUITS: LazyLock<Mutex<HashSet<Binaries>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+#[derive(Debug, Clone)]
+pub struct RestartPolicy {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub backoff_multiplier: u32,
+    /// Fraction of the backoff randomly added or subtracted, between 0.0 and 1.0
+    pub jitter: f64,
+    pub max_restarts: usize,
+    pub restart_window: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            backoff_multiplier: 2,
+            jitter: 0.2,
+            max_restarts: 5,
+            restart_window: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Miners back off for longer and give up sooner, so one crashing on startup doesn't keep
+    /// reconnecting to its pool
+    pub fn for_miner() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(5 * 60),
+            max_restarts: 3,
+            restart_window: Duration::from_secs(30 * 60),
+            ..Self::default()
+        }
+    }
+
+    /// Each node restart can take as long as its startup time, so restarts are counted over a
+    /// longer window
+    pub fn for_node() -> Self {
+        Self {
+            restart_window: Duration::from_secs(60 * 60),
+            ..Self::default()
+        }
+    }
+
+    /// Backoff before the given restart attempt (starting at 1), without jitter
+    pub fn backoff_for_attempt(&self, attempt: u32) -> Duration {
+        let factor = self
+            .backoff_multiplier
+            .saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    pub fn apply_jitter(&self, backoff: Duration) -> Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return backoff;
+        }
+        let factor = rand::thread_rng().gen_range((1.0 - jitter)..=(1.0 + jitter));
+        backoff.mul_f64(factor)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestartDecision {
+    Restart { backoff: Duration },
+    OpenCircuit,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RestartTracker {
+    policy: RestartPolicy,
+    restart_times: VecDeque<Instant>,
+    attempts_since_healthy: u32,
+    last_exit_codes: VecDeque<i32>,
+    circuit_open: bool,
+}
+
+impl RestartTracker {
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    pub fn record_healthy(&mut self) {
+        self.attempts_since_healthy = 0;
+    }
+
+    pub fn record_exit(&mut self, exit_code: i32) {
+        if self.last_exit_codes.len() == MAX_RECORDED_EXIT_CODES {
+            self.last_exit_codes.pop_front();
+        }
+        self.last_exit_codes.push_back(exit_code);
+    }
+
+    pub fn next_restart(&mut self, now: Instant) -> RestartDecision {
+        while let Some(first) = self.restart_times.front() {
+            if now.duration_since(*first) > self.policy.restart_window {
+                self.restart_times.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.restart_times.len() >= self.policy.max_restarts {
+            self.circuit_open = true;
+            return RestartDecision::OpenCircuit;
+        }
+
+        self.restart_times.push_back(now);
+        self.attempts_since_healthy = self.attempts_since_healthy.saturating_add(1);
+        let backoff = self.policy.backoff_for_attempt(self.attempts_since_healthy);
+        RestartDecision::Restart {
+            backoff: self.policy.apply_jitter(backoff),
+        }
+    }
+
+    pub fn is_circuit_open(&self) -> bool {
+        self.circuit_open
+    }
+
+    pub fn last_exit_codes(&self) -> Vec<i32> {
+        self.last_exit_codes.iter().copied().collect()
+    }
+}
+
+/// Marks the circuit of `binary` as open and reports it with the last exit codes and log tail
+pub async fn report_open_circuit(
+    binary: Binaries,
+    name: &str,
+    last_exit_codes: &[i32],
+    log_file: Option<&Path>,
+) {
+    OPEN_CIRCUITS.lock().await.insert(binary);
+
+    let mut error_message = format!(
+        "{name} was restarted too many times and has been stopped. Last exit codes: {last_exit_codes:?}"
+    );
+    // Prefer the captured output, not every process writes its own log file
+    let output = last_output_lines(name, LOG_TAIL_LINES).await;
+    let tail = if output.is_empty() {
+        match log_file {
+            Some(path) => read_log_tail(path, LOG_TAIL_LINES).await,
+            None => None,
+        }
+    } else {
+        Some(output.join("\n"))
+    };
+    if let Some(tail) = tail {
+        error_message.push_str("\n\n");
+        error_message.push_str(&tail);
+    }
+    error!(target: LOG_TARGET_STATUSES, "Circuit breaker opened for {name}. Last exit codes: {last_exit_codes:?}");
+
+    EventsEmitter::emit_critical_problem(CriticalProblemPayload {
+        title: Some("error.title.crash-loop".to_string()),
+        description: Some("error.description.crash-loop".to_string()),
+        error_message: Some(error_message),
+    })
+    .await;
+}
+
+/// Closes the circuit of `binary`, returns whether it was open
+pub async fn close_circuit(binary: Binaries) -> bool {
+    let was_open = OPEN_CIRCUITS.lock().await.remove(&binary);
+    if was_open {
+        info!(target: LOG_TARGET_STATUSES, "Circuit breaker closed for {}", binary.name());
+    }
+    was_open
+}
+
+pub async fn read_log_tail(path: &Path, lines: usize) -> Option<String> {
+    let mut file = tokio::fs::File::open(path).await.ok()?;
+    let len = file.metadata().await.ok()?.len();
+    file.seek(std::io::SeekFrom::Start(
+        len.saturating_sub(LOG_TAIL_MAX_BYTES),
+    ))
+    .await
+    .ok()?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).await.ok()?;
+
+    let content = String::from_utf8_lossy(&buffer);
+    let all_lines: Vec<&str> = content.lines().collect();
+    let tail = all_lines[all_lines.len().saturating_sub(lines)..].join("\n");
+    if tail.trim().is_empty() {
+        None
+    } else {
+        Some(tail)
+    }
+}
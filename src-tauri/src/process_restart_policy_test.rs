@@ -0,0 +1,188 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for process_restart_policy
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::process_restart_policy::{
+    RestartDecision, RestartPolicy, RestartTracker, read_log_tail,
+};
+use crate::testing::test_utils::TestContext;
+
+fn policy_without_jitter() -> RestartPolicy {
+    RestartPolicy {
+        initial_backoff: Duration::from_secs(1),
+        max_backoff: Duration::from_secs(10),
+        backoff_multiplier: 2,
+        jitter: 0.0,
+        max_restarts: 3,
+        restart_window: Duration::from_secs(60),
+    }
+}
+
+fn backoff_of(decision: RestartDecision) -> Duration {
+    match decision {
+        RestartDecision::Restart { backoff } => backoff,
+        RestartDecision::OpenCircuit => panic!("expected a restart"),
+    }
+}
+
+// ==================== Backoff ====================
+
+#[test]
+fn backoff_grows_exponentially_and_is_capped() {
+    let policy = policy_without_jitter();
+
+    assert_eq!(policy.backoff_for_attempt(1), Duration::from_secs(1));
+    assert_eq!(policy.backoff_for_attempt(2), Duration::from_secs(2));
+    assert_eq!(policy.backoff_for_attempt(4), Duration::from_secs(8));
+    assert_eq!(policy.backoff_for_attempt(5), Duration::from_secs(10));
+    assert_eq!(policy.backoff_for_attempt(100), Duration::from_secs(10));
+}
+
+#[test]
+fn jitter_stays_within_bounds() {
+    let policy = RestartPolicy {
+        jitter: 0.5,
+        ..policy_without_jitter()
+    };
+
+    for _ in 0..100 {
+        let backoff = policy.apply_jitter(Duration::from_secs(10));
+        assert!(backoff >= Duration::from_secs(5));
+        assert!(backoff <= Duration::from_secs(15));
+    }
+}
+
+#[test]
+fn healthy_run_resets_backoff() {
+    let mut tracker = RestartTracker::new(RestartPolicy {
+        max_restarts: 10,
+        ..policy_without_jitter()
+    });
+    let now = Instant::now();
+
+    assert_eq!(
+        backoff_of(tracker.next_restart(now)),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        backoff_of(tracker.next_restart(now)),
+        Duration::from_secs(2)
+    );
+
+    tracker.record_healthy();
+    assert_eq!(
+        backoff_of(tracker.next_restart(now)),
+        Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn miners_back_off_longer_and_give_up_sooner() {
+    let miner = RestartPolicy::for_miner();
+    let default = RestartPolicy::default();
+
+    assert!(miner.backoff_for_attempt(1) > default.backoff_for_attempt(1));
+    assert!(miner.max_backoff > default.max_backoff);
+    assert!(miner.max_restarts < default.max_restarts);
+}
+
+#[test]
+fn node_counts_restarts_over_a_longer_window() {
+    assert!(RestartPolicy::for_node().restart_window > RestartPolicy::default().restart_window);
+}
+
+// ==================== Circuit breaker ====================
+
+#[test]
+fn circuit_opens_after_max_restarts_within_window() {
+    let mut tracker = RestartTracker::new(policy_without_jitter());
+    let now = Instant::now();
+
+    for _ in 0..3 {
+        assert!(matches!(
+            tracker.next_restart(now),
+            RestartDecision::Restart { .. }
+        ));
+    }
+    assert!(!tracker.is_circuit_open());
+
+    assert_eq!(tracker.next_restart(now), RestartDecision::OpenCircuit);
+    assert!(tracker.is_circuit_open());
+}
+
+#[test]
+fn restarts_outside_window_are_forgotten() {
+    let mut tracker = RestartTracker::new(policy_without_jitter());
+    let start = Instant::now();
+
+    for _ in 0..3 {
+        tracker.next_restart(start);
+    }
+
+    let later = start + Duration::from_secs(61);
+    assert!(matches!(
+        tracker.next_restart(later),
+        RestartDecision::Restart { .. }
+    ));
+    assert!(!tracker.is_circuit_open());
+}
+
+#[test]
+fn keeps_only_most_recent_exit_codes() {
+    let mut tracker = RestartTracker::default();
+
+    for code in 1..=7 {
+        tracker.record_exit(code);
+    }
+
+    assert_eq!(tracker.last_exit_codes(), vec![3, 4, 5, 6, 7]);
+}
+
+// ==================== Log tail ====================
+
+#[tokio::test]
+async fn log_tail_returns_last_lines() {
+    let ctx = TestContext::new();
+    let path = ctx.log_dir.join("process.log");
+    let content: Vec<String> = (1..=30).map(|i| format!("line {i}")).collect();
+    std::fs::write(&path, content.join("\n")).unwrap();
+
+    let tail = read_log_tail(&path, 3).await.unwrap();
+
+    assert_eq!(tail, "line 28\nline 29\nline 30");
+}
+
+#[tokio::test]
+async fn log_tail_of_missing_file_is_none() {
+    let ctx = TestContext::new();
+
+    assert!(
+        read_log_tail(&ctx.log_dir.join("missing.log"), 3)
+            .await
+            .is_none()
+    );
+}
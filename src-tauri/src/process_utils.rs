@@ -23,6 +23,8 @@
 use std::{fs, future::Future, io::Write, path::Path, pin::Pin, time::Duration};
 
 use crate::process_adapter::ProcessStartupSpec;
+use crate::process_exit_status::WRAPPER_STATUS_FILE_ENV;
+use crate::process_resource_limits::PreExecLimits;
 use crate::process_wrapper;
 
 pub fn launch_child_process(
@@ -30,54 +32,58 @@ pub fn launch_child_process(
     current_dir: &Path,
     envs: Option<&std::collections::HashMap<String, String>>,
     args: &[String],
-    allow_output: bool,
+    resource_limits: Option<&PreExecLimits>,
+    exit_status_file: Option<&Path>,
 ) -> Result<tokio::process::Child, anyhow::Error> {
-    let stdout = if allow_output {
-        std::process::Stdio::piped()
+    // Output is always piped, callers either wait for it or capture it with `process_output`
+    let stdout = std::process::Stdio::piped();
+    let stderr = std::process::Stdio::piped();
+
+    let wrapper_path = process_wrapper::get_wrapper_path();
+    let (actual_binary, actual_args) = if let Some(wrapper_path) = wrapper_path.clone() {
+        let parent_pid = std::process::id().to_string();
+        let mut wrapper_args = vec![parent_pid, file_path.to_string_lossy().to_string()];
+        wrapper_args.extend(args.iter().cloned());
+        (wrapper_path, wrapper_args)
     } else {
-        std::process::Stdio::null()
-    };
-    let stderr = if allow_output {
-        std::process::Stdio::piped()
-    } else {
-        std::process::Stdio::null()
+        (file_path.to_path_buf(), args.to_vec())
     };
 
-    let (actual_binary, actual_args) =
-        if let Some(wrapper_path) = process_wrapper::get_wrapper_path() {
-            let parent_pid = std::process::id().to_string();
-            let mut wrapper_args = vec![parent_pid, file_path.to_string_lossy().to_string()];
-            wrapper_args.extend(args.iter().cloned());
-            (wrapper_path, wrapper_args)
-        } else {
-            (file_path.to_path_buf(), args.to_vec())
-        };
-
-    #[cfg(not(target_os = "windows"))]
+    let mut command = tokio::process::Command::new(&actual_binary);
+    command
+        .args(&actual_args)
+        .current_dir(current_dir)
+        .envs(envs.cloned().unwrap_or_default())
+        .stdout(stdout)
+        .stderr(stderr)
+        .kill_on_drop(true);
+
+    // Only the wrapper writes the status file, it doesn't pass the variable on to the binary
+    if let Some(exit_status_file) = exit_status_file
+        && wrapper_path.is_some()
     {
-        Ok(tokio::process::Command::new(&actual_binary)
-            .args(&actual_args)
-            .current_dir(current_dir)
-            .envs(envs.cloned().unwrap_or_default())
-            .stdout(stdout)
-            .stderr(stderr)
-            .kill_on_drop(true)
-            .spawn()?)
+        command.env(WRAPPER_STATUS_FILE_ENV, exit_status_file);
     }
+
+    #[cfg(unix)]
+    if let Some(resource_limits) = resource_limits.cloned() {
+        // Applied to the wrapper, the binary it launches inherits them
+        // SAFETY: `PreExecLimits::apply` only makes async-signal-safe system calls
+        unsafe {
+            command.pre_exec(move || resource_limits.apply());
+        }
+    }
+    #[cfg(not(unix))]
+    let _unused = resource_limits;
+
     #[cfg(target_os = "windows")]
     {
         use crate::consts::PROCESS_CREATION_NO_WINDOW;
 
-        Ok(tokio::process::Command::new(&actual_binary)
-            .args(&actual_args)
-            .current_dir(current_dir)
-            .envs(envs.cloned().unwrap_or_default())
-            .stdout(stdout)
-            .stderr(stderr)
-            .kill_on_drop(true)
-            .creation_flags(PROCESS_CREATION_NO_WINDOW)
-            .spawn()?)
+        command.creation_flags(PROCESS_CREATION_NO_WINDOW);
     }
+
+    Ok(command.spawn()?)
 }
 
 // pub async fn launch_and_get_outputs(
@@ -20,9 +20,20 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::binaries::version_history::{AUTO_ROLLBACK_STARTUP_FAILURES, VersionHistory};
 use crate::binaries::{Binaries, BinaryResolver};
+use crate::notifications::{NotificationEvent, Notifications};
+use crate::port_registry::ensure_ports_available;
 use crate::process_adapter::{HandleUnhealthyResult, ProcessInstanceTrait};
 use crate::process_adapter::{HealthStatus, ProcessAdapter, StatusMonitor};
+use crate::process_exit_status::{ProcessExitStatus, take_exit_status};
+use crate::process_inventory::{record_ports, record_stats};
+use crate::process_resource_limits::{
+    EffectiveResourceLimits, effective_limits, set_requested_limits,
+};
+use crate::process_restart_policy::{
+    RestartDecision, RestartTracker, close_circuit, report_open_circuit,
+};
 use crate::{LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 use futures_util::future::FusedFuture;
 use log::{error, info, warn};
@@ -35,8 +46,9 @@ use tokio::task::JoinHandle;
 
 use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
+use crate::setup::setup_manager::SetupManager;
 use tokio::select;
-use tokio::sync::watch;
+use tokio::sync::{Notify, watch};
 use tokio::time::sleep;
 use tokio::time::{Instant, MissedTickBehavior};
 use tokio_util::task::TaskTracker;
@@ -50,6 +62,28 @@ pub(crate) struct ProcessWatcherStats {
     pub num_restarts: u64,
     pub max_health_check_duration: Duration,
     pub total_health_check_duration: Duration,
+    /// Restarts in a row of a process that never reported healthy after (re)starting
+    pub consecutive_startup_failures: u64,
+    pub healthy_since_start: bool,
+    pub resource_limits: Option<EffectiveResourceLimits>,
+    /// How the last run of the process ended
+    pub last_exit_status: Option<ProcessExitStatus>,
+    /// CPU time used by all finished runs, as reported by the process wrapper
+    pub total_cpu_time: Duration,
+    pub peak_rss_kb: u64,
+    pub last_health_status: Option<HealthStatus>,
+}
+
+impl ProcessWatcherStats {
+    pub fn record_exit(&mut self, exit_status: ProcessExitStatus) {
+        if let Some(cpu_time) = exit_status.cpu_time() {
+            self.total_cpu_time += cpu_time;
+        }
+        if let Some(max_rss_kb) = exit_status.max_rss_kb {
+            self.peak_rss_kb = self.peak_rss_kb.max(max_rss_kb);
+        }
+        self.last_exit_status = Some(exit_status);
+    }
 }
 
 pub struct ProcessWatcher<TAdapter: ProcessAdapter> {
@@ -64,6 +98,8 @@ pub struct ProcessWatcher<TAdapter: ProcessAdapter> {
     pub stop_on_exit_codes: Vec<i32>,
     stats_broadcast: watch::Sender<ProcessWatcherStats>,
     is_first_start: Arc<AtomicBool>,
+    /// Restarts the process without stopping the watcher, see `request_restart`
+    restart_request: Arc<Notify>,
 }
 
 impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
@@ -79,6 +115,7 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
             stop_on_exit_codes: Vec::new(),
             stats_broadcast,
             is_first_start: Arc::new(AtomicBool::new(true)),
+            restart_request: Arc::new(Notify::new()),
         }
     }
 }
@@ -117,6 +154,9 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
         let binary_path = BinaryResolver::current().get_binary_path(binary).await?;
         self.kill_previous_instances(base_path.clone(), &binary_path)
             .await?;
+        let service_ports = self.adapter.service_ports();
+        ensure_ports_available(&service_ports)?;
+        record_ports(&name, service_ports).await;
 
         self.internal_shutdown = Shutdown::new();
         let mut inner_shutdown = self.internal_shutdown.to_signal();
@@ -132,6 +172,18 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
         }
 
         info!(target: LOG_TARGET_APP_LOGIC, "Using {binary_path:?} for {name}");
+        let log_file = self.adapter.log_file_path(&log_path);
+        // A fresh watcher starts with a closed circuit
+        close_circuit(binary).await;
+        set_requested_limits(
+            &name,
+            ConfigCore::content()
+                .await
+                .process_resource_limits()
+                .get(binary.name())
+                .cloned(),
+        )
+        .await;
         let first_start = self
             .is_first_start
             .load(std::sync::atomic::Ordering::SeqCst);
@@ -153,7 +205,9 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
         let mut global_shutdown_signal: ShutdownSignal = global_shutdown_signal.clone();
         let task_tracker = task_tracker.clone();
         let stop_on_exit_codes = self.stop_on_exit_codes.clone();
+        let mut restart_tracker = RestartTracker::new(self.adapter.restart_policy());
         let stats_broadcast = self.stats_broadcast.clone();
+        let restart_request = self.restart_request.clone();
         self.watcher_task = Some(task_tracker.clone().spawn(async move {
             child.start(task_tracker.clone()).await?;
             let mut uptime = Instant::now();
@@ -165,7 +219,16 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
                 num_restarts: 0,
                 max_health_check_duration: Duration::from_secs(0),
                 total_health_check_duration: Duration::from_secs(0),
+                consecutive_startup_failures: 0,
+                healthy_since_start: false,
+                resource_limits: None,
+                last_exit_status: None,
+                total_cpu_time: Duration::from_secs(0),
+                peak_rss_kb: 0,
+                last_health_status: None,
             };
+            let mut rollback_checked = false;
+            let mut version_confirmed = false;
             // sleep(Duration::from_secs(10)).await;
             info!(target: LOG_TARGET_APP_LOGIC, "Starting process watcher for {name}");
             let mut watch_timer = tokio::time::interval(poll_time);
@@ -183,8 +246,20 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
                       _ = global_shutdown_signal.wait() => {
                           return child.stop().await;
                       }
+                      _ = restart_request.notified() => {
+                        info!(target: LOG_TARGET_APP_LOGIC, "Restarting {name} on request");
+                        child.stop().await?;
+                        if let Some(exit_status) = take_exit_status(&name).await {
+                            stats.record_exit(exit_status);
+                        }
+                        child.start(task_tracker.clone()).await?;
+                        uptime = Instant::now();
+                        stats.num_restarts += 1;
+                        stats.healthy_since_start = false;
+                      }
                       _ = watch_timer.tick() => {
                         let status_monitor3 = status_monitor2.clone();
+                        let restarts_before = stats.num_restarts;
 
                         if let Some(exit_code) = do_health_check(
                             &mut child,
@@ -200,19 +275,77 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
                             inner_shutdown.clone(),
                             &mut warning_count,
                             &stop_on_exit_codes,
-                            &mut stats
+                            &mut stats,
+                            &mut restart_tracker,
                         ).await? {
+                            if restart_tracker.is_circuit_open() {
+                                report_open_circuit(
+                                    binary,
+                                    &name,
+                                    &restart_tracker.last_exit_codes(),
+                                    log_file.as_deref(),
+                                ).await;
+                            }
+                            if binary.is_miner() && exit_code != 0 {
+                                Notifications::notify(NotificationEvent::MinerCrashed {
+                                    miner: name.clone(),
+                                    exit_code: Some(exit_code),
+                                    restarting: false,
+                                }).await;
+                            }
                             return Ok(exit_code);
                         }
+                        if binary.is_miner() && stats.num_restarts > restarts_before {
+                            Notifications::notify(NotificationEvent::MinerCrashed {
+                                miner: name.clone(),
+                                exit_code: restart_tracker.last_exit_codes().last().copied(),
+                                restarting: true,
+                            }).await;
+                        }
+
+                        if !version_confirmed
+                            && stats.healthy_since_start
+                            && uptime.elapsed() >= expected_startup_time
+                        {
+                            version_confirmed = true;
+                            if let Err(e) = VersionHistory::confirm_active(binary).await {
+                                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to confirm {name} version: {e:?}");
+                            }
+                        }
+
+                        if !rollback_checked
+                            && stats.consecutive_startup_failures >= AUTO_ROLLBACK_STARTUP_FAILURES
+                        {
+                            rollback_checked = true;
+                            if BinaryResolver::current()
+                                .handle_repeated_startup_failures(binary)
+                                .await
+                            {
+                                // Restarting the phase stops this watcher, so it can't be awaited from here
+                                let phase = binary.setup_phase();
+                                tokio::spawn(async move {
+                                    SetupManager::get_instance().restart_phases(vec![phase]).await;
+                                });
+                            }
+                        }
                     },
 
                 }
+                stats.resource_limits = effective_limits(&name).await;
+                record_stats(&name, &stats).await;
                 stats_broadcast.send_replace(stats.clone());
             }
         }));
         Ok(())
     }
 
+    /// Restarts the process under the running watcher, keeping its startup settings
+    pub fn request_restart(&self) {
+        if self.is_running() {
+            self.restart_request.notify_one();
+        }
+    }
+
     pub fn is_running(&self) -> bool {
         if let Some(task) = self.watcher_task.as_ref() {
             !task.is_finished()
@@ -268,6 +401,7 @@ pub(crate) async fn do_health_check<
     warning_count: &mut u32,
     stop_on_exit_codes: &[i32],
     stats: &mut ProcessWatcherStats,
+    restart_tracker: &mut RestartTracker,
 ) -> Result<Option<i32>, anyhow::Error> {
     let mut is_healthy = false;
     let mut ping_failed = false;
@@ -279,15 +413,20 @@ pub(crate) async fn do_health_check<
         let mut app_shutdown2 = global_shutdown_signal.clone();
         let current_uptime = uptime.elapsed();
 
-        match select! {
+        let health_status = select! {
             r = status_monitor3.check_health(current_uptime, health_timeout) => r,
             // Watch for shutdown signals
             _ = inner_shutdown2.wait() => HealthStatus::Healthy,
             _ = app_shutdown2.wait() => HealthStatus::Healthy
-        } {
+        };
+        stats.last_health_status = Some(health_status.clone());
+        match health_status {
             HealthStatus::Healthy => {
                 *warning_count = 0;
                 is_healthy = true;
+                stats.healthy_since_start = true;
+                stats.consecutive_startup_failures = 0;
+                restart_tracker.record_healthy();
             }
             HealthStatus::Initializing => {
                 // TODO(testing): If process stays in Initializing forever, no restart occurs.
@@ -314,6 +453,7 @@ pub(crate) async fn do_health_check<
         }
     } else {
         ping_failed = true;
+        stats.last_health_status = Some(HealthStatus::Unhealthy);
     }
     let health_check_duration = health_timer.elapsed();
     if health_check_duration > stats.max_health_check_duration {
@@ -333,6 +473,11 @@ pub(crate) async fn do_health_check<
         } else {
             match child.stop().await {
                 Ok(exit_code) => {
+                    restart_tracker.record_exit(exit_code);
+                    if let Some(exit_status) = take_exit_status(&name).await {
+                        info!(target: LOG_TARGET_STATUSES, "{name} {exit_status}");
+                        stats.record_exit(exit_status);
+                    }
                     if exit_code != 0 {
                         if stop_on_exit_codes.contains(&exit_code) {
                             return Ok(Some(exit_code));
@@ -350,11 +495,35 @@ pub(crate) async fn do_health_check<
                 }
             }
 
+            let backoff = match restart_tracker.next_restart(Instant::now()) {
+                RestartDecision::Restart { backoff } => backoff,
+                RestartDecision::OpenCircuit => {
+                    error!(target: LOG_TARGET_STATUSES, "{name} keeps failing, not restarting it until the circuit breaker is reset");
+                    return Ok(Some(
+                        restart_tracker
+                            .last_exit_codes()
+                            .last()
+                            .copied()
+                            .unwrap_or(1),
+                    ));
+                }
+            };
+
             // Restart dead app
-            sleep(Duration::from_secs(1)).await;
-            warn!(target: LOG_TARGET_STATUSES, "Restarting {name} after health check failure");
+            warn!(target: LOG_TARGET_STATUSES, "Restarting {name} in {backoff:?} after health check failure");
+            let mut inner_shutdown2 = inner_shutdown.clone();
+            let mut app_shutdown2 = global_shutdown_signal.clone();
+            select! {
+                _ = sleep(backoff) => {},
+                _ = inner_shutdown2.wait() => return Ok(None),
+                _ = app_shutdown2.wait() => return Ok(None),
+            }
             *uptime = Instant::now();
             stats.num_restarts += 1;
+            if !stats.healthy_since_start {
+                stats.consecutive_startup_failures += 1;
+            }
+            stats.healthy_since_start = false;
             match status_monitor3
                 .handle_unhealthy(*duration_since_last_healthy_status)
                 .await
@@ -30,6 +30,10 @@ use tokio::time::Instant;
 use tokio_util::task::TaskTracker;
 
 use crate::process_adapter::{HandleUnhealthyResult, HealthStatus, ProcessInstanceTrait};
+use crate::process_exit_status::{
+    ExitCause, ProcessExitStatus, record_exit_status, take_exit_status,
+};
+use crate::process_restart_policy::{RestartPolicy, RestartTracker};
 use crate::process_watcher::{ProcessWatcherStats, do_health_check};
 use crate::testing::mocks::process_mocks::{MockProcessInstance, MockStatusMonitor};
 
@@ -97,6 +101,7 @@ async fn healthy_status_resets_warning_count() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -139,6 +144,7 @@ async fn initializing_status_not_healthy_but_no_restart() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -181,6 +187,7 @@ async fn warning_under_threshold_treated_as_healthy() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -224,6 +231,7 @@ async fn warning_over_threshold_resets_count() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -266,6 +274,7 @@ async fn unhealthy_during_startup_waits_no_restart() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -310,6 +319,7 @@ async fn unhealthy_after_startup_triggers_restart() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -354,6 +364,7 @@ async fn ping_failure_triggers_restart_after_startup() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -399,6 +410,7 @@ async fn stop_on_exit_code_returns_exit_code() {
         &mut warning_count,
         &stop_on_exit_codes,
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -442,6 +454,7 @@ async fn non_listed_exit_code_triggers_restart() {
         &mut warning_count,
         &stop_on_exit_codes,
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -485,6 +498,7 @@ async fn handle_unhealthy_stop_returns_exit_code() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -527,6 +541,7 @@ async fn handle_unhealthy_continue_restarts_process() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -571,6 +586,7 @@ async fn shutdown_signal_prevents_restart() {
         &mut warning_count,
         &[],
         &mut stats,
+        &mut RestartTracker::default(),
     )
     .await
     .unwrap();
@@ -578,3 +594,230 @@ async fn shutdown_signal_prevents_restart() {
     assert!(result.is_none());
     assert_eq!(stats.num_restarts, 0);
 }
+
+#[tokio::test]
+async fn crashes_during_startup_count_as_startup_failures() {
+    let (
+        mut child,
+        status_monitor,
+        mut uptime,
+        mut duration_since_last_healthy,
+        global_shutdown,
+        tracker,
+        inner_shutdown,
+        mut stats,
+    ) = setup_test_context().await;
+
+    child.set_ping_result(false);
+    let mut warning_count = 0u32;
+    let mut restart_tracker = RestartTracker::default();
+
+    for _ in 0..2 {
+        do_health_check(
+            &mut child,
+            status_monitor.clone(),
+            "test_process".to_string(),
+            &mut uptime,
+            &mut duration_since_last_healthy,
+            Instant::now(),
+            Duration::from_secs(30),
+            Duration::from_secs(10),
+            global_shutdown.to_signal(),
+            tracker.clone(),
+            inner_shutdown.to_signal(),
+            &mut warning_count,
+            &[],
+            &mut stats,
+            &mut restart_tracker,
+        )
+        .await
+        .unwrap();
+    }
+
+    assert_eq!(stats.num_restarts, 2);
+    assert_eq!(stats.consecutive_startup_failures, 2);
+
+    child.set_ping_result(true);
+    do_health_check(
+        &mut child,
+        status_monitor,
+        "test_process".to_string(),
+        &mut uptime,
+        &mut duration_since_last_healthy,
+        Instant::now(),
+        Duration::from_secs(30),
+        Duration::from_secs(10),
+        global_shutdown.to_signal(),
+        tracker,
+        inner_shutdown.to_signal(),
+        &mut warning_count,
+        &[],
+        &mut stats,
+        &mut restart_tracker,
+    )
+    .await
+    .unwrap();
+
+    assert!(stats.healthy_since_start);
+    assert_eq!(stats.consecutive_startup_failures, 0);
+}
+
+#[tokio::test]
+async fn restart_after_healthy_run_is_not_a_startup_failure() {
+    let (
+        mut child,
+        status_monitor,
+        _uptime,
+        mut duration_since_last_healthy,
+        global_shutdown,
+        tracker,
+        inner_shutdown,
+        mut stats,
+    ) = setup_test_context().await;
+
+    stats.healthy_since_start = true;
+    status_monitor.set_health_status(HealthStatus::Unhealthy);
+
+    let mut uptime = Instant::now() - Duration::from_secs(60);
+    let mut warning_count = 0u32;
+
+    do_health_check(
+        &mut child,
+        status_monitor,
+        "test_process".to_string(),
+        &mut uptime,
+        &mut duration_since_last_healthy,
+        Instant::now(),
+        Duration::from_secs(30),
+        Duration::from_secs(10),
+        global_shutdown.to_signal(),
+        tracker,
+        inner_shutdown.to_signal(),
+        &mut warning_count,
+        &[],
+        &mut stats,
+        &mut RestartTracker::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(stats.num_restarts, 1);
+    assert_eq!(stats.consecutive_startup_failures, 0);
+    assert!(!stats.healthy_since_start);
+}
+
+#[tokio::test]
+async fn open_circuit_stops_restarting() {
+    let (
+        mut child,
+        status_monitor,
+        mut uptime,
+        mut duration_since_last_healthy,
+        global_shutdown,
+        tracker,
+        inner_shutdown,
+        mut stats,
+    ) = setup_test_context().await;
+
+    child.set_ping_result(false);
+    let mut warning_count = 0u32;
+    let mut restart_tracker = RestartTracker::new(RestartPolicy {
+        initial_backoff: Duration::from_millis(10),
+        jitter: 0.0,
+        max_restarts: 1,
+        ..Default::default()
+    });
+
+    let mut results = Vec::new();
+    for _ in 0..2 {
+        results.push(
+            do_health_check(
+                &mut child,
+                status_monitor.clone(),
+                "test_process".to_string(),
+                &mut uptime,
+                &mut duration_since_last_healthy,
+                Instant::now(),
+                Duration::from_secs(30),
+                Duration::from_secs(10),
+                global_shutdown.to_signal(),
+                tracker.clone(),
+                inner_shutdown.to_signal(),
+                &mut warning_count,
+                &[],
+                &mut stats,
+                &mut restart_tracker,
+            )
+            .await
+            .unwrap(),
+        );
+    }
+
+    assert_eq!(results[0], None);
+    assert!(results[1].is_some());
+    assert_eq!(stats.num_restarts, 1);
+    assert!(restart_tracker.is_circuit_open());
+}
+
+#[tokio::test]
+async fn restart_records_exit_status_and_resource_usage() {
+    let (
+        mut child,
+        status_monitor,
+        _uptime,
+        mut duration_since_last_healthy,
+        global_shutdown,
+        tracker,
+        inner_shutdown,
+        mut stats,
+    ) = setup_test_context().await;
+
+    status_monitor.set_health_status(HealthStatus::Unhealthy);
+    child.exit_code.store(137, Ordering::SeqCst);
+    stats.peak_rss_kb = 4096;
+    record_exit_status(
+        "exit_status_process",
+        ProcessExitStatus {
+            cause: ExitCause::Signaled,
+            signal: Some(9),
+            user_cpu_ms: Some(1500),
+            system_cpu_ms: Some(500),
+            max_rss_kb: Some(2048),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let mut uptime = Instant::now() - Duration::from_secs(60);
+    let mut warning_count = 0u32;
+
+    let result = do_health_check(
+        &mut child,
+        status_monitor,
+        "exit_status_process".to_string(),
+        &mut uptime,
+        &mut duration_since_last_healthy,
+        Instant::now(),
+        Duration::from_secs(30),
+        Duration::from_secs(10),
+        global_shutdown.to_signal(),
+        tracker,
+        inner_shutdown.to_signal(),
+        &mut warning_count,
+        &[],
+        &mut stats,
+        &mut RestartTracker::default(),
+    )
+    .await
+    .unwrap();
+
+    assert!(result.is_none());
+    assert_eq!(
+        stats.last_exit_status.as_ref().map(|status| status.cause),
+        Some(ExitCause::Signaled)
+    );
+    assert_eq!(stats.total_cpu_time, Duration::from_secs(2));
+    // The peak is kept across runs
+    assert_eq!(stats.peak_rss_kb, 4096);
+    assert!(take_exit_status("exit_status_process").await.is_none());
+}
@@ -29,7 +29,7 @@ use std::{
 use anyhow::{Error, anyhow};
 use dirs::cache_dir;
 use log::{debug, error, warn};
-use reqwest::{self, Client, Response};
+use reqwest::{self, Response};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
 use semver::Version;
@@ -44,6 +44,7 @@ use crate::{
         trait_config::ConfigImpl,
     },
     events::ShowReleaseNotesPayload,
+    requests::connectivity,
 };
 
 const CHANGELOG_URL: &str = "https://cdn-universe.tari.com/tari-project/universe/CHANGELOG.md";
@@ -124,13 +125,13 @@ impl ReleaseNotes {
         Ok(())
     }
 
-    fn build_retry_reqwest_client() -> ClientWithMiddleware {
+    fn build_retry_reqwest_client() -> Result<ClientWithMiddleware, Error> {
         debug!(target: LOG_TARGET_APP_LOGIC, "[build_retry_reqwest_client]");
         let retry_policy = ExponentialBackoff::builder().build_with_max_retries(5);
 
-        ClientBuilder::new(Client::new())
+        Ok(ClientBuilder::new(connectivity::client()?)
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-            .build()
+            .build())
     }
 
     pub fn get_etag_from_response(response: &Response) -> Result<String, Error> {
@@ -148,7 +149,7 @@ impl ReleaseNotes {
 
     async fn fetch_release_notes_header(&self) -> Result<String, Error> {
         debug!(target: LOG_TARGET_APP_LOGIC, "[fetch_release_notes_header]");
-        let client = ReleaseNotes::build_retry_reqwest_client();
+        let client = ReleaseNotes::build_retry_reqwest_client()?;
 
         debug!(target: LOG_TARGET_APP_LOGIC, "[fetch_release_notes_header] Fetching release notes header from {CHANGELOG_URL}");
         let response = client.head(CHANGELOG_URL).send().await?;
@@ -164,7 +165,7 @@ impl ReleaseNotes {
 
     async fn fetch_release_notes(&self) -> Result<(String, String), Error> {
         debug!(target: LOG_TARGET_APP_LOGIC, "[fetch_release_notes]");
-        let client = ReleaseNotes::build_retry_reqwest_client();
+        let client = ReleaseNotes::build_retry_reqwest_client()?;
 
         debug!(target: LOG_TARGET_APP_LOGIC, "[fetch_release_notes] Fetching release notes from {CHANGELOG_URL}");
         let response = client.get(CHANGELOG_URL).send().await?;
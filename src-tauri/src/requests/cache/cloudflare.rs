@@ -131,7 +131,7 @@ impl CloudFlareCache {
         const MIN_WAIT_TIME: u64 = 2;
         let mut retries = 0;
 
-        let http_client = HttpClient::default();
+        let http_client = HttpClient::default()?;
 
         loop {
             if retries >= MAX_RETRIES {
@@ -22,21 +22,20 @@
 
 use anyhow::anyhow;
 
-use crate::requests::utils::create_user_agent;
+use crate::requests::{connectivity, utils::create_user_agent};
 
 pub struct HttpClient {
     pub client: reqwest_middleware::ClientWithMiddleware,
 }
 
 impl HttpClient {
-    pub fn default() -> Self {
-        let inner_client = reqwest::ClientBuilder::new()
+    pub fn default() -> Result<Self, anyhow::Error> {
+        let inner_client = connectivity::client_builder()?
             .user_agent(create_user_agent())
-            .build()
-            .expect("Failed to create reqwest client");
+            .build()?;
         let client = reqwest_middleware::ClientBuilder::new(inner_client).build();
 
-        HttpClient { client }
+        Ok(HttpClient { client })
     }
 
     #[allow(dead_code)]
@@ -45,14 +44,13 @@ impl HttpClient {
     }
 
     #[allow(dead_code)]
-    pub fn with_retries(retries: u32) -> Self {
+    pub fn with_retries(retries: u32) -> Result<Self, anyhow::Error> {
         let retry_policy =
             reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(retries);
 
-        let inner_client = reqwest::ClientBuilder::new()
+        let inner_client = connectivity::client_builder()?
             .user_agent(create_user_agent())
-            .build()
-            .expect("Failed to create reqwest client");
+            .build()?;
 
         let client = reqwest_middleware::ClientBuilder::new(inner_client)
             .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
@@ -60,7 +58,7 @@ impl HttpClient {
             ))
             .build();
 
-        HttpClient { client }
+        Ok(HttpClient { client })
     }
 
     pub async fn send_head_request(&self, url: &str) -> Result<reqwest::Response, anyhow::Error> {
@@ -27,12 +27,13 @@ use std::path::PathBuf;
 use tokio::fs::create_dir_all;
 use tokio::sync::watch;
 use tokio::{fs::File, io::AsyncWriteExt};
+use url::Url;
 
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::download_utils::extract;
 use crate::requests::utils::{create_exponential_timeout, get_content_size_from_file};
 use crate::requests::{
-    cache::cloudflare::CloudFlareCache, utils::get_content_length_from_head_response,
+    cache::cloudflare::CloudFlareCache, connectivity, utils::get_content_length_from_head_response,
 };
 use crate::utils::network_status::NetworkStatus;
 
@@ -43,6 +44,7 @@ const MAX_RETRIES: u32 = 5;
 struct FileClientConfig {
     progress_status_sender: Option<watch::Sender<f64>>,
     should_extract: bool,
+    should_defer_extract: bool,
     should_use_range_header: bool,
     should_check_cloudflare_cache: bool,
 }
@@ -56,6 +58,7 @@ impl FileClientBuilder {
             config: FileClientConfig {
                 progress_status_sender: None,
                 should_extract: false,
+                should_defer_extract: false,
                 should_use_range_header: false,
                 should_check_cloudflare_cache: false,
             },
@@ -72,6 +75,13 @@ impl FileClientBuilder {
         self
     }
 
+    /// Downloads into the archive folder but leaves the extraction to the caller, so the archive
+    /// can be verified before anything is unpacked
+    pub fn with_deferred_extract(mut self) -> Self {
+        self.config.should_defer_extract = true;
+        self
+    }
+
     pub fn with_download_resume(mut self) -> Self {
         self.config.should_use_range_header = true;
         self
@@ -111,6 +121,7 @@ impl FileClientBuilder {
             config: FileClientConfig {
                 progress_status_sender: self.config.progress_status_sender,
                 should_extract: self.config.should_extract,
+                should_defer_extract: self.config.should_defer_extract,
                 should_use_range_header: self.config.should_use_range_header,
                 should_check_cloudflare_cache: self.config.should_check_cloudflare_cache,
             },
@@ -142,25 +153,57 @@ impl HttpFileClient {
     }
 
     pub async fn execute(&self) -> Result<PathBuf, anyhow::Error> {
-        if self.config.should_check_cloudflare_cache {
-            CloudFlareCache::check_if_cache_hits(&self.url).await?;
+        if !self.url.starts_with("file://") {
+            // Binaries of other phases are downloaded while Tor may still be starting
+            connectivity::wait_until_routable().await?;
         }
-
-        if self.config.should_use_range_header {
+        if self.url.starts_with("file://") {
+            self.handle_local_file_flow().await?;
+        } else if self.config.should_use_range_header {
+            if self.config.should_check_cloudflare_cache {
+                CloudFlareCache::check_if_cache_hits(&self.url).await?;
+            }
             self.handle_resume_flow().await?;
         } else {
+            if self.config.should_check_cloudflare_cache {
+                CloudFlareCache::check_if_cache_hits(&self.url).await?;
+            }
             self.handle_default_flow().await?;
         }
 
-        if self.config.should_extract {
+        if self.config.should_extract && !self.config.should_defer_extract {
             self.extract().await?;
         }
 
         Ok(self.get_destination().join(&self.file_name))
     }
 
+    /// Copies from a local binaries mirror instead of downloading
+    async fn handle_local_file_flow(&self) -> Result<(), anyhow::Error> {
+        let source = Url::parse(&self.url)?
+            .to_file_path()
+            .map_err(|_| anyhow!("Invalid file URL: {}", self.url))?;
+        let destination = self.get_destination();
+        let destination_file = destination.join(&self.file_name);
+
+        if !destination.exists() {
+            create_dir_all(destination)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to create directory: {}", e))?;
+        }
+
+        tokio::fs::copy(&source, &destination_file)
+            .await
+            .map_err(|e| anyhow!("Failed to copy {}: {}", source.display(), e))?;
+        if let Some(sender) = &self.config.progress_status_sender {
+            sender.send(100.0).ok();
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "File copied successfully to {}", destination.display());
+        Ok(())
+    }
+
     pub async fn handle_default_flow(&self) -> Result<(), anyhow::Error> {
-        let head_response = HttpClient::default().send_head_request(&self.url).await?;
+        let head_response = HttpClient::default()?.send_head_request(&self.url).await?;
         let expected_size = get_content_length_from_head_response(&head_response);
 
         let destination = self.get_destination();
@@ -204,7 +247,7 @@ impl HttpFileClient {
 
     #[allow(clippy::too_many_lines)]
     pub async fn handle_resume_flow(&self) -> Result<(), anyhow::Error> {
-        let head_response = HttpClient::default().send_head_request(&self.url).await?;
+        let head_response = HttpClient::default()?.send_head_request(&self.url).await?;
         let expected_size = get_content_length_from_head_response(&head_response);
         let destination = self.get_destination();
         let destination_file = destination.join(&self.file_name);
@@ -325,7 +368,7 @@ impl HttpFileClient {
         file: &mut File,
         resume: bool,
     ) -> Result<(), anyhow::Error> {
-        let mut request = HttpClient::default()
+        let mut request = HttpClient::default()?
             .client
             .get(&self.url)
             .header(reqwest::header::ACCEPT, "application/octet-stream");
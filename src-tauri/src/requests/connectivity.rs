@@ -0,0 +1,410 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Outbound connectivity shared by every HTTP and websocket client the app creates.
+
+use std::fmt::{self, Debug, Formatter};
+use std::net::SocketAddr;
+use std::sync::{LazyLock, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+use anyhow::{Error, anyhow, bail};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use log::info;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::sleep;
+use tokio_socks::tcp::Socks5Stream;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, client_async_tls, connect_async};
+use url::{Host, Url};
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_core::ConfigCore;
+use crate::configs::trait_config::ConfigImpl;
+
+const LOCAL_HOSTS: [&str; 3] = ["localhost", "127.0.0.1", "::1"];
+const MAX_CONNECT_RESPONSE_LENGTH: usize = 8 * 1024;
+/// How long downloads wait for Tor to come up when traffic is routed through it
+const TOR_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
+const TOR_WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+tokio::task_local! {
+    /// Set while the Tor bundle is downloaded, Tor can't route its own download
+    static TOR_BUNDLE_DOWNLOAD: ();
+}
+
+static STATE: LazyLock<RwLock<ConnectivityState>> =
+    LazyLock::new(|| RwLock::new(ConnectivityState::default()));
+
+#[derive(Default)]
+struct ConnectivityState {
+    connectivity: OutboundConnectivity,
+    /// Set while the bundled or system Tor is bootstrapped
+    tor_socks_address: Option<SocketAddr>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyKind {
+    #[default]
+    Socks5,
+    Http,
+}
+
+impl ProxyKind {
+    fn scheme(self) -> &'static str {
+        match self {
+            // Let the proxy resolve host names so lookups don't leak around it
+            ProxyKind::Socks5 => "socks5h",
+            ProxyKind::Http => "http",
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxySettings {
+    pub kind: ProxyKind,
+    pub host: String,
+    pub port: u16,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+impl ProxySettings {
+    fn tor(socks_address: SocketAddr) -> Self {
+        Self {
+            kind: ProxyKind::Socks5,
+            host: socks_address.ip().to_string(),
+            port: socks_address.port(),
+            username: None,
+            password: None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.host.is_empty()
+            || self
+                .host
+                .contains(|c: char| c.is_whitespace() || matches!(c, '/' | '@' | '?' | '#'))
+        {
+            bail!("Invalid proxy host: {:?}", self.host);
+        }
+        if self.port == 0 {
+            bail!("Proxy port must not be 0");
+        }
+        match (&self.username, &self.password) {
+            (Some(username), Some(_)) if username.is_empty() => {
+                bail!("Proxy username must not be empty")
+            }
+            (Some(_), None) | (None, Some(_)) => {
+                bail!("Proxy username and password must be set together")
+            }
+            _ => {}
+        }
+        self.url().map(drop)
+    }
+
+    fn credentials(&self) -> Option<(&str, &str)> {
+        self.username.as_deref().zip(self.password.as_deref())
+    }
+
+    fn authority(&self) -> String {
+        if self.host.contains(':') && !self.host.starts_with('[') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+
+    /// Proxy URL with percent encoded credentials, as expected by reqwest
+    pub fn url(&self) -> Result<Url, Error> {
+        let mut url = Url::parse(&format!("{}://{}", self.kind.scheme(), self.authority()))?;
+        if let Some((username, password)) = self.credentials() {
+            url.set_username(username)
+                .map_err(|()| anyhow!("Proxy URL can't hold a username"))?;
+            url.set_password(Some(password))
+                .map_err(|()| anyhow!("Proxy URL can't hold a password"))?;
+        }
+        Ok(url)
+    }
+}
+
+impl Debug for ProxySettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxySettings")
+            .field("kind", &self.kind)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum OutboundConnectivity {
+    #[default]
+    Direct,
+    /// Uses the SOCKS port of the managed Tor instance. Requests fail until Tor has bootstrapped,
+    /// only the Tor binaries themselves are downloaded directly.
+    Tor,
+    Proxy(ProxySettings),
+}
+
+impl OutboundConnectivity {
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            OutboundConnectivity::Direct | OutboundConnectivity::Tor => Ok(()),
+            OutboundConnectivity::Proxy(settings) => settings.validate(),
+        }
+    }
+}
+
+pub fn set_connectivity(connectivity: OutboundConnectivity) {
+    info!(target: LOG_TARGET_APP_LOGIC, "Outbound connectivity set to {connectivity:?}");
+    STATE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .connectivity = connectivity;
+}
+
+/// Applies the saved setting, before anything is requested on startup
+pub async fn load_from_config() {
+    set_connectivity(ConfigCore::content().await.outbound_connectivity().clone());
+}
+
+pub fn set_tor_socks_address(socks_address: Option<SocketAddr>) {
+    STATE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .tor_socks_address = socks_address;
+}
+
+/// Whether traffic is routed through Tor, whether or not Tor is up yet
+pub fn routes_through_tor() -> bool {
+    STATE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .connectivity
+        == OutboundConnectivity::Tor
+}
+
+/// Runs the download of the Tor bundle, which goes out directly while Tor isn't up
+pub async fn download_tor_bundle<F: Future>(download: F) -> F::Output {
+    TOR_BUNDLE_DOWNLOAD.scope((), download).await
+}
+
+/// The proxy new connections should go through, if any. Fails while traffic is routed through
+/// Tor and Tor isn't up.
+pub fn active_proxy() -> Result<Option<ProxySettings>, Error> {
+    let state = STATE.read().unwrap_or_else(PoisonError::into_inner);
+    match &state.connectivity {
+        OutboundConnectivity::Direct => Ok(None),
+        OutboundConnectivity::Tor => match state.tor_socks_address {
+            Some(socks_address) => Ok(Some(ProxySettings::tor(socks_address))),
+            None if TOR_BUNDLE_DOWNLOAD.try_with(|()| ()).is_ok() => Ok(None),
+            None => bail!("Tor is not running, connections are blocked until it is"),
+        },
+        OutboundConnectivity::Proxy(settings) => Ok(Some(settings.clone())),
+    }
+}
+
+/// Waits until connections can go out, for downloads that shouldn't fail only because Tor is
+/// still starting
+pub async fn wait_until_routable() -> Result<(), Error> {
+    let deadline = Instant::now() + TOR_WAIT_TIMEOUT;
+    loop {
+        match active_proxy() {
+            Ok(_) => return Ok(()),
+            Err(e) if Instant::now() >= deadline => return Err(e),
+            Err(_) => sleep(TOR_WAIT_POLL_INTERVAL).await,
+        }
+    }
+}
+
+fn reqwest_proxy(settings: &ProxySettings) -> Result<reqwest::Proxy, Error> {
+    Ok(reqwest::Proxy::all(settings.url()?)?
+        .no_proxy(reqwest::NoProxy::from_string(&LOCAL_HOSTS.join(","))))
+}
+
+/// Client builder routed through the active proxy. Callers add their own options on top.
+pub fn client_builder() -> Result<reqwest::ClientBuilder, Error> {
+    let builder = reqwest::Client::builder();
+    match active_proxy()? {
+        Some(settings) => Ok(builder.proxy(reqwest_proxy(&settings)?)),
+        None => Ok(builder),
+    }
+}
+
+pub fn client() -> Result<reqwest::Client, Error> {
+    Ok(client_builder()?.build()?)
+}
+
+pub fn blocking_client() -> Result<reqwest::blocking::Client, Error> {
+    let builder = reqwest::blocking::Client::builder();
+    let builder = match active_proxy()? {
+        Some(settings) => builder.proxy(reqwest_proxy(&settings)?),
+        None => builder,
+    };
+    Ok(builder.build()?)
+}
+
+fn is_local(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Domain(domain)) => LOCAL_HOSTS.contains(&domain),
+        Some(Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
+/// Host and port to ask the proxy for, with IPv6 addresses unbracketed
+fn target_address(url: &Url) -> Result<(String, u16), Error> {
+    let host = match url.host() {
+        Some(Host::Domain(domain)) => domain.to_string(),
+        Some(Host::Ipv4(ip)) => ip.to_string(),
+        Some(Host::Ipv6(ip)) => ip.to_string(),
+        None => bail!("URL has no host: {url}"),
+    };
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| anyhow!("URL has no port: {url}"))?;
+    Ok((host, port))
+}
+
+async fn socks5_tunnel(settings: &ProxySettings, url: &Url) -> Result<TcpStream, Error> {
+    let (host, port) = target_address(url)?;
+    let proxy = (settings.host.as_str(), settings.port);
+    let stream = match settings.credentials() {
+        Some((username, password)) => {
+            Socks5Stream::connect_with_password(proxy, (host.as_str(), port), username, password)
+                .await?
+        }
+        None => Socks5Stream::connect(proxy, (host.as_str(), port)).await?,
+    };
+    Ok(stream.into_inner())
+}
+
+async fn http_connect_tunnel(settings: &ProxySettings, url: &Url) -> Result<TcpStream, Error> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("URL has no host: {url}"))?;
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| anyhow!("URL has no port: {url}"))?;
+    let authority = format!("{host}:{port}");
+
+    let mut stream = TcpStream::connect((settings.host.as_str(), settings.port)).await?;
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some((username, password)) = settings.credentials() {
+        let token = BASE64_STANDARD.encode(format!("{username}:{password}"));
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read byte by byte so nothing after the response head is taken from the tunnel
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_CONNECT_RESPONSE_LENGTH {
+            bail!("Proxy CONNECT response is too long");
+        }
+        if stream.read(&mut byte).await? == 0 {
+            bail!("Proxy closed the connection during CONNECT");
+        }
+        response.push(byte[0]);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some("200") => Ok(stream),
+        _ => bail!("Proxy refused CONNECT to {authority}: {status_line}"),
+    }
+}
+
+/// Opens a websocket through the active proxy
+pub async fn connect_websocket(
+    url: &str,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, Error> {
+    let parsed_url = Url::parse(url)?;
+    let proxy = if is_local(&parsed_url) {
+        None
+    } else {
+        active_proxy()?
+    };
+    let stream = match proxy {
+        Some(settings) if settings.kind == ProxyKind::Socks5 => {
+            socks5_tunnel(&settings, &parsed_url).await?
+        }
+        Some(settings) => http_connect_tunnel(&settings, &parsed_url).await?,
+        None => {
+            let (ws_stream, _) = connect_async(url).await?;
+            return Ok(ws_stream);
+        }
+    };
+    let (ws_stream, _) = client_async_tls(url, stream).await?;
+    Ok(ws_stream)
+}
+
+/// Opens a plain TCP connection through the active proxy, for protocols other than HTTP
+pub async fn connect_tcp(host: &str, port: u16) -> Result<TcpStream, Error> {
+    let authority = if host.contains(':') && !host.starts_with('[') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+    let url = Url::parse(&format!("tcp://{authority}"))?;
+    let proxy = if is_local(&url) {
+        None
+    } else {
+        active_proxy()?
+    };
+    match proxy {
+        Some(settings) if settings.kind == ProxyKind::Socks5 => {
+            socks5_tunnel(&settings, &url).await
+        }
+        Some(settings) => http_connect_tunnel(&settings, &url).await,
+        None => {
+            let host = host.trim_start_matches('[').trim_end_matches(']');
+            Ok(TcpStream::connect((host, port)).await?)
+        }
+    }
+}
+
+/// Value for xmrig's `--proxy` option. xmrig only speaks SOCKS5 without authentication, so other
+/// proxies are refused rather than letting the miner connect to its pool directly.
+pub fn miner_socks_proxy() -> Result<Option<String>, Error> {
+    let Some(settings) = active_proxy()? else {
+        return Ok(None);
+    };
+    if settings.kind != ProxyKind::Socks5 {
+        bail!("The CPU miner can only use a SOCKS5 proxy, change or disable the proxy to mine");
+    }
+    if settings.credentials().is_some() {
+        bail!("The CPU miner can't use a proxy that requires a username and password");
+    }
+    Ok(Some(settings.authority()))
+}
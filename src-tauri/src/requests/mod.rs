@@ -22,6 +22,9 @@
 
 mod cache;
 pub mod clients;
+pub mod connectivity;
+#[cfg(test)]
+mod connectivity_test;
 pub mod utils;
 
 // https://github.com/tari-project/glytex/releases/download/v0.2.29/glytex-opencl-linux-x86_64-testnet-0.2.29-fd0dd7f.zip
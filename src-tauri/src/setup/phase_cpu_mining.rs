@@ -22,8 +22,13 @@
 
 use crate::{
     LOG_TARGET_APP_LOGIC, UniverseAppState,
-    binaries::{Binaries, BinaryResolver},
-    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    binaries::{Binaries, BinaryResolver, binaries_list::BinaryPlatformAssets},
+    configs::{
+        config_core::ConfigCore,
+        config_mining::{ConfigMining, ConfigMiningContent},
+        trait_config::ConfigImpl,
+    },
+    events_emitter::EventsEmitter,
     hardware::hardware_status_monitor::HardwareStatusMonitor,
     internal_wallet::InternalWallet,
     mm_proxy_manager::StartConfig,
@@ -38,7 +43,7 @@ use crate::{
     tasks_tracker::TasksTrackers,
 };
 use anyhow::Error;
-use log::warn;
+use log::{info, warn};
 use std::sync::atomic::AtomicBool;
 use tari_shutdown::ShutdownSignal;
 use tauri::{AppHandle, Manager};
@@ -166,6 +171,15 @@ impl SetupPhaseImpl for CpuMiningSetupPhase {
     }
 
     async fn setup_inner(&self) -> Result<(), Error> {
+        // XMRig has no build for every platform (e.g., Linux ARM64), disable CPU mining there
+        if !Binaries::Xmrig.has_build_for(BinaryPlatformAssets::current()) {
+            info!(target: LOG_TARGET_APP_LOGIC, "CPU mining not supported on this platform, disabling CPU mining");
+            ConfigMining::update_field(ConfigMiningContent::set_cpu_mining_enabled, false).await?;
+            EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+            return Ok(());
+        }
+
         let mut progress_stepper = self.progress_stepper.lock().await;
         let (data_dir, config_dir, log_dir) = self.get_app_dirs()?;
         let state = self.app_handle.state::<UniverseAppState>();
@@ -149,7 +149,7 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
     #[allow(clippy::too_many_lines)]
     async fn setup_inner(&self) -> Result<(), Error> {
         // Check if any GPU miner is supported on this platform
-        // If not (e.g., macOS), disable GPU mining and skip the entire phase
+        // If not (e.g., macOS or Linux ARM64), disable GPU mining and skip the entire phase
         if !GpuMinerType::LolMiner.is_supported_on_current_platform() {
             info!(target: LOG_TARGET_APP_LOGIC, "GPU mining not supported on this platform, disabling GPU mining");
             ConfigMining::update_field(ConfigMiningContent::set_gpu_mining_enabled, false).await?;
@@ -171,7 +171,7 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
             .complete_step(SetupStep::BinariesGpuMiner, || async {
                 let mut is_any_miner_succeeded = false;
 
-                // LolMiner is supported on Windows | Linux
+                // LolMiner is supported on Windows x64 | Linux x64
                 if GpuMinerType::LolMiner.is_supported_on_current_platform() {
                     let lolminer_initialization_result = binary_resolver
                         .initialize_binary(Binaries::LolMiner, lolminer_binary_progress_tracker)
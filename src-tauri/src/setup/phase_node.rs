@@ -179,8 +179,17 @@ impl SetupPhaseImpl for NodeSetupPhase {
 
         let tor_binary_progress_tracker =
             progress_stepper.track_step_incrementally(SetupStep::BinariesTor);
+        // A system Tor daemon is used as it is, without the bundled binary or libtor
+        let use_external_tor = use_tor
+            && state
+                .tor_manager
+                .load_config(config_dir.clone())
+                .await?
+                .external()
+                .is_some();
         // MacOS uses built-in libtor
-        let skip_tor_binary = !use_tor || cfg!(target_os = "macos");
+        let skip_tor_binary = !use_tor || use_external_tor || cfg!(target_os = "macos");
+        let skip_tor_start = !use_tor || (cfg!(target_os = "macos") && !use_external_tor);
 
         progress_stepper
             .complete_step(SetupStep::BinariesTor, || async {
@@ -189,6 +198,26 @@ impl SetupPhaseImpl for NodeSetupPhase {
                 }
                 binary_resolver
                     .initialize_binary(Binaries::Tor, tor_binary_progress_tracker)
+                    .await?;
+                // Bridges can switch transports at any time, the plugins come with the Tor bundle
+                for transport_binary in [Binaries::Lyrebird, Binaries::Snowflake] {
+                    binary_resolver
+                        .initialize_binary(transport_binary, None)
+                        .await?;
+                }
+                Ok(())
+            })
+            .await?;
+
+        // Started before the node binary is downloaded, which may have to go through Tor
+        progress_stepper
+            .complete_step(SetupStep::StartTor, || async {
+                if skip_tor_start {
+                    return Ok(());
+                }
+                state
+                    .tor_manager
+                    .ensure_started(data_dir.clone(), config_dir.clone(), log_dir.clone())
                     .await
             })
             .await?;
@@ -204,28 +233,16 @@ impl SetupPhaseImpl for NodeSetupPhase {
             })
             .await?;
 
-        progress_stepper
-            .complete_step(SetupStep::StartTor, || async {
-                if skip_tor_binary {
-                    return Ok(());
-                }
-                state
-                    .tor_manager
-                    .ensure_started(data_dir.clone(), config_dir.clone(), log_dir.clone())
-                    .await
-            })
-            .await?;
-
         progress_stepper.complete_step(SetupStep::StartingNode, || async {
             for _i in 0..2 {
-                let tor_control_port = state.tor_manager.get_control_port().await?;
+                let tor_control = state.tor_manager.get_control_endpoint().await;
                 match
                     state.node_manager.ensure_started(
                         node_data_dir.clone(),
                         config_dir.clone(),
                         log_dir.clone(),
                         use_tor,
-                        tor_control_port,
+                        tor_control,
                         Some(app_configuration.base_node_grpc_address.clone())
                     ).await
                 {
@@ -321,8 +338,10 @@ impl SetupPhaseImpl for NodeSetupPhase {
             app_handle_clone.state::<UniverseAppState>();
         let node_type = state.node_manager.get_node_type().await;
         let app_configuration = Self::load_app_configuration().await.unwrap_or_default();
-        let use_tor =
-            app_configuration.use_tor && node_type.is_local() && !cfg!(target_os = "macos");
+        // Entry guards come from the control port, which libtor on macOS doesn't expose
+        let use_tor = app_configuration.use_tor
+            && node_type.is_local()
+            && state.tor_manager.get_control_endpoint().await.is_some();
 
         if use_tor {
             let tor_guards = state.tor_manager.get_entry_guards().await;
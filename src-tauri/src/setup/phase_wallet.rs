@@ -163,7 +163,16 @@ impl SetupPhaseImpl for WalletSetupPhase {
         let (data_dir, config_dir, log_dir) = self.get_app_dirs()?;
         let app_state_clone = app_state.clone();
         let is_local_node = app_state.node_manager.is_local_current().await;
-        let use_tor = self.app_configuration.use_tor && is_local_node && !cfg!(target_os = "macos");
+        // Libtor on macOS is only used by the node, a system Tor can be shared with the wallet
+        let uses_external_tor = app_state
+            .tor_manager
+            .get_tor_config()
+            .await
+            .external()
+            .is_some();
+        let use_tor = self.app_configuration.use_tor
+            && is_local_node
+            && (!cfg!(target_os = "macos") || uses_external_tor);
 
         let binary_resolver = BinaryResolver::current();
 
@@ -55,7 +55,8 @@ use crate::utils::platform_utils::PlatformUtils;
 use crate::{
     UniverseAppState,
     configs::{
-        config_core::ConfigCore, config_mcp::ConfigMcp, config_mining::ConfigMining,
+        config_core::ConfigCore, config_fleet::ConfigFleet, config_mcp::ConfigMcp,
+        config_mining::ConfigMining, config_notifications::ConfigNotifications,
         config_ui::ConfigUI, config_wallet::ConfigWallet, trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
@@ -310,6 +311,8 @@ impl SetupManager {
         ConfigUI::initialize(app_handle.clone()).await;
         ConfigPools::initialize(app_handle.clone()).await;
         ConfigMcp::initialize(app_handle.clone()).await;
+        ConfigFleet::initialize(app_handle.clone()).await;
+        ConfigNotifications::initialize(app_handle.clone()).await;
 
         // Initialize MCP server with node status receiver for chain tools
         crate::mcp::server::McpServerManager::initialize(
@@ -326,6 +329,14 @@ impl SetupManager {
             warn!(target: LOG_TARGET_APP_LOGIC, "Failed to auto-start MCP server: {e}");
         }
 
+        if let Err(e) = crate::fleet::restart(app_handle.package_info().version.to_string()).await {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to start fleet mode: {e}");
+        }
+
+        if let Err(e) = crate::notifications::Notifications::start().await {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to start notifications: {e}");
+        }
+
         let _ = check_data_import(app_handle.clone()).await.map_err(|e| {
             error!(target: LOG_TARGET_APP_LOGIC, "Error in data import: {e}");
         });
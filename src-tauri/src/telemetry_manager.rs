@@ -38,6 +38,10 @@ use crate::node::node_adapter::BaseNodeStatus;
 use crate::node::node_manager::NodeManager;
 use crate::process_stats_collector::ProcessStatsCollector;
 use crate::process_utils::retry_with_backoff;
+use crate::requests::connectivity;
+use crate::telemetry_sink::{
+    self, TelemetryEnvelope, TelemetryKind, TelemetrySink, VendorEndpoints,
+};
 use crate::tor_control_client::TorStatus;
 use crate::utils::address_utils::extract_payment_id;
 use crate::utils::network_status::NetworkStatus;
@@ -330,10 +334,11 @@ impl TelemetryManager {
                         let airdrop_access_token_validated = airdrop::validate_jwt(airdrop_access_token).await;
                         let memory_config = in_memory_config_cloned.read().await;
                         let exchange_id = memory_config.exchange_id.clone();
+                        let vendor = VendorEndpoints::new(&memory_config, airdrop_access_token_validated);
                         let telemetry_data = cancellable_get_telemetry_data(app_handle.clone(),&cpu_miner_status_watch_rx, &gpu_status, &node_status,
                             &tor_status, network, exchange_id, uptime, &stats_collector, &node_manager, &mut (shutdown_signal.clone())).await;
                         let airdrop_api_url = in_memory_config_cloned.read().await.airdrop_api_url.clone();
-                        handle_data(telemetry_data, airdrop_api_url, airdrop_access_token_validated, app_handle.clone(), &mut (shutdown_signal.clone()), allow_telemetry, allow_notifications).await;
+                        handle_data(telemetry_data, airdrop_api_url, vendor, app_handle.clone(), &mut (shutdown_signal.clone()), allow_telemetry, allow_notifications).await;
 
                     },
                     _ = shutdown_signal.wait() => {
@@ -535,6 +540,13 @@ async fn get_telemetry_data_inner(
         "tor_circuit_ok".to_string(),
         tor_status.circuit_ok.to_string(),
     );
+    if let Some(transport) = tor_status.transport {
+        extra_data.insert("tor_transport".to_string(), transport.to_string());
+        extra_data.insert(
+            "tor_transport_health".to_string(),
+            tor_status.transport_health.to_string(),
+        );
+    }
 
     if !all_cpus.is_empty() {
         extra_data.insert("all_cpus".to_string(), all_cpus.join(","));
@@ -728,12 +740,32 @@ fn add_process_stats(
             .as_secs()
             .to_string(),
     );
+    if let Some(resource_limits) = process_stats.resource_limits {
+        extra_data.insert(
+            format!("{process}_resource_limits"),
+            resource_limits.mechanism.to_string(),
+        );
+    }
+    extra_data.insert(
+        format!("{process}_total_cpu_seconds"),
+        process_stats.total_cpu_time.as_secs().to_string(),
+    );
+    extra_data.insert(
+        format!("{process}_peak_rss_kb"),
+        process_stats.peak_rss_kb.to_string(),
+    );
+    if let Some(exit_status) = process_stats.last_exit_status {
+        extra_data.insert(
+            format!("{process}_last_exit_cause"),
+            exit_status.cause.to_string(),
+        );
+    }
 }
 
 async fn handle_data(
     data: Result<TelemetryData, TelemetryManagerError>,
     airdrop_api_url: String,
-    airdrop_access_token: Option<String>,
+    vendor: VendorEndpoints,
     app_handle: tauri::AppHandle,
     shutdown_signal: &mut ShutdownSignal,
     allow_telemetry: bool,
@@ -742,13 +774,31 @@ async fn handle_data(
     match data {
         Ok(telemetry) => {
             if allow_telemetry {
+                let (sink, redacted_fields) = {
+                    let config = ConfigCore::content().await;
+                    (
+                        config.telemetry_sink().clone(),
+                        config.telemetry_redacted_fields().clone(),
+                    )
+                };
+                let envelope = match TelemetryEnvelope::new(
+                    TelemetryKind::Heartbeat,
+                    &telemetry,
+                    &redacted_fields,
+                ) {
+                    Ok(envelope) => envelope,
+                    Err(e) => {
+                        error!(target: LOG_TARGET_APP_LOGIC, "Error preparing telemetry data: {e}");
+                        return;
+                    }
+                };
                 let telemetry_response = tokio::select! {
                     response = retry_with_backoff(
                         || {
                             Box::pin(send_telemetry_data(
-                                telemetry.clone(),
-                                airdrop_access_token.clone(),
-                                airdrop_api_url.clone(),
+                                envelope.clone(),
+                                sink.clone(),
+                                vendor.clone(),
                             ))
                         },
                         3,
@@ -763,6 +813,7 @@ async fn handle_data(
 
                 match telemetry_response {
                     Ok(response) => {
+                        telemetry_sink::replay(&sink, &vendor).await;
                         if let Some(response_inner) = response
                             && let Some(user_points) = response_inner.user_points
                         {
@@ -785,13 +836,14 @@ async fn handle_data(
                     }
                     Err(e) => {
                         error!(target: LOG_TARGET_APP_LOGIC,"Error sending telemetry data: {e}");
+                        telemetry_sink::spool(&envelope).await;
                     }
                 }
             }
 
             if allow_notifications {
                 let notification_data_response = tokio::select! {
-                    response = send_notification_data(telemetry.into(), airdrop_access_token, airdrop_api_url)
+                    response = send_notification_data(telemetry.into(), vendor.access_token, airdrop_api_url)
                         =>response,
                     _ = shutdown_signal.wait() => {
                         debug!(target: LOG_TARGET_APP_LOGIC, "mining status notification data sending cancelled by shutdown signal");
@@ -819,49 +871,23 @@ async fn handle_data(
 }
 
 async fn send_telemetry_data(
-    data: TelemetryData,
-    airdrop_access_token: Option<String>,
-    airdrop_api_url: String,
+    envelope: TelemetryEnvelope,
+    sink: TelemetrySink,
+    vendor: VendorEndpoints,
 ) -> Result<Option<TelemetryDataResponse>, TelemetryManagerError> {
-    let request = reqwest::Client::new();
-    let mut request_builder = request
-        .post(format!("{airdrop_api_url}/miner/heartbeat"))
-        .header(
-            "User-Agent".to_string(),
-            format!("tari-universe/{}", data.version.clone()),
-        )
-        .json(&data);
-
-    if let Some(token) = airdrop_access_token.clone() {
-        request_builder = request_builder.header("Authorization", format!("Bearer {token}"));
-    }
-
-    let response = request_builder.send().await?;
-
-    if response.status() == 429 {
-        warn!(target: LOG_TARGET_APP_LOGIC,"Telemetry data rate limited by http {:?}", response.status());
-        return Ok(None);
-    }
-
-    if response.status() != 200 {
-        let status = response.status();
-        let response = response.text().await?;
-        let response_as_json: Result<Value, serde_json::Error> = serde_json::from_str(&response);
-        return Err(anyhow::anyhow!(
-            "Telemetry data sending error. Status {:?} response text: {:?}",
-            status.to_string(),
-            response_as_json.unwrap_or(response.into()),
-        )
-        .into());
-    }
+    let response = telemetry_sink::deliver(&sink, &envelope, &vendor).await?;
 
     debug!(target: LOG_TARGET_APP_LOGIC,"Telemetry data sent");
 
-    if airdrop_access_token.is_some() {
-        let data: TelemetryDataResponse = response.json().await?;
-        return Ok(Some(data));
+    // Points are only reported back by the vendor endpoint for signed in users
+    match response {
+        Some(body) if vendor.access_token.is_some() => {
+            let data: TelemetryDataResponse =
+                serde_json::from_value(body).map_err(anyhow::Error::from)?;
+            Ok(Some(data))
+        }
+        _ => Ok(None),
     }
-    Ok(None)
 }
 
 async fn send_notification_data(
@@ -869,7 +895,7 @@ async fn send_notification_data(
     airdrop_access_token: Option<String>,
     airdrop_api_url: String,
 ) -> Result<(), TelemetryManagerError> {
-    let request = reqwest::Client::new();
+    let request = connectivity::client()?;
 
     let mut request_builder = request
         .post(format!("{airdrop_api_url}/miner/notifications"))
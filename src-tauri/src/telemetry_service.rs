@@ -36,6 +36,9 @@ use crate::{
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
     hardware::hardware_status_monitor::HardwareStatusMonitor,
     tasks_tracker::TasksTrackers,
+    telemetry_sink::{
+        self, TelemetryEnvelope, TelemetryInspection, TelemetryKind, VendorEndpoints,
+    },
     utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
@@ -68,6 +71,8 @@ pub enum TelemetryServiceError {
     ReqwestError(#[from] reqwest::Error),
     #[error("TelemetryService has already been started")]
     AlreadyStarted,
+    #[error("TelemetryService has not been started")]
+    NotStarted,
 }
 
 pub struct TelemetryService {
@@ -76,6 +81,7 @@ pub struct TelemetryService {
     rx_channel: Option<Receiver<TelemetryData>>,
     cancellation_token: CancellationToken,
     in_memory_config: Arc<RwLock<AppInMemoryConfig>>,
+    system_info: Option<SystemInfo>,
 }
 
 impl TelemetryService {
@@ -89,6 +95,7 @@ impl TelemetryService {
             rx_channel: Some(rx),
             cancellation_token,
             in_memory_config,
+            system_info: None,
         }
     }
     pub async fn init(
@@ -102,25 +109,22 @@ impl TelemetryService {
         self.version = app_version;
         let cancellation_token = self.cancellation_token.clone();
         let in_memory_config_cloned = self.in_memory_config.clone();
-        let in_memory_config_guard = in_memory_config_cloned.read().await;
-        let telemetry_api_url = in_memory_config_guard.telemetry_api_url.clone();
-        let in_memory_config_cloned_2 = self.in_memory_config.clone();
-        let version = self.version.clone();
         let mut rx = self
             .rx_channel
             .take()
             .ok_or_else(|| TelemetryServiceError::AlreadyStarted)?;
+        let system_info = SystemInfo {
+            version: self.version.clone(),
+            user_id: user,
+            os,
+        };
+        self.system_info = Some(system_info.clone());
 
         TasksTrackers::current()
             .common
             .get_task_tracker()
             .await
             .spawn(async move {
-                let system_info = SystemInfo {
-                    version,
-                    user_id: user,
-                    os,
-                };
                 loop {
                 tokio::select! {
                     telemetry_data = rx.recv() => {
@@ -135,10 +139,9 @@ impl TelemetryService {
                         let anon_id = ConfigCore::content().await.anon_id().clone();
                         let _unused = send_telemetry_data(
                             telemetry_data,
-                            telemetry_api_url.clone(),
                             system_info.clone(),
                             anon_id.clone(),
-                            in_memory_config_cloned_2 .clone()).await.inspect_err(|e| warn!(target: LOG_TARGET_APP_LOGIC,"Could not send telemetry data. Error: {:?}", e));
+                            in_memory_config_cloned.clone()).await.inspect_err(|e| warn!(target: LOG_TARGET_APP_LOGIC,"Could not send telemetry data. Error: {:?}", e));
                         } else {
                             warn!(target: LOG_TARGET_APP_LOGIC,"TelemetryService::init telemetry data is None");
                             break;
@@ -175,6 +178,34 @@ impl TelemetryService {
         }
         Ok(())
     }
+
+    /// Builds a sample event exactly as it would be sent and lists its fields with the
+    /// configured redactions applied
+    pub async fn inspect(&self) -> Result<TelemetryInspection, TelemetryServiceError> {
+        let system_info = self
+            .system_info
+            .clone()
+            .ok_or(TelemetryServiceError::NotStarted)?;
+        let config = ConfigCore::content().await;
+        let sample = TelemetryData {
+            event_name: "telemetry-inspector".to_string(),
+            event_value: Value::Null,
+        };
+        let full_data = build_full_telemetry_data(
+            sample,
+            system_info,
+            config.anon_id().clone(),
+            &self.in_memory_config,
+        )
+        .await?;
+        let payload = serde_json::to_value(&full_data).map_err(anyhow::Error::from)?;
+
+        Ok(TelemetryInspection {
+            sink: config.telemetry_sink().clone(),
+            spool: telemetry_sink::spool_status().await,
+            fields: telemetry_sink::preview_fields(&payload, config.telemetry_redacted_fields()),
+        })
+    }
 }
 
 #[derive(Clone)]
@@ -184,16 +215,12 @@ struct SystemInfo {
     os: CurrentOperatingSystem,
 }
 
-async fn send_telemetry_data(
+async fn build_full_telemetry_data(
     data: TelemetryData,
-    api_url: String,
     system_info: SystemInfo,
     app_id: String,
-    memory_config: Arc<RwLock<AppInMemoryConfig>>,
-) -> Result<(), TelemetryServiceError> {
-    let config_read_guard = memory_config.read().await;
-    let request = reqwest::Client::new();
-
+    memory_config: &RwLock<AppInMemoryConfig>,
+) -> Result<FullTelemetryData, TelemetryServiceError> {
     let hardware = HardwareStatusMonitor::current();
 
     let cpu_name = hardware.get_cpu_devices().await?;
@@ -207,9 +234,9 @@ async fn send_telemetry_data(
         Some(gpu) => gpu.public_properties.name.clone(),
         None => "Unknown".to_string(),
     };
-    let exchange_id = config_read_guard.exchange_id.clone();
+    let exchange_id = memory_config.read().await.exchange_id.clone();
 
-    let full_data = FullTelemetryData {
+    Ok(FullTelemetryData {
         event_name: data.event_name,
         event_value: data.event_value,
         created_at: SystemTime::now(),
@@ -220,32 +247,29 @@ async fn send_telemetry_data(
         cpu_name,
         gpu_name,
         exchange_id,
-    };
-    let request_builder = request
-        .post(api_url)
-        .header(
-            "User-Agent".to_string(),
-            format!("tari-universe/{}", full_data.version.clone()),
-        )
-        .json(&full_data);
-
-    let response = request_builder.send().await?;
-
-    if response.status() == 429 {
-        warn!(target: LOG_TARGET_APP_LOGIC,"TelemetryService::send_telemetry_data Telemetry data rate limited by http {:?}", response.status());
-        return Ok(());
-    }
+    })
+}
 
-    if response.status() != 200 {
-        let status = response.status();
-        let response = response.text().await?;
-        let response_as_json: Result<Value, serde_json::Error> = serde_json::from_str(&response);
-        return Err(anyhow::anyhow!(
-            "Telemetry data sending error. Status {:?} response text: {:?}",
-            status.to_string(),
-            response_as_json.unwrap_or(response.into()),
+async fn send_telemetry_data(
+    data: TelemetryData,
+    system_info: SystemInfo,
+    app_id: String,
+    memory_config: Arc<RwLock<AppInMemoryConfig>>,
+) -> Result<(), TelemetryServiceError> {
+    let full_data = build_full_telemetry_data(data, system_info, app_id, &memory_config).await?;
+    let (sink, redacted_fields) = {
+        let config = ConfigCore::content().await;
+        (
+            config.telemetry_sink().clone(),
+            config.telemetry_redacted_fields().clone(),
         )
-        .into());
+    };
+    let envelope = TelemetryEnvelope::new(TelemetryKind::Event, &full_data, &redacted_fields)?;
+    let vendor = VendorEndpoints::new(&*memory_config.read().await, None);
+
+    if let Err(e) = telemetry_sink::deliver(&sink, &envelope, &vendor).await {
+        telemetry_sink::spool(&envelope).await;
+        return Err(e.into());
     }
 
     debug!(target: LOG_TARGET_APP_LOGIC,"TelemetryService::send_telemetry_data Telemetry data sent");
@@ -0,0 +1,468 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Destinations for telemetry leaving the app.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Error, anyhow, bail};
+use dirs::config_dir;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tari_common::configuration::Network;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use url::Url;
+
+use crate::app_in_memory_config::AppInMemoryConfig;
+use crate::configs::config_core::ConfigCore;
+use crate::configs::trait_config::ConfigImpl;
+use crate::requests::connectivity;
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+pub const DEFAULT_SPOOL_MAX_BYTES: u64 = 10 * 1024 * 1024;
+const SPOOL_FILE_NAME: &str = "telemetry_spool.jsonl";
+const REPLAY_BATCH_SIZE: usize = 50;
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+static SPOOL: LazyLock<Mutex<TelemetrySpool>> = LazyLock::new(|| {
+    let path = config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join(APPLICATION_FOLDER_ID)
+        .join("app_configs")
+        .join(Network::get_current_or_user_setting_or_default().as_key_str())
+        .join(SPOOL_FILE_NAME);
+    Mutex::new(TelemetrySpool::new(path, DEFAULT_SPOOL_MAX_BYTES))
+});
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum TelemetrySink {
+    /// Tari's own telemetry and airdrop endpoints
+    #[default]
+    Vendor,
+    /// Self-hosted collector receiving each `TelemetryEnvelope` as a JSON POST
+    Http { url: String },
+    /// Local JSON lines file, one `TelemetryEnvelope` per line
+    File { path: PathBuf },
+}
+
+impl TelemetrySink {
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            TelemetrySink::Vendor => Ok(()),
+            TelemetrySink::Http { url } => {
+                let parsed = Url::parse(url).map_err(|e| anyhow!("Invalid collector URL: {e}"))?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    bail!("Collector URL must use http or https");
+                }
+                Ok(())
+            }
+            TelemetrySink::File { path } => {
+                if !path.is_absolute() {
+                    bail!("Telemetry file path must be absolute");
+                }
+                if path.is_dir() {
+                    bail!("Telemetry file path points to a directory");
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TelemetryKind {
+    /// Single app event from `TelemetryService`
+    Event,
+    /// Periodic mining heartbeat from `TelemetryManager`
+    Heartbeat,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryEnvelope {
+    pub kind: TelemetryKind,
+    pub created_at: SystemTime,
+    pub payload: Value,
+}
+
+impl TelemetryEnvelope {
+    /// Serializes `data` and nulls out every redacted field before it is sent or spooled
+    pub fn new<T: Serialize>(
+        kind: TelemetryKind,
+        data: &T,
+        redacted_fields: &[String],
+    ) -> Result<Self, Error> {
+        let mut payload = serde_json::to_value(data)?;
+        redact(&mut payload, redacted_fields);
+        Ok(Self {
+            kind,
+            created_at: SystemTime::now(),
+            payload,
+        })
+    }
+}
+
+/// Vendor URLs and credentials, resolved when a payload is sent rather than when it is spooled
+#[derive(Clone, Debug)]
+pub struct VendorEndpoints {
+    pub events_url: String,
+    pub heartbeat_url: String,
+    pub access_token: Option<String>,
+}
+
+impl VendorEndpoints {
+    pub fn new(config: &AppInMemoryConfig, access_token: Option<String>) -> Self {
+        Self {
+            events_url: config.telemetry_api_url.clone(),
+            heartbeat_url: format!("{}/miner/heartbeat", config.airdrop_api_url),
+            access_token,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TelemetryFieldPreview {
+    pub field: String,
+    pub value: Value,
+    pub redacted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TelemetrySpoolStatus {
+    pub records: usize,
+    pub bytes: u64,
+    pub max_bytes: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TelemetryInspection {
+    pub sink: TelemetrySink,
+    pub spool: TelemetrySpoolStatus,
+    pub fields: Vec<TelemetryFieldPreview>,
+}
+
+fn is_redacted(field: &str, redacted_fields: &[String]) -> bool {
+    redacted_fields.iter().any(|redacted| {
+        field == redacted
+            || field
+                .strip_prefix(redacted.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// Replaces each dotted field path, e.g. `extra_data.all_cpus`, with null.
+/// Paths that don't exist in `value` are ignored.
+pub fn redact(value: &mut Value, redacted_fields: &[String]) {
+    for field in redacted_fields {
+        let mut target = Some(&mut *value);
+        for segment in field.split('.') {
+            target = target.and_then(|v| v.get_mut(segment));
+        }
+        if let Some(target) = target {
+            *target = Value::Null;
+        }
+    }
+}
+
+/// Flattens `value` into dotted field paths in the order they would be serialized
+pub fn preview_fields(value: &Value, redacted_fields: &[String]) -> Vec<TelemetryFieldPreview> {
+    fn walk(
+        prefix: &str,
+        map: &Map<String, Value>,
+        redacted_fields: &[String],
+        fields: &mut Vec<TelemetryFieldPreview>,
+    ) {
+        for (key, value) in map {
+            let field = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            match value {
+                Value::Object(inner) if !inner.is_empty() => {
+                    walk(&field, inner, redacted_fields, fields);
+                }
+                _ => fields.push(TelemetryFieldPreview {
+                    redacted: is_redacted(&field, redacted_fields),
+                    field,
+                    value: value.clone(),
+                }),
+            }
+        }
+    }
+
+    let mut fields = Vec::new();
+    if let Value::Object(map) = value {
+        walk("", map, redacted_fields, &mut fields);
+    }
+    fields
+}
+
+/// Append-only JSON lines file of undelivered envelopes. When it grows past `max_bytes` the
+/// oldest records are dropped first.
+pub struct TelemetrySpool {
+    path: PathBuf,
+    max_bytes: u64,
+}
+
+impl TelemetrySpool {
+    pub fn new(path: PathBuf, max_bytes: u64) -> Self {
+        Self { path, max_bytes }
+    }
+
+    pub fn set_max_bytes(&mut self, max_bytes: u64) {
+        self.max_bytes = max_bytes;
+    }
+
+    fn read_lines(&self) -> Result<Vec<String>, Error> {
+        match File::open(&self.path) {
+            Ok(file) => Ok(BufReader::new(file).lines().collect::<Result<_, _>>()?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_lines(&self, lines: &[String]) -> Result<(), Error> {
+        if lines.is_empty() {
+            return match fs::remove_file(&self.path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+                _ => Ok(()),
+            };
+        }
+        let temp_path = self.path.with_extension("jsonl.tmp");
+        let mut file = File::create(&temp_path)?;
+        for line in lines {
+            writeln!(file, "{line}")?;
+        }
+        file.sync_all()?;
+        fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+
+    fn size(path: &Path) -> u64 {
+        fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    }
+
+    pub fn push(&self, envelope: &TelemetryEnvelope) -> Result<(), Error> {
+        let line = serde_json::to_string(envelope)?;
+        if line.len() as u64 + 1 > self.max_bytes {
+            bail!("Telemetry record is larger than the spool limit");
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{line}")?;
+        drop(file);
+
+        if Self::size(&self.path) > self.max_bytes {
+            let mut lines = self.read_lines()?;
+            let mut size: u64 = lines.iter().map(|l| l.len() as u64 + 1).sum();
+            let mut dropped = 0;
+            // The newest line always fits, so this stops before reaching it
+            while size > self.max_bytes && dropped < lines.len() {
+                size -= lines[dropped].len() as u64 + 1;
+                dropped += 1;
+            }
+            lines.drain(..dropped);
+            warn!(target: LOG_TARGET_APP_LOGIC, "Telemetry spool is full, dropped {dropped} oldest records");
+            self.write_lines(&lines)?;
+        }
+        Ok(())
+    }
+
+    /// Oldest `count` lines. Lines that no longer parse come back as `None` so they can be
+    /// discarded instead of blocking the spool.
+    pub fn peek(&self, count: usize) -> Result<Vec<Option<TelemetryEnvelope>>, Error> {
+        Ok(self
+            .read_lines()?
+            .iter()
+            .take(count)
+            .map(|line| {
+                serde_json::from_str(line)
+                    .inspect_err(|e| warn!(target: LOG_TARGET_APP_LOGIC, "Unreadable telemetry spool record: {e}"))
+                    .ok()
+            })
+            .collect())
+    }
+
+    /// Drops the oldest `count` lines
+    pub fn discard(&self, count: usize) -> Result<(), Error> {
+        let lines = self.read_lines()?;
+        self.write_lines(&lines[count.min(lines.len())..])
+    }
+
+    pub fn status(&self) -> TelemetrySpoolStatus {
+        TelemetrySpoolStatus {
+            records: self.read_lines().map(|l| l.len()).unwrap_or(0),
+            bytes: Self::size(&self.path),
+            max_bytes: self.max_bytes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        Self::size(&self.path) == 0
+    }
+}
+
+/// Sends one envelope to `sink`. For the vendor endpoints the JSON response body is returned.
+pub async fn deliver(
+    sink: &TelemetrySink,
+    envelope: &TelemetryEnvelope,
+    vendor: &VendorEndpoints,
+) -> Result<Option<Value>, Error> {
+    let user_agent = format!("tari-universe/{}", env!("CARGO_PKG_VERSION"));
+    match sink {
+        TelemetrySink::Vendor => {
+            let url = match envelope.kind {
+                TelemetryKind::Event => &vendor.events_url,
+                TelemetryKind::Heartbeat => &vendor.heartbeat_url,
+            };
+            let mut request = connectivity::client()?
+                .post(url)
+                .timeout(DELIVERY_TIMEOUT)
+                .header("User-Agent", user_agent)
+                .json(&envelope.payload);
+            if envelope.kind == TelemetryKind::Heartbeat
+                && let Some(token) = &vendor.access_token
+            {
+                request = request.header("Authorization", format!("Bearer {token}"));
+            }
+            let response = request.send().await?;
+            if response.status() == 429 {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Telemetry data rate limited by http {:?}", response.status());
+                return Ok(None);
+            }
+            if response.status() != 200 {
+                let status = response.status();
+                let text = response.text().await?;
+                let text_as_json: Result<Value, serde_json::Error> = serde_json::from_str(&text);
+                bail!(
+                    "Telemetry data sending error. Status {:?} response text: {:?}",
+                    status.to_string(),
+                    text_as_json.unwrap_or(text.into()),
+                );
+            }
+            Ok(response.json().await.ok())
+        }
+        TelemetrySink::Http { url } => {
+            let response = connectivity::client()?
+                .post(url)
+                .timeout(DELIVERY_TIMEOUT)
+                .header("User-Agent", user_agent)
+                .json(envelope)
+                .send()
+                .await?;
+            if !response.status().is_success() {
+                bail!("Telemetry collector responded with {}", response.status());
+            }
+            Ok(None)
+        }
+        TelemetrySink::File { path } => {
+            let mut line = serde_json::to_vec(envelope)?;
+            line.push(b'\n');
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let mut file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .await?;
+            file.write_all(&line).await?;
+            file.flush().await?;
+            Ok(None)
+        }
+    }
+}
+
+/// Keeps an envelope that couldn't be delivered for a later `replay`
+pub async fn spool(envelope: &TelemetryEnvelope) {
+    let max_bytes = *ConfigCore::content().await.telemetry_spool_max_bytes();
+    let mut spool = SPOOL.lock().await;
+    spool.set_max_bytes(max_bytes);
+    match spool.push(envelope) {
+        Ok(()) => {
+            debug!(target: LOG_TARGET_APP_LOGIC, "Spooled undelivered telemetry {:?}", envelope.kind)
+        }
+        Err(e) => warn!(target: LOG_TARGET_APP_LOGIC, "Could not spool telemetry: {e}"),
+    }
+}
+
+/// Delivers spooled envelopes oldest first, stopping at the first failure
+pub async fn replay(sink: &TelemetrySink, vendor: &VendorEndpoints) {
+    let spool = SPOOL.lock().await;
+    if spool.is_empty() {
+        return;
+    }
+    let mut replayed = 0;
+    loop {
+        let batch = match spool.peek(REPLAY_BATCH_SIZE) {
+            Ok(batch) if !batch.is_empty() => batch,
+            Ok(_) => break,
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Could not read telemetry spool: {e}");
+                return;
+            }
+        };
+        let mut done = 0;
+        let mut failed = false;
+        for envelope in &batch {
+            if let Some(envelope) = envelope {
+                if let Err(e) = deliver(sink, envelope, vendor).await {
+                    debug!(target: LOG_TARGET_APP_LOGIC, "Telemetry replay paused: {e}");
+                    failed = true;
+                    break;
+                }
+                replayed += 1;
+            }
+            done += 1;
+        }
+        if let Err(e) = spool.discard(done) {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Could not trim telemetry spool: {e}");
+            return;
+        }
+        if failed {
+            break;
+        }
+    }
+    if replayed > 0 {
+        info!(target: LOG_TARGET_APP_LOGIC, "Replayed {replayed} spooled telemetry records");
+    }
+}
+
+pub async fn spool_status() -> TelemetrySpoolStatus {
+    let max_bytes = *ConfigCore::content().await.telemetry_spool_max_bytes();
+    let mut spool = SPOOL.lock().await;
+    spool.set_max_bytes(max_bytes);
+    spool.status()
+}
@@ -0,0 +1,487 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for telemetry_sink
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde_json::{Value, json};
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::telemetry_sink::{
+    TelemetryEnvelope, TelemetryKind, TelemetrySink, TelemetrySpool, VendorEndpoints, deliver,
+    preview_fields, redact,
+};
+use crate::testing::test_utils::TestContext;
+
+fn envelope(kind: TelemetryKind, payload: Value) -> TelemetryEnvelope {
+    TelemetryEnvelope {
+        kind,
+        created_at: SystemTime::UNIX_EPOCH,
+        payload,
+    }
+}
+
+fn vendor(server: &MockServer) -> VendorEndpoints {
+    VendorEndpoints {
+        events_url: format!("{}/events", server.uri()),
+        heartbeat_url: format!("{}/miner/heartbeat", server.uri()),
+        access_token: Some("token".to_string()),
+    }
+}
+
+fn fields(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+// ==================== Sink validation ====================
+
+#[test]
+fn vendor_sink_is_always_valid() {
+    assert!(TelemetrySink::Vendor.validate().is_ok());
+}
+
+#[test]
+fn http_sink_requires_http_url() {
+    let valid = TelemetrySink::Http {
+        url: "https://collector.internal/telemetry".to_string(),
+    };
+    let wrong_scheme = TelemetrySink::Http {
+        url: "ftp://collector.internal".to_string(),
+    };
+    let garbage = TelemetrySink::Http {
+        url: "not a url".to_string(),
+    };
+    assert!(valid.validate().is_ok());
+    assert!(wrong_scheme.validate().is_err());
+    assert!(garbage.validate().is_err());
+}
+
+#[test]
+fn file_sink_requires_absolute_file_path() {
+    let ctx = TestContext::new();
+    let relative = TelemetrySink::File {
+        path: PathBuf::from("telemetry.jsonl"),
+    };
+    let directory = TelemetrySink::File {
+        path: ctx.data_dir.clone(),
+    };
+    let file = TelemetrySink::File {
+        path: ctx.data_dir.join("telemetry.jsonl"),
+    };
+    assert!(relative.validate().is_err());
+    assert!(directory.validate().is_err());
+    assert!(file.validate().is_ok());
+}
+
+#[test]
+fn sink_serializes_with_mode_tag() {
+    let sink = TelemetrySink::Http {
+        url: "http://127.0.0.1:9000".to_string(),
+    };
+    let value = serde_json::to_value(&sink).expect("serialize");
+    assert_eq!(
+        value,
+        json!({ "mode": "http", "url": "http://127.0.0.1:9000" })
+    );
+    let parsed: TelemetrySink = serde_json::from_value(json!({ "mode": "vendor" })).expect("parse");
+    assert_eq!(parsed, TelemetrySink::Vendor);
+}
+
+// ==================== Redaction ====================
+
+#[test]
+fn redact_nulls_top_level_and_nested_fields() {
+    let mut value = json!({
+        "cpu_name": "Ryzen",
+        "app_id": "abc",
+        "extra_data": { "all_gpus": "RTX", "uptime": "10" },
+    });
+    redact(&mut value, &fields(&["cpu_name", "extra_data.all_gpus"]));
+    assert_eq!(
+        value,
+        json!({
+            "cpu_name": null,
+            "app_id": "abc",
+            "extra_data": { "all_gpus": null, "uptime": "10" },
+        })
+    );
+}
+
+#[test]
+fn redact_ignores_unknown_fields() {
+    let mut value = json!({ "os": "linux" });
+    redact(
+        &mut value,
+        &fields(&["gpu_name", "os.version", "missing.deeper"]),
+    );
+    assert_eq!(value, json!({ "os": "linux" }));
+}
+
+#[test]
+fn envelope_is_redacted_before_it_is_built() {
+    #[derive(serde::Serialize)]
+    struct Sample {
+        gpu_name: String,
+        version: String,
+    }
+    let sample = Sample {
+        gpu_name: "RTX".to_string(),
+        version: "1.0.0".to_string(),
+    };
+    let envelope = TelemetryEnvelope::new(TelemetryKind::Event, &sample, &fields(&["gpu_name"]))
+        .expect("envelope");
+    assert_eq!(
+        envelope.payload,
+        json!({ "gpu_name": null, "version": "1.0.0" })
+    );
+}
+
+// ==================== Inspector preview ====================
+
+#[test]
+fn preview_flattens_nested_fields() {
+    let value = json!({
+        "event_name": "started",
+        "event_value": { "mode": "eco", "devices": { "gpu": 1 } },
+        "empty": {},
+    });
+    let preview = preview_fields(&value, &[]);
+    let names: Vec<&str> = preview.iter().map(|f| f.field.as_str()).collect();
+    assert!(names.contains(&"event_name"));
+    assert!(names.contains(&"event_value.mode"));
+    assert!(names.contains(&"event_value.devices.gpu"));
+    assert!(names.contains(&"empty"));
+    assert!(!names.contains(&"event_value"));
+}
+
+#[test]
+fn preview_marks_redacted_fields_and_their_children() {
+    let value = json!({
+        "cpu_name": "Ryzen",
+        "event_value": { "a": 1, "b": 2 },
+        "event_value_extra": 3,
+    });
+    let preview = preview_fields(&value, &fields(&["cpu_name", "event_value"]));
+    let redacted = |name: &str| {
+        preview
+            .iter()
+            .find(|f| f.field == name)
+            .map(|f| f.redacted)
+            .expect("field present")
+    };
+    assert!(redacted("cpu_name"));
+    assert!(redacted("event_value.a"));
+    assert!(redacted("event_value.b"));
+    assert!(!redacted("event_value_extra"));
+}
+
+#[test]
+fn preview_keeps_original_values() {
+    let preview = preview_fields(&json!({ "os": "linux" }), &fields(&["os"]));
+    assert_eq!(preview.len(), 1);
+    assert_eq!(preview[0].value, json!("linux"));
+}
+
+// ==================== Spool ====================
+
+#[test]
+fn spool_round_trips_envelopes_in_order() {
+    let ctx = TestContext::new();
+    let spool = TelemetrySpool::new(ctx.data_dir.join("spool.jsonl"), 1024 * 1024);
+    assert!(spool.is_empty());
+
+    for i in 0..3 {
+        spool
+            .push(&envelope(TelemetryKind::Event, json!({ "n": i })))
+            .expect("push");
+    }
+    let batch = spool.peek(2).expect("peek");
+    assert_eq!(batch.len(), 2);
+    assert_eq!(
+        batch[0].as_ref().map(|e| e.payload.clone()),
+        Some(json!({ "n": 0 }))
+    );
+    assert_eq!(
+        batch[1].as_ref().map(|e| e.payload.clone()),
+        Some(json!({ "n": 1 }))
+    );
+    assert_eq!(spool.status().records, 3);
+}
+
+#[test]
+fn spool_discard_removes_oldest_and_deletes_empty_file() {
+    let ctx = TestContext::new();
+    let spool_path = ctx.data_dir.join("spool.jsonl");
+    let spool = TelemetrySpool::new(spool_path.clone(), 1024 * 1024);
+    for i in 0..3 {
+        spool
+            .push(&envelope(TelemetryKind::Heartbeat, json!({ "n": i })))
+            .expect("push");
+    }
+
+    spool.discard(2).expect("discard");
+    let batch = spool.peek(10).expect("peek");
+    assert_eq!(batch.len(), 1);
+    assert_eq!(
+        batch[0].as_ref().map(|e| e.payload.clone()),
+        Some(json!({ "n": 2 }))
+    );
+
+    spool.discard(5).expect("discard");
+    assert!(spool.is_empty());
+    assert!(!spool_path.exists());
+}
+
+#[test]
+fn spool_drops_oldest_records_past_size_limit() {
+    let ctx = TestContext::new();
+    let line_len = serde_json::to_string(&envelope(TelemetryKind::Event, json!({ "n": 0 })))
+        .expect("serialize")
+        .len() as u64
+        + 1;
+    let spool = TelemetrySpool::new(ctx.data_dir.join("spool.jsonl"), line_len * 3);
+
+    for i in 0..5 {
+        spool
+            .push(&envelope(TelemetryKind::Event, json!({ "n": i })))
+            .expect("push");
+    }
+
+    let status = spool.status();
+    assert_eq!(status.records, 3);
+    assert!(status.bytes <= status.max_bytes);
+    let oldest = spool.peek(1).expect("peek");
+    assert_eq!(
+        oldest[0].as_ref().map(|e| e.payload.clone()),
+        Some(json!({ "n": 2 }))
+    );
+}
+
+#[test]
+fn spool_rejects_record_larger_than_limit() {
+    let ctx = TestContext::new();
+    let spool = TelemetrySpool::new(ctx.data_dir.join("spool.jsonl"), 16);
+    let result = spool.push(&envelope(TelemetryKind::Event, json!({ "n": "too big" })));
+    assert!(result.is_err());
+    assert!(spool.is_empty());
+}
+
+#[test]
+fn spool_returns_none_for_corrupt_lines() {
+    let ctx = TestContext::new();
+    let spool_path = ctx.data_dir.join("spool.jsonl");
+    let spool = TelemetrySpool::new(spool_path.clone(), 1024 * 1024);
+    spool
+        .push(&envelope(TelemetryKind::Event, json!({ "n": 0 })))
+        .expect("push");
+    let mut contents = std::fs::read_to_string(&spool_path).expect("read");
+    contents.insert_str(0, "{not json\n");
+    std::fs::write(&spool_path, contents).expect("write");
+
+    let batch = spool.peek(10).expect("peek");
+    assert_eq!(batch.len(), 2);
+    assert!(batch[0].is_none());
+    assert!(batch[1].is_some());
+}
+
+// ==================== Delivery ====================
+
+#[tokio::test]
+async fn vendor_heartbeat_goes_to_heartbeat_url_with_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/miner/heartbeat"))
+        .and(header("Authorization", "Bearer token"))
+        .and(body_partial_json(json!({ "appId": "abc" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let response = deliver(
+        &TelemetrySink::Vendor,
+        &envelope(TelemetryKind::Heartbeat, json!({ "appId": "abc" })),
+        &vendor(&server),
+    )
+    .await
+    .expect("deliver");
+    assert_eq!(response, Some(json!({ "success": true })));
+}
+
+#[tokio::test]
+async fn vendor_event_goes_to_events_url_without_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/events"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    deliver(
+        &TelemetrySink::Vendor,
+        &envelope(TelemetryKind::Event, json!({ "event_name": "x" })),
+        &vendor(&server),
+    )
+    .await
+    .expect("deliver");
+
+    let requests = server.received_requests().await.expect("requests");
+    assert!(requests[0].headers.get("Authorization").is_none());
+}
+
+#[tokio::test]
+async fn vendor_rate_limit_is_not_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429))
+        .mount(&server)
+        .await;
+
+    let response = deliver(
+        &TelemetrySink::Vendor,
+        &envelope(TelemetryKind::Event, json!({})),
+        &vendor(&server),
+    )
+    .await
+    .expect("deliver");
+    assert_eq!(response, None);
+}
+
+#[tokio::test]
+async fn vendor_server_error_fails_delivery() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let result = deliver(
+        &TelemetrySink::Vendor,
+        &envelope(TelemetryKind::Event, json!({})),
+        &vendor(&server),
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn http_sink_posts_whole_envelope_to_collector() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/collect"))
+        .and(body_partial_json(json!({
+            "kind": "heartbeat",
+            "payload": { "cpu_name": null },
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let sink = TelemetrySink::Http {
+        url: format!("{}/collect", server.uri()),
+    };
+    let response = deliver(
+        &sink,
+        &envelope(TelemetryKind::Heartbeat, json!({ "cpu_name": null })),
+        &vendor(&server),
+    )
+    .await
+    .expect("deliver");
+    assert_eq!(response, None);
+}
+
+#[tokio::test]
+async fn http_sink_error_status_fails_delivery() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let sink = TelemetrySink::Http {
+        url: format!("{}/collect", server.uri()),
+    };
+    let result = deliver(
+        &sink,
+        &envelope(TelemetryKind::Event, json!({})),
+        &vendor(&server),
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn unreachable_collector_fails_delivery() {
+    let sink = TelemetrySink::Http {
+        url: "http://127.0.0.1:1/collect".to_string(),
+    };
+    let endpoints = VendorEndpoints {
+        events_url: String::new(),
+        heartbeat_url: String::new(),
+        access_token: None,
+    };
+    let result = deliver(
+        &sink,
+        &envelope(TelemetryKind::Event, json!({})),
+        &endpoints,
+    )
+    .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn file_sink_appends_json_lines() {
+    let ctx = TestContext::new();
+    let file_path = ctx.data_dir.join("nested").join("telemetry.jsonl");
+    let sink = TelemetrySink::File {
+        path: file_path.clone(),
+    };
+    let endpoints = VendorEndpoints {
+        events_url: String::new(),
+        heartbeat_url: String::new(),
+        access_token: None,
+    };
+
+    for i in 0..2 {
+        deliver(
+            &sink,
+            &envelope(TelemetryKind::Event, json!({ "n": i })),
+            &endpoints,
+        )
+        .await
+        .expect("deliver");
+    }
+
+    let contents = std::fs::read_to_string(&file_path).expect("read");
+    let lines: Vec<TelemetryEnvelope> = contents
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("parse"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].payload, json!({ "n": 1 }));
+    assert_eq!(lines[1].kind, TelemetryKind::Event);
+}
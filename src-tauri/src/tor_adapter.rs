@@ -21,23 +21,28 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Error, anyhow};
 use async_trait::async_trait;
-use log::{debug, error, info, warn};
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use tari_shutdown::Shutdown;
 use tokio::fs;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
-use tokio::net::TcpStream;
-use tokio::sync::watch;
+use tokio::sync::{Mutex, watch};
+use tokio::task::JoinHandle;
 use tokio::time::timeout;
 
-use crate::port_allocator::PortAllocator;
+use crate::port_registry::{ServicePort, assign_port, record_port};
 use crate::process_adapter::HandleUnhealthyResult;
-use crate::tor_control_client::{TorControlClient, TorStatus};
+use crate::tor_control_client::{STATUS_EVENTS, TorControlClient, TorStatus};
+use crate::tor_transports::{
+    PluggableTransport, TransportHealth, bridge_transport_name, bridge_transports,
+};
 use crate::{LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 use crate::{
     process_adapter::{
@@ -48,19 +53,23 @@ use crate::{
 
 pub(crate) struct TorAdapter {
     socks_port: u16,
+    control_port: u16,
     config_file: Option<PathBuf>,
     config: TorConfig,
+    /// `ClientTransportPlugin` values for the bridges, see `set_transport_plugins`
+    transport_plugins: Vec<String>,
     status_broadcast: watch::Sender<TorStatus>,
 }
 
 impl TorAdapter {
     pub fn new(status_broadcast: watch::Sender<TorStatus>) -> Self {
-        let port = PortAllocator::new().assign_port_with_fallback();
-
+        // Ports are taken from the port registry before each start, see `assign_ports`
         Self {
-            socks_port: port,
+            socks_port: 0,
+            control_port: 0,
             config_file: None,
             config: TorConfig::default(),
+            transport_plugins: Vec::new(),
             status_broadcast,
         }
     }
@@ -107,92 +116,61 @@ impl TorAdapter {
         Ok(())
     }
 
+    pub async fn assign_ports(&mut self) -> Result<(), anyhow::Error> {
+        self.socks_port = assign_port(ServicePort::TorSocks).await?;
+        // A control port set in the tor config takes precedence over the registry
+        self.control_port = match self.config.control_port {
+            0 => assign_port(ServicePort::TorControl).await?,
+            port => {
+                record_port(ServicePort::TorControl, port).await?;
+                port
+            }
+        };
+        Ok(())
+    }
+
+    pub fn control_port(&self) -> u16 {
+        self.control_port
+    }
+
+    pub fn socks_port(&self) -> u16 {
+        self.socks_port
+    }
+
     pub fn get_tor_config(&self) -> TorConfig {
         self.config.clone()
     }
 
     pub async fn set_tor_config(&mut self, config: TorConfig) -> Result<TorConfig, Error> {
         self.config = config.clone();
-
-        // match self.apply_tor_config_changes(config.clone()).await {
-        //     Ok(_) => info!(target: LOG_TARGET, "Tor config changes applied successfully"),
-        //     Err(e) => {
-        //         warn!(target: LOG_TARGET, "Failed to apply Tor config changes: {:?}", e);
-        //         return Err(e);
-        //     }
-        // }
-
         self.update_config_file().await?;
         Ok(config)
     }
 
-    // pub async fn apply_tor_config_changes(&self, config: TorConfig) -> Result<(), Error> {
-    //     let mut setconf_commands: Vec<String> = vec![];
-    //     let control_port_address = "127.0.0.1:9051";
-
-    //     // Establish a TCP connection
-    //     let mut stream = TcpStream::connect(control_port_address)?;
-
-    //     // Authenticate
-    //     setconf_commands.push(format!("AUTHENTICATE \"{}\"\n", self.password.clone()));
-
-    //     setconf_commands.push("SETCONF".to_string());
-
-    //     // Set Bridge instances
-    //     if config.use_bridges {
-    //         for bridge in config.bridges {
-    //             setconf_commands.push(format!("Bridge=\"{}\"", bridge))
-    //         }
-    //     }
-    //     // Set UseBridges
-    //     setconf_commands.push(format!("UseBridges={}", config.use_bridges as u8));
-    //     // Set ControlPort
-    //     setconf_commands.push(format!("ControlPort=127.0.0.1:{}", config.control_port));
-
-    //     stream.write_all(setconf_commands.join(" ").as_bytes())?;
-
-    //     Ok(())
-    // }
-
-    pub async fn get_entry_guards(&self) -> Result<Vec<String>, Error> {
-        let control_port_address = format!("127.0.0.1:{}", self.config.control_port);
-        let stream = TcpStream::connect(control_port_address.clone()).await?;
-        let mut reader = BufReader::new(stream);
-
-        // AUTHENTICATE
-        let auth_command = "AUTHENTICATE\n";
-        reader.get_mut().write_all(auth_command.as_bytes()).await?;
-        let mut response = String::new();
-        reader.read_line(&mut response).await?;
-        if !response.starts_with("250 OK") {
-            error!(target: LOG_TARGET_APP_LOGIC, "Tor AUTHENTICATE failed for: {control_port_address:?}");
-            return Err(Error::msg("Authentication failed"));
-        }
+    /// Plugin binaries are resolved asynchronously, so they are set before each start
+    pub fn set_transport_plugins(&mut self, transport_plugins: Vec<String>) {
+        self.transport_plugins = transport_plugins;
+    }
 
-        // GETINFO entry-guards
-        let getinfo_command = "GETINFO entry-guards\n";
-        reader
-            .get_mut()
-            .write_all(getinfo_command.as_bytes())
-            .await?;
-        response.clear();
-        reader.read_line(&mut response).await?;
-
-        if response.starts_with("250+entry-guards=") {
-            let mut entry_guards: Vec<String> = vec![];
-            loop {
-                response.clear();
-                reader.read_line(&mut response).await?;
-                if response == ".\r\n" || response.is_empty() {
-                    break;
-                }
-                entry_guards.push(response.trim().to_string());
-            }
+    /// Keeps the plugins applied to the running Tor for restarts and shows the transport of the
+    /// new bridges in the status
+    pub fn apply_live_transport_plugins(&mut self, transport_plugins: Vec<String>) {
+        self.transport_plugins = transport_plugins;
+        let transport = self.config.selected_transport();
+        self.status_broadcast
+            .send_if_modified(|status| status.set_transport(transport));
+    }
 
-            Ok(entry_guards)
-        } else {
-            error!(target: LOG_TARGET_APP_LOGIC, "Tor GETINFO entry-guards with response: {response:?}");
-            Err(Error::msg("Failed to get entry guards"))
+    /// Monitors a system Tor daemon the same way as the bundled Tor, without a process to watch
+    pub fn external_status_monitor(&self, external: &ExternalTorConfig) -> TorStatusMonitor {
+        // Bridges and their transports are up to the daemon's own config
+        self.status_broadcast
+            .send_if_modified(|status| status.set_transport(None));
+        TorStatusMonitor {
+            control_endpoint: external.control_endpoint(),
+            status_broadcast: self.status_broadcast.clone(),
+            base_path: None,
+            event_listener: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -219,17 +197,7 @@ impl ProcessAdapter for TorAdapter {
         let working_dir_string = convert_to_string(working_dir)?;
         let log_dir_string = convert_to_string(log_dir.join("tor.log"))?;
         let torrc_string = convert_to_string(data_dir.join("torrc"))?;
-        let mut lyrebird_path = binary_version_path.clone();
-        lyrebird_path.pop();
-        lyrebird_path.push("pluggable_transports");
-        lyrebird_path.push("lyrebird");
-        if cfg!(target_os = "windows") {
-            lyrebird_path.set_extension("exe");
-        }
-        let mut control_port = self.config.control_port;
-        if control_port == 0 {
-            control_port = PortAllocator::new().assign_port_with_fallback();
-        }
+        let control_port = self.control_port;
         if is_first_start {
             info!(target: LOG_TARGET_APP_LOGIC, "Clearing tor data directory on first start");
             if std::fs::exists(data_dir.join("tor-data"))? {
@@ -253,9 +221,9 @@ impl ProcessAdapter for TorAdapter {
             self.socks_port.to_string(),
             "--controlport".to_string(),
             format!("127.0.0.1:{}", control_port),
-            // TODO: Put hashed password back
-            // "--HashedControlPassword".to_string(),
-            // EncryptedKey::hash_password(&self.password).to_string(),
+            // Tor writes the cookie to its data directory and reports the path in PROTOCOLINFO
+            "--CookieAuthentication".to_string(),
+            "1".to_string(),
             "--clientuseipv6".to_string(),
             "1".to_string(),
             "--DataDirectory".to_string(),
@@ -267,14 +235,12 @@ impl ProcessAdapter for TorAdapter {
         let envs = get_libevent_envs(&binary_version_path);
 
         if self.config.use_bridges {
-            // Used by tor bridges
             // TODO: This does not work when path has space on windows.
-            // Consider running lyrebird binary manually
-            args.push("--ClientTransportPlugin".to_string());
-            args.push(format!(
-                "obfs4 exec {} managed",
-                convert_to_string(lyrebird_path)?
-            ));
+            // Consider running the transport plugins manually
+            for transport_plugin in &self.transport_plugins {
+                args.push("--ClientTransportPlugin".to_string());
+                args.push(transport_plugin.clone());
+            }
             for bridge in &self.config.bridges {
                 args.push("--Bridge".to_string());
                 args.push(bridge.clone());
@@ -283,6 +249,9 @@ impl ProcessAdapter for TorAdapter {
             args.push("--UseBridges".to_string());
             args.push("1".to_string());
         }
+        // The status of a previous run doesn't apply to the new process
+        self.status_broadcast
+            .send_replace(TorStatus::with_transport(self.config.selected_transport()));
 
         Ok((
             ProcessInstance {
@@ -294,13 +263,19 @@ impl ProcessAdapter for TorAdapter {
                     args,
                     data_dir: data_dir.clone(),
                     pid_file_name: self.pid_file_name().to_string(),
+                    log_dir,
                     name: self.name().to_string(),
                 },
             },
             TorStatusMonitor {
-                control_port,
+                control_endpoint: TorControlEndpoint {
+                    control_address: SocketAddr::from(([127, 0, 0, 1], control_port)),
+                    control_password: None,
+                    socks_address: None,
+                },
                 status_broadcast: self.status_broadcast.clone(),
-                base_path: data_dir,
+                base_path: Some(data_dir),
+                event_listener: Arc::new(Mutex::new(None)),
             },
         ))
     }
@@ -312,50 +287,186 @@ impl ProcessAdapter for TorAdapter {
     fn pid_file_name(&self) -> &str {
         "tor_pid"
     }
+
+    fn log_file_path(&self, log_folder: &Path) -> Option<PathBuf> {
+        Some(log_folder.join("tor.log"))
+    }
+
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        vec![
+            (ServicePort::TorSocks, self.socks_port),
+            (ServicePort::TorControl, self.control_port),
+        ]
+    }
 }
 
 #[derive(Clone)]
 pub(crate) struct TorStatusMonitor {
-    pub control_port: u16,
+    control_endpoint: TorControlEndpoint,
     status_broadcast: watch::Sender<TorStatus>,
-    base_path: PathBuf,
+    /// Data directory of the bundled Tor, a system daemon's data is left alone
+    base_path: Option<PathBuf>,
+    /// Task applying control port events to the status, see `start_event_listener`
+    event_listener: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl TorStatusMonitor {
+    pub fn control_endpoint(&self) -> &TorControlEndpoint {
+        &self.control_endpoint
+    }
+
+    /// Reads the current status and subscribes to status events, which keep it up to date
+    /// until the control connection closes
+    async fn start_event_listener(&self) -> Result<JoinHandle<()>, Error> {
+        let mut client = TorControlClient::connect_to(
+            self.control_endpoint.control_address,
+            self.control_endpoint.control_password.as_deref(),
+        )
+        .await?;
+        let mut status = client.status().await?;
+        let transport = self.status_broadcast.borrow().transport;
+        status.set_transport(transport);
+        let _res = self.status_broadcast.send(status);
+        client.set_events(&STATUS_EVENTS).await?;
+
+        let status_broadcast = self.status_broadcast.clone();
+        Ok(tokio::spawn(async move {
+            loop {
+                match client.next_event().await {
+                    Ok(event) => {
+                        debug!(target: LOG_TARGET_STATUSES, "Tor event: {event}");
+                        status_broadcast.send_if_modified(|status| status.apply_event(&event));
+                    }
+                    Err(e) => {
+                        warn!(target: LOG_TARGET_STATUSES, "Tor control event subscription ended: {e}");
+                        break;
+                    }
+                }
+            }
+        }))
+    }
 }
 
 #[async_trait]
 impl StatusMonitor for TorStatusMonitor {
     async fn check_health(&self, _uptime: Duration, timeout_duration: Duration) -> HealthStatus {
-        let client = TorControlClient::new(self.control_port);
-        match timeout(timeout_duration, client.get_info()).await {
-            Ok(Ok(status)) => {
-                let _res = self.status_broadcast.send(status);
-                if status.is_bootstrapped && status.network_liveness {
-                    HealthStatus::Healthy
-                } else {
-                    warn!(target: LOG_TARGET_STATUSES, "Tor Healthcheck status: {status:?}");
-                    HealthStatus::Warning
+        let mut event_listener = self.event_listener.lock().await;
+        if event_listener
+            .as_ref()
+            .is_none_or(|listener| listener.is_finished())
+        {
+            match timeout(timeout_duration, self.start_event_listener()).await {
+                Ok(Ok(listener)) => *event_listener = Some(listener),
+                Ok(Err(e)) => {
+                    warn!(target: LOG_TARGET_STATUSES, "Failed to get Tor Healthcheck status: {e}");
+                    return HealthStatus::Unhealthy;
+                }
+                Err(_) => {
+                    warn!(target: LOG_TARGET_STATUSES, "Tor Healthcheck timeout");
+                    return HealthStatus::Unhealthy;
                 }
-            }
-            Ok(Err(e)) => {
-                warn!(target: LOG_TARGET_STATUSES, "Failed to get Tor Healthcheck status: {e}");
-                HealthStatus::Unhealthy
-            }
-            Err(_) => {
-                warn!(target: LOG_TARGET_STATUSES, "Tor Healthcheck timeout");
-                HealthStatus::Unhealthy
             }
         }
+
+        let status = *self.status_broadcast.borrow();
+        if status.is_bootstrapped
+            && status.network_liveness
+            && status.transport_health != TransportHealth::Failed
+        {
+            HealthStatus::Healthy
+        } else {
+            warn!(target: LOG_TARGET_STATUSES, "Tor Healthcheck status: {status:?}");
+            HealthStatus::Warning
+        }
     }
 
     async fn handle_unhealthy(
         &self,
         _duration_since_last_healthy_status: Duration,
     ) -> Result<HandleUnhealthyResult, anyhow::Error> {
-        fs::remove_dir_all(self.base_path.join("tor-data")).await?;
+        if let Some(base_path) = &self.base_path {
+            fs::remove_dir_all(base_path.join("tor-data")).await?;
+        }
 
         Ok(HandleUnhealthyResult::Continue)
     }
 }
 
+/// A Tor daemon running outside the app, used instead of the bundled binary
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalTorConfig {
+    pub control_address: SocketAddr,
+    pub socks_address: SocketAddr,
+    /// Needed when the daemon uses `HashedControlPassword`, its cookie file is rarely readable
+    #[serde(default)]
+    pub control_password: Option<String>,
+}
+
+impl fmt::Debug for ExternalTorConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalTorConfig")
+            .field("control_address", &self.control_address)
+            .field("socks_address", &self.socks_address)
+            .field(
+                "control_password",
+                &self.control_password.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+impl ExternalTorConfig {
+    fn validate(&self) -> Result<(), Error> {
+        if self.control_address.port() == 0 || self.socks_address.port() == 0 {
+            return Err(anyhow!("System Tor addresses need a port"));
+        }
+        if self
+            .control_password
+            .as_ref()
+            .is_some_and(|password| password.chars().any(char::is_control))
+        {
+            return Err(anyhow!("Tor control password contains invalid characters"));
+        }
+        Ok(())
+    }
+
+    fn control_endpoint(&self) -> TorControlEndpoint {
+        TorControlEndpoint {
+            control_address: self.control_address,
+            control_password: self.control_password.clone(),
+            socks_address: Some(self.socks_address),
+        }
+    }
+}
+
+/// How the node reaches the Tor control port, of the bundled Tor or a system daemon
+#[derive(Clone, PartialEq, Eq)]
+pub struct TorControlEndpoint {
+    pub control_address: SocketAddr,
+    pub control_password: Option<String>,
+    /// Only set for a system daemon, the node asks the bundled Tor for its SOCKS listener
+    pub socks_address: Option<SocketAddr>,
+}
+
+impl fmt::Debug for TorControlEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TorControlEndpoint")
+            .field("control_address", &self.control_address)
+            .field(
+                "control_password",
+                &self.control_password.as_ref().map(|_| "<redacted>"),
+            )
+            .field("socks_address", &self.socks_address)
+            .finish()
+    }
+}
+
+impl TorControlEndpoint {
+    pub fn is_external(&self) -> bool {
+        self.socks_address.is_some()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TorConfig {
     #[serde(default)]
@@ -363,6 +474,86 @@ pub struct TorConfig {
     control_port: u16,
     use_bridges: bool,
     bridges: Vec<String>,
+    /// Set to use a system Tor daemon, which skips downloading and spawning the bundled one
+    #[serde(default)]
+    external: Option<ExternalTorConfig>,
+}
+
+impl TorConfig {
+    pub fn control_port(&self) -> u16 {
+        self.control_port
+    }
+
+    pub fn external(&self) -> Option<&ExternalTorConfig> {
+        self.external.as_ref()
+    }
+
+    /// Checks the bridge lines and system Tor addresses before they are used
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.use_bridges && self.bridges.is_empty() {
+            return Err(anyhow!("Bridges are enabled but none are configured"));
+        }
+        if let Some(external) = &self.external {
+            if self.use_bridges {
+                return Err(anyhow!(
+                    "Bridges of a system Tor are configured in its torrc, not in the app"
+                ));
+            }
+            external.validate()?;
+        }
+        for bridge in &self.bridges {
+            validate_bridge_line(bridge)?;
+        }
+        Ok(())
+    }
+
+    /// Whether switching to `other` changes how Tor reaches the network
+    pub fn routing_differs(&self, other: &TorConfig) -> bool {
+        self.use_bridges != other.use_bridges || (self.use_bridges && self.bridges != other.bridges)
+    }
+
+    /// Transports Tor needs plugins for, empty without bridges
+    pub fn transports(&self) -> Vec<PluggableTransport> {
+        if !self.use_bridges {
+            return Vec::new();
+        }
+        bridge_transports(&self.bridges)
+    }
+
+    /// Transport shown in the Tor status, the one of the first bridge using a transport
+    pub fn selected_transport(&self) -> Option<PluggableTransport> {
+        self.transports().first().copied()
+    }
+
+    /// Options applying the bridge settings to a running Tor with `SETCONF`
+    pub fn bridge_options(
+        &self,
+        transport_plugins: &[String],
+    ) -> Vec<(&'static str, Option<String>)> {
+        if !self.use_bridges {
+            return vec![
+                ("UseBridges", Some("0".to_string())),
+                ("Bridge", None),
+                ("ClientTransportPlugin", None),
+            ];
+        }
+        let mut options = vec![("UseBridges", Some("1".to_string()))];
+        options.extend(
+            transport_plugins
+                .iter()
+                .map(|plugin| ("ClientTransportPlugin", Some(plugin.clone()))),
+        );
+        // Without plugins the option has to be reset, or a previous value would stay
+        if transport_plugins.is_empty() {
+            options.push(("ClientTransportPlugin", None));
+        }
+        options.extend(
+            self.bridges
+                .iter()
+                .map(|bridge| ("Bridge", Some(bridge.clone()))),
+        );
+        options
+    }
 }
 
 impl Default for TorConfig {
@@ -373,8 +564,65 @@ impl Default for TorConfig {
             control_port: 0,
             use_bridges: false,
             bridges: Vec::new(),
+            external: None,
+        }
+    }
+}
+
+/// Checks a bridge line: `[transport] address:port [fingerprint] [key=value ...]`
+pub(crate) fn validate_bridge_line(line: &str) -> Result<(), Error> {
+    if line.chars().any(|c| c.is_control() || c == '"') {
+        return Err(anyhow!("Bridge line contains invalid characters: {line}"));
+    }
+    let mut parts = line.split_whitespace();
+    let transport = match bridge_transport_name(line) {
+        Some(name) => {
+            parts.next();
+            Some(PluggableTransport::from_name(name).ok_or_else(|| {
+                let supported: Vec<&str> = PluggableTransport::all()
+                    .into_iter()
+                    .map(PluggableTransport::name)
+                    .collect();
+                anyhow!(
+                    "Unsupported bridge transport {name}, supported: {}",
+                    supported.join(", ")
+                )
+            })?)
+        }
+        None => None,
+    };
+    let address = parts
+        .next()
+        .ok_or_else(|| anyhow!("Bridge line without an address: {line}"))?;
+    address
+        .parse::<SocketAddr>()
+        .map_err(|_| anyhow!("Invalid bridge address: {address}"))?;
+
+    let rest: Vec<&str> = parts.collect();
+    let arguments = match rest.split_first() {
+        Some((fingerprint, arguments)) if !fingerprint.contains('=') => {
+            if fingerprint.len() != 40 || !fingerprint.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(anyhow!("Invalid bridge fingerprint: {fingerprint}"));
+            }
+            arguments
+        }
+        _ => rest.as_slice(),
+    };
+    if let Some(argument) = arguments.iter().find(|argument| !argument.contains('=')) {
+        return Err(anyhow!("Invalid bridge argument: {argument}"));
+    }
+    if let Some(transport) = transport {
+        for key in transport.required_arguments() {
+            if !arguments.iter().any(|argument| {
+                argument
+                    .split_once('=')
+                    .is_some_and(|(name, _)| name == *key)
+            }) {
+                return Err(anyhow!("{transport} bridge without {key}=: {line}"));
+            }
         }
     }
+    Ok(())
 }
 
 #[cfg(target_os = "linux")]
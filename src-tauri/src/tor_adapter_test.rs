@@ -0,0 +1,216 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for tor_adapter
+
+use serde_json::json;
+
+use crate::tor_adapter::{TorConfig, validate_bridge_line};
+use crate::tor_transports::PluggableTransport;
+
+const OBFS4_BRIDGE: &str =
+    "obfs4 192.0.2.10:443 0123456789ABCDEF0123456789ABCDEF01234567 cert=AbCdEf+123/xyz iat-mode=0";
+const WEBTUNNEL_BRIDGE: &str = "webtunnel [2001:db8::2]:443 0123456789ABCDEF0123456789ABCDEF01234568 url=https://example.com/path ver=0.0.1";
+const SNOWFLAKE_BRIDGE: &str = "snowflake 192.0.2.3:80 2B280B23E1107BB62ABFC40DDCC8824814F80A72 fingerprint=2B280B23E1107BB62ABFC40DDCC8824814F80A72 url=https://snowflake-broker.torproject.net/ ice=stun:stun.l.google.com:19302";
+
+fn tor_config(use_bridges: bool, bridges: &[&str]) -> TorConfig {
+    serde_json::from_value(json!({
+        "version": 1,
+        "control_port": 0,
+        "use_bridges": use_bridges,
+        "bridges": bridges,
+    }))
+    .unwrap()
+}
+
+// ==================== Bridge validation ====================
+
+#[test]
+fn accepts_transport_and_plain_bridges() {
+    assert!(validate_bridge_line(OBFS4_BRIDGE).is_ok());
+    assert!(validate_bridge_line(WEBTUNNEL_BRIDGE).is_ok());
+    assert!(validate_bridge_line(SNOWFLAKE_BRIDGE).is_ok());
+    assert!(validate_bridge_line("192.0.2.11:9001").is_ok());
+    assert!(
+        validate_bridge_line("[2001:db8::1]:9001 0123456789ABCDEF0123456789ABCDEF01234567").is_ok()
+    );
+}
+
+#[test]
+fn rejects_malformed_bridges() {
+    for line in [
+        "",
+        "obfs4",
+        "obfs4 example.com:443 cert=x iat-mode=0",
+        "obfs4 192.0.2.10:443 0123 cert=x iat-mode=0",
+        "obfs4 192.0.2.10:443 0123456789ABCDEF0123456789ABCDEF01234567 iat-mode=0",
+        "snowflake 192.0.2.3:80 2B280B23E1107BB62ABFC40DDCC8824814F80A72",
+        "webtunnel 192.0.2.4:443 0123456789ABCDEF0123456789ABCDEF01234567 urls=https://example.com",
+        "scramblesuit 192.0.2.5:443 0123456789ABCDEF0123456789ABCDEF01234567 password=x",
+        "192.0.2.11:9001 extra",
+        "192.0.2.11:9001\" UseBridges=\"0",
+    ] {
+        assert!(validate_bridge_line(line).is_err(), "accepted: {line}");
+    }
+}
+
+#[test]
+fn enabled_bridges_must_not_be_empty() {
+    assert!(tor_config(true, &[]).validate().is_err());
+    assert!(tor_config(false, &[]).validate().is_ok());
+    assert!(tor_config(true, &[OBFS4_BRIDGE]).validate().is_ok());
+}
+
+// ==================== Live reconfiguration ====================
+
+#[test]
+fn only_used_bridges_change_routing() {
+    let disabled = tor_config(false, &[]);
+    let disabled_with_bridges = tor_config(false, &[OBFS4_BRIDGE]);
+    let enabled = tor_config(true, &[OBFS4_BRIDGE]);
+    let enabled_other = tor_config(true, &["192.0.2.11:9001"]);
+
+    assert!(!disabled.routing_differs(&disabled_with_bridges));
+    assert!(disabled_with_bridges.routing_differs(&enabled));
+    assert!(enabled.routing_differs(&enabled_other));
+    assert!(!enabled.routing_differs(&enabled.clone()));
+}
+
+#[test]
+fn bridge_options_set_or_reset_bridges() {
+    let plugins = [
+        "obfs4 exec /tor/pluggable_transports/lyrebird".to_string(),
+        "snowflake exec /tor/pluggable_transports/snowflake-client".to_string(),
+    ];
+
+    assert_eq!(
+        tor_config(true, &[OBFS4_BRIDGE, SNOWFLAKE_BRIDGE]).bridge_options(&plugins),
+        [
+            ("UseBridges", Some("1".to_string())),
+            ("ClientTransportPlugin", Some(plugins[0].clone())),
+            ("ClientTransportPlugin", Some(plugins[1].clone())),
+            ("Bridge", Some(OBFS4_BRIDGE.to_string())),
+            ("Bridge", Some(SNOWFLAKE_BRIDGE.to_string())),
+        ]
+    );
+    assert_eq!(
+        tor_config(true, &["192.0.2.11:9001"]).bridge_options(&[]),
+        [
+            ("UseBridges", Some("1".to_string())),
+            ("ClientTransportPlugin", None),
+            ("Bridge", Some("192.0.2.11:9001".to_string())),
+        ]
+    );
+    assert_eq!(
+        tor_config(false, &[OBFS4_BRIDGE]).bridge_options(&plugins),
+        [
+            ("UseBridges", Some("0".to_string())),
+            ("Bridge", None),
+            ("ClientTransportPlugin", None),
+        ]
+    );
+}
+
+// ==================== Transports ====================
+
+#[test]
+fn transports_come_from_used_bridges() {
+    let config = tor_config(
+        true,
+        &[
+            "192.0.2.11:9001",
+            SNOWFLAKE_BRIDGE,
+            OBFS4_BRIDGE,
+            SNOWFLAKE_BRIDGE,
+        ],
+    );
+
+    assert_eq!(
+        config.transports(),
+        [PluggableTransport::Snowflake, PluggableTransport::Obfs4]
+    );
+    assert_eq!(
+        config.selected_transport(),
+        Some(PluggableTransport::Snowflake)
+    );
+    assert!(tor_config(false, &[OBFS4_BRIDGE]).transports().is_empty());
+    assert_eq!(
+        tor_config(true, &["192.0.2.11:9001"]).selected_transport(),
+        None
+    );
+}
+
+// ==================== System Tor ====================
+
+fn external_tor_config(use_bridges: bool, external: serde_json::Value) -> TorConfig {
+    serde_json::from_value(json!({
+        "version": 1,
+        "control_port": 0,
+        "use_bridges": use_bridges,
+        "bridges": if use_bridges { vec![OBFS4_BRIDGE] } else { Vec::new() },
+        "external": external,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn configs_without_system_tor_use_the_bundled_tor() {
+    assert!(tor_config(false, &[]).external().is_none());
+}
+
+#[test]
+fn validates_system_tor_settings() {
+    let external = json!({
+        "control_address": "192.0.2.20:9051",
+        "socks_address": "[2001:db8::20]:9050",
+        "control_password": "hunter2",
+    });
+    let config = external_tor_config(false, external.clone());
+    assert!(config.validate().is_ok());
+    let settings = config.external().unwrap();
+    assert_eq!(settings.control_address.to_string(), "192.0.2.20:9051");
+    assert_eq!(settings.control_password.as_deref(), Some("hunter2"));
+    assert!(!format!("{config:?}").contains("hunter2"));
+
+    // Bridges belong in the daemon's torrc
+    assert!(external_tor_config(true, external).validate().is_err());
+    assert!(
+        external_tor_config(
+            false,
+            json!({ "control_address": "127.0.0.1:0", "socks_address": "127.0.0.1:9050" })
+        )
+        .validate()
+        .is_err()
+    );
+    assert!(
+        external_tor_config(
+            false,
+            json!({
+                "control_address": "127.0.0.1:9051",
+                "socks_address": "127.0.0.1:9050",
+                "control_password": "pw\r\nSIGNAL HALT",
+            })
+        )
+        .validate()
+        .is_err()
+    );
+}
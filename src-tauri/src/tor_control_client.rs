@@ -20,109 +20,720 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+//! Client for the Tor control protocol.
+//!
+//! The client reads `PROTOCOLINFO` to pick an authentication method (HASHEDPASSWORD when a
+//! password is configured, SAFECOOKIE, COOKIE or none), parses multi-line replies and queues
+//! asynchronous events (650 replies) that arrive between commands, so the same connection can
+//! be used both for queries and for `SETEVENTS` subscriptions.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
 use anyhow::anyhow;
-use log::warn;
-use regex::Regex;
+use hmac::{Hmac, Mac};
+use log::debug;
+use rand::RngCore;
 use serde::Serialize;
+use sha2::Sha256;
 use tokio::{
-    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
-    net::TcpStream,
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{
+        TcpStream,
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+    },
 };
 
 use crate::LOG_TARGET_APP_LOGIC;
+use crate::tor_transports::{PluggableTransport, TransportHealth};
 
-const BOOTSTRAP_QUERY: &str = "GETINFO status/bootstrap-phase\r\n";
-const AUTH_COMMAND: &str = "AUTHENTICATE\r\n";
-const CIRCUIT_QUERY: &str = "GETINFO status/circuit-established\r\n";
-const NETWORK_QUERY: &str = "GETINFO network-liveness\r\n";
+const ASYNC_EVENT_CODE: u16 = 650;
+const SAFECOOKIE_SERVER_KEY: &[u8] = b"Tor safe cookie authentication server-to-controller hash";
+const SAFECOOKIE_CLIENT_KEY: &[u8] = b"Tor safe cookie authentication controller-to-server hash";
+const SAFECOOKIE_NONCE_LEN: usize = 32;
+/// Events the status monitor subscribes to
+pub(crate) const STATUS_EVENTS: [&str; 6] = [
+    "STATUS_CLIENT",
+    "NETWORK_LIVENESS",
+    "CIRC",
+    "STREAM",
+    "TRANSPORT_LAUNCHED",
+    "PT_LOG",
+];
+/// Bootstrap phase reached once Tor has connected to its pluggable transport (`conn_done_pt`)
+const TRANSPORT_CONNECTED_PHASE: u8 = 2;
 
-#[derive(Default, Clone, Copy, Debug, Serialize)]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize)]
 pub(crate) struct TorStatus {
     pub bootstrap_phase: u8,
     pub is_bootstrapped: bool,
     pub network_liveness: bool,
     pub circuit_ok: bool,
+    /// Transport of the configured bridges, if they use one
+    pub transport: Option<PluggableTransport>,
+    pub transport_health: TransportHealth,
 }
 
-pub(crate) struct TorControlClient {
-    control_port: u16,
+impl TorStatus {
+    pub fn with_transport(transport: Option<PluggableTransport>) -> Self {
+        let mut status = Self::default();
+        status.set_transport(transport);
+        status
+    }
+
+    /// Sets the transport, judging its health by how far Tor has bootstrapped. Returns whether
+    /// the status changed.
+    pub fn set_transport(&mut self, transport: Option<PluggableTransport>) -> bool {
+        let previous = *self;
+        self.transport = transport;
+        self.transport_health = match transport {
+            None => TransportHealth::Unused,
+            Some(_) if self.bootstrap_phase >= TRANSPORT_CONNECTED_PHASE => {
+                TransportHealth::Running
+            }
+            Some(_) => TransportHealth::Launching,
+        };
+        previous != *self
+    }
+
+    /// Applies an event to the status, returning whether it changed
+    pub fn apply_event(&mut self, event: &TorEvent) -> bool {
+        let previous = *self;
+        match event {
+            TorEvent::Bootstrap(progress) => {
+                self.bootstrap_phase = progress.progress;
+                self.is_bootstrapped = progress.is_done();
+                self.apply_bootstrap_to_transport(progress);
+            }
+            TorEvent::CircuitEstablished(established) => self.circuit_ok = *established,
+            TorEvent::NetworkLiveness(up) => self.network_liveness = *up,
+            TorEvent::Circuit { status, .. } if status == "BUILT" => self.circuit_ok = true,
+            TorEvent::TransportLaunched { transport }
+                if self.transport.is_some_and(|t| t.name() == transport) =>
+            {
+                self.transport_health = TransportHealth::Running;
+            }
+            TorEvent::TransportLog { severity, .. }
+                if self.transport.is_some() && severity == "error" =>
+            {
+                self.transport_health = TransportHealth::Failed;
+            }
+            TorEvent::Circuit { .. }
+            | TorEvent::Stream { .. }
+            | TorEvent::TransportLaunched { .. }
+            | TorEvent::TransportLog { .. }
+            | TorEvent::Other(_) => {}
+        }
+        previous != *self
+    }
+
+    fn apply_bootstrap_to_transport(&mut self, progress: &BootstrapProgress) {
+        if self.transport.is_none() {
+            return;
+        }
+        match &progress.warning {
+            // Tags of the phases that connect to the transport end with `_pt`, e.g. `conn_pt`
+            Some(_) if progress.tag.ends_with("_pt") => {
+                self.transport_health = TransportHealth::Failed;
+            }
+            None if progress.progress >= TRANSPORT_CONNECTED_PHASE => {
+                self.transport_health = TransportHealth::Running;
+            }
+            _ => {}
+        }
+    }
 }
 
-impl TorControlClient {
-    pub fn new(control_port: u16) -> Self {
-        Self { control_port }
+/// One line of a control reply. `data` holds the lines of a `+` data block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReplyLine {
+    pub code: u16,
+    pub text: String,
+    pub data: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ControlReply {
+    pub lines: Vec<ReplyLine>,
+}
+
+impl ControlReply {
+    /// Status code of the reply, taken from its final line
+    pub fn code(&self) -> u16 {
+        self.lines.last().map_or(0, |line| line.code)
     }
 
-    pub async fn get_info(&self) -> Result<TorStatus, anyhow::Error> {
-        // info!(target: LOG_TARGET_APP_LOGIC, "Connecting to Tor control port {}", self.control_port);
-        let stream = TcpStream::connect(format!("127.0.0.1:{}", self.control_port)).await?;
-        let (reader, mut writer) = stream.into_split();
-        let mut reader = BufReader::new(reader).lines();
+    pub fn is_ok(&self) -> bool {
+        (200..300).contains(&self.code())
+    }
 
-        writer.write_all(AUTH_COMMAND.as_bytes()).await?;
-        writer.flush().await?;
+    fn into_result(self) -> Result<Self, anyhow::Error> {
+        if self.is_ok() {
+            Ok(self)
+        } else {
+            let line = self.lines.last().map_or("", |line| line.text.as_str());
+            Err(anyhow!("Tor control error {}: {line}", self.code()))
+        }
+    }
+}
 
-        if let Some(response) = reader.next_line().await?
-            && !response.starts_with("250")
-        {
-            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to authenticate with Tor control port: {response}");
-            return Err(anyhow::anyhow!(
-                "Failed to authenticate with Tor control port: {}",
-                response
-            ));
+/// Reads one complete reply, including any data blocks
+pub(crate) async fn read_reply<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+) -> Result<ControlReply, anyhow::Error> {
+    let mut lines = Vec::new();
+    loop {
+        let line = read_line(reader).await?;
+        if line.len() < 4 || !line.is_char_boundary(3) || !line.is_char_boundary(4) {
+            return Err(anyhow!("Malformed Tor control reply line: {line}"));
+        }
+        let code = line[..3]
+            .parse::<u16>()
+            .map_err(|_| anyhow!("Malformed Tor control reply code: {line}"))?;
+        let separator = &line[3..4];
+        let text = line[4..].to_string();
+        let mut data = Vec::new();
+        if separator == "+" {
+            loop {
+                let data_line = read_line(reader).await?;
+                if data_line == "." {
+                    break;
+                }
+                // Lines starting with a dot are escaped with a second one
+                data.push(
+                    data_line
+                        .strip_prefix('.')
+                        .unwrap_or(&data_line)
+                        .to_string(),
+                );
+            }
+        }
+        lines.push(ReplyLine { code, text, data });
+        match separator {
+            " " => return Ok(ControlReply { lines }),
+            "-" | "+" => {}
+            _ => return Err(anyhow!("Malformed Tor control reply line: {line}")),
+        }
+    }
+}
+
+async fn read_line<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<String, anyhow::Error> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Err(anyhow!("Tor control connection closed"));
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Splits reply text into its positional arguments and `KEY=value` pairs, unquoting values
+pub(crate) fn parse_arguments(text: &str) -> (Vec<String>, HashMap<String, String>) {
+    let mut positional = Vec::new();
+    let mut keywords = HashMap::new();
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+        let mut key = String::new();
+        let mut value = None;
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            if c == '=' && value.is_none() {
+                value = Some(read_value(&mut chars));
+                break;
+            }
+            key.push(c);
+        }
+        match value {
+            Some(value) => {
+                keywords.insert(key, value);
+            }
+            None => positional.push(key),
         }
+    }
+    (positional, keywords)
+}
+
+fn read_value(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut value = String::new();
+    if chars.next_if_eq(&'"').is_none() {
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            value.push(c);
+        }
+        return value;
+    }
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => value.push('\r'),
+                Some(escaped) => value.push(escaped),
+                None => {}
+            },
+            c => value.push(c),
+        }
+    }
+    value
+}
+
+pub(crate) fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Bootstrap progress, from `status/bootstrap-phase` or a `STATUS_CLIENT BOOTSTRAP` event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BootstrapProgress {
+    pub progress: u8,
+    pub tag: String,
+    pub summary: String,
+    /// Set when Tor reports a problem with the phase
+    pub warning: Option<String>,
+}
+
+impl BootstrapProgress {
+    /// Parses `NOTICE BOOTSTRAP PROGRESS=100 TAG=done SUMMARY="Done"`
+    pub fn parse(text: &str) -> Option<Self> {
+        let (_, keywords) = parse_arguments(text);
+        Some(Self {
+            progress: keywords.get("PROGRESS")?.parse().ok()?,
+            tag: keywords.get("TAG").cloned().unwrap_or_default(),
+            summary: keywords.get("SUMMARY").cloned().unwrap_or_default(),
+            warning: keywords.get("WARNING").cloned(),
+        })
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == 100
+    }
+}
 
-        writer.write_all(BOOTSTRAP_QUERY.as_bytes()).await?;
-        writer.flush().await?;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TorEvent {
+    Bootstrap(BootstrapProgress),
+    CircuitEstablished(bool),
+    NetworkLiveness(bool),
+    Circuit {
+        id: String,
+        status: String,
+    },
+    Stream {
+        id: String,
+        status: String,
+        target: String,
+        reason: Option<String>,
+    },
+    /// A pluggable transport plugin started and is accepting connections
+    TransportLaunched {
+        transport: String,
+    },
+    /// Log message of a pluggable transport plugin
+    TransportLog {
+        severity: String,
+        message: String,
+    },
+    Other(String),
+}
+
+impl TorEvent {
+    pub fn parse(reply: &ControlReply) -> Option<Self> {
+        let text = &reply.lines.first()?.text;
+        let (positional, keywords) = parse_arguments(text);
+        let mut positional = positional.into_iter();
+        let name = positional.next()?;
+        let event = match name.as_str() {
+            "STATUS_CLIENT" => {
+                let _severity = positional.next();
+                match positional.next()?.as_str() {
+                    "BOOTSTRAP" => TorEvent::Bootstrap(BootstrapProgress::parse(text)?),
+                    "CIRCUIT_ESTABLISHED" => TorEvent::CircuitEstablished(true),
+                    "CIRCUIT_NOT_ESTABLISHED" => TorEvent::CircuitEstablished(false),
+                    _ => TorEvent::Other(name),
+                }
+            }
+            "NETWORK_LIVENESS" => TorEvent::NetworkLiveness(positional.next()? == "UP"),
+            "CIRC" => TorEvent::Circuit {
+                id: positional.next()?,
+                status: positional.next()?,
+            },
+            "STREAM" => {
+                let id = positional.next()?;
+                let status = positional.next()?;
+                let _circuit_id = positional.next();
+                TorEvent::Stream {
+                    id,
+                    status,
+                    target: positional.next().unwrap_or_default(),
+                    reason: keywords.get("REASON").cloned(),
+                }
+            }
+            // `TRANSPORT_LAUNCHED client obfs4 127.0.0.1 43215`
+            "TRANSPORT_LAUNCHED" => {
+                let _side = positional.next();
+                TorEvent::TransportLaunched {
+                    transport: positional.next()?,
+                }
+            }
+            "PT_LOG" => TorEvent::TransportLog {
+                severity: keywords.get("SEVERITY").cloned().unwrap_or_default(),
+                message: keywords.get("MESSAGE").cloned().unwrap_or_default(),
+            },
+            _ => TorEvent::Other(name),
+        };
+        Some(event)
+    }
+}
+
+impl Display for TorEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TorEvent::Bootstrap(BootstrapProgress {
+                progress,
+                tag,
+                summary,
+                warning,
+            }) => {
+                write!(f, "bootstrap {progress}% ({tag}): {summary}")?;
+                match warning {
+                    Some(warning) => write!(f, ", warning: {warning}"),
+                    None => Ok(()),
+                }
+            }
+            TorEvent::CircuitEstablished(true) => write!(f, "circuit established"),
+            TorEvent::CircuitEstablished(false) => write!(f, "circuit not established"),
+            TorEvent::NetworkLiveness(true) => write!(f, "network up"),
+            TorEvent::NetworkLiveness(false) => write!(f, "network down"),
+            TorEvent::Circuit { id, status } => write!(f, "circuit {id} {status}"),
+            TorEvent::Stream {
+                id,
+                status,
+                target,
+                reason,
+            } => {
+                write!(f, "stream {id} to {target} {status}")?;
+                match reason {
+                    Some(reason) => write!(f, " ({reason})"),
+                    None => Ok(()),
+                }
+            }
+            TorEvent::TransportLaunched { transport } => {
+                write!(f, "{transport} transport launched")
+            }
+            TorEvent::TransportLog { severity, message } => {
+                write!(f, "transport {severity}: {message}")
+            }
+            TorEvent::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
 
-        let mut bootstrap_phase = 0;
-        let mut bootstrapped = false;
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ProtocolInfo {
+    pub auth_methods: Vec<String>,
+    pub cookie_file: Option<PathBuf>,
+    pub tor_version: Option<String>,
+}
 
-        if let Some(response) = reader.next_line().await? {
-            // Expected output 250-status/bootstrap-phase=NOTICE BOOTSTRAP PROGRESS=100 TAG=done SUMMARY="Done"
-            let regex = Regex::new(r#"250-status\/bootstrap-phase=NOTICE\s+BOOTSTRAP\s+PROGRESS=(\d+)\s+TAG=(\w+) SUMMARY="([^"]+)""#).expect("If regex is wrong, don't start");
-            if let Some(captures) = regex.captures(&response) {
-                bootstrap_phase = captures
-                    .get(1)
-                    .ok_or_else(|| anyhow!("No progress capture in tor bootstrap status"))?
-                    .as_str()
-                    .parse::<u8>()?;
-                bootstrapped = captures
-                    .get(3)
-                    .ok_or_else(|| anyhow!("No status capture in tor bootstrap status"))?
-                    .as_str()
-                    == "Done";
-                // drop the next line
-                let _s250_ok = reader.next_line().await?;
-            } else {
-                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to parse bootstrap response: {response}");
+impl ProtocolInfo {
+    pub fn parse(reply: &ControlReply) -> Self {
+        let mut info = ProtocolInfo::default();
+        for line in &reply.lines {
+            let (positional, keywords) = parse_arguments(&line.text);
+            match positional.first().map(String::as_str) {
+                Some("AUTH") => {
+                    info.auth_methods = keywords
+                        .get("METHODS")
+                        .map(|methods| methods.split(',').map(str::to_string).collect())
+                        .unwrap_or_default();
+                    info.cookie_file = keywords.get("COOKIEFILE").map(PathBuf::from);
+                }
+                Some("VERSION") => info.tor_version = keywords.get("Tor").cloned(),
+                _ => {}
             }
         }
+        info
+    }
+
+    fn supports(&self, method: &str) -> bool {
+        self.auth_methods.iter().any(|m| m == method)
+    }
+}
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn safecookie_mac(
+    key: &[u8],
+    cookie: &[u8],
+    client_nonce: &[u8],
+    server_nonce: &[u8],
+) -> Result<HmacSha256, anyhow::Error> {
+    let mut mac = HmacSha256::new_from_slice(key)?;
+    mac.update(cookie);
+    mac.update(client_nonce);
+    mac.update(server_nonce);
+    Ok(mac)
+}
+
+/// Hash the controller sends back in a SAFECOOKIE authentication
+pub(crate) fn safecookie_client_hash(
+    cookie: &[u8],
+    client_nonce: &[u8],
+    server_nonce: &[u8],
+) -> Result<Vec<u8>, anyhow::Error> {
+    Ok(
+        safecookie_mac(SAFECOOKIE_CLIENT_KEY, cookie, client_nonce, server_nonce)?
+            .finalize()
+            .into_bytes()
+            .to_vec(),
+    )
+}
+
+/// Checks the hash Tor sends to prove it knows the cookie
+pub(crate) fn verify_safecookie_server_hash(
+    cookie: &[u8],
+    client_nonce: &[u8],
+    server_nonce: &[u8],
+    server_hash: &[u8],
+) -> Result<(), anyhow::Error> {
+    safecookie_mac(SAFECOOKIE_SERVER_KEY, cookie, client_nonce, server_nonce)?
+        .verify_slice(server_hash)
+        .map_err(|_| anyhow!("Tor SAFECOOKIE server hash mismatch"))
+}
+
+pub(crate) struct TorControlClient {
+    reader: BufReader<OwnedReadHalf>,
+    writer: OwnedWriteHalf,
+    events: VecDeque<TorEvent>,
+}
+
+impl TorControlClient {
+    /// Connects to a local control port and authenticates with the best method Tor offers.
+    /// The password is only used when Tor offers HASHEDPASSWORD authentication.
+    pub async fn connect(control_port: u16, password: Option<&str>) -> Result<Self, anyhow::Error> {
+        Self::connect_to(SocketAddr::from(([127, 0, 0, 1], control_port)), password).await
+    }
+
+    /// Connects to a control port at any address, e.g. one of a system Tor daemon
+    pub async fn connect_to(
+        address: SocketAddr,
+        password: Option<&str>,
+    ) -> Result<Self, anyhow::Error> {
+        let stream = TcpStream::connect(address).await?;
+        let (reader, writer) = stream.into_split();
+        let mut client = Self {
+            reader: BufReader::new(reader),
+            writer,
+            events: VecDeque::new(),
+        };
+        let protocol_info = client.protocol_info().await?;
+        debug!(target: LOG_TARGET_APP_LOGIC, "Tor {} control port offers authentication methods: {}", protocol_info.tor_version.as_deref().unwrap_or("unknown"), protocol_info.auth_methods.join(","));
+        client.authenticate(&protocol_info, password).await?;
+        Ok(client)
+    }
 
-        writer.write_all(CIRCUIT_QUERY.as_bytes()).await?;
-        writer.flush().await?;
-        let mut circuit_ok = false;
-        if let Some(response) = reader.next_line().await? {
-            if response.contains("circuit-established=1") {
-                circuit_ok = true;
+    /// Sends a command and waits for its reply, queueing events received meanwhile
+    pub async fn command(&mut self, command: &str) -> Result<ControlReply, anyhow::Error> {
+        self.writer
+            .write_all(format!("{command}\r\n").as_bytes())
+            .await?;
+        self.writer.flush().await?;
+        loop {
+            let reply = read_reply(&mut self.reader).await?;
+            if reply.code() != ASYNC_EVENT_CODE {
+                return Ok(reply);
+            }
+            if let Some(event) = TorEvent::parse(&reply) {
+                self.events.push_back(event);
             }
-            let _s250_ok = reader.next_line().await?;
         }
+    }
 
-        writer.write_all(NETWORK_QUERY.as_bytes()).await?;
-        writer.flush().await?;
+    pub async fn protocol_info(&mut self) -> Result<ProtocolInfo, anyhow::Error> {
+        let reply = self.command("PROTOCOLINFO 1").await?.into_result()?;
+        Ok(ProtocolInfo::parse(&reply))
+    }
 
-        let mut network_liveness = false;
-        if let Some(response) = reader.next_line().await?
-            && response.contains("network-liveness=up")
+    /// A configured password takes precedence over cookie authentication, since the cookie
+    /// file of a system Tor daemon is usually not readable by the app.
+    pub async fn authenticate(
+        &mut self,
+        protocol_info: &ProtocolInfo,
+        password: Option<&str>,
+    ) -> Result<(), anyhow::Error> {
+        let cookie_file = protocol_info.cookie_file.as_ref();
+        let command = if protocol_info.supports("NULL") {
+            "AUTHENTICATE".to_string()
+        } else if let (true, Some(password)) = (protocol_info.supports("HASHEDPASSWORD"), password)
+        {
+            format!("AUTHENTICATE {}", quote(password))
+        } else if let (true, Some(cookie_file)) =
+            (protocol_info.supports("SAFECOOKIE"), cookie_file)
         {
-            network_liveness = true;
+            let cookie = tokio::fs::read(cookie_file).await?;
+            let client_hash = self.safecookie_challenge(&cookie).await?;
+            format!("AUTHENTICATE {}", hex::encode(client_hash))
+        } else if let (true, Some(cookie_file)) = (protocol_info.supports("COOKIE"), cookie_file) {
+            let cookie = tokio::fs::read(cookie_file).await?;
+            format!("AUTHENTICATE {}", hex::encode(cookie))
+        } else {
+            return Err(anyhow!(
+                "No supported Tor control authentication method, Tor offers: {}",
+                protocol_info.auth_methods.join(",")
+            ));
+        };
+        self.command(&command)
+            .await?
+            .into_result()
+            .map_err(|e| anyhow!("Failed to authenticate with Tor control port: {e}"))?;
+        Ok(())
+    }
+
+    async fn safecookie_challenge(&mut self, cookie: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        let mut client_nonce = [0u8; SAFECOOKIE_NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut client_nonce);
+        let reply = self
+            .command(&format!(
+                "AUTHCHALLENGE SAFECOOKIE {}",
+                hex::encode(client_nonce)
+            ))
+            .await?
+            .into_result()?;
+        let text = &reply
+            .lines
+            .first()
+            .ok_or_else(|| anyhow!("Empty AUTHCHALLENGE reply"))?
+            .text;
+        let (_, keywords) = parse_arguments(text);
+        let decode = |key: &str| -> Result<Vec<u8>, anyhow::Error> {
+            Ok(hex::decode(keywords.get(key).ok_or_else(|| {
+                anyhow!("AUTHCHALLENGE reply without {key}")
+            })?)?)
+        };
+        let server_hash = decode("SERVERHASH")?;
+        let server_nonce = decode("SERVERNONCE")?;
+        verify_safecookie_server_hash(cookie, &client_nonce, &server_nonce, &server_hash)?;
+        safecookie_client_hash(cookie, &client_nonce, &server_nonce)
+    }
+
+    /// Queries GETINFO keys. Multi-line values are joined with newlines.
+    pub async fn get_info(
+        &mut self,
+        keys: &[&str],
+    ) -> Result<HashMap<String, String>, anyhow::Error> {
+        let reply = self
+            .command(&format!("GETINFO {}", keys.join(" ")))
+            .await?
+            .into_result()?;
+        Ok(reply
+            .lines
+            .iter()
+            .filter_map(|line| {
+                let (key, value) = line.text.split_once('=')?;
+                let value = if line.data.is_empty() {
+                    value.to_string()
+                } else {
+                    line.data.join("\n")
+                };
+                Some((key.to_string(), value))
+            })
+            .collect())
+    }
+
+    pub async fn set_events(&mut self, events: &[&str]) -> Result<(), anyhow::Error> {
+        self.command(&format!("SETEVENTS {}", events.join(" ")))
+            .await?
+            .into_result()?;
+        Ok(())
+    }
+
+    /// Changes Tor options at runtime. Options without a value are reset to their default.
+    pub async fn set_conf(
+        &mut self,
+        options: &[(&str, Option<String>)],
+    ) -> Result<(), anyhow::Error> {
+        let options: Vec<String> = options
+            .iter()
+            .map(|(key, value)| match value {
+                Some(value) => format!("{key}={}", quote(value)),
+                None => (*key).to_string(),
+            })
+            .collect();
+        self.command(&format!("SETCONF {}", options.join(" ")))
+            .await?
+            .into_result()?;
+        Ok(())
+    }
+
+    pub async fn signal(&mut self, signal: &str) -> Result<(), anyhow::Error> {
+        self.command(&format!("SIGNAL {signal}"))
+            .await?
+            .into_result()?;
+        Ok(())
+    }
+
+    /// Waits for the next event of the subscribed ones
+    pub async fn next_event(&mut self) -> Result<TorEvent, anyhow::Error> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(event);
+            }
+            let reply = read_reply(&mut self.reader).await?;
+            match TorEvent::parse(&reply) {
+                Some(event) if reply.code() == ASYNC_EVENT_CODE => return Ok(event),
+                _ => debug!(target: LOG_TARGET_APP_LOGIC, "Ignoring Tor control reply: {reply:?}"),
+            }
         }
+    }
+
+    /// Waits for a circuit built after this call, needs `CIRC` events. Right after a config
+    /// change the status and already queued events still describe the previous config.
+    pub async fn wait_for_new_circuit(&mut self) -> Result<(), anyhow::Error> {
+        self.events.clear();
+        loop {
+            match self.next_event().await? {
+                TorEvent::Circuit { status, .. } if status == "BUILT" => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    pub async fn status(&mut self) -> Result<TorStatus, anyhow::Error> {
+        let info = self
+            .get_info(&[
+                "status/bootstrap-phase",
+                "status/circuit-established",
+                "network-liveness",
+            ])
+            .await?;
+        let bootstrap = info
+            .get("status/bootstrap-phase")
+            .and_then(|phase| BootstrapProgress::parse(phase));
         Ok(TorStatus {
-            bootstrap_phase,
-            is_bootstrapped: bootstrapped,
-            network_liveness,
-            circuit_ok,
+            bootstrap_phase: bootstrap.as_ref().map_or(0, |b| b.progress),
+            is_bootstrapped: bootstrap.as_ref().is_some_and(BootstrapProgress::is_done),
+            network_liveness: info.get("network-liveness").is_some_and(|l| l == "up"),
+            circuit_ok: info
+                .get("status/circuit-established")
+                .is_some_and(|c| c == "1"),
+            ..TorStatus::default()
         })
     }
+
+    pub async fn entry_guards(&mut self) -> Result<Vec<String>, anyhow::Error> {
+        let info = self.get_info(&["entry-guards"]).await?;
+        Ok(info
+            .get("entry-guards")
+            .map(|guards| {
+                guards
+                    .lines()
+                    .map(str::trim)
+                    .filter(|guard| !guard.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
 }
@@ -0,0 +1,516 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for tor_control_client
+
+use std::net::SocketAddr;
+use std::path::Path;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+use crate::testing::test_utils::TestContext;
+use crate::tor_control_client::{
+    BootstrapProgress, ProtocolInfo, TorControlClient, TorEvent, TorStatus, parse_arguments,
+    read_reply, safecookie_client_hash,
+};
+use crate::tor_transports::{PluggableTransport, TransportHealth};
+
+const COOKIE: [u8; 32] = [7u8; 32];
+const SERVER_NONCE: [u8; 32] = [9u8; 32];
+
+/// Serves a fake control port answering each command with `respond`, and forwards every
+/// received command line to the returned channel
+async fn fake_control_port<F>(respond: F) -> (u16, mpsc::UnboundedReceiver<String>)
+where
+    F: Fn(&str) -> String + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let response = respond(&line);
+            let _unused = commands_tx.send(line);
+            if writer.write_all(response.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+    (port, commands_rx)
+}
+
+fn safecookie_responder(cookie_file: &Path) -> impl Fn(&str) -> String + Send + 'static {
+    let cookie_file = cookie_file.display().to_string();
+    move |command: &str| {
+        if command == "PROTOCOLINFO 1" {
+            format!(
+                "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=COOKIE,SAFECOOKIE COOKIEFILE=\"{cookie_file}\"\r\n250-VERSION Tor=\"0.4.8.12\"\r\n250 OK\r\n"
+            )
+        } else if let Some(client_nonce) = command.strip_prefix("AUTHCHALLENGE SAFECOOKIE ") {
+            let client_nonce = hex::decode(client_nonce).unwrap();
+            // The server hash uses the other key, so a client echoing it back would fail
+            let server_hash = hmac_server_hash(&client_nonce);
+            format!(
+                "250 AUTHCHALLENGE SERVERHASH={} SERVERNONCE={}\r\n",
+                hex::encode(server_hash),
+                hex::encode(SERVER_NONCE)
+            )
+        } else if command.starts_with("AUTHENTICATE ") {
+            "250 OK\r\n".to_string()
+        } else if command.starts_with("GETINFO status/bootstrap-phase") {
+            "250-status/bootstrap-phase=NOTICE BOOTSTRAP PROGRESS=85 TAG=ap_conn SUMMARY=\"Connecting to a relay to build circuits\"\r\n250-status/circuit-established=0\r\n250-network-liveness=up\r\n250 OK\r\n".to_string()
+        } else if command.starts_with("SETEVENTS ") {
+            "250 OK\r\n650 STATUS_CLIENT NOTICE BOOTSTRAP PROGRESS=100 TAG=done SUMMARY=\"Done\"\r\n650 CIRC 3 BUILT $AAAA~relay PURPOSE=GENERAL\r\n".to_string()
+        } else {
+            "510 Unrecognized command\r\n".to_string()
+        }
+    }
+}
+
+fn hmac_server_hash(client_nonce: &[u8]) -> Vec<u8> {
+    use hmac::{Hmac, Mac};
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(
+        b"Tor safe cookie authentication server-to-controller hash",
+    )
+    .unwrap();
+    mac.update(&COOKIE);
+    mac.update(client_nonce);
+    mac.update(&SERVER_NONCE);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// ==================== Reply parsing ====================
+
+#[tokio::test]
+async fn reads_multi_line_reply_with_data_block() {
+    let mut input = BufReader::new(
+        &b"250+entry-guards=\r\n$AAAA~guard1 up\r\n..dotted\r\n.\r\n250-version=0.4.8\r\n250 OK\r\n"[..],
+    );
+    let reply = read_reply(&mut input).await.unwrap();
+
+    assert!(reply.is_ok());
+    assert_eq!(reply.lines.len(), 3);
+    assert_eq!(reply.lines[0].text, "entry-guards=");
+    assert_eq!(reply.lines[0].data, ["$AAAA~guard1 up", ".dotted"]);
+    assert_eq!(reply.lines[1].text, "version=0.4.8");
+}
+
+#[tokio::test]
+async fn rejects_malformed_reply_line() {
+    let mut input = BufReader::new(&b"25\r\n"[..]);
+    assert!(read_reply(&mut input).await.is_err());
+}
+
+#[test]
+fn parses_positional_and_quoted_arguments() {
+    let (positional, keywords) =
+        parse_arguments(r#"NOTICE BOOTSTRAP PROGRESS=50 SUMMARY="Loading \"relay\" descriptors""#);
+    assert_eq!(positional, ["NOTICE", "BOOTSTRAP"]);
+    assert_eq!(keywords["PROGRESS"], "50");
+    assert_eq!(keywords["SUMMARY"], r#"Loading "relay" descriptors"#);
+}
+
+#[tokio::test]
+async fn parses_protocol_info() {
+    let mut input = BufReader::new(
+        &b"250-PROTOCOLINFO 1\r\n250-AUTH METHODS=COOKIE,SAFECOOKIE,HASHEDPASSWORD COOKIEFILE=\"/tmp/tor data/control_auth_cookie\"\r\n250-VERSION Tor=\"0.4.8.12\"\r\n250 OK\r\n"[..],
+    );
+    let info = ProtocolInfo::parse(&read_reply(&mut input).await.unwrap());
+
+    assert_eq!(
+        info.auth_methods,
+        ["COOKIE", "SAFECOOKIE", "HASHEDPASSWORD"]
+    );
+    assert_eq!(
+        info.cookie_file.as_deref(),
+        Some(Path::new("/tmp/tor data/control_auth_cookie"))
+    );
+    assert_eq!(info.tor_version.as_deref(), Some("0.4.8.12"));
+}
+
+// ==================== Events ====================
+
+#[tokio::test]
+async fn parses_status_circuit_and_stream_events() {
+    let mut input = BufReader::new(
+        &b"650 STATUS_CLIENT NOTICE BOOTSTRAP PROGRESS=100 TAG=done SUMMARY=\"Done\"\r\n650 NETWORK_LIVENESS DOWN\r\n650 CIRC 12 FAILED $AAAA~relay REASON=TIMEOUT\r\n650 STREAM 40 FAILED 12 example.com:443 REASON=CONNECTREFUSED\r\n"[..],
+    );
+    let mut events = Vec::new();
+    for _ in 0..4 {
+        events.push(TorEvent::parse(&read_reply(&mut input).await.unwrap()).unwrap());
+    }
+
+    assert_eq!(
+        events,
+        [
+            TorEvent::Bootstrap(BootstrapProgress {
+                progress: 100,
+                tag: "done".to_string(),
+                summary: "Done".to_string(),
+                warning: None,
+            }),
+            TorEvent::NetworkLiveness(false),
+            TorEvent::Circuit {
+                id: "12".to_string(),
+                status: "FAILED".to_string(),
+            },
+            TorEvent::Stream {
+                id: "40".to_string(),
+                status: "FAILED".to_string(),
+                target: "example.com:443".to_string(),
+                reason: Some("CONNECTREFUSED".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn status_follows_events() {
+    let mut status = TorStatus::default();
+
+    assert!(status.apply_event(&TorEvent::NetworkLiveness(true)));
+    assert!(!status.apply_event(&TorEvent::NetworkLiveness(true)));
+    assert!(status.apply_event(&TorEvent::Circuit {
+        id: "1".to_string(),
+        status: "BUILT".to_string(),
+    }));
+    assert!(status.apply_event(&TorEvent::Bootstrap(BootstrapProgress {
+        progress: 100,
+        tag: "done".to_string(),
+        summary: "Done".to_string(),
+        warning: None,
+    })));
+
+    assert!(status.is_bootstrapped && status.network_liveness && status.circuit_ok);
+    assert_eq!(status.bootstrap_phase, 100);
+    assert_eq!(status.transport_health, TransportHealth::Unused);
+}
+
+#[tokio::test]
+async fn parses_transport_events() {
+    let mut input = BufReader::new(
+        &b"650 TRANSPORT_LAUNCHED client snowflake 127.0.0.1 43215\r\n650 PT_LOG PT=\"/tor/pluggable_transports/lyrebird\" SEVERITY=error MESSAGE=\"listen failed\"\r\n650 STATUS_CLIENT WARN BOOTSTRAP PROGRESS=1 TAG=conn_pt SUMMARY=\"Connecting to pluggable transport\" WARNING=\"Connection refused\" REASON=CONNECTREFUSED\r\n"[..],
+    );
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        events.push(TorEvent::parse(&read_reply(&mut input).await.unwrap()).unwrap());
+    }
+
+    assert_eq!(
+        events,
+        [
+            TorEvent::TransportLaunched {
+                transport: "snowflake".to_string(),
+            },
+            TorEvent::TransportLog {
+                severity: "error".to_string(),
+                message: "listen failed".to_string(),
+            },
+            TorEvent::Bootstrap(BootstrapProgress {
+                progress: 1,
+                tag: "conn_pt".to_string(),
+                summary: "Connecting to pluggable transport".to_string(),
+                warning: Some("Connection refused".to_string()),
+            }),
+        ]
+    );
+}
+
+#[test]
+fn transport_health_follows_events() {
+    let mut status = TorStatus::with_transport(Some(PluggableTransport::Obfs4));
+    assert_eq!(status.transport_health, TransportHealth::Launching);
+
+    // Only the configured transport counts
+    assert!(!status.apply_event(&TorEvent::TransportLaunched {
+        transport: "snowflake".to_string(),
+    }));
+    assert!(status.apply_event(&TorEvent::TransportLaunched {
+        transport: "obfs4".to_string(),
+    }));
+    assert_eq!(status.transport_health, TransportHealth::Running);
+
+    assert!(status.apply_event(&TorEvent::TransportLog {
+        severity: "error".to_string(),
+        message: "listen failed".to_string(),
+    }));
+    assert_eq!(status.transport_health, TransportHealth::Failed);
+
+    assert!(status.apply_event(&TorEvent::Bootstrap(BootstrapProgress {
+        progress: 10,
+        tag: "conn_done".to_string(),
+        summary: "Connected to a relay".to_string(),
+        warning: None,
+    })));
+    assert_eq!(status.transport_health, TransportHealth::Running);
+}
+
+#[test]
+fn bootstrap_warning_while_connecting_to_transport_fails_it() {
+    let mut status = TorStatus::with_transport(Some(PluggableTransport::Webtunnel));
+
+    assert!(status.apply_event(&TorEvent::Bootstrap(BootstrapProgress {
+        progress: 1,
+        tag: "conn_pt".to_string(),
+        summary: "Connecting to pluggable transport".to_string(),
+        warning: Some("Connection refused".to_string()),
+    })));
+    assert_eq!(status.transport_health, TransportHealth::Failed);
+
+    // Switching the transport judges it by the bootstrap progress again
+    assert!(status.set_transport(None));
+    assert_eq!(status.transport_health, TransportHealth::Unused);
+}
+
+// ==================== Authentication ====================
+
+#[tokio::test]
+async fn authenticates_with_safecookie_and_receives_events() {
+    let ctx = TestContext::new();
+    let cookie_file = ctx.data_dir.join("control_auth_cookie");
+    std::fs::write(&cookie_file, COOKIE).unwrap();
+    let (port, mut commands) = fake_control_port(safecookie_responder(&cookie_file)).await;
+
+    let mut client = TorControlClient::connect(port, None).await.unwrap();
+    let status = client.status().await.unwrap();
+    client.set_events(&["STATUS_CLIENT", "CIRC"]).await.unwrap();
+
+    assert_eq!(status.bootstrap_phase, 85);
+    assert!(!status.is_bootstrapped);
+    assert!(status.network_liveness);
+    assert!(matches!(
+        client.next_event().await.unwrap(),
+        TorEvent::Bootstrap(BootstrapProgress { progress: 100, .. })
+    ));
+    assert_eq!(
+        client.next_event().await.unwrap(),
+        TorEvent::Circuit {
+            id: "3".to_string(),
+            status: "BUILT".to_string(),
+        }
+    );
+
+    assert_eq!(commands.recv().await.unwrap(), "PROTOCOLINFO 1");
+    let challenge = commands.recv().await.unwrap();
+    let client_nonce =
+        hex::decode(challenge.strip_prefix("AUTHCHALLENGE SAFECOOKIE ").unwrap()).unwrap();
+    let expected_hash = safecookie_client_hash(&COOKIE, &client_nonce, &SERVER_NONCE).unwrap();
+    assert_eq!(
+        commands.recv().await.unwrap(),
+        format!("AUTHENTICATE {}", hex::encode(expected_hash))
+    );
+}
+
+#[tokio::test]
+async fn rejects_safecookie_server_with_wrong_hash() {
+    let ctx = TestContext::new();
+    let cookie_file = ctx.data_dir.join("control_auth_cookie");
+    // Tor proves knowledge of a different cookie than the one on disk
+    std::fs::write(&cookie_file, [1u8; 32]).unwrap();
+    let (port, _commands) = fake_control_port(safecookie_responder(&cookie_file)).await;
+
+    assert!(TorControlClient::connect(port, None).await.is_err());
+}
+
+#[tokio::test]
+async fn authenticates_with_quoted_password() {
+    let (port, mut commands) = fake_control_port(|command: &str| {
+        if command == "PROTOCOLINFO 1" {
+            "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=HASHEDPASSWORD\r\n250 OK\r\n".to_string()
+        } else {
+            "250 OK\r\n".to_string()
+        }
+    })
+    .await;
+
+    TorControlClient::connect(port, Some(r#"pa"ss"#))
+        .await
+        .unwrap();
+
+    assert_eq!(commands.recv().await.unwrap(), "PROTOCOLINFO 1");
+    assert_eq!(commands.recv().await.unwrap(), r#"AUTHENTICATE "pa\"ss""#);
+}
+
+#[tokio::test]
+async fn prefers_a_configured_password_over_the_cookie() {
+    let (port, mut commands) = fake_control_port(|command: &str| {
+        if command == "PROTOCOLINFO 1" {
+            "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=COOKIE,SAFECOOKIE,HASHEDPASSWORD COOKIEFILE=\"/var/lib/tor/control_auth_cookie\"\r\n250 OK\r\n".to_string()
+        } else {
+            "250 OK\r\n".to_string()
+        }
+    })
+    .await;
+
+    TorControlClient::connect_to(SocketAddr::from(([127, 0, 0, 1], port)), Some("hunter2"))
+        .await
+        .unwrap();
+
+    assert_eq!(commands.recv().await.unwrap(), "PROTOCOLINFO 1");
+    assert_eq!(commands.recv().await.unwrap(), r#"AUTHENTICATE "hunter2""#);
+}
+
+#[tokio::test]
+async fn fails_without_a_usable_authentication_method() {
+    let (port, _commands) = fake_control_port(|_: &str| {
+        "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=HASHEDPASSWORD\r\n250 OK\r\n".to_string()
+    })
+    .await;
+
+    assert!(TorControlClient::connect(port, None).await.is_err());
+}
+
+#[test]
+fn describes_events_for_logging() {
+    let event = TorEvent::Stream {
+        id: "40".to_string(),
+        status: "FAILED".to_string(),
+        target: "example.com:443".to_string(),
+        reason: Some("CONNECTREFUSED".to_string()),
+    };
+    assert_eq!(
+        event.to_string(),
+        "stream 40 to example.com:443 FAILED (CONNECTREFUSED)"
+    );
+}
+
+#[tokio::test]
+async fn sets_and_resets_options() {
+    let (port, mut commands) = fake_control_port(|command: &str| {
+        if command == "PROTOCOLINFO 1" {
+            "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=NULL\r\n250 OK\r\n".to_string()
+        } else {
+            "250 OK\r\n".to_string()
+        }
+    })
+    .await;
+
+    let mut client = TorControlClient::connect(port, None).await.unwrap();
+    client
+        .set_conf(&[
+            ("UseBridges", Some("1".to_string())),
+            ("Bridge", Some("192.0.2.11:9001".to_string())),
+            ("ClientTransportPlugin", None),
+        ])
+        .await
+        .unwrap();
+    client.signal("NEWNYM").await.unwrap();
+
+    assert_eq!(commands.recv().await.unwrap(), "PROTOCOLINFO 1");
+    assert_eq!(commands.recv().await.unwrap(), "AUTHENTICATE");
+    assert_eq!(
+        commands.recv().await.unwrap(),
+        r#"SETCONF UseBridges="1" Bridge="192.0.2.11:9001" ClientTransportPlugin"#
+    );
+    assert_eq!(commands.recv().await.unwrap(), "SIGNAL NEWNYM");
+}
+
+#[tokio::test]
+async fn rejected_option_is_an_error() {
+    let (port, _commands) = fake_control_port(|command: &str| {
+        if command == "PROTOCOLINFO 1" {
+            "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=NULL\r\n250 OK\r\n".to_string()
+        } else if command.starts_with("SETCONF") {
+            "513 Unacceptable option value: Bridge line did not parse\r\n".to_string()
+        } else {
+            "250 OK\r\n".to_string()
+        }
+    })
+    .await;
+
+    let mut client = TorControlClient::connect(port, None).await.unwrap();
+    assert!(
+        client
+            .set_conf(&[("Bridge", Some("nonsense".to_string()))])
+            .await
+            .is_err()
+    );
+}
+
+// ==================== Live reconfiguration ====================
+
+/// Control port of a Tor that bootstrapped with the previous config, replying to `SIGNAL NEWNYM`
+/// with `newnym_reply`
+fn reconfigured_tor_responder(
+    newnym_reply: &'static str,
+) -> impl Fn(&str) -> String + Send + 'static {
+    move |command: &str| {
+        if command == "PROTOCOLINFO 1" {
+            "250-PROTOCOLINFO 1\r\n250-AUTH METHODS=NULL\r\n250 OK\r\n".to_string()
+        } else if command.starts_with("GETINFO status/bootstrap-phase") {
+            "250-status/bootstrap-phase=NOTICE BOOTSTRAP PROGRESS=100 TAG=done SUMMARY=\"Done\"\r\n250-status/circuit-established=1\r\n250-network-liveness=up\r\n250 OK\r\n".to_string()
+        } else if command == "SIGNAL NEWNYM" {
+            newnym_reply.to_string()
+        } else {
+            "250 OK\r\n".to_string()
+        }
+    }
+}
+
+async fn reconfigure(port: u16) -> TorControlClient {
+    let mut client = TorControlClient::connect(port, None).await.unwrap();
+    client.set_events(&["CIRC"]).await.unwrap();
+    client
+        .set_conf(&[("Bridge", Some("192.0.2.11:9001".to_string()))])
+        .await
+        .unwrap();
+    client.signal("NEWNYM").await.unwrap();
+    client
+}
+
+#[tokio::test]
+async fn stale_status_is_not_a_new_circuit() {
+    // The circuit finishes building while NEWNYM is processed, before the new bridges are used
+    let (port, _commands) = fake_control_port(reconfigured_tor_responder(
+        "650 CIRC 3 BUILT $AAAA~relay PURPOSE=GENERAL\r\n250 OK\r\n",
+    ))
+    .await;
+    let mut client = reconfigure(port).await;
+
+    let status = client.status().await.unwrap();
+    assert!(status.is_bootstrapped && status.circuit_ok);
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), client.wait_for_new_circuit())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn circuit_built_after_reconfiguring_is_new() {
+    let (port, _commands) = fake_control_port(reconfigured_tor_responder(
+        "250 OK\r\n650 CIRC 4 LAUNCHED PURPOSE=GENERAL\r\n650 CIRC 4 BUILT $BBBB~bridge PURPOSE=GENERAL\r\n",
+    ))
+    .await;
+    let mut client = reconfigure(port).await;
+
+    tokio::time::timeout(Duration::from_secs(5), client.wait_for_new_circuit())
+        .await
+        .unwrap()
+        .unwrap();
+}
@@ -20,24 +20,57 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::process_adapter::ProcessAdapter;
+use crate::binaries::{Binaries, BinaryResolver};
+use crate::process_adapter::{HealthStatus, ProcessAdapter, StatusMonitor};
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
+use crate::requests::connectivity;
 use crate::tasks_tracker::TasksTrackers;
-use crate::tor_adapter::{TorAdapter, TorConfig};
-use crate::tor_control_client::TorStatus;
+use crate::tor_adapter::{
+    ExternalTorConfig, TorAdapter, TorConfig, TorControlEndpoint, TorStatusMonitor,
+};
+use crate::tor_control_client::{TorControlClient, TorStatus};
+use crate::tor_transports::client_transport_plugins;
 use crate::{LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 use anyhow::anyhow;
-use log::{error, info};
-use std::time::Duration;
+use log::{error, info, warn};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
 use std::{path::PathBuf, sync::Arc};
 use tauri_plugin_sentry::sentry;
 use tokio::sync::{RwLock, watch};
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
 
 const STARTUP_TIMEOUT: u64 = 180; // 3mins
+const HEALTH_POLL_TIME: Duration = Duration::from_secs(10);
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(9);
+/// How long Tor gets to build a circuit with changed bridges before they are rolled back
+const LIVE_RECONFIGURE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// How a Tor config change took effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TorConfigUpdate {
+    /// Saved for the next start, as Tor isn't running or nothing it uses changed
+    Saved,
+    /// Applied to the running Tor, whose route to the network changed
+    AppliedLive,
+    /// Tor has to restart, as its control port changed
+    RestartRequired,
+}
+
+/// A system Tor daemon used instead of the bundled Tor
+struct ExternalTor {
+    status_monitor: TorStatusMonitor,
+    /// Health checks keeping the Tor status up to date, as the process watcher does for the
+    /// bundled Tor
+    health_check: JoinHandle<()>,
+}
 
 pub(crate) struct TorManager {
     watcher: Arc<RwLock<ProcessWatcher<TorAdapter>>>,
+    external: Arc<RwLock<Option<ExternalTor>>>,
     status_watch_rx: watch::Receiver<TorStatus>,
 }
 
@@ -45,6 +78,7 @@ impl Clone for TorManager {
     fn clone(&self) -> Self {
         Self {
             watcher: self.watcher.clone(),
+            external: self.external.clone(),
             status_watch_rx: self.status_watch_rx.clone(),
         }
     }
@@ -59,43 +93,114 @@ impl TorManager {
         let adapter = TorAdapter::new(status_broadcast);
         let mut process_watcher = ProcessWatcher::new(adapter, stats_collector.take_tor());
         process_watcher.expected_startup_time = Duration::from_secs(STARTUP_TIMEOUT);
-        process_watcher.health_timeout = Duration::from_secs(9);
-        process_watcher.poll_time = Duration::from_secs(10);
+        process_watcher.health_timeout = HEALTH_TIMEOUT;
+        process_watcher.poll_time = HEALTH_POLL_TIME;
 
         Self {
             watcher: Arc::new(RwLock::new(process_watcher)),
+            external: Arc::new(RwLock::new(None)),
             status_watch_rx,
         }
     }
 
+    /// Loads the saved config, which tells whether a system Tor is used before anything starts
+    pub async fn load_config(&self, config_path: PathBuf) -> Result<TorConfig, anyhow::Error> {
+        let mut process_watcher = self.watcher.write().await;
+        process_watcher
+            .adapter
+            .load_or_create_config(config_path)
+            .await?;
+        Ok(process_watcher.adapter.get_tor_config())
+    }
+
     pub async fn ensure_started(
         &self,
         base_path: PathBuf,
         config_path: PathBuf,
         log_path: PathBuf,
     ) -> Result<(), anyhow::Error> {
+        let config = self.load_config(config_path.clone()).await?;
+        if let Some(external) = config.external() {
+            return self.ensure_external_started(external).await;
+        }
         {
             let shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
             let task_tracker = TasksTrackers::current().node_phase.get_task_tracker().await;
 
             let mut process_watcher = self.watcher.write().await;
 
+            let transport_plugins = resolve_transport_plugins(&config).await?;
             process_watcher
                 .adapter
-                .load_or_create_config(config_path.clone())
-                .await?;
+                .set_transport_plugins(transport_plugins);
+            process_watcher.adapter.assign_ports().await?;
             process_watcher
                 .start(
                     base_path,
                     config_path,
                     log_path,
-                    crate::binaries::Binaries::Tor,
+                    Binaries::Tor,
                     shutdown_signal,
                     task_tracker,
                 )
                 .await?;
         }
         self.wait_ready().await?;
+        let socks_port = self.watcher.read().await.adapter.socks_port();
+        connectivity::set_tor_socks_address(Some(SocketAddr::from(([127, 0, 0, 1], socks_port))));
+        Ok(())
+    }
+
+    /// Uses a running system Tor, health checked through its control port like the bundled one
+    async fn ensure_external_started(
+        &self,
+        external: &ExternalTorConfig,
+    ) -> Result<(), anyhow::Error> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Using system Tor with control port {}", external.control_address);
+        let status_monitor = self
+            .watcher
+            .read()
+            .await
+            .adapter
+            .external_status_monitor(external);
+        if status_monitor
+            .check_health(Duration::ZERO, HEALTH_TIMEOUT)
+            .await
+            == HealthStatus::Unhealthy
+        {
+            return Err(anyhow!(
+                "Could not connect to the system Tor control port at {}",
+                external.control_address
+            ));
+        }
+
+        let mut shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
+        let task_tracker = TasksTrackers::current().node_phase.get_task_tracker().await;
+        let monitor = status_monitor.clone();
+        let health_check = task_tracker.spawn(async move {
+            let started_at = Instant::now();
+            let mut interval = tokio::time::interval(HEALTH_POLL_TIME);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        // Resubscribes to status events if the daemon dropped the connection.
+                        // The daemon isn't ours to restart, so failures are only reported.
+                        let _status = monitor.check_health(started_at.elapsed(), HEALTH_TIMEOUT).await;
+                    }
+                    _ = shutdown_signal.wait() => break,
+                }
+            }
+        });
+        let previous = self.external.write().await.replace(ExternalTor {
+            status_monitor,
+            health_check,
+        });
+        if let Some(previous) = previous {
+            previous.health_check.abort();
+        }
+
+        self.wait_bootstrapped().await?;
+        connectivity::set_tor_socks_address(Some(external.socks_address));
         Ok(())
     }
 
@@ -114,7 +219,11 @@ impl TorManager {
             }
         }
 
-        // Ensure node is fully bootstrapped
+        self.wait_bootstrapped().await
+    }
+
+    /// Waits until Tor has bootstrapped and built a circuit
+    async fn wait_bootstrapped(&self) -> Result<(), anyhow::Error> {
         let mut shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
         let mut tor_status_watch_rx = self.status_watch_rx.clone();
 
@@ -147,25 +256,74 @@ impl TorManager {
         self.watcher.read().await.adapter.get_tor_config()
     }
 
-    pub async fn set_tor_config(&self, config: TorConfig) -> Result<TorConfig, anyhow::Error> {
-        self.watcher
-            .write()
-            .await
-            .adapter
-            .set_tor_config(config)
-            .await
+    /// Validates and saves the config, applying bridge changes to a running Tor through its
+    /// control port
+    pub async fn set_tor_config(
+        &self,
+        config: TorConfig,
+    ) -> Result<(TorConfig, TorConfigUpdate), anyhow::Error> {
+        config.validate()?;
+        let is_external_running = self.external.read().await.is_some();
+        let (current, control_port, is_running) = {
+            let process_watcher = self.watcher.read().await;
+            (
+                process_watcher.adapter.get_tor_config(),
+                process_watcher.adapter.control_port(),
+                process_watcher.is_running() || is_external_running,
+            )
+        };
+
+        let (update, live_transport_plugins) = if !is_running {
+            (TorConfigUpdate::Saved, None)
+        } else if config.control_port() != current.control_port()
+            || config.external() != current.external()
+        {
+            (TorConfigUpdate::RestartRequired, None)
+        } else if config.routing_differs(&current) {
+            let transport_plugins = resolve_transport_plugins(&config).await?;
+            apply_bridges_live(control_port, &current, &config, &transport_plugins).await?;
+            (TorConfigUpdate::AppliedLive, Some(transport_plugins))
+        } else {
+            (TorConfigUpdate::Saved, None)
+        };
+
+        let mut process_watcher = self.watcher.write().await;
+        let config = process_watcher.adapter.set_tor_config(config).await?;
+        if let Some(transport_plugins) = live_transport_plugins {
+            process_watcher
+                .adapter
+                .apply_live_transport_plugins(transport_plugins);
+        }
+        Ok((config, update))
+    }
+
+    pub fn get_status(&self) -> TorStatus {
+        *self.status_watch_rx.borrow()
     }
 
-    pub async fn get_control_port(&self) -> Result<Option<u16>, anyhow::Error> {
+    /// Control port of the system Tor in use, or else of the bundled Tor once it was started
+    pub async fn get_control_endpoint(&self) -> Option<TorControlEndpoint> {
+        if let Some(external) = self.external.read().await.as_ref() {
+            return Some(external.status_monitor.control_endpoint().clone());
+        }
         let process_watcher = self.watcher.read().await;
-        Ok(process_watcher
+        process_watcher
             .status_monitor
             .as_ref()
-            .map(|m| m.control_port))
+            .map(|m| m.control_endpoint().clone())
     }
 
     pub async fn get_entry_guards(&self) -> Result<Vec<String>, anyhow::Error> {
-        self.watcher.read().await.adapter.get_entry_guards().await
+        let endpoint = self
+            .get_control_endpoint()
+            .await
+            .ok_or_else(|| anyhow!("Tor is not running"))?;
+        let mut client = TorControlClient::connect_to(
+            endpoint.control_address,
+            endpoint.control_password.as_deref(),
+        )
+        .await?;
+        client.entry_guards().await
     }
 
     pub async fn on_app_exit(&self) {
@@ -186,8 +344,12 @@ impl TorManager {
         }
     }
 
-    #[allow(dead_code)]
     pub async fn stop(&self) -> Result<i32, anyhow::Error> {
+        connectivity::set_tor_socks_address(None);
+        // A system Tor keeps running, the app only stops checking on it
+        if let Some(external) = self.external.write().await.take() {
+            external.health_check.abort();
+        }
         let mut process_watcher = self.watcher.write().await;
         let exit_code = process_watcher.stop().await?;
         Ok(exit_code)
@@ -196,7 +358,7 @@ impl TorManager {
     #[allow(dead_code)]
     pub async fn is_running(&self) -> bool {
         let process_watcher = self.watcher.read().await;
-        process_watcher.is_running()
+        process_watcher.is_running() || self.external.read().await.is_some()
     }
 
     #[allow(dead_code)]
@@ -205,3 +367,51 @@ impl TorManager {
         lock.is_pid_file_exists(base_path)
     }
 }
+
+/// `ClientTransportPlugin` values for the transports of the config's bridges
+async fn resolve_transport_plugins(config: &TorConfig) -> Result<Vec<String>, anyhow::Error> {
+    let transports = config.transports();
+    let mut binary_paths = HashMap::new();
+    for transport in &transports {
+        let binary = transport.binary();
+        if !binary_paths.contains_key(&binary) {
+            let path = BinaryResolver::current().get_binary_path(binary).await?;
+            binary_paths.insert(binary, path);
+        }
+    }
+    client_transport_plugins(&transports, &binary_paths)
+}
+
+/// Applies bridge settings with `SETCONF` and rolls them back if Tor doesn't build a circuit with them
+async fn apply_bridges_live(
+    control_port: u16,
+    current: &TorConfig,
+    config: &TorConfig,
+    transport_plugins: &[String],
+) -> Result<(), anyhow::Error> {
+    let current_transport_plugins = resolve_transport_plugins(current).await?;
+    let mut client = TorControlClient::connect(control_port, None).await?;
+    client.set_events(&["CIRC"]).await?;
+
+    // Tor rejects a SETCONF with any invalid option as a whole, leaving its config untouched
+    client
+        .set_conf(&config.bridge_options(transport_plugins))
+        .await?;
+    client.signal("NEWNYM").await?;
+    info!(target: LOG_TARGET_APP_LOGIC, "Applied Tor bridge config, waiting for a new circuit");
+
+    let failure = match timeout(LIVE_RECONFIGURE_TIMEOUT, client.wait_for_new_circuit()).await {
+        Ok(Ok(())) => {
+            info!(target: LOG_TARGET_APP_LOGIC, "Tor built a circuit with the new bridge config");
+            return Ok(());
+        }
+        Ok(Err(e)) => e.to_string(),
+        Err(_) => format!("Tor did not build a circuit within {LIVE_RECONFIGURE_TIMEOUT:?}"),
+    };
+    warn!(target: LOG_TARGET_APP_LOGIC, "Rolling back Tor bridge config: {failure}");
+    client
+        .set_conf(&current.bridge_options(&current_transport_plugins))
+        .await?;
+    client.signal("NEWNYM").await?;
+    Err(anyhow!("Tor bridge config was rolled back: {failure}"))
+}
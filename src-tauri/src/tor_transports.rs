@@ -0,0 +1,173 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Pluggable transports Tor reaches bridges through.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use anyhow::{Error, anyhow};
+use serde::Serialize;
+
+use crate::binaries::Binaries;
+use crate::utils::file_utils::convert_to_string;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PluggableTransport {
+    Obfs4,
+    Webtunnel,
+    MeekLite,
+    Snowflake,
+}
+
+impl PluggableTransport {
+    pub fn all() -> [PluggableTransport; 4] {
+        [
+            PluggableTransport::Obfs4,
+            PluggableTransport::Webtunnel,
+            PluggableTransport::MeekLite,
+            PluggableTransport::Snowflake,
+        ]
+    }
+
+    /// Name used in bridge lines and `ClientTransportPlugin`
+    pub fn name(self) -> &'static str {
+        match self {
+            PluggableTransport::Obfs4 => "obfs4",
+            PluggableTransport::Webtunnel => "webtunnel",
+            PluggableTransport::MeekLite => "meek_lite",
+            PluggableTransport::Snowflake => "snowflake",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .into_iter()
+            .find(|transport| transport.name() == name)
+    }
+
+    /// Client binary Tor launches for the transport
+    pub fn binary(self) -> Binaries {
+        match self {
+            PluggableTransport::Obfs4
+            | PluggableTransport::Webtunnel
+            | PluggableTransport::MeekLite => Binaries::Lyrebird,
+            PluggableTransport::Snowflake => Binaries::Snowflake,
+        }
+    }
+
+    /// Bridge line arguments the transport can't connect without
+    pub fn required_arguments(self) -> &'static [&'static str] {
+        match self {
+            PluggableTransport::Obfs4 => &["cert", "iat-mode"],
+            PluggableTransport::Webtunnel
+            | PluggableTransport::MeekLite
+            | PluggableTransport::Snowflake => &["url"],
+        }
+    }
+}
+
+impl Display for PluggableTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TransportHealth {
+    /// No bridges with a pluggable transport are configured
+    #[default]
+    Unused,
+    /// Tor is launching the transport or hasn't connected through it yet
+    Launching,
+    /// Tor connected through the transport
+    Running,
+    /// The transport reported an error, or Tor couldn't connect through it
+    Failed,
+}
+
+impl Display for TransportHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TransportHealth::Unused => "unused",
+            TransportHealth::Launching => "launching",
+            TransportHealth::Running => "running",
+            TransportHealth::Failed => "failed",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Transport named by a bridge line, `None` for a plain `address:port` bridge
+pub(crate) fn bridge_transport_name(line: &str) -> Option<&str> {
+    let first = line.split_whitespace().next()?;
+    first.parse::<SocketAddr>().is_err().then_some(first)
+}
+
+/// Supported transports the bridge lines use, in order of first use
+pub(crate) fn bridge_transports(bridges: &[String]) -> Vec<PluggableTransport> {
+    let mut transports = Vec::new();
+    for transport in bridges
+        .iter()
+        .filter_map(|bridge| bridge_transport_name(bridge))
+        .filter_map(PluggableTransport::from_name)
+    {
+        if !transports.contains(&transport) {
+            transports.push(transport);
+        }
+    }
+    transports
+}
+
+/// `ClientTransportPlugin` values launching each plugin binary for the transports it serves
+pub(crate) fn client_transport_plugins(
+    transports: &[PluggableTransport],
+    binary_paths: &HashMap<Binaries, PathBuf>,
+) -> Result<Vec<String>, Error> {
+    let mut plugins: Vec<(Binaries, Vec<&str>)> = Vec::new();
+    for transport in transports {
+        match plugins
+            .iter_mut()
+            .find(|(binary, _)| *binary == transport.binary())
+        {
+            Some((_, names)) => names.push(transport.name()),
+            None => plugins.push((transport.binary(), vec![transport.name()])),
+        }
+    }
+    plugins
+        .into_iter()
+        .map(|(binary, names)| {
+            let path = binary_paths
+                .get(&binary)
+                .ok_or_else(|| anyhow!("{} transport plugin is not resolved", binary.name()))?;
+            Ok(format!(
+                "{} exec {}",
+                names.join(","),
+                convert_to_string(path.clone())?
+            ))
+        })
+        .collect()
+}
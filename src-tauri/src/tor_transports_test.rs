@@ -0,0 +1,117 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Unit tests for tor_transports
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::binaries::Binaries;
+use crate::tor_transports::{
+    PluggableTransport, bridge_transport_name, bridge_transports, client_transport_plugins,
+};
+
+// ==================== Transports ====================
+
+#[test]
+fn transports_round_trip_through_their_names() {
+    for transport in PluggableTransport::all() {
+        assert_eq!(
+            PluggableTransport::from_name(transport.name()),
+            Some(transport)
+        );
+    }
+    assert_eq!(PluggableTransport::from_name("obfs3"), None);
+}
+
+#[test]
+fn snowflake_has_its_own_plugin_binary() {
+    assert_eq!(PluggableTransport::Obfs4.binary(), Binaries::Lyrebird);
+    assert_eq!(PluggableTransport::Webtunnel.binary(), Binaries::Lyrebird);
+    assert_eq!(PluggableTransport::MeekLite.binary(), Binaries::Lyrebird);
+    assert_eq!(PluggableTransport::Snowflake.binary(), Binaries::Snowflake);
+}
+
+// ==================== Bridge lines ====================
+
+#[test]
+fn reads_transport_from_bridge_lines() {
+    assert_eq!(
+        bridge_transport_name("obfs4 192.0.2.10:443 cert=x iat-mode=0"),
+        Some("obfs4")
+    );
+    assert_eq!(bridge_transport_name("192.0.2.11:9001"), None);
+    assert_eq!(bridge_transport_name("[2001:db8::1]:9001"), None);
+    assert_eq!(bridge_transport_name(""), None);
+}
+
+#[test]
+fn skips_plain_and_unknown_transports() {
+    let bridges = [
+        "webtunnel 192.0.2.4:443 url=https://example.com".to_string(),
+        "192.0.2.11:9001".to_string(),
+        "obfs3 192.0.2.12:443".to_string(),
+        "webtunnel 192.0.2.5:443 url=https://example.org".to_string(),
+    ];
+
+    assert_eq!(bridge_transports(&bridges), [PluggableTransport::Webtunnel]);
+}
+
+// ==================== Plugin lines ====================
+
+#[test]
+fn groups_transports_by_plugin_binary() {
+    let binary_paths = HashMap::from([
+        (Binaries::Lyrebird, PathBuf::from("/tor/lyrebird")),
+        (Binaries::Snowflake, PathBuf::from("/tor/snowflake-client")),
+    ]);
+
+    let plugins = client_transport_plugins(
+        &[
+            PluggableTransport::Obfs4,
+            PluggableTransport::Snowflake,
+            PluggableTransport::Webtunnel,
+        ],
+        &binary_paths,
+    )
+    .unwrap();
+
+    assert_eq!(
+        plugins,
+        [
+            "obfs4,webtunnel exec /tor/lyrebird",
+            "snowflake exec /tor/snowflake-client",
+        ]
+    );
+}
+
+#[test]
+fn unresolved_plugin_binary_is_an_error() {
+    let binary_paths = HashMap::from([(Binaries::Lyrebird, PathBuf::from("/tor/lyrebird"))]);
+
+    assert!(client_transport_plugins(&[PluggableTransport::Snowflake], &binary_paths).is_err());
+    assert!(
+        client_transport_plugins(&[], &binary_paths)
+            .unwrap()
+            .is_empty()
+    );
+}
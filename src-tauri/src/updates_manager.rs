@@ -38,6 +38,7 @@ use crate::{
     LOG_TARGET_APP_LOGIC,
     app_in_memory_config::{DEFAULT_EXCHANGE_ID, EXCHANGE_ID},
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    requests::connectivity,
     tasks_tracker::TasksTrackers,
     utils::{app_flow_utils::FrontendReadyChannel, system_status::SystemStatus},
 };
@@ -219,6 +220,16 @@ impl UpdatesManager {
                     update.version > current
                 }
             });
+        let proxy_url = connectivity::active_proxy()
+            .and_then(|settings| settings.map(|settings| settings.url()).transpose());
+        let builder = match proxy_url {
+            Ok(Some(proxy_url)) => builder.proxy(proxy_url),
+            Ok(None) => builder,
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to set updater proxy: {e}");
+                return Ok(None);
+            }
+        };
         let builder = match builder.endpoints(vec![updates_url]) {
             Ok(b) => b,
             Err(e) => {
@@ -28,6 +28,7 @@ use tokio::task::spawn_blocking;
 
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::events_emitter::EventsEmitter;
+use crate::requests::connectivity;
 use crate::utils::speed_test_utils::{test_download, test_latency, test_upload};
 
 const SPEED_TEST_TIMEOUT: Duration = Duration::from_secs(60);
@@ -149,7 +150,11 @@ impl NetworkStatus {
     }
 
     async fn ping_google_server() -> Result<(), anyhow::Error> {
-        match reqwest::get("https://www.google.com").await {
+        match connectivity::client()?
+            .get("https://www.google.com")
+            .send()
+            .await
+        {
             Ok(response) => {
                 if response.status().is_success() {
                     info!(target: LOG_TARGET_APP_LOGIC, "Successfully pinged Google server");
@@ -50,6 +50,24 @@ impl Display for CurrentOperatingSystem {
     }
 }
 
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+compile_error!("Only x86_64 and aarch64 targets are supported");
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CurrentArchitecture {
+    X64,
+    Arm64,
+}
+
+impl Display for CurrentArchitecture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurrentArchitecture::X64 => write!(f, "x86_64"),
+            CurrentArchitecture::Arm64 => write!(f, "aarch64"),
+        }
+    }
+}
+
 pub struct PlatformUtils {}
 impl PlatformUtils {
     pub fn detect_current_os() -> CurrentOperatingSystem {
@@ -64,6 +82,14 @@ impl PlatformUtils {
         }
     }
 
+    pub fn detect_current_architecture() -> CurrentArchitecture {
+        if cfg!(target_arch = "aarch64") {
+            CurrentArchitecture::Arm64
+        } else {
+            CurrentArchitecture::X64
+        }
+    }
+
     #[allow(unused_variables)]
     pub async fn initialize_preqesities() -> Result<(), anyhow::Error> {
         let current_os = PlatformUtils::detect_current_os();
@@ -22,15 +22,16 @@
 
 use anyhow::anyhow;
 use regex::Regex;
-use reqwest::blocking::Client;
 use std::time::Instant;
 
+use crate::requests::connectivity;
+
 const BASE_URL: &str = "https://speed.cloudflare.com";
 const DOWNLOAD_URL: &str = "__down?bytes=";
 const UPLOAD_URL: &str = "__up";
 
 pub fn test_latency() -> Result<f64, anyhow::Error> {
-    let client = Client::new();
+    let client = connectivity::blocking_client()?;
     let url = &format!("{}/{}{}", BASE_URL, DOWNLOAD_URL, 0);
     let builder = client.get(url);
 
@@ -62,7 +63,7 @@ pub fn test_latency() -> Result<f64, anyhow::Error> {
 }
 
 pub fn test_upload(payload_size_bytes: usize) -> Result<f64, anyhow::Error> {
-    let client = Client::new();
+    let client = connectivity::blocking_client()?;
     let url = &format!("{BASE_URL}/{UPLOAD_URL}");
     let payload: Vec<u8> = vec![1; payload_size_bytes];
     let builder = client.post(url).body(payload);
@@ -83,7 +84,7 @@ pub fn test_upload(payload_size_bytes: usize) -> Result<f64, anyhow::Error> {
 }
 
 pub fn test_download(payload_size_bytes: usize) -> Result<f64, anyhow::Error> {
-    let client = Client::new();
+    let client = connectivity::blocking_client()?;
     let url = &format!("{BASE_URL}/{DOWNLOAD_URL}{payload_size_bytes}");
     let builder = client.get(url);
     let mbits = {
@@ -20,12 +20,18 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod payment_requests;
 pub mod spend_wallet;
+pub mod transaction_export;
 pub mod transaction_service;
 pub mod wallet_adapter;
 pub mod wallet_manager;
 pub mod wallet_status_monitor;
 pub mod wallet_types;
 
+#[cfg(test)]
+mod payment_requests_test;
+#[cfg(test)]
+mod transaction_export_test;
 #[cfg(test)]
 mod wallet_manager_test;
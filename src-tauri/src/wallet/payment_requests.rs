@@ -0,0 +1,451 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Point-of-sale style payment requests tracked as invoices.
+
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use dirs::config_dir;
+use log::{info, warn};
+use qrcode::QrCode;
+use qrcode::render::svg;
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tari_common_types::tari_address::TariAddress;
+use tari_common_types::tari_address::dual_address::DualAddress;
+use tari_transaction_components::tari_amount::MicroMinotari;
+use tokio::sync::RwLock;
+
+use crate::wallet::wallet_manager::{WalletManager, WalletManagerError};
+use crate::wallet::wallet_types::TransactionInfo;
+use crate::{APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC};
+
+pub const DEFAULT_REQUIRED_CONFIRMATIONS: u64 = 3;
+const URI_SCHEME: &str = "tari:";
+const DIRECTION_INBOUND: i32 = 1;
+const QR_MIN_DIMENSION: u32 = 256;
+
+static INSTANCE: LazyLock<RwLock<InvoiceBook>> = LazyLock::new(|| RwLock::new(InvoiceBook::new()));
+
+#[derive(Debug, thiserror::Error)]
+pub enum PaymentRequestError {
+    #[error("Payment request URI must start with {URI_SCHEME}")]
+    InvalidScheme,
+    #[error("Payment request URI has no address")]
+    MissingAddress,
+    #[error("Invalid amount in payment request: {0}")]
+    InvalidAmount(String),
+    #[error("Invoice {0} not found")]
+    InvoiceNotFound(String),
+    #[error("Invoice {0} is not open")]
+    InvoiceNotOpen(String),
+    #[error("An invoice already uses payment ID {0}")]
+    DuplicatePaymentId(String),
+    #[error("Failed to render QR code: {0}")]
+    QrCode(String),
+    #[error("Failed to read wallet transactions: {0}")]
+    Wallet(#[from] WalletManagerError),
+    #[error("Failed to save invoices: {0}")]
+    Storage(#[from] anyhow::Error),
+}
+
+/// Contents of a `tari:<address>?amount=<µT>&payment_id=<id>&message=<text>` URI
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentRequestUri {
+    pub address: String,
+    pub amount: Option<MicroMinotari>,
+    pub payment_id: Option<String>,
+    pub message: Option<String>,
+}
+
+impl PaymentRequestUri {
+    pub fn to_uri(&self) -> String {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(amount) = self.amount {
+            query.append_pair("amount", &amount.as_u64().to_string());
+        }
+        if let Some(payment_id) = &self.payment_id {
+            query.append_pair("payment_id", payment_id);
+        }
+        if let Some(message) = &self.message {
+            query.append_pair("message", message);
+        }
+        let query = query.finish();
+
+        if query.is_empty() {
+            format!("{URI_SCHEME}{}", self.address)
+        } else {
+            format!("{URI_SCHEME}{}?{query}", self.address)
+        }
+    }
+
+    pub fn parse(uri: &str) -> Result<Self, PaymentRequestError> {
+        let uri = uri.trim();
+        let rest = uri
+            .get(..URI_SCHEME.len())
+            .filter(|scheme| scheme.eq_ignore_ascii_case(URI_SCHEME))
+            .map(|_| &uri[URI_SCHEME.len()..])
+            .ok_or(PaymentRequestError::InvalidScheme)?;
+        let rest = rest.trim_start_matches("//");
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        if address.is_empty() {
+            return Err(PaymentRequestError::MissingAddress);
+        }
+
+        let mut request = Self {
+            address: address.to_string(),
+            amount: None,
+            payment_id: None,
+            message: None,
+        };
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "amount" => {
+                    let amount = value
+                        .parse::<u64>()
+                        .map_err(|_| PaymentRequestError::InvalidAmount(value.to_string()))?;
+                    request.amount = Some(MicroMinotari(amount));
+                }
+                "payment_id" => request.payment_id = Some(value.into_owned()),
+                "message" => request.message = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+        Ok(request)
+    }
+}
+
+/// Renders the data as an SVG QR code
+pub fn render_qr_svg(data: &str) -> Result<String, PaymentRequestError> {
+    let code =
+        QrCode::new(data.as_bytes()).map_err(|e| PaymentRequestError::QrCode(e.to_string()))?;
+    Ok(code
+        .render::<svg::Color<'_>>()
+        .min_dimensions(QR_MIN_DIMENSION, QR_MIN_DIMENSION)
+        .build())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    Open,
+    Paid,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: String,
+    pub payment_id: String,
+    pub amount: MicroMinotari,
+    pub description: Option<String>,
+    pub uri: String,
+    pub required_confirmations: u64,
+    /// Unix timestamp (seconds) of when the invoice was created
+    pub created_at: u64,
+    pub status: InvoiceStatus,
+    /// Incoming transaction matched to this invoice, set as soon as it is seen
+    pub paid_tx_id: Option<String>,
+    pub confirmations: u64,
+    pub paid_at_height: Option<u64>,
+}
+
+/// Number of blocks including the one the transaction was mined in, zero while unmined
+pub fn transaction_confirmations(tx: &TransactionInfo, tip_height: u64) -> u64 {
+    if tx.is_cancelled || tx.mined_in_block_height == 0 || tx.mined_in_block_height > tip_height {
+        return 0;
+    }
+    tip_height - tx.mined_in_block_height + 1
+}
+
+/// Payment IDs are never reused, even after an invoice is paid or cancelled, so a late or
+/// repeated payment for an old invoice can't settle a new one
+pub fn ensure_payment_id_unused(
+    invoices: &[Invoice],
+    payment_id: &str,
+) -> Result<(), PaymentRequestError> {
+    if invoices.iter().any(|i| i.payment_id == payment_id) {
+        return Err(PaymentRequestError::DuplicatePaymentId(
+            payment_id.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Finds an incoming transaction paying this invoice, preferring the deepest one. Transactions
+/// from before the invoice was created or already matched to another invoice are skipped.
+pub fn find_invoice_payment<'a>(
+    invoice: &Invoice,
+    transactions: &'a [TransactionInfo],
+    claimed_tx_ids: &HashSet<String>,
+    tip_height: u64,
+) -> Option<&'a TransactionInfo> {
+    transactions
+        .iter()
+        .filter(|tx| {
+            tx.direction == DIRECTION_INBOUND
+                && !tx.is_cancelled
+                && tx.timestamp >= invoice.created_at
+                && tx.payment_id.trim() == invoice.payment_id
+                && tx.amount == invoice.amount
+                && (invoice.paid_tx_id.as_deref() == Some(tx.tx_id.as_str())
+                    || !claimed_tx_ids.contains(&tx.tx_id))
+        })
+        .max_by_key(|tx| transaction_confirmations(tx, tip_height))
+}
+
+/// Applies matching transactions to open invoices. Returns the number of invoices that changed
+/// and the ones that became paid.
+pub fn update_invoices(
+    invoices: &mut [Invoice],
+    transactions: &[TransactionInfo],
+    tip_height: u64,
+) -> (usize, Vec<Invoice>) {
+    let mut changed = 0;
+    let mut paid = Vec::new();
+    let mut claimed_tx_ids: HashSet<String> = invoices
+        .iter()
+        .filter_map(|invoice| invoice.paid_tx_id.clone())
+        .collect();
+    // Invoices are kept in creation order, so the oldest open invoice claims a payment first
+    for invoice in invoices
+        .iter_mut()
+        .filter(|invoice| invoice.status == InvoiceStatus::Open)
+    {
+        let Some(tx) = find_invoice_payment(invoice, transactions, &claimed_tx_ids, tip_height)
+        else {
+            continue;
+        };
+        let confirmations = transaction_confirmations(tx, tip_height);
+        if invoice.paid_tx_id.as_deref() == Some(tx.tx_id.as_str())
+            && invoice.confirmations == confirmations
+        {
+            continue;
+        }
+
+        if let Some(previous) = invoice.paid_tx_id.replace(tx.tx_id.clone()) {
+            claimed_tx_ids.remove(&previous);
+        }
+        claimed_tx_ids.insert(tx.tx_id.clone());
+        invoice.confirmations = confirmations;
+        if confirmations >= invoice.required_confirmations {
+            invoice.status = InvoiceStatus::Paid;
+            invoice.paid_at_height = Some(tip_height);
+            paid.push(invoice.clone());
+        }
+        changed += 1;
+    }
+    (changed, paid)
+}
+
+/// Embeds the payment ID in the address memo field so wallets that ignore URI parameters still
+/// attach it. Single addresses can't carry one and are used as is.
+fn address_with_payment_id(address: &TariAddress, payment_id: &str) -> String {
+    let base58 = address.to_base58();
+    match DualAddress::from_base58(&base58) {
+        Ok(mut dual) => match dual.add_memo_field_payment_id(payment_id.as_bytes().to_vec()) {
+            Ok(()) => dual.to_base58(),
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to add payment ID to address: {e}");
+                base58
+            }
+        },
+        Err(_) => base58,
+    }
+}
+
+fn now_unix() -> u64 {
+    u64::try_from(chrono::Utc::now().timestamp()).unwrap_or(0)
+}
+
+pub struct InvoiceBook {
+    invoices: Vec<Invoice>,
+    invoices_path: PathBuf,
+}
+
+impl InvoiceBook {
+    fn new() -> Self {
+        let invoices_path = Self::_get_invoices_path();
+        let invoices = Self::_load(&invoices_path);
+        Self {
+            invoices,
+            invoices_path,
+        }
+    }
+
+    pub fn current() -> &'static RwLock<Self> {
+        &INSTANCE
+    }
+
+    fn _get_invoices_path() -> PathBuf {
+        let config_dir = config_dir().unwrap_or_else(std::env::temp_dir);
+        config_dir
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str())
+            .join("invoices.json")
+    }
+
+    fn _load(path: &PathBuf) -> Vec<Invoice> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to parse invoices: {e:?}");
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    async fn _save(&self) -> Result<(), anyhow::Error> {
+        if let Some(parent) = self.invoices_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let contents = serde_json::to_string_pretty(&self.invoices)?;
+        tokio::fs::write(&self.invoices_path, contents).await?;
+        Ok(())
+    }
+
+    /// Creates an open invoice payable to `address`. A payment ID is generated when none is given.
+    pub async fn create_invoice(
+        address: &TariAddress,
+        amount: MicroMinotari,
+        payment_id: Option<String>,
+        description: Option<String>,
+        required_confirmations: Option<u64>,
+    ) -> Result<Invoice, PaymentRequestError> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let payment_id = payment_id
+            .map(|payment_id| payment_id.trim().to_string())
+            .filter(|payment_id| !payment_id.is_empty())
+            .unwrap_or_else(|| format!("inv-{}", &id[..8]));
+
+        let mut book = Self::current().write().await;
+        ensure_payment_id_unused(&book.invoices, &payment_id)?;
+
+        let uri = PaymentRequestUri {
+            address: address_with_payment_id(address, &payment_id),
+            amount: Some(amount),
+            payment_id: Some(payment_id.clone()),
+            message: description.clone(),
+        }
+        .to_uri();
+        let invoice = Invoice {
+            id,
+            payment_id,
+            amount,
+            description,
+            uri,
+            required_confirmations: required_confirmations
+                .unwrap_or(DEFAULT_REQUIRED_CONFIRMATIONS)
+                .max(1),
+            created_at: now_unix(),
+            status: InvoiceStatus::Open,
+            paid_tx_id: None,
+            confirmations: 0,
+            paid_at_height: None,
+        };
+        info!(target: LOG_TARGET_APP_LOGIC, "Created invoice {} for {} with payment ID {}", invoice.id, invoice.amount, invoice.payment_id);
+
+        book.invoices.push(invoice.clone());
+        book._save().await?;
+        Ok(invoice)
+    }
+
+    pub async fn cancel_invoice(id: &str) -> Result<Invoice, PaymentRequestError> {
+        let mut book = Self::current().write().await;
+        let invoice = book
+            .invoices
+            .iter_mut()
+            .find(|i| i.id == id)
+            .ok_or_else(|| PaymentRequestError::InvoiceNotFound(id.to_string()))?;
+        if invoice.status != InvoiceStatus::Open {
+            return Err(PaymentRequestError::InvoiceNotOpen(id.to_string()));
+        }
+        invoice.status = InvoiceStatus::Cancelled;
+        let invoice = invoice.clone();
+        book._save().await?;
+        Ok(invoice)
+    }
+
+    /// Invoices newest first, optionally restricted to one status
+    pub async fn get_invoices(status: Option<InvoiceStatus>) -> Vec<Invoice> {
+        let book = Self::current().read().await;
+        book.invoices
+            .iter()
+            .rev()
+            .filter(|i| status.is_none_or(|s| i.status == s))
+            .cloned()
+            .collect()
+    }
+
+    pub async fn has_open_invoices() -> bool {
+        Self::current()
+            .read()
+            .await
+            .invoices
+            .iter()
+            .any(|i| i.status == InvoiceStatus::Open)
+    }
+
+    /// Matches incoming transactions against open invoices and returns the ones that became paid
+    pub async fn reconcile(
+        wallet_manager: &WalletManager,
+        tip_height: u64,
+    ) -> Result<Vec<Invoice>, PaymentRequestError> {
+        let open_payment_ids: HashSet<String> = Self::current()
+            .read()
+            .await
+            .invoices
+            .iter()
+            .filter(|i| i.status == InvoiceStatus::Open)
+            .map(|i| i.payment_id.clone())
+            .collect();
+        if open_payment_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut candidates = Vec::new();
+        wallet_manager
+            .stream_transactions(|tx| {
+                if tx.direction == DIRECTION_INBOUND
+                    && open_payment_ids.contains(tx.payment_id.trim())
+                {
+                    candidates.push(tx);
+                }
+                Ok(ControlFlow::Continue(()))
+            })
+            .await?;
+
+        let mut book = Self::current().write().await;
+        let (changed, paid) = update_invoices(&mut book.invoices, &candidates, tip_height);
+        if changed > 0 {
+            book._save().await?;
+        }
+        for invoice in &paid {
+            info!(target: LOG_TARGET_APP_LOGIC, "Invoice {} paid by transaction {:?}", invoice.id, invoice.paid_tx_id);
+        }
+        Ok(paid)
+    }
+}
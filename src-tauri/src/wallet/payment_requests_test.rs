@@ -0,0 +1,257 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Tests for payment request URIs and invoice matching
+
+use std::collections::HashSet;
+
+use tari_transaction_components::tari_amount::MicroMinotari;
+
+use super::payment_requests::{
+    Invoice, InvoiceStatus, PaymentRequestError, PaymentRequestUri, ensure_payment_id_unused,
+    find_invoice_payment, transaction_confirmations, update_invoices,
+};
+use super::wallet_types::{TransactionInfo, TransactionStatus};
+
+fn create_transaction(tx_id: &str, amount: u64, payment_id: &str, height: u64) -> TransactionInfo {
+    TransactionInfo {
+        tx_id: tx_id.to_string(),
+        source_address: "customer".to_string(),
+        dest_address: "merchant".to_string(),
+        status: TransactionStatus::OneSidedConfirmed,
+        amount: MicroMinotari(amount),
+        is_cancelled: false,
+        direction: 1,
+        excess_sig: vec![],
+        fee: 0,
+        timestamp: 1_700_000_000,
+        payment_id: payment_id.to_string(),
+        mined_in_block_height: height,
+        payment_reference: None,
+    }
+}
+
+fn create_invoice(payment_id: &str, amount: u64) -> Invoice {
+    Invoice {
+        id: format!("id-{payment_id}"),
+        payment_id: payment_id.to_string(),
+        amount: MicroMinotari(amount),
+        description: None,
+        uri: String::new(),
+        required_confirmations: 3,
+        created_at: 1_700_000_000,
+        status: InvoiceStatus::Open,
+        paid_tx_id: None,
+        confirmations: 0,
+        paid_at_height: None,
+    }
+}
+
+// ==================== URI ====================
+
+#[test]
+fn uri_round_trips_with_encoded_fields() {
+    let request = PaymentRequestUri {
+        address: "12abcXYZ".to_string(),
+        amount: Some(MicroMinotari(1_500_000)),
+        payment_id: Some("order #42".to_string()),
+        message: Some("Coffee & cake".to_string()),
+    };
+
+    let uri = request.to_uri();
+
+    assert!(uri.starts_with("tari:12abcXYZ?amount=1500000&"));
+    assert!(!uri.contains(' '));
+    assert_eq!(PaymentRequestUri::parse(&uri).unwrap(), request);
+}
+
+#[test]
+fn uri_without_parameters_is_just_the_address() {
+    let request = PaymentRequestUri {
+        address: "12abcXYZ".to_string(),
+        amount: None,
+        payment_id: None,
+        message: None,
+    };
+
+    assert_eq!(request.to_uri(), "tari:12abcXYZ");
+    assert_eq!(
+        PaymentRequestUri::parse("TARI://12abcXYZ").unwrap(),
+        request
+    );
+}
+
+#[test]
+fn parse_rejects_invalid_uris() {
+    assert!(matches!(
+        PaymentRequestUri::parse("bitcoin:abc"),
+        Err(PaymentRequestError::InvalidScheme)
+    ));
+    assert!(matches!(
+        PaymentRequestUri::parse("tari:?amount=1"),
+        Err(PaymentRequestError::MissingAddress)
+    ));
+    assert!(matches!(
+        PaymentRequestUri::parse("tari:abc?amount=1.5"),
+        Err(PaymentRequestError::InvalidAmount(_))
+    ));
+}
+
+// ==================== Matching ====================
+
+#[test]
+fn confirmations_count_the_mined_block() {
+    let tx = create_transaction("tx1", 100, "inv-1", 100);
+    let mut unmined = tx.clone();
+    unmined.mined_in_block_height = 0;
+
+    assert_eq!(transaction_confirmations(&tx, 100), 1);
+    assert_eq!(transaction_confirmations(&tx, 102), 3);
+    assert_eq!(transaction_confirmations(&unmined, 102), 0);
+}
+
+#[test]
+fn payment_must_match_payment_id_amount_and_direction() {
+    let invoice = create_invoice("inv-1", 100);
+    let mut outbound = create_transaction("out", 100, "inv-1", 10);
+    outbound.direction = 2;
+    let transactions = vec![
+        create_transaction("wrong_amount", 99, "inv-1", 10),
+        create_transaction("wrong_id", 100, "inv-2", 10),
+        outbound,
+        create_transaction("match", 100, " inv-1 ", 10),
+    ];
+
+    let payment = find_invoice_payment(&invoice, &transactions, &HashSet::new(), 20);
+
+    assert_eq!(payment.map(|tx| tx.tx_id.as_str()), Some("match"));
+}
+
+#[test]
+fn payment_from_before_the_invoice_is_ignored() {
+    let invoice = create_invoice("inv-1", 100);
+    let mut earlier = create_transaction("earlier", 100, "inv-1", 10);
+    earlier.timestamp = invoice.created_at - 1;
+
+    assert!(find_invoice_payment(&invoice, &[earlier], &HashSet::new(), 20).is_none());
+}
+
+#[test]
+fn payment_claimed_by_another_invoice_is_ignored() {
+    let invoice = create_invoice("inv-1", 100);
+    let transactions = vec![create_transaction("tx1", 100, "inv-1", 10)];
+    let claimed = HashSet::from(["tx1".to_string()]);
+
+    assert!(find_invoice_payment(&invoice, &transactions, &claimed, 20).is_none());
+
+    let mut own = invoice.clone();
+    own.paid_tx_id = Some("tx1".to_string());
+    assert!(find_invoice_payment(&own, &transactions, &claimed, 20).is_some());
+}
+
+#[test]
+fn payment_ids_are_never_reused() {
+    let mut paid = create_invoice("inv-1", 100);
+    paid.status = InvoiceStatus::Paid;
+    let mut cancelled = create_invoice("inv-2", 100);
+    cancelled.status = InvoiceStatus::Cancelled;
+    let invoices = vec![paid, cancelled, create_invoice("inv-3", 100)];
+
+    for payment_id in ["inv-1", "inv-2", "inv-3"] {
+        assert!(matches!(
+            ensure_payment_id_unused(&invoices, payment_id),
+            Err(PaymentRequestError::DuplicatePaymentId(_))
+        ));
+    }
+    assert!(ensure_payment_id_unused(&invoices, "inv-4").is_ok());
+}
+
+// ==================== Invoice updates ====================
+
+#[test]
+fn invoice_is_paid_after_required_confirmations() {
+    let mut invoices = vec![create_invoice("inv-1", 100)];
+    let transactions = vec![create_transaction("tx1", 100, "inv-1", 100)];
+
+    let (changed, paid) = update_invoices(&mut invoices, &transactions, 101);
+    assert_eq!(changed, 1);
+    assert!(paid.is_empty());
+    assert_eq!(invoices[0].status, InvoiceStatus::Open);
+    assert_eq!(invoices[0].paid_tx_id.as_deref(), Some("tx1"));
+    assert_eq!(invoices[0].confirmations, 2);
+
+    let (_, paid) = update_invoices(&mut invoices, &transactions, 102);
+    assert_eq!(paid.len(), 1);
+    assert_eq!(invoices[0].status, InvoiceStatus::Paid);
+    assert_eq!(invoices[0].paid_at_height, Some(102));
+}
+
+#[test]
+fn one_transaction_settles_only_one_invoice() {
+    let mut older = create_invoice("inv-1", 100);
+    older.id = "older".to_string();
+    let mut newer = create_invoice("inv-1", 100);
+    newer.id = "newer".to_string();
+    let mut invoices = vec![older, newer];
+    let transactions = vec![create_transaction("tx1", 100, "inv-1", 100)];
+
+    let (_, paid) = update_invoices(&mut invoices, &transactions, 110);
+
+    assert_eq!(paid.len(), 1);
+    assert_eq!(paid[0].id, "older");
+    assert_eq!(invoices[1].status, InvoiceStatus::Open);
+    assert_eq!(invoices[1].paid_tx_id, None);
+}
+
+#[test]
+fn paid_invoice_keeps_its_transaction_from_new_invoices() {
+    let mut settled = create_invoice("inv-1", 100);
+    settled.status = InvoiceStatus::Paid;
+    settled.paid_tx_id = Some("tx1".to_string());
+    let mut invoices = vec![settled, create_invoice("inv-1", 100)];
+    let transactions = vec![create_transaction("tx1", 100, "inv-1", 100)];
+
+    let (changed, paid) = update_invoices(&mut invoices, &transactions, 110);
+
+    assert_eq!(changed, 0);
+    assert!(paid.is_empty());
+    assert_eq!(invoices[1].paid_tx_id, None);
+}
+
+#[test]
+fn unchanged_and_closed_invoices_are_skipped() {
+    let mut cancelled = create_invoice("inv-2", 100);
+    cancelled.status = InvoiceStatus::Cancelled;
+    let mut invoices = vec![create_invoice("inv-1", 100), cancelled];
+    let transactions = vec![
+        create_transaction("tx1", 100, "inv-1", 100),
+        create_transaction("tx2", 100, "inv-2", 100),
+    ];
+
+    update_invoices(&mut invoices, &transactions, 100);
+    let (changed, paid) = update_invoices(&mut invoices, &transactions, 100);
+
+    assert_eq!(changed, 0);
+    assert!(paid.is_empty());
+    assert_eq!(invoices[1].status, InvoiceStatus::Cancelled);
+    assert_eq!(invoices[1].paid_tx_id, None);
+}
@@ -310,7 +310,7 @@ impl ProcessAdapter for SpendWallet {
         )?;
 
         let shared_args = self
-            .get_shared_args(data_dir.clone(), log_dir)
+            .get_shared_args(data_dir.clone(), log_dir.clone())
             .context("Failed to build shared arguments")?;
         let envs = HashMap::from([(
             "MINOTARI_WALLET_PASSWORD".to_string(),
@@ -326,6 +326,7 @@ impl ProcessAdapter for SpendWallet {
                 args: shared_args,
                 pid_file_name: self.pid_file_name().to_string(),
                 data_dir,
+                log_dir,
                 name: self.name().to_string(),
             },
         };
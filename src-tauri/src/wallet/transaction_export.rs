@@ -0,0 +1,281 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Bookkeeping export of the wallet's transaction history.
+
+use std::io::{self, Write};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus};
+
+const DIRECTION_INBOUND: i32 = 1;
+const DIRECTION_OUTBOUND: i32 = 2;
+/// Words pools put in payout memos, matched against whole words of the payment ID
+const POOL_PAYOUT_MARKERS: [&str; 5] = ["pool", "payout", "kryptex", "luckypool", "supportxtm"];
+/// Leading characters spreadsheets treat as the start of a formula
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+const CSV_HEADER: [&str; 14] = [
+    "timestamp",
+    "date",
+    "tx_id",
+    "direction",
+    "category",
+    "amount",
+    "fee",
+    "status",
+    "is_cancelled",
+    "block_height",
+    "payment_id",
+    "payment_reference",
+    "source_address",
+    "dest_address",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionCategory {
+    Coinbase,
+    PoolPayout,
+    Transfer,
+}
+
+/// Inclusive unix timestamp bounds (seconds) applied to exported transactions
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+pub struct ExportFilter {
+    pub from_timestamp: Option<u64>,
+    pub to_timestamp: Option<u64>,
+}
+
+impl ExportFilter {
+    pub fn matches(&self, tx: &TransactionInfo) -> bool {
+        self.from_timestamp.is_none_or(|from| tx.timestamp >= from)
+            && self.to_timestamp.is_none_or(|to| tx.timestamp <= to)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportSummary {
+    pub path: String,
+    pub format: ExportFormat,
+    pub rows_written: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransactionExportRow<'a> {
+    pub timestamp: u64,
+    pub date: String,
+    pub tx_id: &'a str,
+    pub direction: &'static str,
+    pub category: TransactionCategory,
+    pub amount: u64,
+    pub fee: u64,
+    pub status: String,
+    pub is_cancelled: bool,
+    pub block_height: u64,
+    pub payment_id: &'a str,
+    pub payment_reference: Option<&'a str>,
+    pub source_address: &'a str,
+    pub dest_address: &'a str,
+}
+
+impl<'a> TransactionExportRow<'a> {
+    pub fn from_transaction(tx: &'a TransactionInfo) -> Self {
+        Self {
+            timestamp: tx.timestamp,
+            date: format_timestamp(tx.timestamp),
+            tx_id: &tx.tx_id,
+            direction: direction_name(tx.direction),
+            category: classify_transaction(tx),
+            amount: tx.amount.as_u64(),
+            fee: tx.fee,
+            status: format!("{:?}", tx.status),
+            is_cancelled: tx.is_cancelled,
+            block_height: tx.mined_in_block_height,
+            payment_id: &tx.payment_id,
+            payment_reference: tx.payment_reference.as_deref(),
+            source_address: &tx.source_address,
+            dest_address: &tx.dest_address,
+        }
+    }
+
+    fn to_csv_record(&self) -> String {
+        [
+            self.timestamp.to_string(),
+            self.date.clone(),
+            self.tx_id.to_string(),
+            self.direction.to_string(),
+            category_name(self.category).to_string(),
+            self.amount.to_string(),
+            self.fee.to_string(),
+            self.status.clone(),
+            self.is_cancelled.to_string(),
+            self.block_height.to_string(),
+            neutralize_formula(self.payment_id),
+            neutralize_formula(self.payment_reference.unwrap_or_default()),
+            neutralize_formula(self.source_address),
+            neutralize_formula(self.dest_address),
+        ]
+        .iter()
+        .map(|field| escape_csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+pub fn classify_transaction(tx: &TransactionInfo) -> TransactionCategory {
+    if matches!(
+        tx.status,
+        TransactionStatus::Coinbase
+            | TransactionStatus::CoinbaseConfirmed
+            | TransactionStatus::CoinbaseUnconfirmed
+            | TransactionStatus::CoinbaseNotInBlockChain
+    ) {
+        return TransactionCategory::Coinbase;
+    }
+
+    let payment_id = tx.payment_id.to_lowercase();
+    if tx.direction == DIRECTION_INBOUND
+        && payment_id
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .any(|word| POOL_PAYOUT_MARKERS.contains(&word))
+    {
+        return TransactionCategory::PoolPayout;
+    }
+
+    TransactionCategory::Transfer
+}
+
+fn category_name(category: TransactionCategory) -> &'static str {
+    match category {
+        TransactionCategory::Coinbase => "coinbase",
+        TransactionCategory::PoolPayout => "pool_payout",
+        TransactionCategory::Transfer => "transfer",
+    }
+}
+
+fn direction_name(direction: i32) -> &'static str {
+    match direction {
+        DIRECTION_INBOUND => "inbound",
+        DIRECTION_OUTBOUND => "outbound",
+        _ => "unknown",
+    }
+}
+
+fn format_timestamp(timestamp: u64) -> String {
+    i64::try_from(timestamp)
+        .ok()
+        .and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0))
+        .map(|date| date.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Keeps free-text cells from being evaluated as formulas when the CSV is opened in a spreadsheet
+pub fn neutralize_formula(field: &str) -> String {
+    if field.starts_with(FORMULA_PREFIXES) {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    }
+}
+
+/// Keeps the `limit` most recent transactions, newest first
+pub fn retain_newest(transactions: &mut Vec<TransactionInfo>, limit: usize) {
+    transactions.sort_by(|a, b| {
+        b.timestamp
+            .cmp(&a.timestamp)
+            .then_with(|| b.mined_in_block_height.cmp(&a.mined_in_block_height))
+    });
+    transactions.truncate(limit);
+}
+
+pub fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Writes transactions incrementally as CSV rows or as elements of a JSON array
+pub struct TransactionExportWriter<W: Write> {
+    writer: W,
+    format: ExportFormat,
+    rows_written: u64,
+}
+
+impl<W: Write> TransactionExportWriter<W> {
+    pub fn new(mut writer: W, format: ExportFormat) -> io::Result<Self> {
+        match format {
+            ExportFormat::Csv => writeln!(writer, "{}", CSV_HEADER.join(","))?,
+            ExportFormat::Json => write!(writer, "[")?,
+        }
+        Ok(Self {
+            writer,
+            format,
+            rows_written: 0,
+        })
+    }
+
+    pub fn write_transaction(&mut self, tx: &TransactionInfo) -> io::Result<()> {
+        let row = TransactionExportRow::from_transaction(tx);
+        match self.format {
+            ExportFormat::Csv => writeln!(self.writer, "{}", row.to_csv_record())?,
+            ExportFormat::Json => {
+                if self.rows_written > 0 {
+                    write!(self.writer, ",")?;
+                }
+                writeln!(self.writer)?;
+                serde_json::to_writer(&mut self.writer, &row)?;
+            }
+        }
+        self.rows_written += 1;
+        Ok(())
+    }
+
+    /// Closes the document and returns the number of rows written
+    pub fn finish(mut self) -> io::Result<u64> {
+        if self.format == ExportFormat::Json {
+            writeln!(self.writer, "\n]")?;
+        }
+        self.writer.flush()?;
+        Ok(self.rows_written)
+    }
+}
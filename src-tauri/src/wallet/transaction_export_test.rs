@@ -0,0 +1,284 @@
+// Copyright 2026. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Tests for transaction history export formatting and classification
+
+use tari_transaction_components::tari_amount::MicroMinotari;
+
+use super::transaction_export::{
+    ExportFilter, ExportFormat, TransactionCategory, TransactionExportWriter, classify_transaction,
+    escape_csv_field, neutralize_formula, retain_newest,
+};
+use super::wallet_types::{TransactionInfo, TransactionStatus};
+
+fn create_transaction(
+    tx_id: &str,
+    status: TransactionStatus,
+    direction: i32,
+    timestamp: u64,
+    payment_id: &str,
+) -> TransactionInfo {
+    TransactionInfo {
+        tx_id: tx_id.to_string(),
+        source_address: "source".to_string(),
+        dest_address: "dest".to_string(),
+        status,
+        amount: MicroMinotari(1_500_000),
+        is_cancelled: false,
+        direction,
+        excess_sig: vec![],
+        fee: 25,
+        timestamp,
+        payment_id: payment_id.to_string(),
+        mined_in_block_height: 42,
+        payment_reference: Some("abcd".to_string()),
+    }
+}
+
+fn export_to_string(format: ExportFormat, transactions: &[TransactionInfo]) -> String {
+    let mut buffer = Vec::new();
+    let mut writer = TransactionExportWriter::new(&mut buffer, format).unwrap();
+    for tx in transactions {
+        writer.write_transaction(tx).unwrap();
+    }
+    writer.finish().unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+// ==================== Classification ====================
+
+#[test]
+fn classify_coinbase_statuses() {
+    for status in [
+        TransactionStatus::Coinbase,
+        TransactionStatus::CoinbaseConfirmed,
+        TransactionStatus::CoinbaseUnconfirmed,
+        TransactionStatus::CoinbaseNotInBlockChain,
+    ] {
+        let tx = create_transaction("1", status, 1, 0, "");
+        assert_eq!(classify_transaction(&tx), TransactionCategory::Coinbase);
+    }
+}
+
+#[test]
+fn classify_pool_payout_by_payment_id() {
+    let tx = create_transaction(
+        "1",
+        TransactionStatus::OneSidedConfirmed,
+        1,
+        0,
+        "Kryptex Payout #12",
+    );
+    assert_eq!(classify_transaction(&tx), TransactionCategory::PoolPayout);
+}
+
+#[test]
+fn classify_outbound_with_pool_memo_as_transfer() {
+    let tx = create_transaction("1", TransactionStatus::MinedConfirmed, 2, 0, "pool deposit");
+    assert_eq!(classify_transaction(&tx), TransactionCategory::Transfer);
+}
+
+#[test]
+fn classify_pool_marker_inside_word_as_transfer() {
+    for payment_id in ["carpool share", "whirlpools", "payouts-ledger"] {
+        let tx = create_transaction("1", TransactionStatus::MinedConfirmed, 1, 0, payment_id);
+        assert_eq!(classify_transaction(&tx), TransactionCategory::Transfer);
+    }
+}
+
+#[test]
+fn classify_plain_transfer() {
+    let tx = create_transaction("1", TransactionStatus::MinedConfirmed, 1, 0, "rent");
+    assert_eq!(classify_transaction(&tx), TransactionCategory::Transfer);
+}
+
+// ==================== Filtering ====================
+
+#[test]
+fn filter_bounds_are_inclusive() {
+    let filter = ExportFilter {
+        from_timestamp: Some(100),
+        to_timestamp: Some(200),
+    };
+
+    assert!(!filter.matches(&create_transaction(
+        "1",
+        TransactionStatus::MinedConfirmed,
+        1,
+        99,
+        ""
+    )));
+    assert!(filter.matches(&create_transaction(
+        "1",
+        TransactionStatus::MinedConfirmed,
+        1,
+        100,
+        ""
+    )));
+    assert!(filter.matches(&create_transaction(
+        "1",
+        TransactionStatus::MinedConfirmed,
+        1,
+        200,
+        ""
+    )));
+    assert!(!filter.matches(&create_transaction(
+        "1",
+        TransactionStatus::MinedConfirmed,
+        1,
+        201,
+        ""
+    )));
+}
+
+#[test]
+fn empty_filter_matches_everything() {
+    let filter = ExportFilter::default();
+    assert!(filter.matches(&create_transaction(
+        "1",
+        TransactionStatus::MinedConfirmed,
+        1,
+        0,
+        ""
+    )));
+}
+
+#[test]
+fn retain_newest_keeps_most_recent_first() {
+    let mut transactions: Vec<TransactionInfo> = [300, 100, 500, 200, 400]
+        .iter()
+        .map(|ts| {
+            create_transaction(
+                &ts.to_string(),
+                TransactionStatus::MinedConfirmed,
+                1,
+                *ts,
+                "",
+            )
+        })
+        .collect();
+
+    retain_newest(&mut transactions, 3);
+
+    let timestamps: Vec<u64> = transactions.iter().map(|tx| tx.timestamp).collect();
+    assert_eq!(timestamps, vec![500, 400, 300]);
+}
+
+// ==================== CSV ====================
+
+#[test]
+fn escape_csv_field_quotes_special_characters() {
+    assert_eq!(escape_csv_field("plain"), "plain");
+    assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+    assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(escape_csv_field("line\nbreak"), "\"line\nbreak\"");
+}
+
+#[test]
+fn neutralize_formula_prefixes_formula_cells() {
+    assert_eq!(neutralize_formula("rent"), "rent");
+    assert_eq!(neutralize_formula("a=b"), "a=b");
+    for cell in ["=1+1", "+SUM(A1)", "-2", "@cmd", "\tx", "\rx"] {
+        assert_eq!(neutralize_formula(cell), format!("'{cell}"));
+    }
+}
+
+#[test]
+fn csv_export_neutralizes_formula_payment_id() {
+    let transactions = vec![create_transaction(
+        "1",
+        TransactionStatus::MinedConfirmed,
+        1,
+        1_700_000_000,
+        "=HYPERLINK(\"http://evil\",\"x\")",
+    )];
+
+    let output = export_to_string(ExportFormat::Csv, &transactions);
+    let row = output.lines().nth(1).unwrap();
+
+    assert!(row.contains("\"'=HYPERLINK(\"\"http://evil\"\",\"\"x\"\")\""));
+}
+
+#[test]
+fn csv_export_writes_header_and_rows() {
+    let transactions = vec![
+        create_transaction(
+            "1",
+            TransactionStatus::CoinbaseConfirmed,
+            1,
+            1_700_000_000,
+            "",
+        ),
+        create_transaction(
+            "2",
+            TransactionStatus::MinedConfirmed,
+            2,
+            1_700_000_100,
+            "for, you",
+        ),
+    ];
+
+    let output = export_to_string(ExportFormat::Csv, &transactions);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("timestamp,date,tx_id,direction,category,amount,fee,status"));
+    assert_eq!(
+        lines[1],
+        "1700000000,2023-11-14T22:13:20+00:00,1,inbound,coinbase,1500000,25,CoinbaseConfirmed,false,42,,abcd,source,dest"
+    );
+    assert!(lines[2].contains("outbound,transfer"));
+    assert!(lines[2].contains("\"for, you\""));
+}
+
+// ==================== JSON ====================
+
+#[test]
+fn json_export_is_a_valid_array() {
+    let transactions = vec![
+        create_transaction("1", TransactionStatus::CoinbaseConfirmed, 1, 10, ""),
+        create_transaction(
+            "2",
+            TransactionStatus::OneSidedConfirmed,
+            1,
+            20,
+            "LuckyPool payout",
+        ),
+    ];
+
+    let output = export_to_string(ExportFormat::Json, &transactions);
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+    let rows = parsed.as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["category"], "coinbase");
+    assert_eq!(rows[1]["category"], "pool_payout");
+    assert_eq!(rows[1]["payment_reference"], "abcd");
+    assert_eq!(rows[1]["amount"], 1_500_000);
+}
+
+#[test]
+fn json_export_without_rows_is_empty_array() {
+    let output = export_to_string(ExportFormat::Json, &[]);
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(parsed.as_array().unwrap().len(), 0);
+}
@@ -21,7 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::events_emitter::EventsEmitter;
-use crate::port_allocator::PortAllocator;
+use crate::port_registry::ServicePort;
 use crate::process_adapter::{ProcessAdapter, ProcessInstance, ProcessStartupSpec};
 use crate::process_adapter_utils::setup_working_directory;
 use crate::tasks_tracker::TasksTrackers;
@@ -38,9 +38,13 @@ use crate::{LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 use anyhow::Error;
 use log::{info, warn};
 use minotari_node_grpc_client::grpc::wallet_client::WalletClient;
-use minotari_node_grpc_client::grpc::{GetAllCompletedTransactionsRequest, GetBalanceRequest};
+use minotari_node_grpc_client::grpc::{
+    GetAllCompletedTransactionsRequest, GetBalanceRequest, GetCompletedTransactionsRequest,
+    TransactionInfo as GrpcTransactionInfo,
+};
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tari_common::configuration::Network;
@@ -85,13 +89,13 @@ pub struct WalletAdapter {
 
 impl WalletAdapter {
     pub fn new(state_broadcast: watch::Sender<Option<WalletState>>) -> Self {
-        let grpc_port = PortAllocator::new().assign_port_with_fallback();
+        // The gRPC port is taken from the port registry before each start
         Self {
             use_tor: false,
             connect_with_local_node: false,
             view_private_key: "".to_string(),
             spend_key: "".to_string(),
-            grpc_port,
+            grpc_port: 0,
             state_broadcast,
             wallet_birthday: None,
             http_client_url: None,
@@ -102,6 +106,11 @@ impl WalletAdapter {
         self.use_tor = use_tor;
     }
 
+    /// Connections to the local node are always direct
+    pub fn routes_through_tor(&self) -> bool {
+        self.use_tor && !self.connect_with_local_node
+    }
+
     pub fn connect_with_local_node(&mut self, connect_with_local_node: bool) {
         self.connect_with_local_node = connect_with_local_node;
     }
@@ -143,45 +152,48 @@ impl WalletAdapter {
             .into_inner()
             .transactions
             .into_iter()
-            .map(|tx| {
-                let confirmations = if current_block_height > 0
-                    && tx.mined_in_block_height <= current_block_height
-                {
-                    current_block_height - tx.mined_in_block_height
-                } else {
-                    0
-                };
-                let payment_reference = if confirmations >= 5 {
-                    match tx.direction {
-                        1 => tx.payment_references_received.last().map(hex::encode),
-                        2 => tx.payment_references_sent.last().map(hex::encode),
-                        _ => None,
-                    }
-                } else {
-                    None
-                };
-
-                Ok(TransactionInfo {
-                    tx_id: tx.tx_id.to_string(),
-                    source_address: TariAddress::from_bytes(&tx.source_address)?.to_base58(),
-                    dest_address: TariAddress::from_bytes(&tx.dest_address)?.to_base58(),
-                    status: TransactionStatus::from(tx.status),
-                    amount: MicroMinotari(tx.amount),
-                    is_cancelled: tx.is_cancelled,
-                    direction: tx.direction,
-                    excess_sig: tx.excess_sig,
-                    fee: tx.fee,
-                    timestamp: tx.timestamp,
-                    payment_id: MemoField::stringify_bytes(&tx.user_payment_id),
-                    mined_in_block_height: tx.mined_in_block_height,
-                    payment_reference,
-                })
-            })
+            .map(|tx| transaction_info_from_grpc(tx, current_block_height))
             .collect::<Result<Vec<_>, TariAddressError>>()?;
 
         Ok(transactions)
     }
 
+    /// Streams every completed transaction to `on_transaction` without buffering the whole
+    /// history. The callback can return `ControlFlow::Break` to stop early.
+    pub async fn stream_transactions<F>(
+        &self,
+        current_block_height: u64,
+        mut on_transaction: F,
+    ) -> Result<(), WalletStatusMonitorError>
+    where
+        F: FnMut(TransactionInfo) -> Result<ControlFlow<()>, anyhow::Error>,
+    {
+        let mut client = WalletClient::connect(self.wallet_grpc_address())
+            .await
+            .map_err(|_e| WalletStatusMonitorError::WalletNotStarted)?;
+        let mut stream = client
+            .get_completed_transactions(GetCompletedTransactionsRequest::default())
+            .await
+            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?
+            .into_inner();
+
+        while let Some(response) = stream
+            .message()
+            .await
+            .map_err(|e| WalletStatusMonitorError::UnknownError(e.into()))?
+        {
+            let Some(tx) = response.transaction else {
+                continue;
+            };
+            let transaction = transaction_info_from_grpc(tx, current_block_height)?;
+            if on_transaction(transaction)?.is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn send_one_sided_to_stealth_address(
         &self,
         amount: u64,
@@ -272,6 +284,43 @@ impl WalletAdapter {
     }
 }
 
+fn transaction_info_from_grpc(
+    tx: GrpcTransactionInfo,
+    current_block_height: u64,
+) -> Result<TransactionInfo, TariAddressError> {
+    let confirmations =
+        if current_block_height > 0 && tx.mined_in_block_height <= current_block_height {
+            current_block_height - tx.mined_in_block_height
+        } else {
+            0
+        };
+    let payment_reference = if confirmations >= 5 {
+        match tx.direction {
+            1 => tx.payment_references_received.last().map(hex::encode),
+            2 => tx.payment_references_sent.last().map(hex::encode),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    Ok(TransactionInfo {
+        tx_id: tx.tx_id.to_string(),
+        source_address: TariAddress::from_bytes(&tx.source_address)?.to_base58(),
+        dest_address: TariAddress::from_bytes(&tx.dest_address)?.to_base58(),
+        status: TransactionStatus::from(tx.status),
+        amount: MicroMinotari(tx.amount),
+        is_cancelled: tx.is_cancelled,
+        direction: tx.direction,
+        excess_sig: tx.excess_sig,
+        fee: tx.fee,
+        timestamp: tx.timestamp,
+        payment_id: MemoField::stringify_bytes(&tx.user_payment_id),
+        mined_in_block_height: tx.mined_in_block_height,
+        payment_reference,
+    })
+}
+
 impl ProcessAdapter for WalletAdapter {
     type StatusMonitor = WalletStatusMonitor;
     type ProcessInstance = ProcessInstance;
@@ -358,8 +407,7 @@ impl ProcessAdapter for WalletAdapter {
             .join(Network::get_current_or_user_setting_or_default().to_string())
             .join("peer_db");
 
-        // Always use direct connections with the local node
-        if self.use_tor && !self.connect_with_local_node {
+        if self.routes_through_tor() {
             args.push("-p".to_string());
             args.push("wallet.p2p.transport.tor.proxy_bypass_for_outbound_tcp=true".to_string());
             args.push("-p".to_string());
@@ -435,6 +483,7 @@ impl ProcessAdapter for WalletAdapter {
                     args,
                     data_dir,
                     pid_file_name: self.pid_file_name().to_string(),
+                    log_dir,
                     name: self.name().to_string(),
                 },
             },
@@ -449,4 +498,12 @@ impl ProcessAdapter for WalletAdapter {
     fn pid_file_name(&self) -> &str {
         "wallet_pid"
     }
+
+    fn log_file_path(&self, log_folder: &Path) -> Option<PathBuf> {
+        Some(log_folder.join("wallet").join("log").join("base_layer.log"))
+    }
+
+    fn service_ports(&self) -> Vec<(ServicePort, u16)> {
+        vec![(ServicePort::WalletGrpc, self.grpc_port)]
+    }
 }
@@ -25,16 +25,22 @@ use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
 use crate::internal_wallet::InternalWallet;
 use crate::node::node_manager::{NodeManager, NodeManagerError};
+use crate::port_registry::{ServicePort, assign_port};
 use crate::process_adapter::ProcessAdapter;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
 use crate::tasks_tracker::TasksTrackers;
+use crate::wallet::transaction_export::{
+    ExportFilter, ExportFormat, ExportSummary, TransactionExportWriter, retain_newest,
+};
 use crate::wallet::wallet_adapter::WalletAdapter;
 use crate::wallet::wallet_status_monitor::WalletStatusMonitorError;
 use crate::wallet::wallet_types::{TransactionInfo, TransactionStatus, WalletBalance, WalletState};
 use crate::{BaseNodeStatus, LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES};
 use futures_util::future::FusedFuture;
 use log::{error, info};
+use std::io::BufWriter;
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -47,6 +53,8 @@ use tokio::fs;
 use tokio::sync::RwLock;
 use tokio::sync::watch;
 
+const DIRECTION_INBOUND: i32 = 1;
+
 #[derive(Debug, Clone)]
 pub struct WalletStartupConfig {
     pub base_path: PathBuf,
@@ -127,6 +135,7 @@ impl WalletManager {
         }
 
         process_watcher.adapter.http_client_url = Some(self.node_manager.get_http_api_url().await);
+        process_watcher.adapter.grpc_port = assign_port(ServicePort::WalletGrpc).await?;
         process_watcher.poll_time = Duration::from_secs(5);
         process_watcher.adapter.use_tor(config.use_tor);
         info!(target: LOG_TARGET_APP_LOGIC, "Using Tor: {}", config.use_tor);
@@ -250,6 +259,106 @@ impl WalletManager {
             })
     }
 
+    pub async fn stream_transactions<F>(&self, on_transaction: F) -> Result<(), WalletManagerError>
+    where
+        F: FnMut(TransactionInfo) -> Result<ControlFlow<()>, anyhow::Error>,
+    {
+        let current_block_height = self.base_node_watch_rx.borrow().block_height;
+        let process_watcher = self.watcher.read().await;
+        process_watcher
+            .adapter
+            .stream_transactions(current_block_height, on_transaction)
+            .await
+            .map_err(|e| match e {
+                WalletStatusMonitorError::WalletNotStarted => WalletManagerError::WalletNotStarted,
+                _ => WalletManagerError::UnknownError(e.into()),
+            })
+    }
+
+    /// Returns up to `limit` transactions within the filter's date range
+    pub async fn get_filtered_transactions(
+        &self,
+        filter: ExportFilter,
+        limit: usize,
+    ) -> Result<Vec<TransactionInfo>, WalletManagerError> {
+        // The wallet stream is not ordered newest first, so scan it all and keep only the latest matches
+        let mut transactions = Vec::new();
+        let trim_at = limit.saturating_mul(2).max(64);
+        self.stream_transactions(|tx| {
+            if filter.matches(&tx) {
+                transactions.push(tx);
+                if transactions.len() >= trim_at {
+                    retain_newest(&mut transactions, limit);
+                }
+            }
+            Ok(ControlFlow::Continue(()))
+        })
+        .await?;
+        retain_newest(&mut transactions, limit);
+        Ok(transactions)
+    }
+
+    /// Writes the transaction history to `path` row by row as it streams from the wallet.
+    /// Rows go to a temporary file that only replaces `path` once the export is complete.
+    pub async fn export_transaction_history(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+        filter: ExportFilter,
+    ) -> Result<ExportSummary, WalletManagerError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| WalletManagerError::UnknownError(e.into()))?;
+        }
+        let partial_path = path.with_extension(format!("{}.partial", format.extension()));
+        let result = self
+            .write_transaction_export(&partial_path, format, filter)
+            .await
+            .and_then(|rows_written| {
+                std::fs::rename(&partial_path, path)
+                    .map(|()| rows_written)
+                    .map_err(|e| WalletManagerError::UnknownError(e.into()))
+            });
+        let rows_written = match result {
+            Ok(rows_written) => rows_written,
+            Err(e) => {
+                let _unused = std::fs::remove_file(&partial_path);
+                return Err(e);
+            }
+        };
+        info!(target: LOG_TARGET_APP_LOGIC, "Exported {rows_written} transactions to {}", path.display());
+
+        Ok(ExportSummary {
+            path: path.to_string_lossy().to_string(),
+            format,
+            rows_written,
+        })
+    }
+
+    async fn write_transaction_export(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+        filter: ExportFilter,
+    ) -> Result<u64, WalletManagerError> {
+        let file =
+            std::fs::File::create(path).map_err(|e| WalletManagerError::UnknownError(e.into()))?;
+        let mut writer = TransactionExportWriter::new(BufWriter::new(file), format)
+            .map_err(|e| WalletManagerError::UnknownError(e.into()))?;
+
+        self.stream_transactions(|tx| {
+            if filter.matches(&tx) {
+                writer.write_transaction(&tx)?;
+            }
+            Ok(ControlFlow::Continue(()))
+        })
+        .await?;
+
+        writer
+            .finish()
+            .map_err(|e| WalletManagerError::UnknownError(e.into()))
+    }
+
     pub async fn wait_for_scan_to_height(
         &self,
         block_height: u64,
@@ -286,10 +395,7 @@ impl WalletManager {
         // TODO: check if node is synced?
         self.node_manager.wait_ready().await?;
 
-        let minotari_amount = Minotari::from_str(&amount_str)
-            .map_err(|e| WalletManagerError::UnknownError(e.into()))?;
-        let micro_minotari_amount = MicroMinotari::from(minotari_amount);
-        let amount = micro_minotari_amount.as_u64();
+        let amount = parse_minotari_amount(&amount_str)?.as_u64();
 
         // Payment ID can't be an empty string
         let payment_id = match payment_id {
@@ -325,6 +431,30 @@ impl WalletManager {
         Ok(matching_tx)
     }
 
+    /// Incoming transfers, not coinbases, mined in the given block
+    pub async fn find_incoming_transactions_for_block(
+        &self,
+        block_height: u64,
+    ) -> Result<Vec<TransactionInfo>, WalletManagerError> {
+        const INCOMING_STATUSES_BITFLAG: u32 = (1 << TransactionStatus::MinedUnconfirmed as u32)
+            | (1 << TransactionStatus::MinedConfirmed as u32)
+            | (1 << TransactionStatus::OneSidedUnconfirmed as u32)
+            | (1 << TransactionStatus::OneSidedConfirmed as u32);
+
+        let transactions = self
+            .get_transactions(Some(0), Some(20), Some(INCOMING_STATUSES_BITFLAG))
+            .await?;
+
+        Ok(transactions
+            .into_iter()
+            .filter(|tx| {
+                tx.direction == DIRECTION_INBOUND
+                    && !tx.is_cancelled
+                    && tx.mined_in_block_height == block_height
+            })
+            .collect())
+    }
+
     #[allow(clippy::too_many_lines)]
     pub async fn wait_for_initial_wallet_scan(
         &self,
@@ -522,7 +652,16 @@ impl WalletManager {
         Ok(())
     }
 
-    #[allow(dead_code)]
+    /// Restarts the wallet when it reaches its peers through Tor, so it reconnects over the
+    /// new Tor route
+    pub async fn on_tor_reconfigured(&self) {
+        let process_watcher = self.watcher.read().await;
+        if process_watcher.adapter.routes_through_tor() {
+            info!(target: LOG_TARGET_APP_LOGIC, "Tor route changed, restarting the wallet");
+            process_watcher.request_restart();
+        }
+    }
+
     pub async fn stop(&self) -> Result<i32, WalletManagerError> {
         // Reset the initial scan flag
         self.initial_scan_completed
@@ -545,3 +684,9 @@ impl WalletManager {
         lock.is_pid_file_exists(base_path)
     }
 }
+
+pub fn parse_minotari_amount(amount_str: &str) -> Result<MicroMinotari, WalletManagerError> {
+    let minotari_amount =
+        Minotari::from_str(amount_str).map_err(|e| WalletManagerError::UnknownError(e.into()))?;
+    Ok(MicroMinotari::from(minotari_amount))
+}
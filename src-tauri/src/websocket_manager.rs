@@ -42,7 +42,6 @@ use tokio::sync::watch;
 use tokio::time::sleep;
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::WebSocketStream;
-use tokio_tungstenite::connect_async;
 use tungstenite::Message;
 use tungstenite::Utf8Bytes;
 use urlencoding::encode;
@@ -51,6 +50,7 @@ use crate::LOG_TARGET_APP_LOGIC;
 use crate::app_in_memory_config::AppInMemoryConfig;
 use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
+use crate::requests::connectivity;
 use crate::tasks_tracker::TasksTrackers;
 
 #[derive(Debug, thiserror::Error)]
@@ -159,7 +159,7 @@ impl WebsocketManager {
         let app_id = ConfigCore::content().await.anon_id().clone();
         adjusted_ws_url.push_str(&format!("/v2/ws?app_id={}", encode(&app_id)));
 
-        let (ws_stream, _) = connect_async(adjusted_ws_url).await?;
+        let ws_stream = connectivity::connect_websocket(&adjusted_ws_url).await?;
         info!(target:LOG_TARGET_APP_LOGIC,"websocket connection established...");
 
         Ok(ws_stream)
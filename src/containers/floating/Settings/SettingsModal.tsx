@@ -15,9 +15,11 @@ import {
     AirdropSettings,
     ConnectionsSettings,
     ExperimentalSettings,
+    FleetSettings,
     GeneralSettings,
     McpSettings,
     MiningSettings,
+    NotificationSettings,
     WalletSettings,
     ReleaseNotes,
 } from './sections';
@@ -39,6 +41,8 @@ const SettingsModal = memo(function SettingsModal() {
         wallet: <WalletSettings />,
         airdrop: <AirdropSettings />,
         mcp: <McpSettings />,
+        fleet: <FleetSettings />,
+        notifications: <NotificationSettings />,
         experimental: <ExperimentalSettings />,
         releaseNotes: <ReleaseNotes />,
     };
@@ -6,6 +6,7 @@ import NodeDataLocationSettings from './NodeDataLocation.tsx';
 import Network from './Network.tsx';
 import Peers from './Peers.tsx';
 import Node from './Node.tsx';
+import OutboundConnectivity from './OutboundConnectivity.tsx';
 
 export const ConnectionsSettings = () => {
     const nodeType = useNodeStore((s) => s.node_type);
@@ -22,6 +23,7 @@ export const ConnectionsSettings = () => {
         <>
             {nonRemoteMarkup}
             <NodeTypeConfiguration />
+            <OutboundConnectivity />
             <Node />
             <Network />
             <Peers />
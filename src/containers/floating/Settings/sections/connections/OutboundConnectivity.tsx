@@ -0,0 +1,147 @@
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { offset } from '@floating-ui/react';
+
+import { Typography } from '@app/components/elements/Typography.tsx';
+import { Stack } from '@app/components/elements/Stack.tsx';
+import { Input } from '@app/components/elements/inputs/Input.tsx';
+import { Button } from '@app/components/elements/buttons/Button.tsx';
+import { Select, SelectOption } from '@app/components/elements/inputs/Select.tsx';
+import { setOutboundConnectivity } from '@app/store/actions/config/core.ts';
+import { useConfigCoreStore } from '@app/store/stores/config/useConfigCoreStore.ts';
+import { ProxyKind, ProxySettings } from '@app/types/config/core.ts';
+
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles.ts';
+
+type ConnectivityOption = 'direct' | 'tor' | ProxyKind;
+
+const emptyProxy: ProxySettings = { kind: 'socks5', host: '', port: 1080, username: null, password: null };
+
+export default function OutboundConnectivity() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const connectivity = useConfigCoreStore((s) => s.outbound_connectivity);
+    const savedOption: ConnectivityOption =
+        connectivity?.mode === 'proxy' ? connectivity.kind : (connectivity?.mode ?? 'direct');
+
+    const [selectedOption, setSelectedOption] = useState<ConnectivityOption>(savedOption);
+    const [proxy, setProxy] = useState<ProxySettings>(emptyProxy);
+
+    useEffect(() => {
+        setSelectedOption(savedOption);
+        if (connectivity?.mode === 'proxy') {
+            const { kind, host, port, username, password } = connectivity;
+            setProxy({ kind, host, port, username, password });
+        }
+    }, [connectivity, savedOption]);
+
+    const options: SelectOption[] = useMemo(
+        () => [
+            { label: t('outbound-connectivity.direct'), value: 'direct' },
+            { label: t('outbound-connectivity.tor'), value: 'tor' },
+            { label: t('outbound-connectivity.socks5'), value: 'socks5' },
+            { label: t('outbound-connectivity.http'), value: 'http' },
+        ],
+        [t]
+    );
+
+    const handleChange = useCallback(async (value: string) => {
+        const option = value as ConnectivityOption;
+        setSelectedOption(option);
+        if (option === 'direct' || option === 'tor') {
+            await setOutboundConnectivity({ mode: option });
+        } else {
+            setProxy((prev) => ({ ...prev, kind: option }));
+        }
+    }, []);
+
+    const isProxySelected = selectedOption === 'socks5' || selectedOption === 'http';
+    const isProxyValid = proxy.host.trim().length > 0 && proxy.port > 0 && !proxy.username === !proxy.password;
+
+    const onSave = useCallback(async () => {
+        await setOutboundConnectivity({
+            mode: 'proxy',
+            ...proxy,
+            host: proxy.host.trim(),
+            username: proxy.username || null,
+            password: proxy.password || null,
+        });
+    }, [proxy]);
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('outbound-connectivity.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography>{t('outbound-connectivity.description')}</Typography>
+                </SettingsGroupContent>
+                <SettingsGroupAction>
+                    <Stack style={{ width: '100%', minWidth: 160 }}>
+                        <Select
+                            onChange={handleChange}
+                            forceHeight={36}
+                            selectedValue={selectedOption}
+                            options={options}
+                            floatingProps={{
+                                middleware: [offset({ crossAxis: -40, mainAxis: 10 })],
+                            }}
+                            variant="bordered"
+                        />
+                    </Stack>
+                </SettingsGroupAction>
+            </SettingsGroup>
+            {isProxySelected && (
+                <Stack direction="column" gap={8} style={{ width: '100%' }}>
+                    <Stack direction="row" gap={8}>
+                        <Input
+                            name="proxy-host"
+                            labelText={t('outbound-connectivity.host')}
+                            value={proxy.host}
+                            placeholder="127.0.0.1"
+                            hasError={!proxy.host.trim()}
+                            onChange={({ target }) => setProxy((prev) => ({ ...prev, host: target.value }))}
+                        />
+                        <Input
+                            name="proxy-port"
+                            labelText={t('outbound-connectivity.port')}
+                            value={proxy.port || ''}
+                            placeholder="1080"
+                            hasError={!proxy.port}
+                            onChange={({ target }) => {
+                                if (target.value && isNaN(+target.value)) return;
+                                setProxy((prev) => ({ ...prev, port: +target.value.trim() }));
+                            }}
+                        />
+                    </Stack>
+                    <Stack direction="row" gap={8}>
+                        <Input
+                            name="proxy-username"
+                            labelText={t('outbound-connectivity.username')}
+                            value={proxy.username ?? ''}
+                            onChange={({ target }) => setProxy((prev) => ({ ...prev, username: target.value }))}
+                        />
+                        <Input
+                            name="proxy-password"
+                            type="password"
+                            labelText={t('outbound-connectivity.password')}
+                            value={proxy.password ?? ''}
+                            onChange={({ target }) => setProxy((prev) => ({ ...prev, password: target.value }))}
+                        />
+                    </Stack>
+                    <Stack direction="row" justifyContent="flex-end">
+                        <Button size="smaller" onClick={onSave} disabled={!isProxyValid}>
+                            {t('save')}
+                        </Button>
+                    </Stack>
+                </Stack>
+            )}
+        </SettingsGroupWrapper>
+    );
+}
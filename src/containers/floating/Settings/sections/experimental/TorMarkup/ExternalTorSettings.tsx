@@ -0,0 +1,80 @@
+import { useTranslation } from 'react-i18next';
+
+import { ToggleSwitch } from '@app/components/elements/inputs/switch/ToggleSwitch.tsx';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack.tsx';
+import { ExternalTorConfig } from '@app/types/app-status';
+
+import { ErrorTypography, StyledInput } from './TorMarkup.styles';
+
+const defaultExternalTor: ExternalTorConfig = {
+    control_address: '127.0.0.1:9051',
+    socks_address: '127.0.0.1:9050',
+    control_password: null,
+};
+
+// Addresses are IP literals with a port, as the backend parses them into socket addresses
+const ADDRESS_PATTERN = /^(\d{1,3}(\.\d{1,3}){3}|\[[0-9a-fA-F:.]+\]):\d{1,5}$/;
+
+export const hasAddressError = (address: string) => {
+    if (!ADDRESS_PATTERN.test(address)) return true;
+    const port = Number(address.slice(address.lastIndexOf(':') + 1));
+    return port <= 0 || port > 65535;
+};
+
+interface ExternalTorSettingsProps {
+    external?: ExternalTorConfig | null;
+    onChange: (external: ExternalTorConfig | null) => void;
+}
+
+export const ExternalTorSettings = ({ external, onChange }: ExternalTorSettingsProps) => {
+    const { t } = useTranslation('settings', { useSuspense: false });
+
+    return (
+        <Stack style={{ width: '100%', marginBottom: '16px' }} direction="column">
+            <Stack direction="row" justifyContent="space-between" alignItems="center">
+                <Typography variant="h6">{t('system-tor.title')}</Typography>
+                <ToggleSwitch
+                    label={t('system-tor.use')}
+                    checked={Boolean(external)}
+                    onChange={() => onChange(external ? null : defaultExternalTor)}
+                />
+            </Stack>
+            <Typography>{t('system-tor.description')}</Typography>
+
+            {external && (
+                <Stack direction="column" style={{ marginTop: '16px' }}>
+                    <StyledInput
+                        name="tor-control-address"
+                        labelText={t('system-tor.control-address')}
+                        placeholder="127.0.0.1:9051"
+                        value={external.control_address}
+                        hasError={hasAddressError(external.control_address)}
+                        onChange={({ target }) => onChange({ ...external, control_address: target.value.trim() })}
+                    />
+                    <ErrorTypography variant="p">
+                        {hasAddressError(external.control_address) && t('system-tor.invalid-address')}
+                    </ErrorTypography>
+                    <StyledInput
+                        name="tor-socks-address"
+                        labelText={t('system-tor.socks-address')}
+                        placeholder="127.0.0.1:9050"
+                        value={external.socks_address}
+                        hasError={hasAddressError(external.socks_address)}
+                        onChange={({ target }) => onChange({ ...external, socks_address: target.value.trim() })}
+                    />
+                    <ErrorTypography variant="p">
+                        {hasAddressError(external.socks_address) && t('system-tor.invalid-address')}
+                    </ErrorTypography>
+                    <StyledInput
+                        name="tor-control-password"
+                        type="password"
+                        labelText={t('system-tor.control-password')}
+                        value={external.control_password ?? ''}
+                        onChange={({ target }) => onChange({ ...external, control_password: target.value || null })}
+                    />
+                </Stack>
+            )}
+        </Stack>
+    );
+};
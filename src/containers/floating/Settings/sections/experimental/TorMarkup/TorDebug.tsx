@@ -1,6 +1,9 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
 import { useTranslation } from 'react-i18next';
 
 import { Typography } from '@app/components/elements/Typography';
+import { TorStatus } from '@app/types/app-status';
 
 import {
     SettingsGroup,
@@ -13,6 +16,15 @@ import { useNodeStore } from '@app/store/useNodeStore.ts';
 export const TorDebug = () => {
     const { t } = useTranslation('settings', { useSuspense: false });
     const torEntryGuards = useNodeStore((s) => s.tor_entry_guards);
+    const [torStatus, setTorStatus] = useState<TorStatus>();
+
+    useEffect(() => {
+        invoke('get_tor_status')
+            .then(setTorStatus)
+            .catch((e) => {
+                console.error('Get Tor status error:', e);
+            });
+    }, []);
 
     return (
         <SettingsGroupWrapper>
@@ -32,6 +44,18 @@ export const TorDebug = () => {
                     )}
                 </SettingsGroupContent>
             </SettingsGroup>
+            {torStatus?.transport && (
+                <>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('tor-transport')}</Typography>
+                    </SettingsGroupTitle>
+                    <SettingsGroup>
+                        <SettingsGroupContent style={{ fontSize: '11px' }}>
+                            {`${torStatus.transport}: ${t(`tor-transport-health.${torStatus.transport_health}`)}`}
+                        </SettingsGroupContent>
+                    </SettingsGroup>
+                </>
+            )}
         </SettingsGroupWrapper>
     );
 };
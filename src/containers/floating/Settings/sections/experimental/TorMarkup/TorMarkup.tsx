@@ -6,7 +6,7 @@ import { setError } from '@app/store/actions';
 import { ToggleSwitch } from '@app/components/elements/inputs/switch/ToggleSwitch.tsx';
 
 import { Typography } from '@app/components/elements/Typography';
-import { TorConfig } from '@app/types/app-status';
+import { ExternalTorConfig, TorConfig } from '@app/types/app-status';
 import { Input } from '@app/components/elements/inputs/Input';
 
 import { Stack } from '@app/components/elements/Stack.tsx';
@@ -20,6 +20,7 @@ import {
     SettingsGroupWrapper,
 } from '../../../components/SettingsGroup.styles.ts';
 import { TorDebug } from './TorDebug';
+import { ExternalTorSettings, hasAddressError } from './ExternalTorSettings';
 import { ErrorTypography, StyledInput, TorSettingsContainer } from './TorMarkup.styles';
 
 import { type } from '@tauri-apps/plugin-os';
@@ -31,6 +32,7 @@ interface EditedTorConfig {
     control_port: string | number;
     use_bridges: boolean;
     bridges: string[];
+    external?: ExternalTorConfig | null;
 }
 
 const hasBridgeError = (bridge: string) => {
@@ -105,6 +107,10 @@ export const TorMarkup = () => {
         if (editedUseTor !== defaultUseTor) return true;
 
         if (JSON.stringify(defaultTorConfig) === JSON.stringify(editedConfig)) return false;
+        const external = editedConfig?.external;
+        if (external) {
+            return !hasAddressError(external.control_address) && !hasAddressError(external.socks_address);
+        }
         return !(
             (editedConfig?.use_bridges &&
                 (!editedConfig?.bridges?.length || editedConfig?.bridges.some((bridge) => hasBridgeError(bridge))) &&
@@ -131,6 +137,15 @@ export const TorMarkup = () => {
         }));
     }, [editedConfig?.bridges, editedConfig?.use_bridges, t]);
 
+    const onExternalChange = useCallback((external: ExternalTorConfig | null) => {
+        // A system Tor brings its own bridges
+        setEditedConfig((prev) => ({
+            ...(prev as TorConfig),
+            use_bridges: external ? false : Boolean(prev?.use_bridges),
+            external,
+        }));
+    }, []);
+
     const toggleRandomControlPort = useCallback(() => {
         setEditedConfig((prev) => ({
             ...(prev as TorConfig),
@@ -162,6 +177,10 @@ export const TorMarkup = () => {
                 </SettingsGroup>
 
                 {editedUseTor && editedConfig ? (
+                    <ExternalTorSettings external={editedConfig.external} onChange={onExternalChange} />
+                ) : null}
+
+                {editedUseTor && editedConfig && !editedConfig.external ? (
                     <TorSettingsContainer $isMac={isMac}>
                         <Stack
                             direction="row"
@@ -0,0 +1,111 @@
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Button } from '@app/components/elements/buttons/Button';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { ConfigFleet } from '@app/types/config/fleet';
+import { SettingsGroupContent, SettingsGroupTitle, SettingsGroupWrapper } from '../../components/SettingsGroup.styles';
+import FleetRigList from './FleetRigList';
+
+interface ControllerSettingsProps {
+    config: ConfigFleet;
+    onChanged: () => void;
+}
+
+export default function ControllerSettings({ config, onChanged }: ControllerSettingsProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const [listenAddress, setListenAddress] = useState(config.listen_address);
+    const [rigId, setRigId] = useState('');
+    const [name, setName] = useState('');
+    const [pubKey, setPubKey] = useState('');
+
+    useEffect(() => {
+        setListenAddress(config.listen_address);
+    }, [config.listen_address]);
+
+    const handleSaveListenAddress = async () => {
+        try {
+            await invoke('set_fleet_listen_address', { listenAddress: listenAddress.trim() });
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('fleet.controller.error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const handleEnroll = async () => {
+        try {
+            await invoke('enroll_fleet_rig', { rigId: rigId.trim(), name: name.trim(), pubKey: pubKey.trim() });
+            setRigId('');
+            setName('');
+            setPubKey('');
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('fleet.controller.enroll-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const canEnroll = rigId.trim().length > 0 && pubKey.trim().length > 0;
+
+    return (
+        <>
+            <SettingsGroupWrapper>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('fleet.controller.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography variant="p">{t('fleet.controller.description')}</Typography>
+                </SettingsGroupContent>
+                <Stack direction="row" gap={8} alignItems="flex-end" style={{ width: '100%' }}>
+                    <Input
+                        name="fleet-listen-address"
+                        labelText={t('fleet.controller.listen-address')}
+                        value={listenAddress}
+                        onChange={(e) => setListenAddress(e.target.value)}
+                    />
+                    <Button
+                        size="smaller"
+                        onClick={handleSaveListenAddress}
+                        disabled={listenAddress.trim() === config.listen_address}
+                    >
+                        {t('save')}
+                    </Button>
+                </Stack>
+            </SettingsGroupWrapper>
+            <SettingsGroupWrapper>
+                <SettingsGroupTitle>
+                    <Typography variant="h6">{t('fleet.controller.enroll-title')}</Typography>
+                </SettingsGroupTitle>
+                <Typography variant="p">{t('fleet.controller.enroll-description')}</Typography>
+                <Stack direction="column" gap={8} style={{ width: '100%' }}>
+                    <Input
+                        name="fleet-rig-id"
+                        labelText={t('fleet.controller.rig-id')}
+                        value={rigId}
+                        onChange={(e) => setRigId(e.target.value)}
+                    />
+                    <Input
+                        name="fleet-rig-name"
+                        labelText={t('fleet.controller.rig-name')}
+                        value={name}
+                        onChange={(e) => setName(e.target.value)}
+                    />
+                    <Input
+                        name="fleet-rig-pub-key"
+                        labelText={t('fleet.controller.rig-pub-key')}
+                        value={pubKey}
+                        onChange={(e) => setPubKey(e.target.value)}
+                    />
+                    <Stack direction="row" justifyContent="flex-end">
+                        <Button size="smaller" onClick={handleEnroll} disabled={!canEnroll}>
+                            {t('fleet.controller.enroll')}
+                        </Button>
+                    </Stack>
+                </Stack>
+            </SettingsGroupWrapper>
+            <FleetRigList enrolled={config.rigs} onChanged={onChanged} />
+        </>
+    );
+}
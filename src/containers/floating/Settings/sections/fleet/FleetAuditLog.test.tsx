@@ -0,0 +1,39 @@
+/**
+ * @vitest-environment jsdom
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { render, screen } from '@app/test/test-utils';
+import { FleetAuditEntry } from '@app/types/config/fleet';
+import FleetAuditLog from './FleetAuditLog';
+
+describe('FleetAuditLog', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+    });
+
+    it('shows empty message when no entries', async () => {
+        vi.mocked(invoke).mockResolvedValue([]);
+        render(<FleetAuditLog />);
+        expect(await screen.findByText('fleet.audit-log.empty')).toBeInTheDocument();
+    });
+
+    it('renders audit entries', async () => {
+        const entries: FleetAuditEntry[] = [
+            {
+                timestamp: { secs_since_epoch: 1_767_225_600 },
+                rig_id: 'rig-1',
+                command_id: 'c1',
+                command: { action: 'stop_mining' },
+                status: 'Failed',
+                details: 'miner not running',
+            },
+        ];
+        vi.mocked(invoke).mockResolvedValue(entries);
+        render(<FleetAuditLog />);
+        expect(await screen.findByText('stop_mining')).toBeInTheDocument();
+        expect(screen.getByText('rig-1')).toBeInTheDocument();
+        expect(screen.getByText('❌')).toBeInTheDocument();
+        expect(screen.getByText('miner not running')).toBeInTheDocument();
+    });
+});
@@ -0,0 +1,68 @@
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { FleetAuditEntry } from '@app/types/config/fleet';
+import {
+    SettingsGroup,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles';
+
+const statusIcons: Record<FleetAuditEntry['status'], string> = {
+    Sent: '📤',
+    Rejected: '🚫',
+    Succeeded: '✅',
+    Failed: '❌',
+};
+
+export default function FleetAuditLog() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const [entries, setEntries] = useState<FleetAuditEntry[]>([]);
+
+    useEffect(() => {
+        invoke('get_fleet_audit_log', { count: 50 })
+            .then(setEntries)
+            .catch((e) => console.error('Failed to load fleet audit log:', e));
+    }, []);
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('fleet.audit-log.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <div style={{ maxHeight: 200, overflowY: 'auto', fontSize: 11, marginTop: 4 }}>
+                        {entries.length === 0 ? (
+                            <Typography variant="p" style={{ opacity: 0.5 }}>
+                                {t('fleet.audit-log.empty')}
+                            </Typography>
+                        ) : (
+                            entries.map((entry, i) => (
+                                <div
+                                    key={i}
+                                    style={{
+                                        display: 'flex',
+                                        gap: 8,
+                                        padding: '2px 0',
+                                        borderBottom: '1px solid rgba(128,128,128,0.1)',
+                                    }}
+                                >
+                                    <span style={{ opacity: 0.5 }}>
+                                        {new Date(entry.timestamp.secs_since_epoch * 1000).toLocaleTimeString()}
+                                    </span>
+                                    <span>{statusIcons[entry.status]}</span>
+                                    <span>{entry.rig_id}</span>
+                                    <span>{entry.command.action}</span>
+                                    {entry.details && <span style={{ opacity: 0.5 }}>{entry.details}</span>}
+                                </div>
+                            ))
+                        )}
+                    </div>
+                </SettingsGroupContent>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+}
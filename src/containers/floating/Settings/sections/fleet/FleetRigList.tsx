@@ -0,0 +1,132 @@
+import { useCallback, useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Button } from '@app/components/elements/buttons/Button';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { useConfigMiningStore } from '@app/store/useAppConfigStore';
+import { FleetCommand, FleetRig, FleetRigView } from '@app/types/config/fleet';
+import { SettingsGroupTitle, SettingsGroupWrapper } from '../../components/SettingsGroup.styles';
+
+const REFRESH_INTERVAL_MS = 15_000;
+
+interface FleetRigListProps {
+    enrolled: FleetRig[];
+    onChanged: () => void;
+}
+
+export default function FleetRigList({ enrolled, onChanged }: FleetRigListProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const miningModes = useConfigMiningStore((s) => s.mining_modes);
+    const [rigs, setRigs] = useState<FleetRigView[]>([]);
+
+    const loadRigs = useCallback(() => {
+        invoke('get_fleet_rigs')
+            .then(setRigs)
+            .catch((e) => console.error('Failed to load fleet rigs:', e));
+    }, []);
+
+    useEffect(() => {
+        loadRigs();
+        const interval = setInterval(loadRigs, REFRESH_INTERVAL_MS);
+        return () => clearInterval(interval);
+    }, [loadRigs, enrolled]);
+
+    const sendCommand = async (rigId: string, command: FleetCommand) => {
+        try {
+            await invoke('send_fleet_command', { rigId, command });
+        } catch (e) {
+            addToast({ title: t('fleet.rigs.command-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const handleRemove = async (rigId: string) => {
+        try {
+            await invoke('remove_fleet_rig', { rigId });
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('fleet.rigs.remove-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroupTitle>
+                <Typography variant="h6">{t('fleet.rigs.title')}</Typography>
+            </SettingsGroupTitle>
+            {enrolled.length === 0 ? (
+                <Typography variant="p" style={{ opacity: 0.5 }}>
+                    {t('fleet.rigs.empty')}
+                </Typography>
+            ) : (
+                enrolled.map((rig) => {
+                    const view = rigs.find((r) => r.rig_id === rig.rig_id);
+                    const status = view?.status;
+                    return (
+                        <Stack
+                            key={rig.rig_id}
+                            direction="column"
+                            gap={4}
+                            style={{ width: '100%', padding: '6px 0', borderBottom: '1px solid rgba(128,128,128,0.1)' }}
+                        >
+                            <Stack direction="row" justifyContent="space-between" alignItems="center">
+                                <Typography variant="p" style={{ fontWeight: 600 }}>
+                                    {rig.name || rig.rig_id}
+                                </Typography>
+                                <Typography variant="p" style={{ opacity: 0.7, fontSize: 11 }}>
+                                    {view ? t('fleet.rigs.connected') : t('fleet.rigs.offline')}
+                                </Typography>
+                            </Stack>
+                            {status && (
+                                <Typography variant="p" style={{ opacity: 0.7, fontSize: 11 }}>
+                                    {t('fleet.rigs.status', {
+                                        version: status.app_version,
+                                        mode: status.mining.mode,
+                                        cpu: status.mining.cpu_mining ? status.mining.cpu_hash_rate.toFixed(0) : '-',
+                                        gpu: status.mining.gpu_mining ? status.mining.gpu_hash_rate.toFixed(0) : '-',
+                                        height: status.node.block_height,
+                                    })}
+                                </Typography>
+                            )}
+                            <Stack direction="row" gap={6} style={{ flexWrap: 'wrap' }}>
+                                {view && (
+                                    <>
+                                        <Button
+                                            size="smaller"
+                                            onClick={() => sendCommand(rig.rig_id, { action: 'start_mining' })}
+                                        >
+                                            {t('fleet.rigs.start')}
+                                        </Button>
+                                        <Button
+                                            size="smaller"
+                                            onClick={() => sendCommand(rig.rig_id, { action: 'stop_mining' })}
+                                        >
+                                            {t('fleet.rigs.stop')}
+                                        </Button>
+                                        {Object.keys(miningModes ?? {}).map((mode) => (
+                                            <Button
+                                                key={mode}
+                                                size="smaller"
+                                                variant="outlined"
+                                                disabled={status?.mining.mode === mode}
+                                                onClick={() =>
+                                                    sendCommand(rig.rig_id, { action: 'set_mining_mode', mode })
+                                                }
+                                            >
+                                                {mode}
+                                            </Button>
+                                        ))}
+                                    </>
+                                )}
+                                <Button size="smaller" variant="outlined" onClick={() => handleRemove(rig.rig_id)}>
+                                    {t('fleet.rigs.remove')}
+                                </Button>
+                            </Stack>
+                        </Stack>
+                    );
+                })
+            )}
+        </SettingsGroupWrapper>
+    );
+}
@@ -0,0 +1,91 @@
+import { useEffect, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Button } from '@app/components/elements/buttons/Button';
+import { Select, SelectOption } from '@app/components/elements/inputs/Select';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { useCopyToClipboard } from '@app/hooks/helpers/useCopyToClipboard';
+import { useConfigCoreStore } from '@app/store/stores/config/useConfigCoreStore';
+import { FleetRole } from '@app/types/config/fleet';
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles';
+
+interface FleetRoleSettingsProps {
+    role: FleetRole;
+    onChanged: () => void;
+}
+
+export default function FleetRoleSettings({ role, onChanged }: FleetRoleSettingsProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const { copyToClipboard, isCopied } = useCopyToClipboard();
+    const rigId = useConfigCoreStore((s) => s.anon_id);
+    const [identity, setIdentity] = useState('');
+
+    useEffect(() => {
+        invoke('get_fleet_identity')
+            .then(setIdentity)
+            .catch((e) => console.error('Failed to load fleet identity:', e));
+    }, []);
+
+    const options: SelectOption[] = useMemo(
+        () => [
+            { label: t('fleet.role.disabled'), value: 'disabled' },
+            { label: t('fleet.role.rig'), value: 'rig' },
+            { label: t('fleet.role.controller'), value: 'controller' },
+        ],
+        [t]
+    );
+
+    const handleChange = async (value: string) => {
+        try {
+            await invoke('set_fleet_role', { role: value as FleetRole });
+        } catch (e) {
+            addToast({ title: t('fleet.role.error'), text: String(e), type: 'error' });
+        }
+        onChanged();
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('fleet.role.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography variant="p">{t('fleet.role.description')}</Typography>
+                </SettingsGroupContent>
+                <SettingsGroupAction style={{ minWidth: 160 }}>
+                    <Select
+                        onChange={handleChange}
+                        forceHeight={36}
+                        selectedValue={role}
+                        options={options}
+                        variant="bordered"
+                    />
+                </SettingsGroupAction>
+            </SettingsGroup>
+            {identity && (
+                <Stack direction="row" gap={8} alignItems="center" style={{ width: '100%' }}>
+                    <Stack direction="column" gap={2} style={{ flex: 1 }}>
+                        <Typography variant="p" style={{ opacity: 0.7, fontSize: 11 }}>
+                            {t('fleet.role.rig-id', { id: rigId })}
+                        </Typography>
+                        <Typography variant="p" style={{ opacity: 0.7, fontSize: 11, wordBreak: 'break-all' }}>
+                            {t('fleet.role.identity', { key: identity })}
+                        </Typography>
+                    </Stack>
+                    <Button size="smaller" onClick={() => copyToClipboard(`${rigId} ${identity}`)}>
+                        {isCopied ? t('fleet.copied') : t('fleet.copy')}
+                    </Button>
+                </Stack>
+            )}
+        </SettingsGroupWrapper>
+    );
+}
@@ -0,0 +1,65 @@
+/**
+ * @vitest-environment jsdom
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { render, screen } from '@app/test/test-utils';
+import { ConfigFleet } from '@app/types/config/fleet';
+import { FleetSettings } from './FleetSettings';
+
+const baseConfig: ConfigFleet = {
+    created_at: '2026-01-01T00:00:00Z',
+    role: 'disabled',
+    controller_url: null,
+    controller_pub_key: null,
+    listen_address: '127.0.0.1:18190',
+    rigs: [],
+};
+
+function mockInvoke(config: ConfigFleet) {
+    vi.mocked(invoke).mockImplementation(async (cmd: string) => {
+        switch (cmd) {
+            case 'get_fleet_config':
+                return config;
+            case 'get_fleet_identity':
+                return 'abcd';
+            default:
+                return [];
+        }
+    });
+}
+
+describe('FleetSettings', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+    });
+
+    it('shows only the role selector when fleet mode is disabled', async () => {
+        mockInvoke(baseConfig);
+        render(<FleetSettings />);
+        expect(await screen.findByText('fleet.role.title')).toBeInTheDocument();
+        expect(screen.queryByText('fleet.rig.title')).not.toBeInTheDocument();
+        expect(screen.queryByText('fleet.controller.title')).not.toBeInTheDocument();
+        expect(screen.queryByText('fleet.audit-log.title')).not.toBeInTheDocument();
+    });
+
+    it('shows controller connection settings for a rig', async () => {
+        mockInvoke({ ...baseConfig, role: 'rig', controller_url: 'ws://controller.lan:18190' });
+        render(<FleetSettings />);
+        expect(await screen.findByText('fleet.rig.title')).toBeInTheDocument();
+        expect(screen.getByDisplayValue('ws://controller.lan:18190')).toBeInTheDocument();
+        expect(screen.getByText('fleet.audit-log.title')).toBeInTheDocument();
+    });
+
+    it('lists enrolled rigs for a controller', async () => {
+        mockInvoke({
+            ...baseConfig,
+            role: 'controller',
+            rigs: [{ rig_id: 'rig-1', name: 'Basement', pub_key: 'abcd' }],
+        });
+        render(<FleetSettings />);
+        expect(await screen.findByText('fleet.controller.title')).toBeInTheDocument();
+        expect(screen.getByText('Basement')).toBeInTheDocument();
+        expect(screen.getByText('fleet.rigs.offline')).toBeInTheDocument();
+    });
+});
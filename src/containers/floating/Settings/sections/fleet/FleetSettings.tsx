@@ -0,0 +1,32 @@
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { ConfigFleet } from '@app/types/config/fleet';
+import FleetRoleSettings from './FleetRoleSettings';
+import RigSettings from './RigSettings';
+import ControllerSettings from './ControllerSettings';
+import FleetAuditLog from './FleetAuditLog';
+
+export const FleetSettings = () => {
+    const [config, setConfig] = useState<ConfigFleet>();
+
+    const loadConfig = useCallback(() => {
+        invoke('get_fleet_config')
+            .then(setConfig)
+            .catch((e) => console.error('Failed to load fleet config:', e));
+    }, []);
+
+    useEffect(() => {
+        loadConfig();
+    }, [loadConfig]);
+
+    if (!config) return null;
+
+    return (
+        <>
+            <FleetRoleSettings role={config.role} onChanged={loadConfig} />
+            {config.role === 'rig' && <RigSettings config={config} onChanged={loadConfig} />}
+            {config.role === 'controller' && <ControllerSettings config={config} onChanged={loadConfig} />}
+            {config.role !== 'disabled' && <FleetAuditLog />}
+        </>
+    );
+};
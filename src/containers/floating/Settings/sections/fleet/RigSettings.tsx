@@ -0,0 +1,78 @@
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Button } from '@app/components/elements/buttons/Button';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { ConfigFleet } from '@app/types/config/fleet';
+import { SettingsGroupContent, SettingsGroupTitle, SettingsGroupWrapper } from '../../components/SettingsGroup.styles';
+
+const isValidControllerUrl = (url: string) => /^wss?:\/\/.+/.test(url.trim());
+
+interface RigSettingsProps {
+    config: ConfigFleet;
+    onChanged: () => void;
+}
+
+export default function RigSettings({ config, onChanged }: RigSettingsProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const [controllerUrl, setControllerUrl] = useState(config.controller_url ?? '');
+    const [controllerPubKey, setControllerPubKey] = useState(config.controller_pub_key ?? '');
+    const [saving, setSaving] = useState(false);
+
+    useEffect(() => {
+        setControllerUrl(config.controller_url ?? '');
+        setControllerPubKey(config.controller_pub_key ?? '');
+    }, [config.controller_url, config.controller_pub_key]);
+
+    const canSave = isValidControllerUrl(controllerUrl) && controllerPubKey.trim().length > 0;
+
+    const handleSave = async () => {
+        setSaving(true);
+        try {
+            await invoke('set_fleet_controller', {
+                controllerUrl: controllerUrl.trim(),
+                controllerPubKey: controllerPubKey.trim(),
+            });
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('fleet.rig.error'), text: String(e), type: 'error' });
+        } finally {
+            setSaving(false);
+        }
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroupContent>
+                <SettingsGroupTitle>
+                    <Typography variant="h6">{t('fleet.rig.title')}</Typography>
+                </SettingsGroupTitle>
+                <Typography variant="p">{t('fleet.rig.description')}</Typography>
+            </SettingsGroupContent>
+            <Stack direction="column" gap={8} style={{ width: '100%' }}>
+                <Input
+                    name="fleet-controller-url"
+                    labelText={t('fleet.rig.controller-url')}
+                    placeholder="wss://controller.lan:18190"
+                    value={controllerUrl}
+                    hasError={controllerUrl.length > 0 && !isValidControllerUrl(controllerUrl)}
+                    onChange={(e) => setControllerUrl(e.target.value)}
+                />
+                <Input
+                    name="fleet-controller-pub-key"
+                    labelText={t('fleet.rig.controller-pub-key')}
+                    value={controllerPubKey}
+                    onChange={(e) => setControllerPubKey(e.target.value)}
+                />
+                <Stack direction="row" justifyContent="flex-end">
+                    <Button size="smaller" onClick={handleSave} disabled={!canSave || saving}>
+                        {t('save')}
+                    </Button>
+                </Stack>
+            </Stack>
+        </SettingsGroupWrapper>
+    );
+}
@@ -2,6 +2,7 @@ import { SettingsGroupWrapper } from '@app/containers/floating/Settings/componen
 import AppDataSettings from './AppDataSettings';
 import ThemeSettings from './ThemeSettings';
 import AirdropPermissionSettings from './AirdropPermissionSettings.tsx';
+import TelemetrySettings from './TelemetrySettings.tsx';
 import LogsSettings from './LogsSettings.tsx';
 import LanguageSettings from './LanguageSettings.tsx';
 import { ResetSettingsButton } from './ResetSettingsButton.tsx';
@@ -21,6 +22,7 @@ export const GeneralSettings = () => {
             <PreReleaseSettings />
             <TaskTrayModeSettings />
             <AirdropPermissionSettings />
+            <TelemetrySettings />
             <AirdropNotificationsSettings />
             <LanguageSettings />
             <ThemeSettings />
@@ -0,0 +1,175 @@
+import { useCallback, useEffect, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+
+import { Typography } from '@app/components/elements/Typography.tsx';
+import { Stack } from '@app/components/elements/Stack.tsx';
+import { Input } from '@app/components/elements/inputs/Input.tsx';
+import { Button } from '@app/components/elements/buttons/Button.tsx';
+import { Select, SelectOption } from '@app/components/elements/inputs/Select.tsx';
+import { setTelemetryRedactedFields, setTelemetrySink } from '@app/store/actions/config/core.ts';
+import { useConfigCoreStore } from '@app/store/stores/config/useConfigCoreStore.ts';
+import { TelemetryInspection, TelemetrySink } from '@app/types/config/core.ts';
+
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles.ts';
+
+type SinkMode = TelemetrySink['mode'];
+
+const formatValue = (value: unknown) => (typeof value === 'string' ? value : JSON.stringify(value));
+
+export default function TelemetrySettings() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const allowTelemetry = useConfigCoreStore((s) => s.allow_telemetry);
+    const sink = useConfigCoreStore((s) => s.telemetry_sink);
+    const redactedFields = useConfigCoreStore((s) => s.telemetry_redacted_fields);
+
+    const [selectedMode, setSelectedMode] = useState<SinkMode>(sink?.mode ?? 'vendor');
+    const [target, setTarget] = useState('');
+    const [inspection, setInspection] = useState<TelemetryInspection>();
+
+    useEffect(() => {
+        setSelectedMode(sink?.mode ?? 'vendor');
+        if (sink?.mode === 'http') setTarget(sink.url);
+        if (sink?.mode === 'file') setTarget(sink.path);
+    }, [sink]);
+
+    const options: SelectOption[] = useMemo(
+        () => [
+            { label: t('telemetry.sink.vendor'), value: 'vendor' },
+            { label: t('telemetry.sink.http'), value: 'http' },
+            { label: t('telemetry.sink.file'), value: 'file' },
+        ],
+        [t]
+    );
+
+    const handleModeChange = useCallback(async (value: string) => {
+        const mode = value as SinkMode;
+        setSelectedMode(mode);
+        setTarget('');
+        if (mode === 'vendor') {
+            await setTelemetrySink({ mode });
+        }
+    }, []);
+
+    const onSave = useCallback(async () => {
+        const trimmed = target.trim();
+        await setTelemetrySink(
+            selectedMode === 'http' ? { mode: 'http', url: trimmed } : { mode: 'file', path: trimmed }
+        );
+    }, [selectedMode, target]);
+
+    const loadInspection = useCallback(() => {
+        invoke('inspect_telemetry_data')
+            .then(setInspection)
+            .catch((e) => console.error('Failed to inspect telemetry data:', e));
+    }, []);
+
+    const toggleRedaction = useCallback(
+        async (field: string) => {
+            const current = redactedFields ?? [];
+            const next = current.includes(field) ? current.filter((f) => f !== field) : [...current, field];
+            await setTelemetryRedactedFields(next);
+            loadInspection();
+        },
+        [redactedFields, loadInspection]
+    );
+
+    const isTargetValid =
+        selectedMode === 'http' ? /^https?:\/\/.+/.test(target.trim()) : target.trim().length > 0;
+
+    if (!allowTelemetry) return null;
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('telemetry.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography>{t('telemetry.description')}</Typography>
+                </SettingsGroupContent>
+                <SettingsGroupAction>
+                    <Stack style={{ width: '100%', minWidth: 160 }}>
+                        <Select
+                            onChange={handleModeChange}
+                            forceHeight={36}
+                            selectedValue={selectedMode}
+                            options={options}
+                            variant="bordered"
+                        />
+                    </Stack>
+                </SettingsGroupAction>
+            </SettingsGroup>
+            {selectedMode !== 'vendor' && (
+                <Stack direction="row" gap={8} alignItems="flex-end" style={{ width: '100%' }}>
+                    <Input
+                        name="telemetry-sink-target"
+                        labelText={selectedMode === 'http' ? t('telemetry.sink.url') : t('telemetry.sink.path')}
+                        value={target}
+                        placeholder={
+                            selectedMode === 'http'
+                                ? 'https://collector.example/telemetry'
+                                : '/var/log/tari/telemetry.jsonl'
+                        }
+                        hasError={target.length > 0 && !isTargetValid}
+                        onChange={({ target }) => setTarget(target.value)}
+                    />
+                    <Button size="smaller" onClick={onSave} disabled={!isTargetValid}>
+                        {t('save')}
+                    </Button>
+                </Stack>
+            )}
+            <Stack direction="row" justifyContent="space-between" alignItems="center" style={{ width: '100%' }}>
+                <Typography variant="p" style={{ opacity: 0.7 }}>
+                    {inspection
+                        ? t('telemetry.spool', {
+                              records: inspection.spool.records,
+                              size: Math.round(inspection.spool.bytes / 1024),
+                              max: Math.round(inspection.spool.max_bytes / 1024),
+                          })
+                        : t('telemetry.inspect-description')}
+                </Typography>
+                <Button size="smaller" onClick={loadInspection}>
+                    {inspection ? t('telemetry.refresh') : t('telemetry.inspect')}
+                </Button>
+            </Stack>
+            {inspection && (
+                <div style={{ maxHeight: 240, overflowY: 'auto', fontSize: 11, width: '100%' }}>
+                    {inspection.fields.map((field) => (
+                        <div
+                            key={field.field}
+                            style={{
+                                display: 'flex',
+                                gap: 8,
+                                alignItems: 'center',
+                                padding: '2px 0',
+                                borderBottom: '1px solid rgba(128,128,128,0.1)',
+                                opacity: field.redacted ? 0.5 : 1,
+                            }}
+                        >
+                            <span style={{ minWidth: 120, fontWeight: 600 }}>{field.field}</span>
+                            <span
+                                style={{
+                                    flex: 1,
+                                    wordBreak: 'break-all',
+                                    textDecoration: field.redacted ? 'line-through' : 'none',
+                                }}
+                            >
+                                {formatValue(field.value)}
+                            </span>
+                            <Button size="xs" variant="outlined" onClick={() => toggleRedaction(field.field)}>
+                                {field.redacted ? t('telemetry.include') : t('telemetry.redact')}
+                            </Button>
+                        </div>
+                    ))}
+                </div>
+            )}
+        </SettingsGroupWrapper>
+    );
+}
@@ -1,9 +1,11 @@
 import { AirdropSettings } from './airdrop/AirdropSettings.tsx';
 import { ConnectionsSettings } from './connections/ConnectionsSettings.tsx';
 import { ExperimentalSettings } from './experimental/ExperimentalSettings.tsx';
+import { FleetSettings } from './fleet/FleetSettings.tsx';
 import { GeneralSettings } from './general/GeneralSettings.tsx';
 import { McpSettings } from './mcp/McpSettings.tsx';
 import { MiningSettings } from './mining/MiningSettings.tsx';
+import { NotificationSettings } from './notifications/NotificationSettings.tsx';
 
 import { WalletSettings } from './wallet/WalletSettings.tsx';
 import { ReleaseNotes } from './releaseNotes/ReleaseNotes.tsx';
@@ -12,9 +14,11 @@ export {
     AirdropSettings,
     ConnectionsSettings,
     ExperimentalSettings,
+    FleetSettings,
     GeneralSettings,
     McpSettings,
     MiningSettings,
+    NotificationSettings,
     WalletSettings,
     ReleaseNotes,
 };
@@ -83,7 +83,10 @@ export default function AuditLog() {
                                     <span style={{ color: tierColors[entry.tier] || '#888', minWidth: 70 }}>
                                         [{entry.tier}]
                                     </span>
-                                    <span style={{ flex: 1 }}>{entry.tool_name}</span>
+                                    <span style={{ flex: 1 }} title={entry.details}>
+                                        {entry.tool_name}
+                                    </span>
+                                    {entry.client_info && <span style={{ opacity: 0.7 }}>{entry.client_info}</span>}
                                     {entry.duration_ms != null && (
                                         <span style={{ opacity: 0.5 }}>
                                             {t('mcp.audit-log.duration-ms', { ms: entry.duration_ms })}
@@ -0,0 +1,49 @@
+/**
+ * @vitest-environment jsdom
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { render, screen } from '@app/test/test-utils';
+import { useConfigMcpStore } from '@app/store/useAppConfigStore';
+import LocalSocket from './LocalSocket';
+
+const bridge = {
+    socket_path: '/home/miner/.config/com.tari.universe.alpha/mcp/mcp.sock',
+    command: '/opt/tari-universe/tari-universe',
+    args: ['mcp-stdio'],
+};
+
+describe('LocalSocket', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+        vi.mocked(invoke).mockResolvedValue(bridge);
+        useConfigMcpStore.setState({ enabled: true, unix_socket_enabled: false });
+    });
+
+    it('returns null when MCP is disabled', () => {
+        useConfigMcpStore.setState({ enabled: false });
+        const { container } = render(<LocalSocket />);
+        expect(container.firstChild).toBeNull();
+    });
+
+    it('stays hidden where the local socket is unsupported', async () => {
+        vi.mocked(invoke).mockResolvedValue(null);
+        const { container } = render(<LocalSocket />);
+        await vi.waitFor(() => expect(invoke).toHaveBeenCalledWith('get_mcp_stdio_bridge'));
+        expect(container.firstChild).toBeNull();
+    });
+
+    it('shows the toggle without the bridge config while disabled', async () => {
+        render(<LocalSocket />);
+        expect(await screen.findByText('mcp.local-socket.title')).toBeInTheDocument();
+        expect(screen.getByRole('checkbox')).not.toBeChecked();
+        expect(screen.queryByText('mcp.local-socket.copy')).not.toBeInTheDocument();
+    });
+
+    it('shows the stdio bridge config when enabled', async () => {
+        useConfigMcpStore.setState({ unix_socket_enabled: true });
+        render(<LocalSocket />);
+        expect(await screen.findByText(/"mcp-stdio"/)).toBeInTheDocument();
+        expect(screen.getByText('mcp.local-socket.copy')).toBeInTheDocument();
+    });
+});
@@ -0,0 +1,95 @@
+import { useEffect, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { Typography } from '@app/components/elements/Typography';
+import { ToggleSwitch } from '@app/components/elements/inputs/switch/ToggleSwitch';
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles';
+import { useConfigMcpStore } from '@app/store/useAppConfigStore';
+import { invoke } from '@tauri-apps/api/core';
+import { writeText } from '@tauri-apps/plugin-clipboard-manager';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { McpStdioBridge } from '@app/types/configs';
+
+export default function LocalSocket() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const enabled = useConfigMcpStore((s) => s.enabled);
+    const socketEnabled = useConfigMcpStore((s) => s.unix_socket_enabled);
+    const [bridge, setBridge] = useState<McpStdioBridge | null>(null);
+    const [copied, setCopied] = useState(false);
+
+    useEffect(() => {
+        invoke<McpStdioBridge | null>('get_mcp_stdio_bridge')
+            .then(setBridge)
+            .catch((e) => console.error('Failed to load MCP stdio bridge:', e));
+    }, []);
+
+    const snippet = useMemo(
+        () =>
+            bridge
+                ? JSON.stringify(
+                      { mcpServers: { 'tari-universe': { command: bridge.command, args: bridge.args } } },
+                      null,
+                      2
+                  )
+                : '',
+        [bridge]
+    );
+
+    // The bridge is null on platforms without Unix domain sockets
+    if (!enabled || !bridge) return null;
+
+    const handleToggle = async (checked: boolean) => {
+        try {
+            await invoke('set_mcp_unix_socket_enabled', { enabled: checked });
+        } catch (e) {
+            addToast({ title: t('mcp.local-socket.error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const handleCopy = async () => {
+        try {
+            await writeText(snippet);
+            setCopied(true);
+            setTimeout(() => setCopied(false), 2000);
+        } catch (e) {
+            console.error('Failed to copy MCP bridge configuration:', e);
+        }
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('mcp.local-socket.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography variant="p">{t('mcp.local-socket.description')}</Typography>
+                </SettingsGroupContent>
+                <SettingsGroupAction>
+                    <ToggleSwitch checked={socketEnabled} onChange={(e) => handleToggle(e.target.checked)} />
+                </SettingsGroupAction>
+            </SettingsGroup>
+            {socketEnabled && (
+                <div style={{ display: 'flex', flexDirection: 'column', gap: 6, marginTop: 8 }}>
+                    <Typography variant="p" style={{ fontSize: 11, opacity: 0.7 }}>
+                        {t('mcp.local-socket.socket-path', { path: bridge.socket_path })}
+                    </Typography>
+                    <Typography variant="p" style={{ fontSize: 11, opacity: 0.7 }}>
+                        {t('mcp.local-socket.bridge-description')}
+                    </Typography>
+                    <pre style={{ fontSize: 11, margin: 0, whiteSpace: 'pre-wrap', wordBreak: 'break-all' }}>
+                        <code>{snippet}</code>
+                    </pre>
+                    <button onClick={handleCopy} style={{ fontSize: 11, cursor: 'pointer', alignSelf: 'flex-start' }}>
+                        {copied ? t('mcp.local-socket.copied') : t('mcp.local-socket.copy')}
+                    </button>
+                </div>
+            )}
+        </SettingsGroupWrapper>
+    );
+}
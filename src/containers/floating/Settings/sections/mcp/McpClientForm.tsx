@@ -0,0 +1,180 @@
+import { useTranslation } from 'react-i18next';
+import { Typography } from '@app/components/elements/Typography';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Checkbox } from '@app/components/elements/inputs/Checkbox';
+import { invoke } from '@tauri-apps/api/core';
+import { useEffect, useState } from 'react';
+import { requestPin } from '@app/store/useSecurityStore';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { McpClient, McpClientSettings } from '@app/types/configs';
+
+export const MICRO_PER_XTM = 1_000_000;
+const SECONDS_PER_DAY = 24 * 60 * 60;
+
+const toXtmInput = (micro?: number | null) => (micro != null ? String(micro / MICRO_PER_XTM) : '');
+
+const parseXtm = (value: string) => {
+    const trimmed = value.trim();
+    if (!trimmed) return null;
+    const parsed = Number(trimmed);
+    return Number.isFinite(parsed) && parsed > 0 ? Math.round(parsed * MICRO_PER_XTM) : NaN;
+};
+
+const remainingDays = (client?: McpClient) => {
+    if (!client) return '30';
+    if (!client.expires_at) return '';
+    const secs = client.expires_at.secs_since_epoch - Date.now() / 1000;
+    return String(Math.max(1, Math.ceil(secs / SECONDS_PER_DAY)));
+};
+
+interface McpClientFormProps {
+    client?: McpClient;
+    onCancel: () => void;
+    onSaved: (token?: string) => void;
+}
+
+export default function McpClientForm({ client, onCancel, onSaved }: McpClientFormProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const [tools, setTools] = useState<string[]>([]);
+    const [name, setName] = useState(client?.name ?? '');
+    const [readTier, setReadTier] = useState(client?.read_tier_enabled ?? true);
+    const [controlTier, setControlTier] = useState(client?.control_tier_enabled ?? false);
+    const [transactionTier, setTransactionTier] = useState(client?.transactions_enabled ?? false);
+    const [allowedTools, setAllowedTools] = useState<string[]>(client?.allowed_tools ?? []);
+    const [maxAmount, setMaxAmount] = useState(toXtmInput(client?.max_transaction_amount));
+    const [dailyLimit, setDailyLimit] = useState(toXtmInput(client?.daily_spend_limit));
+    const [expiryDays, setExpiryDays] = useState(remainingDays(client));
+    const [saving, setSaving] = useState(false);
+
+    useEffect(() => {
+        invoke<string[]>('list_mcp_tools')
+            .then(setTools)
+            .catch((e) => console.error('Failed to list MCP tools:', e));
+    }, []);
+
+    const toggleTool = (tool: string, checked: boolean) =>
+        setAllowedTools((current) => (checked ? [...current, tool] : current.filter((entry) => entry !== tool)));
+
+    const maxAmountMicro = parseXtm(maxAmount);
+    const dailyLimitMicro = parseXtm(dailyLimit);
+    const expiry = expiryDays.trim() ? Number(expiryDays) : null;
+    const isExpiryValid = expiry === null || (Number.isInteger(expiry) && expiry > 0);
+    const isValid =
+        name.trim().length > 0 &&
+        (readTier || controlTier || transactionTier) &&
+        !Number.isNaN(maxAmountMicro) &&
+        !Number.isNaN(dailyLimitMicro) &&
+        isExpiryValid;
+
+    const handleSave = async () => {
+        const settings: McpClientSettings = {
+            name: name.trim(),
+            read_tier_enabled: readTier,
+            control_tier_enabled: controlTier,
+            transactions_enabled: transactionTier,
+            allowed_tools: allowedTools,
+            max_transaction_amount: transactionTier ? maxAmountMicro : null,
+            daily_spend_limit: transactionTier ? dailyLimitMicro : null,
+            expiry_days: expiry,
+        };
+        let pin: string | undefined;
+        if (transactionTier) {
+            pin = await requestPin();
+            if (!pin) return;
+        }
+        setSaving(true);
+        try {
+            if (client) {
+                await invoke('update_mcp_client', { id: client.id, settings, pin: pin ?? null });
+                onSaved();
+            } else {
+                const token = await invoke<string>('create_mcp_client', { settings, pin: pin ?? null });
+                onSaved(token);
+            }
+        } catch (e) {
+            addToast({ title: t('mcp.clients.save-error'), text: String(e), type: 'error' });
+        } finally {
+            setSaving(false);
+        }
+    };
+
+    return (
+        <div style={{ display: 'flex', flexDirection: 'column', gap: 8, marginTop: 8 }}>
+            <Typography variant="p" style={{ fontWeight: 600 }}>
+                {client ? t('mcp.clients.edit-title') : t('mcp.clients.add-title')}
+            </Typography>
+            <Input
+                name="mcp-client-name"
+                labelText={t('mcp.clients.name')}
+                placeholder={t('mcp.clients.name-placeholder')}
+                value={name}
+                onChange={(e) => setName(e.target.value)}
+            />
+            <Checkbox
+                id="mcp-client-read-tier"
+                checked={readTier}
+                handleChange={setReadTier}
+                labelText={t('mcp.advanced.read-tier')}
+            />
+            <Checkbox
+                id="mcp-client-control-tier"
+                checked={controlTier}
+                handleChange={setControlTier}
+                labelText={t('mcp.advanced.control-tier')}
+            />
+            <Checkbox
+                id="mcp-client-transaction-tier"
+                checked={transactionTier}
+                handleChange={setTransactionTier}
+                labelText={t('mcp.clients.transaction-tier')}
+            />
+            {transactionTier && (
+                <>
+                    <Input
+                        name="mcp-client-max-amount"
+                        labelText={t('mcp.clients.max-amount')}
+                        value={maxAmount}
+                        hasError={Number.isNaN(maxAmountMicro)}
+                        onChange={(e) => setMaxAmount(e.target.value)}
+                    />
+                    <Input
+                        name="mcp-client-daily-limit"
+                        labelText={t('mcp.clients.daily-limit')}
+                        value={dailyLimit}
+                        hasError={Number.isNaN(dailyLimitMicro)}
+                        onChange={(e) => setDailyLimit(e.target.value)}
+                    />
+                </>
+            )}
+            <Input
+                name="mcp-client-expiry"
+                labelText={t('mcp.clients.expiry-days')}
+                value={expiryDays}
+                hasError={!isExpiryValid}
+                onChange={(e) => setExpiryDays(e.target.value)}
+            />
+            <Typography variant="p" style={{ fontSize: 11, opacity: 0.7 }}>
+                {t('mcp.clients.tools-hint')}
+            </Typography>
+            <div style={{ display: 'flex', flexDirection: 'column', gap: 4, maxHeight: 180, overflowY: 'auto' }}>
+                {tools.map((tool) => (
+                    <Checkbox
+                        key={tool}
+                        id={`mcp-client-tool-${tool}`}
+                        checked={allowedTools.includes(tool)}
+                        handleChange={(checked) => toggleTool(tool, checked)}
+                        labelText={tool}
+                    />
+                ))}
+            </div>
+            <div style={{ display: 'flex', gap: 8, justifyContent: 'flex-end' }}>
+                <button onClick={onCancel} style={{ fontSize: 11, cursor: 'pointer' }}>
+                    {t('cancel')}
+                </button>
+                <button onClick={handleSave} disabled={!isValid || saving} style={{ fontSize: 11, cursor: 'pointer' }}>
+                    {t('save')}
+                </button>
+            </div>
+        </div>
+    );
+}
@@ -0,0 +1,60 @@
+/**
+ * @vitest-environment jsdom
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { fireEvent, render, screen } from '@app/test/test-utils';
+import { useConfigMcpStore } from '@app/store/useAppConfigStore';
+import { McpClient } from '@app/types/configs';
+import McpClients from './McpClients';
+
+const monitor: McpClient = {
+    id: 'c1',
+    name: 'Monitoring agent',
+    token_redacted: 'tu_••••••••',
+    created_at: { secs_since_epoch: 1767225600, nanos_since_epoch: 0 },
+    expires_at: null,
+    read_tier_enabled: true,
+    control_tier_enabled: false,
+    transactions_enabled: false,
+    allowed_tools: ['get_wallet_balance'],
+    max_transaction_amount: null,
+    daily_spend_limit: null,
+    spent_last_day: 0,
+};
+
+describe('McpClients', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+        vi.mocked(invoke).mockResolvedValue(['get_wallet_balance', 'start_mining']);
+        useConfigMcpStore.setState({ enabled: true, clients: [] });
+    });
+
+    it('returns null when MCP is disabled', () => {
+        useConfigMcpStore.setState({ enabled: false });
+        const { container } = render(<McpClients />);
+        expect(container.firstChild).toBeNull();
+    });
+
+    it('shows the empty state', () => {
+        render(<McpClients />);
+        expect(screen.getByText('mcp.clients.title')).toBeInTheDocument();
+        expect(screen.getByText('mcp.clients.empty')).toBeInTheDocument();
+    });
+
+    it('lists named clients with a redacted token', () => {
+        useConfigMcpStore.setState({ clients: [monitor] });
+        render(<McpClients />);
+        expect(screen.getByText('Monitoring agent')).toBeInTheDocument();
+        expect(screen.getByText('tu_••••••••')).toBeInTheDocument();
+        expect(screen.getByText('mcp.clients.revoke')).toBeInTheDocument();
+    });
+
+    it('opens the form with the available tools', async () => {
+        render(<McpClients />);
+        fireEvent.click(screen.getByText('mcp.clients.add'));
+        expect(screen.getByText('mcp.clients.add-title')).toBeInTheDocument();
+        expect(await screen.findByText('start_mining')).toBeInTheDocument();
+        expect(invoke).toHaveBeenCalledWith('list_mcp_tools');
+    });
+});
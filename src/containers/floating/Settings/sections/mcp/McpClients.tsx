@@ -0,0 +1,185 @@
+import { useTranslation } from 'react-i18next';
+import { Typography } from '@app/components/elements/Typography';
+import {
+    SettingsGroup,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles';
+import { useConfigMcpStore } from '@app/store/useAppConfigStore';
+import { useWalletStore } from '@app/store/useWalletStore';
+import { invoke } from '@tauri-apps/api/core';
+import { writeText } from '@tauri-apps/plugin-clipboard-manager';
+import { useCallback, useState } from 'react';
+import { requestPin } from '@app/store/useSecurityStore';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { McpClient } from '@app/types/configs';
+import McpClientForm, { MICRO_PER_XTM } from './McpClientForm';
+
+const formatXtm = (micro: number) => `${(micro / MICRO_PER_XTM).toLocaleString()} XTM`;
+
+export default function McpClients() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const enabled = useConfigMcpStore((s) => s.enabled);
+    const clients = useConfigMcpStore((s) => s.clients);
+    const isPinLocked = useWalletStore((s) => s.is_pin_locked);
+    const [editing, setEditing] = useState<McpClient | 'new' | null>(null);
+    const [newToken, setNewToken] = useState<string | null>(null);
+
+    const handleCopyToken = useCallback(
+        async (id: string) => {
+            let pin: string | undefined;
+            if (isPinLocked) {
+                pin = await requestPin();
+                if (!pin) return;
+            }
+            try {
+                const token = await invoke<string>('get_mcp_client_token', { id, pin: pin ?? null });
+                await writeText(token);
+                addToast({ title: t('mcp.clients.token-copied'), type: 'success' });
+            } catch (e) {
+                addToast({ title: t('mcp.clients.copy-error'), text: String(e), type: 'error' });
+            }
+        },
+        [isPinLocked, t]
+    );
+
+    const handleRevoke = useCallback(
+        async (client: McpClient) => {
+            if (!confirm(t('mcp.clients.revoke-confirm', { name: client.name }))) return;
+            try {
+                await invoke('revoke_mcp_client', { id: client.id });
+            } catch (e) {
+                addToast({ title: t('mcp.clients.revoke-error'), text: String(e), type: 'error' });
+            }
+        },
+        [t]
+    );
+
+    const handleSaved = useCallback((token?: string) => {
+        setEditing(null);
+        if (token) setNewToken(token);
+    }, []);
+
+    if (!enabled) return null;
+
+    const formatDate = (ts?: { secs_since_epoch: number } | null) =>
+        ts ? new Date(ts.secs_since_epoch * 1000).toLocaleDateString() : t('mcp.clients.never');
+
+    const tiersOf = (client: McpClient) =>
+        [
+            client.read_tier_enabled && t('mcp.clients.tier-read'),
+            client.control_tier_enabled && t('mcp.clients.tier-control'),
+            client.transactions_enabled && t('mcp.clients.tier-transaction'),
+        ]
+            .filter(Boolean)
+            .join(', ');
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('mcp.clients.title')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography variant="p">{t('mcp.clients.description')}</Typography>
+                    {newToken && (
+                        <div style={{ display: 'flex', flexDirection: 'column', gap: 4, marginTop: 4 }}>
+                            <Typography variant="p" style={{ fontSize: 11 }}>
+                                {t('mcp.clients.new-token')}
+                            </Typography>
+                            <code style={{ fontSize: 12, wordBreak: 'break-all' }}>{newToken}</code>
+                            <div style={{ display: 'flex', gap: 8 }}>
+                                <button onClick={() => writeText(newToken)} style={{ fontSize: 11, cursor: 'pointer' }}>
+                                    {t('mcp.token-display.copy')}
+                                </button>
+                                <button onClick={() => setNewToken(null)} style={{ fontSize: 11, cursor: 'pointer' }}>
+                                    {t('mcp.clients.dismiss')}
+                                </button>
+                            </div>
+                        </div>
+                    )}
+                    <div style={{ display: 'flex', flexDirection: 'column', marginTop: 4 }}>
+                        {clients.length === 0 ? (
+                            <Typography variant="p" style={{ opacity: 0.5 }}>
+                                {t('mcp.clients.empty')}
+                            </Typography>
+                        ) : (
+                            clients.map((client) => (
+                                <div
+                                    key={client.id}
+                                    style={{
+                                        display: 'flex',
+                                        flexDirection: 'column',
+                                        gap: 2,
+                                        padding: '4px 0',
+                                        borderBottom: '1px solid rgba(128,128,128,0.1)',
+                                    }}
+                                >
+                                    <div style={{ display: 'flex', justifyContent: 'space-between', gap: 8 }}>
+                                        <Typography variant="p" style={{ fontWeight: 600 }}>
+                                            {client.name}
+                                        </Typography>
+                                        <code style={{ fontSize: 11 }}>{client.token_redacted}</code>
+                                    </div>
+                                    <Typography variant="p" style={{ fontSize: 11, opacity: 0.7 }}>
+                                        {tiersOf(client)}
+                                        {' · '}
+                                        {client.allowed_tools.length > 0
+                                            ? t('mcp.clients.tool-count', { count: client.allowed_tools.length })
+                                            : t('mcp.clients.all-tools')}
+                                        {' · '}
+                                        {t('mcp.token-display.expires')}: {formatDate(client.expires_at)}
+                                    </Typography>
+                                    {client.transactions_enabled && client.daily_spend_limit != null && (
+                                        <Typography variant="p" style={{ fontSize: 11, opacity: 0.7 }}>
+                                            {t('mcp.clients.spent-today', {
+                                                spent: formatXtm(client.spent_last_day),
+                                                limit: formatXtm(client.daily_spend_limit),
+                                            })}
+                                        </Typography>
+                                    )}
+                                    <div style={{ display: 'flex', gap: 8 }}>
+                                        <button
+                                            onClick={() => handleCopyToken(client.id)}
+                                            style={{ fontSize: 11, cursor: 'pointer' }}
+                                        >
+                                            {t('mcp.clients.copy-token')}
+                                        </button>
+                                        <button
+                                            onClick={() => setEditing(client)}
+                                            style={{ fontSize: 11, cursor: 'pointer' }}
+                                        >
+                                            {t('mcp.clients.edit')}
+                                        </button>
+                                        <button
+                                            onClick={() => handleRevoke(client)}
+                                            style={{ fontSize: 11, cursor: 'pointer', color: '#e55' }}
+                                        >
+                                            {t('mcp.clients.revoke')}
+                                        </button>
+                                    </div>
+                                </div>
+                            ))
+                        )}
+                    </div>
+                    {editing ? (
+                        <McpClientForm
+                            key={editing === 'new' ? 'new' : editing.id}
+                            client={editing === 'new' ? undefined : editing}
+                            onCancel={() => setEditing(null)}
+                            onSaved={handleSaved}
+                        />
+                    ) : (
+                        <button
+                            onClick={() => setEditing('new')}
+                            style={{ fontSize: 11, cursor: 'pointer', marginTop: 4, alignSelf: 'flex-start' }}
+                        >
+                            {t('mcp.clients.add')}
+                        </button>
+                    )}
+                </SettingsGroupContent>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+}
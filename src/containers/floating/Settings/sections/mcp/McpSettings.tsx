@@ -1,6 +1,8 @@
 import ServerToggle from './ServerToggle';
 import TokenDisplay from './TokenDisplay';
+import McpClients from './McpClients';
 import AgentConfig from './AgentConfig';
+import LocalSocket from './LocalSocket';
 import TransactionSettings from './TransactionSettings';
 import AuditLog from './AuditLog';
 import AdvancedSettings from './AdvancedSettings';
@@ -10,7 +12,9 @@ export const McpSettings = () => {
         <>
             <ServerToggle />
             <TokenDisplay />
+            <McpClients />
             <AgentConfig />
+            <LocalSocket />
             <TransactionSettings />
             <AuditLog />
             <AdvancedSettings />
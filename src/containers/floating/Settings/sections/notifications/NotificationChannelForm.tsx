@@ -0,0 +1,292 @@
+import { useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Button } from '@app/components/elements/buttons/Button';
+import { Checkbox } from '@app/components/elements/inputs/Checkbox';
+import { Select, SelectOption } from '@app/components/elements/inputs/Select';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import {
+    NOTIFICATION_EVENT_TYPES,
+    NotificationChannel,
+    NotificationEventType,
+    NotificationTransport,
+    NotificationTransportType,
+    SmtpSecurity,
+} from '@app/types/config/notifications';
+import { SettingsGroupTitle, SettingsGroupWrapper } from '../../components/SettingsGroup.styles';
+
+const TRANSPORT_TYPES: NotificationTransportType[] = ['webhook', 'email', 'ntfy', 'matrix'];
+const SMTP_SECURITY: SmtpSecurity[] = ['start_tls', 'tls', 'none'];
+const TEMPLATE_PLACEHOLDERS = '{{event}}, {{timestamp}}, {{miner}}, {{block_height}}';
+
+function defaultTransport(type: NotificationTransportType): NotificationTransport {
+    switch (type) {
+        case 'webhook':
+            return { type, url: '', secret: null };
+        case 'email':
+            return {
+                type,
+                host: '',
+                port: 587,
+                security: 'start_tls',
+                username: null,
+                password: null,
+                from: '',
+                to: [],
+            };
+        case 'ntfy':
+            return { type, server_url: 'https://ntfy.sh', topic: '', token: null };
+        case 'matrix':
+            return { type, homeserver_url: '', room_id: '', access_token: '' };
+    }
+}
+
+const optional = (value: string) => value || null;
+
+interface NotificationChannelFormProps {
+    channel: NotificationChannel;
+    onCancel: () => void;
+    onSaved: () => void;
+}
+
+export default function NotificationChannelForm({ channel, onCancel, onSaved }: NotificationChannelFormProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const [draft, setDraft] = useState<NotificationChannel>(channel);
+    const [recipients, setRecipients] = useState(
+        channel.transport.type === 'email' ? channel.transport.to.join(', ') : ''
+    );
+
+    const typeOptions: SelectOption[] = useMemo(
+        () => TRANSPORT_TYPES.map((type) => ({ label: t(`notifications.types.${type}`), value: type })),
+        [t]
+    );
+    const securityOptions: SelectOption[] = useMemo(
+        () => SMTP_SECURITY.map((security) => ({ label: t(`notifications.security.${security}`), value: security })),
+        [t]
+    );
+
+    const transport = draft.transport;
+    const updateTransport = (changes: Partial<NotificationTransport>) =>
+        setDraft((d) => ({ ...d, transport: { ...d.transport, ...changes } as NotificationTransport }));
+
+    const handleTypeChange = (value: string) => {
+        setRecipients('');
+        setDraft((d) => ({ ...d, transport: defaultTransport(value as NotificationTransportType) }));
+    };
+
+    const toggleEvent = (event: NotificationEventType, checked: boolean) =>
+        setDraft((d) => ({
+            ...d,
+            events: checked ? [...d.events, event] : d.events.filter((e) => e !== event),
+        }));
+
+    const buildChannel = (): NotificationChannel => {
+        if (transport.type !== 'email') return draft;
+        const to = recipients
+            .split(',')
+            .map((r) => r.trim())
+            .filter(Boolean);
+        return { ...draft, transport: { ...transport, to } };
+    };
+
+    const handleSave = async () => {
+        try {
+            await invoke('save_notification_channel', { channel: buildChannel() });
+            onSaved();
+        } catch (e) {
+            addToast({ title: t('notifications.save-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const handleTest = async () => {
+        try {
+            await invoke('test_notification_channel', { channel: buildChannel() });
+            addToast({ title: t('notifications.test-sent'), type: 'success' });
+        } catch (e) {
+            addToast({ title: t('notifications.test-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroupTitle>
+                <Typography variant="h6">
+                    {channel.id ? t('notifications.form.edit-title') : t('notifications.form.add-title')}
+                </Typography>
+            </SettingsGroupTitle>
+            <Stack direction="column" gap={8} style={{ width: '100%' }}>
+                <Input
+                    name="notification-name"
+                    labelText={t('notifications.form.name')}
+                    value={draft.name}
+                    onChange={(e) => setDraft((d) => ({ ...d, name: e.target.value }))}
+                />
+                <Select
+                    onChange={handleTypeChange}
+                    forceHeight={36}
+                    selectedValue={transport.type}
+                    options={typeOptions}
+                    variant="bordered"
+                />
+                {transport.type === 'webhook' && (
+                    <>
+                        <Input
+                            name="notification-webhook-url"
+                            labelText={t('notifications.form.url')}
+                            placeholder="https://example.com/hooks/universe"
+                            value={transport.url}
+                            onChange={(e) => updateTransport({ url: e.target.value })}
+                        />
+                        <Input
+                            name="notification-webhook-secret"
+                            type="password"
+                            labelText={t('notifications.form.secret')}
+                            value={transport.secret ?? ''}
+                            onChange={(e) => updateTransport({ secret: optional(e.target.value) })}
+                        />
+                    </>
+                )}
+                {transport.type === 'email' && (
+                    <>
+                        <Stack direction="row" gap={8} style={{ width: '100%' }}>
+                            <Input
+                                name="notification-smtp-host"
+                                labelText={t('notifications.form.host')}
+                                value={transport.host}
+                                onChange={(e) => updateTransport({ host: e.target.value })}
+                            />
+                            <Input
+                                name="notification-smtp-port"
+                                labelText={t('notifications.form.port')}
+                                value={String(transport.port)}
+                                onChange={(e) => updateTransport({ port: Number(e.target.value) || 0 })}
+                            />
+                        </Stack>
+                        <Select
+                            onChange={(value) => updateTransport({ security: value as SmtpSecurity })}
+                            forceHeight={36}
+                            selectedValue={transport.security}
+                            options={securityOptions}
+                            variant="bordered"
+                        />
+                        <Input
+                            name="notification-smtp-username"
+                            labelText={t('notifications.form.username')}
+                            value={transport.username ?? ''}
+                            onChange={(e) => updateTransport({ username: optional(e.target.value) })}
+                        />
+                        <Input
+                            name="notification-smtp-password"
+                            type="password"
+                            labelText={t('notifications.form.password')}
+                            value={transport.password ?? ''}
+                            onChange={(e) => updateTransport({ password: optional(e.target.value) })}
+                        />
+                        <Input
+                            name="notification-smtp-from"
+                            labelText={t('notifications.form.from')}
+                            value={transport.from}
+                            onChange={(e) => updateTransport({ from: e.target.value })}
+                        />
+                        <Input
+                            name="notification-smtp-to"
+                            labelText={t('notifications.form.to')}
+                            placeholder="ops@example.com, me@example.com"
+                            value={recipients}
+                            onChange={(e) => setRecipients(e.target.value)}
+                        />
+                    </>
+                )}
+                {transport.type === 'ntfy' && (
+                    <>
+                        <Input
+                            name="notification-ntfy-server"
+                            labelText={t('notifications.form.server-url')}
+                            value={transport.server_url}
+                            onChange={(e) => updateTransport({ server_url: e.target.value })}
+                        />
+                        <Input
+                            name="notification-ntfy-topic"
+                            labelText={t('notifications.form.topic')}
+                            value={transport.topic}
+                            onChange={(e) => updateTransport({ topic: e.target.value })}
+                        />
+                        <Input
+                            name="notification-ntfy-token"
+                            type="password"
+                            labelText={t('notifications.form.token')}
+                            value={transport.token ?? ''}
+                            onChange={(e) => updateTransport({ token: optional(e.target.value) })}
+                        />
+                    </>
+                )}
+                {transport.type === 'matrix' && (
+                    <>
+                        <Input
+                            name="notification-matrix-homeserver"
+                            labelText={t('notifications.form.homeserver-url')}
+                            placeholder="https://matrix.org"
+                            value={transport.homeserver_url}
+                            onChange={(e) => updateTransport({ homeserver_url: e.target.value })}
+                        />
+                        <Input
+                            name="notification-matrix-room"
+                            labelText={t('notifications.form.room-id')}
+                            placeholder="!room:matrix.org"
+                            value={transport.room_id}
+                            onChange={(e) => updateTransport({ room_id: e.target.value })}
+                        />
+                        <Input
+                            name="notification-matrix-token"
+                            type="password"
+                            labelText={t('notifications.form.access-token')}
+                            value={transport.access_token}
+                            onChange={(e) => updateTransport({ access_token: e.target.value })}
+                        />
+                    </>
+                )}
+                <Typography variant="p" style={{ fontWeight: 600 }}>
+                    {t('notifications.form.events')}
+                </Typography>
+                {NOTIFICATION_EVENT_TYPES.map((event) => (
+                    <Checkbox
+                        key={event}
+                        id={`notification-event-${event}`}
+                        checked={draft.events.includes(event)}
+                        handleChange={(checked) => toggleEvent(event, checked)}
+                        labelText={t(`notifications.events.${event}`)}
+                    />
+                ))}
+                <Input
+                    name="notification-title-template"
+                    labelText={t('notifications.form.title-template')}
+                    value={draft.title_template ?? ''}
+                    onChange={(e) => setDraft((d) => ({ ...d, title_template: optional(e.target.value) }))}
+                />
+                <Input
+                    name="notification-body-template"
+                    labelText={t('notifications.form.body-template')}
+                    value={draft.body_template ?? ''}
+                    onChange={(e) => setDraft((d) => ({ ...d, body_template: optional(e.target.value) }))}
+                />
+                <Typography variant="p" style={{ opacity: 0.7, fontSize: 11 }}>
+                    {t('notifications.form.template-hint', { placeholders: TEMPLATE_PLACEHOLDERS })}
+                </Typography>
+                <Stack direction="row" justifyContent="flex-end" gap={8}>
+                    <Button size="smaller" onClick={onCancel}>
+                        {t('cancel')}
+                    </Button>
+                    <Button size="smaller" onClick={handleTest}>
+                        {t('notifications.test')}
+                    </Button>
+                    <Button size="smaller" onClick={handleSave} disabled={draft.events.length === 0}>
+                        {t('save')}
+                    </Button>
+                </Stack>
+            </Stack>
+        </SettingsGroupWrapper>
+    );
+}
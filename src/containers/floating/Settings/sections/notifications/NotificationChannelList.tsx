@@ -0,0 +1,105 @@
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Button } from '@app/components/elements/buttons/Button';
+import { ToggleSwitch } from '@app/components/elements/inputs/switch/ToggleSwitch';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { NotificationChannel } from '@app/types/config/notifications';
+import { SettingsGroupContent, SettingsGroupTitle, SettingsGroupWrapper } from '../../components/SettingsGroup.styles';
+
+interface NotificationChannelListProps {
+    channels: NotificationChannel[];
+    onAdd: () => void;
+    onEdit: (channel: NotificationChannel) => void;
+    onChanged: () => void;
+}
+
+export default function NotificationChannelList({ channels, onAdd, onEdit, onChanged }: NotificationChannelListProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+
+    const handleToggle = async (channel: NotificationChannel, enabled: boolean) => {
+        try {
+            await invoke('save_notification_channel', { channel: { ...channel, enabled } });
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('notifications.save-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const handleRemove = async (id: string) => {
+        try {
+            await invoke('remove_notification_channel', { id });
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('notifications.remove-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    const handleTest = async (channel: NotificationChannel) => {
+        try {
+            await invoke('test_notification_channel', { channel });
+            addToast({ title: t('notifications.test-sent'), type: 'success' });
+        } catch (e) {
+            addToast({ title: t('notifications.test-error'), text: String(e), type: 'error' });
+        }
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroupContent>
+                <SettingsGroupTitle>
+                    <Typography variant="h6">{t('notifications.title')}</Typography>
+                </SettingsGroupTitle>
+                <Typography variant="p">{t('notifications.description')}</Typography>
+            </SettingsGroupContent>
+            {channels.length === 0 ? (
+                <Typography variant="p" style={{ opacity: 0.5 }}>
+                    {t('notifications.empty')}
+                </Typography>
+            ) : (
+                channels.map((channel) => (
+                    <Stack
+                        key={channel.id}
+                        direction="row"
+                        justifyContent="space-between"
+                        alignItems="center"
+                        gap={8}
+                        style={{ width: '100%', padding: '6px 0', borderBottom: '1px solid rgba(128,128,128,0.1)' }}
+                    >
+                        <Stack direction="column" gap={2}>
+                            <Typography variant="p" style={{ fontWeight: 600 }}>
+                                {channel.name || channel.id}
+                            </Typography>
+                            <Typography variant="p" style={{ opacity: 0.7, fontSize: 11 }}>
+                                {t(`notifications.types.${channel.transport.type}`)}
+                                {' · '}
+                                {t('notifications.event-count', { count: channel.events.length })}
+                            </Typography>
+                        </Stack>
+                        <Stack direction="row" gap={6} alignItems="center">
+                            <ToggleSwitch
+                                checked={channel.enabled}
+                                onChange={(e) => handleToggle(channel, e.target.checked)}
+                            />
+                            <Button size="smaller" onClick={() => handleTest(channel)}>
+                                {t('notifications.test')}
+                            </Button>
+                            <Button size="smaller" onClick={() => onEdit(channel)}>
+                                {t('notifications.edit')}
+                            </Button>
+                            <Button size="smaller" onClick={() => handleRemove(channel.id)}>
+                                {t('notifications.remove')}
+                            </Button>
+                        </Stack>
+                    </Stack>
+                ))
+            )}
+            <Stack direction="row" justifyContent="flex-end" style={{ width: '100%' }}>
+                <Button size="smaller" onClick={onAdd}>
+                    {t('notifications.add')}
+                </Button>
+            </Stack>
+        </SettingsGroupWrapper>
+    );
+}
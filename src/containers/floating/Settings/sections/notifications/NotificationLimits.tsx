@@ -0,0 +1,73 @@
+import { useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+import { Typography } from '@app/components/elements/Typography';
+import { Stack } from '@app/components/elements/Stack';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Button } from '@app/components/elements/buttons/Button';
+import { addToast } from '@app/components/ToastStack/useToastStore';
+import { ConfigNotifications } from '@app/types/config/notifications';
+import { SettingsGroupContent, SettingsGroupTitle, SettingsGroupWrapper } from '../../components/SettingsGroup.styles';
+
+interface NotificationLimitsProps {
+    config: ConfigNotifications;
+    onChanged: () => void;
+}
+
+export default function NotificationLimits({ config, onChanged }: NotificationLimitsProps) {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const [dropPercent, setDropPercent] = useState(String(config.hashrate_drop_percent));
+    const [intervalMinutes, setIntervalMinutes] = useState(String(Math.round(config.min_interval_secs / 60)));
+
+    useEffect(() => {
+        setDropPercent(String(config.hashrate_drop_percent));
+        setIntervalMinutes(String(Math.round(config.min_interval_secs / 60)));
+    }, [config.hashrate_drop_percent, config.min_interval_secs]);
+
+    const parsedPercent = Number(dropPercent);
+    const parsedMinutes = Number(intervalMinutes);
+    const isPercentValid = Number.isInteger(parsedPercent) && parsedPercent >= 1 && parsedPercent <= 95;
+    const isIntervalValid = Number.isInteger(parsedMinutes) && parsedMinutes >= 0;
+
+    const handleSave = async () => {
+        try {
+            await invoke('set_notification_limits', {
+                hashrateDropPercent: parsedPercent,
+                minIntervalSecs: parsedMinutes * 60,
+            });
+            onChanged();
+        } catch (e) {
+            addToast({ title: t('notifications.limits.error'), text: String(e), type: 'error' });
+        }
+    };
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroupContent>
+                <SettingsGroupTitle>
+                    <Typography variant="h6">{t('notifications.limits.title')}</Typography>
+                </SettingsGroupTitle>
+                <Typography variant="p">{t('notifications.limits.description')}</Typography>
+            </SettingsGroupContent>
+            <Stack direction="row" gap={8} alignItems="flex-end" style={{ width: '100%' }}>
+                <Input
+                    name="notification-hashrate-drop"
+                    labelText={t('notifications.limits.hashrate-drop')}
+                    value={dropPercent}
+                    hasError={!isPercentValid}
+                    onChange={(e) => setDropPercent(e.target.value)}
+                />
+                <Input
+                    name="notification-min-interval"
+                    labelText={t('notifications.limits.min-interval')}
+                    value={intervalMinutes}
+                    hasError={!isIntervalValid}
+                    onChange={(e) => setIntervalMinutes(e.target.value)}
+                />
+                <Button size="smaller" onClick={handleSave} disabled={!isPercentValid || !isIntervalValid}>
+                    {t('save')}
+                </Button>
+            </Stack>
+        </SettingsGroupWrapper>
+    );
+}
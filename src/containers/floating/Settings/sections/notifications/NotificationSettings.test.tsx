@@ -0,0 +1,73 @@
+/**
+ * @vitest-environment jsdom
+ */
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { invoke } from '@tauri-apps/api/core';
+import { fireEvent, render, screen } from '@app/test/test-utils';
+import { ConfigNotifications } from '@app/types/config/notifications';
+import { NotificationSettings } from './NotificationSettings';
+
+const baseConfig: ConfigNotifications = {
+    created_at: '2026-01-01T00:00:00Z',
+    channels: [],
+    hashrate_drop_percent: 30,
+    min_interval_secs: 300,
+};
+
+function mockInvoke(config: ConfigNotifications) {
+    vi.mocked(invoke).mockImplementation(async (cmd: string) => {
+        switch (cmd) {
+            case 'get_notification_config':
+                return config;
+            default:
+                return undefined;
+        }
+    });
+}
+
+describe('NotificationSettings', () => {
+    beforeEach(() => {
+        vi.mocked(invoke).mockReset();
+    });
+
+    it('shows an empty channel list and the alert limits', async () => {
+        mockInvoke(baseConfig);
+        render(<NotificationSettings />);
+        expect(await screen.findByText('notifications.empty')).toBeInTheDocument();
+        expect(screen.getByText('notifications.limits.title')).toBeInTheDocument();
+        expect(screen.getByDisplayValue('30')).toBeInTheDocument();
+        expect(screen.getByDisplayValue('5')).toBeInTheDocument();
+    });
+
+    it('lists configured channels', async () => {
+        mockInvoke({
+            ...baseConfig,
+            channels: [
+                {
+                    id: 'c1',
+                    name: 'Ops room',
+                    enabled: true,
+                    transport: {
+                        type: 'matrix',
+                        homeserver_url: 'https://matrix.org',
+                        room_id: '!ops:matrix.org',
+                        access_token: 'token',
+                    },
+                    events: ['miner_crashed'],
+                },
+            ],
+        });
+        render(<NotificationSettings />);
+        expect(await screen.findByText('Ops room')).toBeInTheDocument();
+        expect(screen.queryByText('notifications.empty')).not.toBeInTheDocument();
+    });
+
+    it('opens the channel form with webhook fields by default', async () => {
+        mockInvoke(baseConfig);
+        render(<NotificationSettings />);
+        fireEvent.click(await screen.findByText('notifications.add'));
+        expect(screen.getByText('notifications.form.add-title')).toBeInTheDocument();
+        expect(screen.getByText('notifications.form.url')).toBeInTheDocument();
+        expect(screen.getByText('notifications.events.block_won')).toBeInTheDocument();
+    });
+});
@@ -0,0 +1,58 @@
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { ConfigNotifications, NotificationChannel } from '@app/types/config/notifications';
+import NotificationChannelList from './NotificationChannelList';
+import NotificationChannelForm from './NotificationChannelForm';
+import NotificationLimits from './NotificationLimits';
+
+const newChannel = (): NotificationChannel => ({
+    id: '',
+    name: '',
+    enabled: true,
+    transport: { type: 'webhook', url: '', secret: null },
+    events: ['miner_crashed', 'block_won'],
+    title_template: null,
+    body_template: null,
+});
+
+export const NotificationSettings = () => {
+    const [config, setConfig] = useState<ConfigNotifications>();
+    const [editing, setEditing] = useState<NotificationChannel | null>(null);
+
+    const loadConfig = useCallback(() => {
+        invoke('get_notification_config')
+            .then(setConfig)
+            .catch((e) => console.error('Failed to load notification config:', e));
+    }, []);
+
+    useEffect(() => {
+        loadConfig();
+    }, [loadConfig]);
+
+    const handleSaved = useCallback(() => {
+        setEditing(null);
+        loadConfig();
+    }, [loadConfig]);
+
+    if (!config) return null;
+
+    return (
+        <>
+            <NotificationChannelList
+                channels={config.channels}
+                onAdd={() => setEditing(newChannel())}
+                onEdit={setEditing}
+                onChanged={loadConfig}
+            />
+            {editing && (
+                <NotificationChannelForm
+                    key={editing.id || 'new'}
+                    channel={editing}
+                    onCancel={() => setEditing(null)}
+                    onSaved={handleSaved}
+                />
+            )}
+            <NotificationLimits config={config} onChanged={loadConfig} />
+        </>
+    );
+};
@@ -6,6 +6,8 @@ export const SETTINGS_TYPES = [
     'pools',
     'connections',
     'mcp',
+    'fleet',
+    'notifications',
     'experimental',
     'releaseNotes',
 ] as const;
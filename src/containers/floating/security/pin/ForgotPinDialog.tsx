@@ -1,3 +1,4 @@
+import { useState } from 'react';
 import { useTranslation } from 'react-i18next';
 
 import { setError, useSecurityStore } from '@app/store';
@@ -14,6 +15,10 @@ import { invoke } from '@tauri-apps/api/core';
 
 import { Button } from '@app/components/elements/buttons/Button.tsx';
 import { CTAWrapper } from '@app/components/security/pin/styles.ts';
+import { Typography } from '@app/components/elements/Typography.tsx';
+
+// Returned by the backend when resetting the PIN would replace a credential vault locked with the old PIN
+const CREDENTIAL_VAULT_RESET_REQUIRED = 'credential_vault_reset_required';
 
 export default function ForgotPinDialog() {
     const { t } = useTranslation('wallet');
@@ -24,6 +29,21 @@ export default function ForgotPinDialog() {
 
     const methods = useForm({ defaultValues: { seedWords: '' } });
     const { isValid } = methods.formState;
+    const [pendingSeedWords, setPendingSeedWords] = useState<string[] | null>(null);
+
+    const resetPin = async (seedWords: string[], resetCredentialVault: boolean) => {
+        try {
+            await invoke('forgot_pin', { seedWords, resetCredentialVault });
+            setPendingSeedWords(null);
+        } catch (error) {
+            if (!resetCredentialVault && error === CREDENTIAL_VAULT_RESET_REQUIRED) {
+                setPendingSeedWords(seedWords);
+                return;
+            }
+            setPendingSeedWords(null);
+            setError('Could not reset PIN: ' + error);
+        }
+    };
 
     const handleApply = async (data: { seedWords: string }) => {
         methods.reset({ seedWords: '' });
@@ -31,15 +51,12 @@ export default function ForgotPinDialog() {
             return;
         }
 
-        try {
-            await invoke('forgot_pin', { seedWords: data.seedWords.split(' ') });
-        } catch (error) {
-            setError('Could not reset PIN: ' + error);
-        }
+        await resetPin(data.seedWords.split(' '), false);
     };
 
     function handleClose() {
         methods.reset({ seedWords: '' });
+        setPendingSeedWords(null);
         setModal(null);
     }
 
@@ -50,18 +67,34 @@ export default function ForgotPinDialog() {
                     <Header>
                         <Heading>{t('security.pin.forgot')}</Heading> <CloseButton onClick={handleClose} />
                     </Header>
-                    <FormProvider {...methods}>
-                        <Form onSubmit={methods.handleSubmit(handleApply)}>
-                            <InputArea>
-                                <Edit />
-                            </InputArea>
+                    {pendingSeedWords ? (
+                        <>
+                            <Typography variant="p">{t('security.pin.vault-reset-warning')}</Typography>
                             <CTAWrapper>
-                                <Button fluid type="submit" variant="black" size="xlarge">
-                                    {t('security.pin.forgot')}
+                                <Button
+                                    fluid
+                                    variant="black"
+                                    size="xlarge"
+                                    onClick={() => resetPin(pendingSeedWords, true)}
+                                >
+                                    {t('security.pin.vault-reset-confirm')}
                                 </Button>
                             </CTAWrapper>
-                        </Form>
-                    </FormProvider>
+                        </>
+                    ) : (
+                        <FormProvider {...methods}>
+                            <Form onSubmit={methods.handleSubmit(handleApply)}>
+                                <InputArea>
+                                    <Edit />
+                                </InputArea>
+                                <CTAWrapper>
+                                    <Button fluid type="submit" variant="black" size="xlarge">
+                                        {t('security.pin.forgot')}
+                                    </Button>
+                                </CTAWrapper>
+                            </Form>
+                        </FormProvider>
+                    )}
                 </Wrapper>
             </DialogContent>
         </Dialog>
@@ -9,7 +9,7 @@ import { setCurrentExchangeMinerId } from '@app/store/useExchangeStore.ts';
 import { fetchExchangeList } from '@app/hooks/exchanges/fetchExchanges.ts';
 import { fetchExchangeContent } from '@app/hooks/exchanges/fetchExchangeContent.ts';
 
-import { ConfigCore, ShutdownMode } from '@app/types/config/core.ts';
+import { ConfigCore, OutboundConnectivity, ShutdownMode, TelemetrySink } from '@app/types/config/core.ts';
 
 import { NodeType } from '@app/types/mining/node.ts';
 import { SchedulerEvent, SchedulerEventState } from '@app/types/mining/schedule.ts';
@@ -45,6 +45,24 @@ export const setAllowTelemetry = async (allowTelemetry: boolean) => {
         store.setState((c) => ({ ...c, allow_telemetry: !allowTelemetry }));
     });
 };
+export const setTelemetrySink = async (telemetrySink: TelemetrySink) => {
+    const previous = store.getState().telemetry_sink;
+    store.setState((c) => ({ ...c, telemetry_sink: telemetrySink }));
+    await invoke('set_telemetry_sink', { telemetrySink }).catch((e) => {
+        console.error('Could not set telemetry destination', e);
+        setError(`Could not change telemetry destination: ${e}`);
+        store.setState((c) => ({ ...c, telemetry_sink: previous }));
+    });
+};
+export const setTelemetryRedactedFields = async (telemetryRedactedFields: string[]) => {
+    const previous = store.getState().telemetry_redacted_fields;
+    store.setState((c) => ({ ...c, telemetry_redacted_fields: telemetryRedactedFields }));
+    await invoke('set_telemetry_redacted_fields', { telemetryRedactedFields }).catch((e) => {
+        console.error('Could not set redacted telemetry fields', e);
+        setError('Could not change redacted telemetry fields');
+        store.setState((c) => ({ ...c, telemetry_redacted_fields: previous }));
+    });
+};
 export const setAirdropTokensInConfig = (
     airdropTokensParam: Pick<AirdropTokens, 'refreshToken' | 'token'> | undefined,
     isSuccessFn?: (airdropTokens: { token: string; refresh_token: string } | undefined) => void
@@ -147,6 +165,15 @@ export const setUseTor = async (useTor: boolean) => {
         store.setState((c) => ({ ...c, use_tor: !useTor }));
     });
 };
+export const setOutboundConnectivity = async (outboundConnectivity: OutboundConnectivity) => {
+    const previous = store.getState().outbound_connectivity;
+    store.setState((c) => ({ ...c, outbound_connectivity: outboundConnectivity }));
+    await invoke('set_outbound_connectivity', { outboundConnectivity }).catch((e) => {
+        console.error('Could not set outbound connectivity', e);
+        setError(`Could not change outbound connectivity: ${e}`);
+        store.setState((c) => ({ ...c, outbound_connectivity: previous }));
+    });
+};
 
 export const removeSchedulerEvent = async (eventId: string) => {
     invoke('remove_scheduler_event', { eventId })
@@ -99,6 +99,8 @@ const configMcpInitialState: ConfigMcp = {
     read_tier_enabled: true,
     control_tier_enabled: true,
     rate_limit_transaction: 5,
+    clients: [],
+    unix_socket_enabled: false,
 };
 
 export const useConfigMcpStore = create<ConfigMcp>()(() => ({
@@ -1,9 +1,28 @@
 import { AppModule } from '@app/store/types/setup';
 
+export interface ExternalTorConfig {
+    control_address: string;
+    socks_address: string;
+    control_password: string | null;
+}
+
 export interface TorConfig {
     control_port: number;
     use_bridges: boolean;
     bridges: string[];
+    external?: ExternalTorConfig | null;
+}
+
+export type TorTransport = 'obfs4' | 'webtunnel' | 'meek_lite' | 'snowflake';
+export type TorTransportHealth = 'unused' | 'launching' | 'running' | 'failed';
+
+export interface TorStatus {
+    bootstrap_phase: number;
+    is_bootstrapped: boolean;
+    network_liveness: boolean;
+    circuit_ok: boolean;
+    transport: TorTransport | null;
+    transport_health: TorTransportHealth;
 }
 
 export enum SystemDependencyStatus {
@@ -270,6 +270,22 @@ export type BackendStateUpdateEvent =
               error?: string;
           };
       }
+    | {
+          event_type: 'InvoicePaid';
+          payload: {
+              id: string;
+              payment_id: string;
+              amount: number;
+              description?: string;
+              uri: string;
+              required_confirmations: number;
+              created_at: number;
+              status: 'open' | 'paid' | 'cancelled';
+              paid_tx_id?: string;
+              confirmations: number;
+              paid_at_height?: number;
+          };
+      }
     | {
           event_type: 'McpAuditEntry';
           payload: {
@@ -24,6 +24,45 @@ export interface ConfigCore {
     use_tor: boolean;
     shutdown_mode: ShutdownMode;
     node_data_directory?: string;
+    binary_overrides?: Record<string, BinaryOverride>;
+    process_resource_limits?: Record<string, ResourceLimits>;
+    service_ports?: Record<string, PortAssignment>;
+    outbound_connectivity?: OutboundConnectivity;
+    telemetry_sink?: TelemetrySink;
+    telemetry_redacted_fields?: string[];
+    telemetry_spool_max_bytes?: number;
+}
+export type BinaryOverride = { type: 'local_executable'; path: string } | { type: 'mirror'; location: string };
+export interface ResourceLimits {
+    cpu_max_percent?: number | null;
+    cpus?: number[] | null;
+    memory_max_bytes?: number | null;
+    io_weight?: number | null;
+    nice?: number | null;
+}
+export interface PortAssignment {
+    port: number;
+    pinned?: boolean;
+}
+export type ProxyKind = 'socks5' | 'http';
+export interface ProxySettings {
+    kind: ProxyKind;
+    host: string;
+    port: number;
+    username?: string | null;
+    password?: string | null;
+}
+export type OutboundConnectivity = { mode: 'direct' } | { mode: 'tor' } | ({ mode: 'proxy' } & ProxySettings);
+export type TelemetrySink = { mode: 'vendor' } | { mode: 'http'; url: string } | { mode: 'file'; path: string };
+export interface TelemetryFieldPreview {
+    field: string;
+    value: unknown;
+    redacted: boolean;
+}
+export interface TelemetryInspection {
+    sink: TelemetrySink;
+    spool: { records: number; bytes: number; max_bytes: number };
+    fields: TelemetryFieldPreview[];
 }
 export enum ShutdownMode {
     Direct = 'Direct',
@@ -0,0 +1,78 @@
+export type FleetRole = 'disabled' | 'rig' | 'controller';
+
+export interface FleetRig {
+    rig_id: string;
+    name: string;
+    pub_key: string;
+}
+
+export interface ConfigFleet {
+    created_at: string;
+    role: FleetRole;
+    controller_url?: string | null;
+    controller_pub_key?: string | null;
+    listen_address: string;
+    rigs: FleetRig[];
+}
+
+export interface RigStatus {
+    app_version: string;
+    mining: {
+        mode: string;
+        cpu_mining: boolean;
+        cpu_hash_rate: number;
+        cpu_pool: string;
+        gpu_mining: boolean;
+        gpu_hash_rate: number;
+        gpu_pool: string;
+    };
+    node: {
+        block_height: number;
+        is_synced: boolean;
+        num_connections: number;
+    };
+    wallet: {
+        available_balance?: number | null;
+    };
+    hardware: {
+        name: string;
+        usage_percentage?: number | null;
+        temperature?: number | null;
+    }[];
+}
+
+export interface FleetRigView {
+    rig_id: string;
+    name: string;
+    connected_at?: { secs_since_epoch: number } | null;
+    last_status_at?: { secs_since_epoch: number } | null;
+    status?: RigStatus | null;
+}
+
+export type FleetCommand =
+    | { action: 'set_mining_mode'; mode: string }
+    | { action: 'start_mining'; cpu?: boolean | null; gpu?: boolean | null }
+    | { action: 'stop_mining'; cpu?: boolean | null; gpu?: boolean | null }
+    | { action: 'change_cpu_pool'; pool: string }
+    | { action: 'change_gpu_pool'; pool: string }
+    | {
+          action: 'schedule_mining';
+          event_id: string;
+          mining_mode: string;
+          start_hour: number;
+          start_minute?: number | null;
+          start_period: 'AM' | 'PM';
+          end_hour: number;
+          end_minute?: number | null;
+          end_period: 'AM' | 'PM';
+      }
+    | { action: 'cancel_schedule'; event_id: string };
+
+export interface FleetAuditEntry {
+    timestamp: { secs_since_epoch: number };
+    rig_id: string;
+    command_id: string;
+    command: FleetCommand;
+    status: 'Sent' | 'Rejected' | 'Succeeded' | 'Failed';
+    details?: string | null;
+}
@@ -0,0 +1,46 @@
+export const NOTIFICATION_EVENT_TYPES = [
+    'miner_crashed',
+    'pool_failover',
+    'hashrate_dropped',
+    'node_out_of_sync',
+    'incoming_transaction',
+    'block_won',
+    'pin_lockout',
+] as const;
+export type NotificationEventType = (typeof NOTIFICATION_EVENT_TYPES)[number];
+
+export type SmtpSecurity = 'start_tls' | 'tls' | 'none';
+
+export type NotificationTransport =
+    | { type: 'webhook'; url: string; secret?: string | null }
+    | {
+          type: 'email';
+          host: string;
+          port: number;
+          security: SmtpSecurity;
+          username?: string | null;
+          password?: string | null;
+          from: string;
+          to: string[];
+      }
+    | { type: 'ntfy'; server_url: string; topic: string; token?: string | null }
+    | { type: 'matrix'; homeserver_url: string; room_id: string; access_token: string };
+
+export type NotificationTransportType = NotificationTransport['type'];
+
+export interface NotificationChannel {
+    id: string;
+    name: string;
+    enabled: boolean;
+    transport: NotificationTransport;
+    events: NotificationEventType[];
+    title_template?: string | null;
+    body_template?: string | null;
+}
+
+export interface ConfigNotifications {
+    created_at: string;
+    channels: NotificationChannel[];
+    hashrate_drop_percent: number;
+    min_interval_secs: number;
+}
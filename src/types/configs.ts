@@ -140,4 +140,38 @@ export interface ConfigMcp {
     read_tier_enabled: boolean;
     control_tier_enabled: boolean;
     rate_limit_transaction: number;
+    clients: McpClient[];
+    unix_socket_enabled: boolean;
+}
+
+export interface McpStdioBridge {
+    socket_path: string;
+    command: string;
+    args: string[];
+}
+
+export interface McpClient {
+    id: string;
+    name: string;
+    token_redacted: string;
+    created_at: { secs_since_epoch: number; nanos_since_epoch: number };
+    expires_at?: { secs_since_epoch: number; nanos_since_epoch: number } | null;
+    read_tier_enabled: boolean;
+    control_tier_enabled: boolean;
+    transactions_enabled: boolean;
+    allowed_tools: string[];
+    max_transaction_amount?: number | null;
+    daily_spend_limit?: number | null;
+    spent_last_day: number;
+}
+
+export interface McpClientSettings {
+    name: string;
+    read_tier_enabled: boolean;
+    control_tier_enabled: boolean;
+    transactions_enabled: boolean;
+    allowed_tools: string[];
+    max_transaction_amount: number | null;
+    daily_spend_limit: number | null;
+    expiry_days: number | null;
 }
@@ -1,6 +1,7 @@
 import {
     ApplicationsVersions,
     TorConfig,
+    TorStatus,
     TransactionInfo,
     BridgeEnvs,
     TariAddressVariants,
@@ -13,6 +14,9 @@ import { BasePoolData, ConfigBackendInMemory, PauseOnBatteryModeState } from './
 import { ExchangeMiner } from './exchange';
 import { ActiveTapplet } from './tapplets/tapplet.types';
 import { SchedulerEventTiming, SchedulerEventType } from './mining/schedule.ts';
+import { OutboundConnectivity, TelemetryInspection, TelemetrySink } from './config/core.ts';
+import { ConfigFleet, FleetAuditEntry, FleetCommand, FleetRigView, FleetRole } from './config/fleet.ts';
+import { ConfigNotifications, NotificationChannel } from './config/notifications.ts';
 
 declare module '@tauri-apps/api/core' {
     function invoke(
@@ -35,6 +39,12 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'stop_cpu_mining'): Promise<void>;
     function invoke(param: 'stop_gpu_mining'): Promise<void>;
     function invoke(param: 'set_allow_telemetry', payload: { allow_telemetry: boolean }): Promise<void>;
+    function invoke(param: 'set_telemetry_sink', payload: { telemetrySink: TelemetrySink }): Promise<void>;
+    function invoke(
+        param: 'set_telemetry_redacted_fields',
+        payload: { telemetryRedactedFields: string[] }
+    ): Promise<void>;
+    function invoke(param: 'inspect_telemetry_data'): Promise<TelemetryInspection>;
     function invoke(param: 'select_mining_mode', payload: { mode: string }): Promise<void>;
     function invoke(
         param: 'update_custom_mining_mode',
@@ -56,12 +66,17 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'exit_application'): Promise<string>;
     function invoke(param: 'restart_application'): Promise<string>;
     function invoke(param: 'set_use_tor', payload: { useTor: boolean }): Promise<void>;
+    function invoke(
+        param: 'set_outbound_connectivity',
+        payload: { outboundConnectivity: OutboundConnectivity }
+    ): Promise<void>;
     function invoke(
         param: 'get_transactions',
         payload: { offset?: number; limit?: number; statusBitflag?: number }
     ): Promise<TransactionInfo[]>;
     function invoke(param: 'import_seed_words', payload: { seedWords: string[] }): Promise<void>;
     function invoke(param: 'get_tor_config'): Promise<TorConfig>;
+    function invoke(param: 'get_tor_status'): Promise<TorStatus>;
     function invoke(param: 'set_tor_config', payload: { config: TorConfig }): Promise<TorConfig>;
     function invoke(param: 'fetch_tor_bridges'): Promise<string[]>;
     function invoke(param: 'start_mining_status'): Promise<string[]>;
@@ -116,7 +131,10 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'refresh_wallet_history'): Promise<void>;
     function invoke(param: 'get_base_node_status'): Promise<BaseNodeStatus>;
     function invoke(param: 'create_pin'): Promise<void>;
-    function invoke(param: 'forgot_pin', payload: { seedWords: string[] }): Promise<void>;
+    function invoke(
+        param: 'forgot_pin',
+        payload: { seedWords: string[]; resetCredentialVault?: boolean }
+    ): Promise<void>;
     function invoke(param: 'toggle_cpu_pool_mining', payload: { enabled: boolean }): Promise<void>;
     function invoke(param: 'toggle_gpu_pool_mining', payload: { enabled: boolean }): Promise<void>;
     function invoke(
@@ -152,4 +170,30 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'pause_scheduler_event', payload: { eventId: string }): Promise<void>;
     function invoke(param: 'resume_scheduler_event', payload: { eventId: string }): Promise<void>;
     function invoke(param: 'set_custom_node_directory', payload: { path: string }): Promise<void>;
+    // Fleet commands
+    function invoke(param: 'get_fleet_config'): Promise<ConfigFleet>;
+    function invoke(param: 'get_fleet_identity'): Promise<string>;
+    function invoke(param: 'set_fleet_role', payload: { role: FleetRole }): Promise<void>;
+    function invoke(
+        param: 'set_fleet_controller',
+        payload: { controllerUrl: string; controllerPubKey: string }
+    ): Promise<void>;
+    function invoke(param: 'set_fleet_listen_address', payload: { listenAddress: string }): Promise<void>;
+    function invoke(param: 'enroll_fleet_rig', payload: { rigId: string; name: string; pubKey: string }): Promise<void>;
+    function invoke(param: 'remove_fleet_rig', payload: { rigId: string }): Promise<void>;
+    function invoke(param: 'get_fleet_rigs'): Promise<FleetRigView[]>;
+    function invoke(param: 'send_fleet_command', payload: { rigId: string; command: FleetCommand }): Promise<string>;
+    function invoke(param: 'get_fleet_audit_log', payload: { count?: number }): Promise<FleetAuditEntry[]>;
+    // Notification channels
+    function invoke(param: 'get_notification_config'): Promise<ConfigNotifications>;
+    function invoke(
+        param: 'save_notification_channel',
+        payload: { channel: NotificationChannel }
+    ): Promise<NotificationChannel>;
+    function invoke(param: 'remove_notification_channel', payload: { id: string }): Promise<void>;
+    function invoke(
+        param: 'set_notification_limits',
+        payload: { hashrateDropPercent: number; minIntervalSecs: number }
+    ): Promise<void>;
+    function invoke(param: 'test_notification_channel', payload: { channel: NotificationChannel }): Promise<void>;
 }